@@ -14,6 +14,7 @@ pub struct Attribute {
 
 impl Attribute {
     pub const NAME_EXTERN: &'static str = "uitheems";
+    pub const NAME_TEST: &'static str = "test";
 }
 
 #[derive(Debug, Clone)]
@@ -1,16 +1,34 @@
 // Copyright (C) 2023 - 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::fmt::{Debug, Display, Write};
+use std::{fmt::{Debug, Display, Write}, sync::Arc};
 
-use crate::{BabString, FileRange, Ranged, Type};
+use crate::{BabString, FileRange, FunctionStatement, MatchExpression, Ranged, Type};
 
 #[derive(Clone, Debug)]
 pub enum PrimaryExpression {
+    /// The elements of an array, e.g. `[1, 2, 3]`.
+    ArrayLiteral(Vec<Ranged<Expression>>),
     Boolean(bool),
     CharacterLiteral(char),
+    Closure(ClosureExpression),
     StringLiteral(BabString),
     IntegerLiteral(i64),
+    FloatLiteral(f64),
+    Match(MatchExpression),
+
+    /// `Iets(waarde)`, a `Misschien` that contains a value.
+    OptionalSome(Box<Ranged<Expression>>),
+
+    /// `Niets`, a `Misschien` without a value.
+    OptionalNone,
+
+    /// `Goed(waarde)`, a `Resultaat` of an operation that succeeded.
+    ResultOk(Box<Ranged<Expression>>),
+
+    /// `Fout(fout)`, a `Resultaat` of an operation that failed.
+    ResultError(Box<Ranged<Expression>>),
+
     Reference(Ranged<BabString>),
     ReferenceThis,
     StructureInstantiation(StructureInstantiationExpression),
@@ -18,27 +36,78 @@ pub enum PrimaryExpression {
         parts: Vec<TemplateStringExpressionPart>,
     },
     Parenthesized(Box<Ranged<Expression>>),
+
+    /// Multiple values grouped together, e.g. `(quotiënt, rest)`.
+    Tuple(Vec<Ranged<Expression>>),
+
     SizedArrayInitializer {
         typ: Ranged<Type>,
         size: Box<Ranged<Expression>>,
     },
+
+    /// A range of numbers as a value, e.g. `reeks(0, 10, stap: 2)`.
+    Range(RangeExpression),
 }
 
 impl Display for PrimaryExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            PrimaryExpression::ArrayLiteral(elements) => {
+                f.write_char('[')?;
+
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+                    Display::fmt(element.value(), f)?;
+                }
+
+                f.write_char(']')
+            }
             PrimaryExpression::Boolean(b) => f.write_str(if *b { "waar" } else { "onwaar" }),
             PrimaryExpression::CharacterLiteral(c) => {
                 f.write_char('\'')?;
                 f.write_char(*c)?;
                 f.write_char('\'')
             }
+            PrimaryExpression::Closure(closure) => Display::fmt(closure, f),
             PrimaryExpression::StringLiteral(bab_string) => {
                 f.write_char('"')?;
                 f.write_str(bab_string.as_str())?;
                 f.write_char('"')
             }
             PrimaryExpression::IntegerLiteral(i) => f.write_fmt(format_args!("{i}")),
+            PrimaryExpression::FloatLiteral(float) => f.write_str(&format!("{float:?}").replace('.', ",")),
+            PrimaryExpression::Match(expression) => {
+                f.write_str("kies ")?;
+                Display::fmt(expression.scrutinee.value(), f)?;
+                f.write_str(" { ")?;
+
+                for arm in &expression.arms {
+                    Display::fmt(arm.pattern.value(), f)?;
+                    f.write_str(" => ")?;
+                    Display::fmt(arm.expression.value(), f)?;
+                    f.write_str(", ")?;
+                }
+
+                f.write_char('}')
+            }
+            PrimaryExpression::OptionalSome(expr) => {
+                f.write_str("Iets(")?;
+                Display::fmt(expr.value(), f)?;
+                f.write_char(')')
+            }
+            PrimaryExpression::OptionalNone => f.write_str("Niets"),
+            PrimaryExpression::ResultOk(expr) => {
+                f.write_str("Goed(")?;
+                Display::fmt(expr.value(), f)?;
+                f.write_char(')')
+            }
+            PrimaryExpression::ResultError(expr) => {
+                f.write_str("Fout(")?;
+                Display::fmt(expr.value(), f)?;
+                f.write_char(')')
+            }
             PrimaryExpression::Reference(bab_string) => {
                 f.write_str(bab_string.as_str())
             }
@@ -50,13 +119,58 @@ impl Display for PrimaryExpression {
                 Display::fmt(expr.value(), f)?;
                 f.write_char(')')
             }
+            PrimaryExpression::Tuple(elements) => {
+                f.write_char('(')?;
+
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+                    Display::fmt(element.value(), f)?;
+                }
+
+                f.write_char(')')
+            }
             PrimaryExpression::SizedArrayInitializer { typ, size } => {
                 f.write_fmt(format_args!("nieuw {}[{}]", typ.value(), size.value()))
             }
+            PrimaryExpression::Range(range) => Display::fmt(range, f),
         }
     }
 }
 
+/// An anonymous `werkwijze`, e.g. `werkwijze(x: g32) -> g32 { bekeer x * 2; }`.
+#[derive(Debug, Clone)]
+pub struct ClosureExpression {
+    pub keyword: FileRange,
+
+    /// The name of this function is the `werkwijze` keyword, since the
+    /// function doesn't have a name of its own.
+    pub function: Arc<FunctionStatement>,
+}
+
+impl Display for ClosureExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("werkwijze(")?;
+
+        for (idx, param) in self.function.parameters.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_fmt(format_args!("{}: {}", param.name.value(), param.ty.value()))?;
+        }
+
+        f.write_char(')')?;
+
+        if let Some(return_type) = &self.function.return_type {
+            f.write_fmt(format_args!(" -> {}", return_type.value()))?;
+        }
+
+        f.write_str(" { .. }")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StructureInstantiationExpression {
     pub name: Ranged<BabString>,
@@ -96,6 +210,31 @@ impl Expression {
             _ => None,
         }
     }
+
+    /// The value of an integer literal, possibly negated, parenthesized or
+    /// combined with other literals, like `-(127 + 1)`. Such an expression
+    /// takes the integer type of the place it is used.
+    #[must_use]
+    pub fn as_integer_literal(&self) -> Option<i128> {
+        match self {
+            Self::Primary(PrimaryExpression::IntegerLiteral(value)) => Some(*value as i128),
+            Self::Primary(PrimaryExpression::Parenthesized(expression)) => expression.as_integer_literal(),
+            Self::Unary(UnaryExpression { kind, rhs }) if *kind.value() == UnaryExpressionKind::Negate => {
+                rhs.as_integer_literal().map(|value| -value)
+            }
+            Self::BiExpression(BiExpression { operator, lhs, rhs }) => {
+                let lhs = lhs.as_integer_literal()?;
+                let rhs = rhs.as_integer_literal()?;
+                match operator.value() {
+                    BiOperator::Math(MathOperator::Add) => lhs.checked_add(rhs),
+                    BiOperator::Math(MathOperator::Subtract) => lhs.checked_sub(rhs),
+                    BiOperator::Math(MathOperator::Multiply) => lhs.checked_mul(rhs),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Display for Expression {
@@ -140,6 +279,8 @@ impl Display for PostfixExpression {
                 Display::fmt(expr.value(), f)?;
                 f.write_char(']')
             }
+
+            PostfixExpressionKind::Propagate => f.write_char('?'),
         }
     }
 }
@@ -150,6 +291,10 @@ pub enum PostfixExpressionKind {
     Member(Ranged<BabString>),
     MethodCall(MethodCallExpression),
     Subscript(Box<Ranged<Expression>>),
+
+    /// `waarde?`, which unpacks a `Goed`, or returns a `Fout` early from the
+    /// enclosing `werkwijze`.
+    Propagate,
 }
 
 #[derive(Debug, Clone)]
@@ -163,6 +308,7 @@ impl Display for UnaryExpression {
         match self.kind.value() {
             UnaryExpressionKind::AddressOf => f.write_char('&')?,
             UnaryExpressionKind::Negate => f.write_char('-')?,
+            UnaryExpressionKind::Not => f.write_char('!')?,
         }
 
         Display::fmt(self.rhs.value(), f)
@@ -173,6 +319,9 @@ impl Display for UnaryExpression {
 pub enum UnaryExpressionKind {
     AddressOf,
     Negate,
+
+    /// `!waarde`, logically inverts a boolean.
+    Not,
 }
 
 #[derive(Clone, Debug)]
@@ -313,6 +462,29 @@ pub struct RangeExpression {
     /// Start, inclusive
     pub start: Box<Ranged<Expression>>,
 
-    /// End, exclusive
+    /// End, exclusive unless `is_inclusive` is set
     pub end: Box<Ranged<Expression>>,
+
+    /// The amount each value differs from the previous one, e.g. `stap: -2`.
+    /// When absent, the range counts upwards by one.
+    pub step: Option<Box<Ranged<Expression>>>,
+
+    /// Whether or not the end is part of the range, e.g. `totEnMet: waar`.
+    pub is_inclusive: bool,
+}
+
+impl Display for RangeExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("reeks({}, {}", self.start.value(), self.end.value()))?;
+
+        if let Some(step) = &self.step {
+            f.write_fmt(format_args!(", stap: {}", step.value()))?;
+        }
+
+        if self.is_inclusive {
+            f.write_str(", totEnMet: waar")?;
+        }
+
+        f.write_char(')')
+    }
 }
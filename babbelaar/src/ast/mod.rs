@@ -3,7 +3,9 @@
 
 mod attribute;
 mod expression;
+mod module;
 mod parser;
+mod pattern;
 mod statement;
 mod structure;
 mod tree;
@@ -18,6 +20,7 @@ pub use self::{
     expression::{
         BiExpression,
         BiOperator,
+        ClosureExpression,
         Comparison,
         Expression,
         FieldInstantiation,
@@ -33,13 +36,30 @@ pub use self::{
         UnaryExpression,
         UnaryExpressionKind,
     },
+    module::{
+        qualify_name,
+        ImportStatement,
+        ModulePath,
+        ModuleStatement,
+    },
     parser::{
         Parser,
         ParseDiagnostic,
         ParseError,
     },
+    pattern::{
+        FieldPattern,
+        MatchArm,
+        MatchExpression,
+        Pattern,
+    },
     statement::{
         AssignStatement,
+        BreakStatement,
+        ConstantStatement,
+        ContinueStatement,
+        ElseStatement,
+        ElseStatementKind,
         ExtensionStatement,
         ForIterableKind,
         ForStatement,
@@ -48,16 +68,22 @@ pub use self::{
         ReturnStatement,
         Statement,
         StatementKind,
-        VariableStatement
+        VariableName,
+        VariableStatement,
+        WhileStatement,
     },
     structure::{
+        Enumeration,
+        EnumerationVariant,
         Field,
         InterfaceStatement,
         Method,
         Structure,
+        Visibility,
     },
     tree::ParseTree,
     type_::{
+        GenericTypeDeclaration,
         InterfaceSpecifier,
         Parameter,
         Type,
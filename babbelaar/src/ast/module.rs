@@ -0,0 +1,59 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{BabString, FileRange, Ranged};
+
+/// `module pad.naar.module;`
+#[derive(Debug, Clone)]
+pub struct ModuleStatement {
+    pub keyword: FileRange,
+    pub path: ModulePath,
+}
+
+/// `gebruik pad.naar.module;`
+#[derive(Debug, Clone)]
+pub struct ImportStatement {
+    pub keyword: FileRange,
+    pub path: ModulePath,
+}
+
+/// The dotted path of a module, e.g. `pad.naar.module`.
+#[derive(Debug, Clone)]
+pub struct ModulePath {
+    pub segments: Vec<Ranged<BabString>>,
+}
+
+impl ModulePath {
+    /// The full name of the module, e.g. `pad.naar.module`.
+    #[must_use]
+    pub fn name(&self) -> BabString {
+        let segments: Vec<&str> = self.segments.iter().map(|x| x.value().as_str()).collect();
+        BabString::new(segments.join("."))
+    }
+
+    /// The name under which an imported module is referred to, which is the
+    /// last segment of the path, e.g. `module` for `pad.naar.module`.
+    #[must_use]
+    pub fn alias(&self) -> &Ranged<BabString> {
+        self.segments.last().expect("a module path always has at least one segment")
+    }
+
+    #[must_use]
+    pub fn range(&self) -> FileRange {
+        let start = self.segments.first().map(|x| x.range().start()).unwrap_or_default();
+        let end = self.segments.last().map(|x| x.range().end()).unwrap_or_default();
+        FileRange::new(start, end)
+    }
+}
+
+/// Prefixes the `name` of a top-level declaration with the `module` it is
+/// declared in, which gives the name it is known by to other modules. Files
+/// without a module declaration live in the root namespace, so their names
+/// stay as-is.
+#[must_use]
+pub fn qualify_name(module: Option<&BabString>, name: &BabString) -> BabString {
+    match module {
+        Some(module) => BabString::new(format!("{module}.{name}")),
+        None => name.clone(),
+    }
+}
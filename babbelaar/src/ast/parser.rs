@@ -1,7 +1,7 @@
 // Copyright (C) 2023 - 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use log::error;
 use strum::AsRefStr;
@@ -19,6 +19,9 @@ pub struct Parser<'tokens> {
     pub token_end: FileLocation,
     diagnostics: Vec<ParseDiagnostic>,
     end_of_file_token: Token,
+
+    /// The modules imported using `gebruik`, by their alias.
+    imports: HashMap<BabString, BabString>,
 }
 
 impl<'tokens> Parser<'tokens> {
@@ -36,16 +39,29 @@ impl<'tokens> Parser<'tokens> {
                 kind: TokenKind::Identifier(BabString::empty()),
                 begin: end,
                 end,
-            }
+                documentation: None,
+            },
+            imports: HashMap::new(),
         }
     }
 
     pub fn parse_tree(&mut self) -> ParseTree {
         let mut tree = ParseTree::new(self.path.clone());
+        self.parse_module_header(&mut tree);
 
         let mut cursor = self.cursor;
         while !self.is_at_end() {
             let diag_count = self.diagnostics.len();
+
+            if let Some(keyword @ (Keyword::Module | Keyword::Gebruik)) = self.peek_keyword() {
+                let token = self.consume_token().expect("we just peeked a keyword");
+                self.emit_diagnostic(ParseDiagnostic::ModuleHeaderNotAtTop { token, keyword });
+                _ = self.parse_module_path(keyword);
+                self.expect_semicolon_after_statement();
+                cursor = self.cursor;
+                continue;
+            }
+
             match self.parse_statement() {
                 Ok(statement) => tree.push(statement),
                 Err(ParseError::EndOfFile) => break,
@@ -62,6 +78,77 @@ impl<'tokens> Parser<'tokens> {
         tree
     }
 
+    /// Parses the `module` declaration and `gebruik` imports at the top of
+    /// the file.
+    fn parse_module_header(&mut self, tree: &mut ParseTree) {
+        while let Some(keyword @ (Keyword::Module | Keyword::Gebruik)) = self.peek_keyword() {
+            let token = self.consume_token().expect("we just peeked a keyword");
+            let path = self.parse_module_path(keyword);
+            self.expect_semicolon_after_statement();
+
+            if path.segments.iter().any(|x| x.value().is_empty()) {
+                continue;
+            }
+
+            if keyword == Keyword::Module {
+                if tree.module().is_some() {
+                    self.emit_diagnostic(ParseDiagnostic::DuplicateModuleDeclaration { token });
+                    continue;
+                }
+
+                tree.set_module(ModuleStatement { keyword: token.range(), path });
+            } else {
+                self.imports.insert(path.alias().value().clone(), path.name());
+                tree.push_import(ImportStatement { keyword: token.range(), path });
+            }
+        }
+    }
+
+    fn parse_module_path(&mut self, keyword: Keyword) -> ModulePath {
+        let mut segments = Vec::new();
+
+        let Ok(first) = self.consume_identifier("Modulenaam", BabString::new(keyword.as_ref())) else {
+            return ModulePath { segments };
+        };
+
+        segments.push(first);
+
+        while self.peek_punctuator() == Some(Punctuator::Period) {
+            _ = self.consume_token();
+
+            match self.consume_identifier("Modulenaam", BabString::new_static(".")) {
+                Ok(segment) => segments.push(segment),
+                Err(..) => break,
+            }
+        }
+
+        ModulePath { segments }
+    }
+
+    /// Turns `alias.naam` into the qualified name `pad.naar.module.naam` if
+    /// `alias` refers to a module imported using `gebruik`.
+    fn parse_qualified_name(&mut self, name: Ranged<BabString>) -> Ranged<BabString> {
+        let Some(module) = self.imports.get(name.value()).cloned() else {
+            return name;
+        };
+
+        if self.peek_punctuator() != Some(Punctuator::Period) {
+            return name;
+        }
+
+        let Some(Token { kind: TokenKind::Identifier(member), end, .. }) = self.tokens.get(self.cursor + 1).cloned() else {
+            return name;
+        };
+
+        _ = self.consume_token();
+        _ = self.consume_token();
+
+        Ranged::new(
+            FileRange::new(name.range().start(), end),
+            BabString::new(format!("{module}.{member}")),
+        )
+    }
+
     #[must_use]
     pub fn diagnostics(&self) -> &[ParseDiagnostic] {
         &self.diagnostics
@@ -74,15 +161,20 @@ impl<'tokens> Parser<'tokens> {
 
     pub fn parse_statement(&mut self) -> Result<Statement, ParseError> {
         let start = self.peek_token()?.begin;
+        let documentation = self.peek_token()?.documentation.clone();
         let attributes = self.parse_attribute_list();
 
         let first_token = self.peek_token()?;
-        let kind = match first_token.kind {
+        let mut kind = match first_token.kind {
             TokenKind::Keyword(Keyword::Als) => {
                 _ = self.consume_token().ok();
                 StatementKind::If(self.parse_if_statement()?)
             }
 
+            TokenKind::Keyword(Keyword::Ga) => {
+                StatementKind::Continue(self.parse_continue_statement()?)
+            }
+
             TokenKind::Keyword(Keyword::Bekeer) => {
                 let range = first_token.range();
                 _ = self.consume_token().ok();
@@ -94,16 +186,51 @@ impl<'tokens> Parser<'tokens> {
                 StatementKind::Interface(self.parse_interface_statement()?)
             }
 
+            TokenKind::Keyword(Keyword::Opsomming) => {
+                _ = self.consume_token().ok();
+                StatementKind::Enumeration(self.parse_enumeration_statement()?)
+            }
+
             TokenKind::Keyword(Keyword::Werkwijze) => {
                 _ = self.consume_token().ok();
                 StatementKind::Function(self.parse_function(FunctionParsingContext::Function)?)
             }
 
+            TokenKind::Keyword(Keyword::Publiek | Keyword::Intern) => {
+                let visibility = self.parse_visibility();
+
+                let token = self.peek_token()?;
+                if token.kind == TokenKind::Keyword(Keyword::Werkwijze) {
+                    _ = self.consume_token()?;
+                } else {
+                    let token = token.clone();
+                    let visibility = visibility.as_ref().map(|x| x.keyword()).unwrap_or_default();
+                    self.emit_diagnostic(ParseDiagnostic::ExpectedWerkwijzeAfterVisibility { token, visibility });
+                }
+
+                let mut function = self.parse_function(FunctionParsingContext::Function)?;
+                function.visibility = visibility;
+                StatementKind::Function(function)
+            }
+
             TokenKind::Keyword(Keyword::Stel) => {
                 _ = self.consume_token().ok();
                 StatementKind::Variable(self.parse_variable_statement()?)
             }
 
+            TokenKind::Keyword(Keyword::Vast) => {
+                let keyword = self.consume_token()?.range();
+                let constant = self.parse_constant_statement(keyword)?;
+                self.expect_semicolon_after_statement();
+                StatementKind::Constant(constant)
+            }
+
+            TokenKind::Keyword(Keyword::Stop) => {
+                let keyword = self.consume_token()?.range();
+                self.expect_semicolon_after_statement();
+                StatementKind::Break(BreakStatement { keyword })
+            }
+
             TokenKind::Keyword(Keyword::Structuur) => {
                 _ = self.consume_token().ok();
                 StatementKind::Structure(self.parse_structure_statement()?)
@@ -118,6 +245,10 @@ impl<'tokens> Parser<'tokens> {
                 StatementKind::For(self.parse_for_statement()?)
             }
 
+            TokenKind::Keyword(Keyword::Zolang) => {
+                StatementKind::While(self.parse_while_statement()?)
+            }
+
             _ => {
                 let reset = self.cursor;
 
@@ -129,13 +260,26 @@ impl<'tokens> Parser<'tokens> {
                         self.cursor = reset;
 
                         let expression = self.parse_expression()?;
-                        self.expect_semicolon_after_statement();
+
+                        // A `kies` ends with a curly bracket, so the semicolon is optional.
+                        let is_match = matches!(expression.value(), Expression::Primary(PrimaryExpression::Match(..)));
+                        if !is_match || self.peek_punctuator() == Some(Punctuator::Semicolon) {
+                            self.expect_semicolon_after_statement();
+                        }
+
                         StatementKind::Expression(expression)
                     }
                 }
             }
         };
 
+        match &mut kind {
+            StatementKind::Function(function) => function.documentation = documentation,
+            StatementKind::Interface(interface) => interface.documentation = documentation,
+            StatementKind::Structure(structure) => structure.documentation = documentation,
+            _ => (),
+        }
+
         let range = FileRange::new(start, self.token_end);
         Ok(Statement {
             range,
@@ -153,9 +297,15 @@ impl<'tokens> Parser<'tokens> {
             return Ok(None);
         };
 
-        if equals.kind != TokenKind::Punctuator(Punctuator::Assignment) {
-            return Ok(None);
-        }
+        let operator = match equals.kind {
+            TokenKind::Punctuator(Punctuator::Assignment) => None,
+            TokenKind::Punctuator(Punctuator::PlusEquals) => Some(MathOperator::Add),
+            TokenKind::Punctuator(Punctuator::MinusEquals) => Some(MathOperator::Subtract),
+            TokenKind::Punctuator(Punctuator::AsteriskEquals) => Some(MathOperator::Multiply),
+            TokenKind::Punctuator(Punctuator::SolidusEquals) => Some(MathOperator::Divide),
+            TokenKind::Punctuator(Punctuator::PercentageEquals) => Some(MathOperator::Modulo),
+            _ => return Ok(None),
+        };
 
         let source = self.parse_expression()?;
         self.expect_semicolon_after_statement();
@@ -163,6 +313,7 @@ impl<'tokens> Parser<'tokens> {
         Ok(Some(AssignStatement {
             range: FileRange::new(destination.range().start(), source.range().end()),
             equals_sign: equals.range(),
+            operator,
             destination,
             source,
         }))
@@ -213,6 +364,7 @@ impl<'tokens> Parser<'tokens> {
             }
 
             let peeked_token = self.peek_token()?;
+            let documentation = peeked_token.documentation.clone();
             match peeked_token.kind {
                 TokenKind::Keyword(Keyword::Veld) => {
                     _ = self.consume_token();
@@ -222,13 +374,15 @@ impl<'tokens> Parser<'tokens> {
                 TokenKind::Keyword(Keyword::Werkwijze) => {
                     let start = self.consume_token()?.begin;
 
-                    let function = self.parse_function(FunctionParsingContext::Method)?;
+                    let mut function = self.parse_function(FunctionParsingContext::Method)?;
+                    function.documentation = documentation;
 
                     let range = FileRange::new(start, self.token_end);
 
                     extension.methods.push(Method {
                         range,
                         function,
+                        is_static: false,
                     });
                 }
 
@@ -263,32 +417,31 @@ impl<'tokens> Parser<'tokens> {
         };
         let name = Ranged::new(name_range, name);
 
-        self.expect_left_paren("werkwijzenaam")?;
+        self.parse_function_after_name(name, ctx)
+    }
 
-        let mut parameters = Vec::new();
-        while self.peek_punctuator() != Some(Punctuator::RightParenthesis) {
-            match self.parse_parameter() {
-                Ok(parameter) => parameters.push(parameter),
-                Err(error) => self.handle_error(error),
-            }
+    /// Parses an anonymous `werkwijze` in expression position, e.g.
+    /// `werkwijze(x: g32) -> g32 { bekeer x * 2; }`.
+    fn parse_closure(&mut self, keyword: FileRange) -> ParseResult<ClosureExpression> {
+        let name = Ranged::new(keyword, BabString::new_static("werkwijze"));
+        let function = self.parse_function_after_name(name, FunctionParsingContext::Closure)?;
 
-            match self.peek_punctuator() {
-                Some(Punctuator::Comma) => {
-                    _ = self.consume_token()?;
-                    continue;
-                }
+        Ok(ClosureExpression {
+            keyword,
+            function: Arc::new(function),
+        })
+    }
 
-                Some(Punctuator::RightParenthesis) => break,
+    fn parse_function_after_name(&mut self, name: Ranged<BabString>, ctx: FunctionParsingContext) -> Result<FunctionStatement, ParseError> {
+        let name_range = name.range();
 
-                _ => {
-                    self.emit_diagnostic(ParseDiagnostic::ParameterExpectedComma{
-                        token: self.peek_token().ok().cloned().unwrap_or_else(|| self.tokens[self.tokens.len() - 1].clone()),
-                    });
-                    break;
-                }
-            }
-        }
+        let generic_types = match ctx {
+            FunctionParsingContext::Closure => Vec::new(),
+            _ => self.parse_type_generic_parameters_declarations(),
+        };
 
+        self.expect_left_paren("werkwijzenaam")?;
+        let parameters = self.parse_parameters()?;
         let parameters_right_paren_range = self.expect_right_paren("werkwijzenaam");
 
         let return_type = if self.peek_punctuator() == Some(Punctuator::Arrow) {
@@ -353,11 +506,14 @@ impl<'tokens> Parser<'tokens> {
 
         Ok(FunctionStatement {
             name,
+            generic_types,
             body,
             parameters,
             parameters_right_paren_range,
             return_type,
             range,
+            visibility: None,
+            documentation: None,
         })
     }
 
@@ -405,6 +561,7 @@ impl<'tokens> Parser<'tokens> {
             left_curly_range,
             right_curly_range: left_curly_range,
             methods: Vec::new(),
+            documentation: None,
         };
 
         let mut is_closed_correctly = false;
@@ -418,17 +575,20 @@ impl<'tokens> Parser<'tokens> {
             let mut require_comma = true;
 
             let peeked_token = self.peek_token()?;
+            let documentation = peeked_token.documentation.clone();
             match peeked_token.kind {
                 TokenKind::Keyword(Keyword::Werkwijze) => {
                     let start = self.consume_token()?.begin;
 
-                    let function = self.parse_function(FunctionParsingContext::Interface)?;
+                    let mut function = self.parse_function(FunctionParsingContext::Interface)?;
+                    function.documentation = documentation;
 
                     let range = FileRange::new(start, self.token_end);
 
                     interface.methods.push(Method {
                         range,
                         function,
+                        is_static: false,
                     });
 
                     require_comma = false;
@@ -477,6 +637,22 @@ impl<'tokens> Parser<'tokens> {
         Ok(interface)
     }
 
+    fn parse_continue_statement(&mut self) -> ParseResult<ContinueStatement> {
+        let start = self.consume_token()?.begin;
+
+        if self.peek_keyword() == Some(Keyword::Door) {
+            _ = self.consume_token()?;
+        } else {
+            let token = self.peek_current_or_last_token();
+            self.emit_diagnostic(ParseDiagnostic::ExpectedKeywordDoor { token });
+        }
+
+        let range = FileRange::new(start, self.token_end);
+        self.expect_semicolon_after_statement();
+
+        Ok(ContinueStatement { range })
+    }
+
     fn parse_return_statement(&mut self, keyword_range: FileRange) -> Result<ReturnStatement, ParseError> {
         match self.peek_punctuator() {
             Some(Punctuator::Semicolon) => {
@@ -514,6 +690,61 @@ impl<'tokens> Parser<'tokens> {
         }
     }
 
+    fn parse_enumeration_statement(&mut self) -> ParseResult<Enumeration> {
+        let name = self.consume_identifier("Naam van opsomming", BabString::new_static(Keyword::Opsomming.as_ref()))?;
+        let left_curly_range = self.expect_left_curly_bracket("opsommingsnaam")?;
+
+        let mut variants = Vec::new();
+        let mut is_closed_correctly = false;
+        while !self.is_at_end() {
+            if self.peek_punctuator() == Some(Punctuator::RightCurlyBracket) {
+                _ = self.consume_token()?;
+                is_closed_correctly = true;
+                break;
+            }
+
+            variants.push(self.parse_enumeration_variant(&name)?);
+
+            if self.peek_punctuator() == Some(Punctuator::Comma) {
+                _ = self.consume_token()?;
+                continue;
+            }
+
+            if self.peek_punctuator() != Some(Punctuator::RightCurlyBracket) {
+                self.emit_diagnostic(ParseDiagnostic::ExpectedCommaAfterEnumerationVariant { token: self.peek_token()?.clone(), location: self.token_end });
+            }
+        }
+
+        if !is_closed_correctly {
+            let token = self.tokens.last().unwrap().clone();
+            let range = token.range().end().as_zero_range();
+            self.emit_diagnostic(ParseDiagnostic::ExpectedRightCurlyBracket { token, range, after: "opsomming" });
+        }
+
+        Ok(Enumeration {
+            name,
+            left_curly_range,
+            right_curly_range: self.previous_range(),
+            variants,
+        })
+    }
+
+    fn parse_enumeration_variant(&mut self, enumeration_name: &Ranged<BabString>) -> ParseResult<EnumerationVariant> {
+        let name = self.consume_identifier("Variantnaam", enumeration_name.value().clone())?;
+
+        let mut fields = Vec::new();
+        if self.peek_punctuator() == Some(Punctuator::LeftParenthesis) {
+            _ = self.consume_token()?;
+            fields = self.parse_parameters()?;
+            self.expect_right_paren("opsommingsvariant");
+        }
+
+        Ok(EnumerationVariant {
+            name,
+            fields,
+        })
+    }
+
     fn parse_structure_statement(&mut self) -> Result<Structure, ParseError> {
         let name_token = self.consume_token()?;
 
@@ -534,8 +765,10 @@ impl<'tokens> Parser<'tokens> {
             generic_types,
             left_curly_range,
             right_curly_range: left_curly_range,
+            constants: Vec::new(),
             fields: Vec::new(),
             methods: Vec::new(),
+            documentation: None,
         };
 
         let mut is_closed_correctly = false;
@@ -548,23 +781,71 @@ impl<'tokens> Parser<'tokens> {
 
             let mut require_comma = true;
 
+            let documentation = self.peek_token()?.documentation.clone();
+            let visibility = self.parse_visibility();
+
             let peeked_token = self.peek_token()?;
             match peeked_token.kind {
+                TokenKind::Keyword(Keyword::Vast) => {
+                    if let Some(visibility) = &visibility {
+                        let token = peeked_token.clone();
+                        let visibility = visibility.keyword();
+                        self.emit_diagnostic(ParseDiagnostic::ExpectedMemberAfterVisibility { token, visibility });
+                    }
+
+                    let keyword = self.consume_token()?.range();
+                    structure.constants.push(self.parse_constant_statement(keyword)?);
+                }
+
                 TokenKind::Keyword(Keyword::Veld) => {
                     _ = self.consume_token();
-                    structure.fields.push(self.parse_structure_field()?);
+                    let mut field = self.parse_structure_field()?;
+                    field.visibility = visibility;
+                    field.documentation = documentation;
+                    structure.fields.push(field);
                 }
 
                 TokenKind::Keyword(Keyword::Werkwijze) => {
-                    let start = self.consume_token()?.begin;
+                    let keyword = self.consume_token()?.begin;
+                    let start = visibility.as_ref().map_or(keyword, |x| x.range().start());
+
+                    let mut function = self.parse_function(FunctionParsingContext::Method)?;
+                    function.visibility = visibility;
+                    function.documentation = documentation;
 
-                    let function = self.parse_function(FunctionParsingContext::Method)?;
+                    let range = FileRange::new(start, self.token_end);
+
+                    structure.methods.push(Method {
+                        range,
+                        function,
+                        is_static: false,
+                    });
+
+                    require_comma = false;
+                }
+
+                TokenKind::Keyword(Keyword::Gedeeld) => {
+                    let keyword = self.consume_token()?.begin;
+                    let start = visibility.as_ref().map_or(keyword, |x| x.range().start());
+
+                    let token = self.peek_token()?;
+                    if token.kind == TokenKind::Keyword(Keyword::Werkwijze) {
+                        _ = self.consume_token()?;
+                    } else {
+                        let token = token.clone();
+                        self.emit_diagnostic(ParseDiagnostic::ExpectedWerkwijzeAfterGedeeld { token });
+                    }
+
+                    let mut function = self.parse_function(FunctionParsingContext::Method)?;
+                    function.visibility = visibility;
+                    function.documentation = documentation;
 
                     let range = FileRange::new(start, self.token_end);
 
                     structure.methods.push(Method {
                         range,
                         function,
+                        is_static: true,
                     });
 
                     require_comma = false;
@@ -588,7 +869,15 @@ impl<'tokens> Parser<'tokens> {
 
                 _ => {
                     let token = self.consume_token()?;
-                    self.emit_diagnostic(ParseDiagnostic::UnexpectedTokenAtStartOfStructureMember { token });
+                    match &visibility {
+                        Some(visibility) => {
+                            let visibility = visibility.keyword();
+                            self.emit_diagnostic(ParseDiagnostic::ExpectedMemberAfterVisibility { token, visibility });
+                        }
+                        None => {
+                            self.emit_diagnostic(ParseDiagnostic::UnexpectedTokenAtStartOfStructureMember { token });
+                        }
+                    }
                 }
             }
 
@@ -613,6 +902,53 @@ impl<'tokens> Parser<'tokens> {
         Ok(structure)
     }
 
+    /// Parses the optional `publiek` or `intern` keyword in front of a
+    /// declaration.
+    fn parse_visibility(&mut self) -> Option<Ranged<Visibility>> {
+        let visibility = match self.peek_token().ok()?.kind {
+            TokenKind::Keyword(Keyword::Publiek) => Visibility::Publiek,
+            TokenKind::Keyword(Keyword::Intern) => Visibility::Intern,
+            _ => return None,
+        };
+
+        let range = self.consume_token().ok()?.range();
+        Some(Ranged::new(range, visibility))
+    }
+
+    /// Parses the constant after the `vast` keyword, up to but not including
+    /// the terminating `;` (or `,` inside a structure).
+    fn parse_constant_statement(&mut self, keyword: FileRange) -> ParseResult<ConstantStatement> {
+        let name_token = self.consume_token()?;
+
+        let name = Ranged::new(name_token.range(), match name_token.kind {
+            TokenKind::Identifier(ident) => ident,
+            _ => {
+                self.emit_diagnostic(ParseDiagnostic::ExpectedNameOfConstant { token: name_token });
+                BabString::empty()
+            }
+        });
+
+        self.expect_colon("naam van vaste waarde");
+        let ty = self.parse_type();
+
+        let equals = self.peek_token()?.clone();
+        if equals.kind != TokenKind::Punctuator(Punctuator::Assignment) {
+            self.emit_diagnostic(ParseDiagnostic::ExpectedEqualsInsideConstant { token: equals });
+        } else {
+            _ = self.consume_token();
+        }
+
+        let value = self.parse_expression()?;
+
+        Ok(ConstantStatement {
+            range: FileRange::new(keyword.start(), value.range().end()),
+            keyword,
+            name,
+            ty,
+            value,
+        })
+    }
+
     fn parse_structure_field(&mut self) -> Result<Field, ParseError> {
         let name_token = self.consume_token()?;
 
@@ -636,25 +972,30 @@ impl<'tokens> Parser<'tokens> {
 
         Ok(Field {
             attributes: Vec::new(),
+            visibility: None,
             name,
             ty,
             default_value,
+            documentation: None,
         })
     }
 
     fn parse_variable_statement(&mut self) -> Result<VariableStatement, ParseError> {
-        let name_token = self.consume_token()?;
-        let name_range = name_token.range();
+        let start = self.peek_token()?.begin;
 
-        let name = match name_token.kind {
-            TokenKind::Identifier(ident) => ident,
-            _ => {
-                self.emit_diagnostic(ParseDiagnostic::ExpectedNameOfVariable { token: name_token });
-                BabString::empty()
-            }
+        let name = if self.peek_punctuator() == Some(Punctuator::LeftParenthesis) {
+            _ = self.consume_token();
+            VariableName::Tuple(self.parse_variable_tuple_names()?)
+        } else {
+            VariableName::Single(self.parse_variable_name()?)
         };
 
-        let name = Ranged::new(name_range, name);
+        let ty = if self.peek_punctuator() == Some(Punctuator::Colon) {
+            _ = self.consume_token();
+            Some(self.parse_type())
+        } else {
+            None
+        };
 
         let equals = self.peek_token()?.clone();
         if equals.kind != TokenKind::Punctuator(Punctuator::Assignment) {
@@ -667,12 +1008,76 @@ impl<'tokens> Parser<'tokens> {
         self.expect_semicolon_after_statement();
 
         Ok(VariableStatement {
-            range: FileRange::new(name.range().start(), expression.range().end()),
+            range: FileRange::new(start, expression.range().end()),
             name,
+            ty,
             expression,
         })
     }
 
+    fn parse_variable_name(&mut self) -> Result<Ranged<BabString>, ParseError> {
+        let name_token = self.consume_token()?;
+        let name_range = name_token.range();
+
+        let name = match name_token.kind {
+            TokenKind::Identifier(ident) => ident,
+            _ => {
+                self.emit_diagnostic(ParseDiagnostic::ExpectedNameOfVariable { token: name_token });
+                BabString::empty()
+            }
+        };
+
+        Ok(Ranged::new(name_range, name))
+    }
+
+    /// Parses the names of `stel (q, r) = ...` after the `(`, up to and
+    /// including the `)`.
+    fn parse_variable_tuple_names(&mut self) -> Result<Vec<Ranged<BabString>>, ParseError> {
+        let mut names = Vec::new();
+
+        while !self.is_at_end() && self.peek_punctuator() != Some(Punctuator::RightParenthesis) {
+            names.push(self.parse_variable_name()?);
+
+            if self.peek_punctuator() != Some(Punctuator::Comma) {
+                break;
+            }
+
+            _ = self.consume_token();
+        }
+
+        self.expect_right_paren("namen van variabelen");
+        Ok(names)
+    }
+
+    /// Parses the parameters up to, but not including, the `)`.
+    fn parse_parameters(&mut self) -> ParseResult<Vec<Parameter>> {
+        let mut parameters = Vec::new();
+        while self.peek_punctuator() != Some(Punctuator::RightParenthesis) {
+            match self.parse_parameter() {
+                Ok(parameter) => parameters.push(parameter),
+                Err(error) => self.handle_error(error),
+            }
+
+            match self.peek_punctuator() {
+                Some(Punctuator::Comma) => {
+                    _ = self.consume_token()?;
+                    continue;
+                }
+
+                Some(Punctuator::RightParenthesis) => break,
+
+                _ => {
+                    self.emit_diagnostic(ParseDiagnostic::ParameterExpectedComma{
+                        token: self.peek_token().ok().cloned().unwrap_or_else(|| self.tokens[self.tokens.len() - 1].clone()),
+                    });
+                    break;
+                }
+            }
+        }
+
+        Ok(parameters)
+    }
+
     fn parse_parameter(&mut self) -> Result<Parameter, ParseError> {
         let attributes = self.parse_attribute_list();
 
@@ -792,6 +1197,7 @@ impl<'tokens> Parser<'tokens> {
         _ = self.consume_token();
 
         let name = Ranged::new(name_token.range(), name.clone());
+        let name = self.parse_qualified_name(name);
         let type_parameters = self.parse_type_generic_parameters_definitions();
 
         let range = FileRange::new(name.range().start(), type_parameters.range().end());
@@ -808,25 +1214,86 @@ impl<'tokens> Parser<'tokens> {
             return Ranged::new(range, TypeSpecifier::BuiltIn(Ranged::new(range, BuiltinType::Null)));
         };
 
-        let TokenKind::Identifier(ref name) = name_token.kind else {
-            let range = name_token.begin.as_zero_range();
-            self.emit_diagnostic(ParseDiagnostic::TypeExpectedSpecifierName { token: name_token });
-            return Ranged::new(range, TypeSpecifier::BuiltIn(Ranged::new(range, BuiltinType::Null)));
+        if name_token.kind == TokenKind::Keyword(Keyword::Werkwijze) {
+            _ = self.consume_token();
+            return self.parse_function_type_specifier(name_token.range());
+        }
+
+        if name_token.kind == TokenKind::Punctuator(Punctuator::LeftParenthesis) {
+            _ = self.consume_token();
+            return self.parse_tuple_type_specifier(name_token.range());
+        }
+
+        let TokenKind::Identifier(ref name) = name_token.kind else {
+            let range = name_token.begin.as_zero_range();
+            self.emit_diagnostic(ParseDiagnostic::TypeExpectedSpecifierName { token: name_token });
+            return Ranged::new(range, TypeSpecifier::BuiltIn(Ranged::new(range, BuiltinType::Null)));
+        };
+
+        _ = self.consume_token();
+
+        let name = Ranged::new(name_token.range(), name.clone());
+        let name = self.parse_qualified_name(name);
+        let range = name.range();
+
+        let specifier = match Builtin::type_by_name(name.value()) {
+            Some(builtin) => TypeSpecifier::BuiltIn(Ranged::new(name.range(), builtin)),
+            None => {
+                let type_parameters = self.parse_type_generic_parameters_definitions();
+                TypeSpecifier::Custom { name, type_parameters }
+            }
+        };
+
+        Ranged::new(range, specifier)
+    }
+
+    /// Parses the type of a `werkwijze` as a value, e.g. `werkwijze(g32) -> bool`.
+    fn parse_function_type_specifier(&mut self, keyword: FileRange) -> Ranged<TypeSpecifier> {
+        let mut parameters = Vec::new();
+
+        if self.expect_left_paren("werkwijze").is_ok() {
+            while !self.is_at_end() && self.peek_punctuator() != Some(Punctuator::RightParenthesis) {
+                parameters.push(self.parse_type());
+
+                if self.peek_punctuator() != Some(Punctuator::Comma) {
+                    break;
+                }
+
+                _ = self.consume_token();
+            }
+
+            self.expect_right_paren("werkwijzeparameters");
+        }
+
+        let return_type = if self.peek_punctuator() == Some(Punctuator::Arrow) {
+            _ = self.consume_token();
+            Some(Box::new(self.parse_type()))
+        } else {
+            None
         };
 
-        _ = self.consume_token();
+        let range = FileRange::new(keyword.start(), self.previous_end());
+        Ranged::new(range, TypeSpecifier::Function { parameters, return_type })
+    }
 
-        let name = Ranged::new(name_token.range(), name.clone());
+    /// Parses the type of a tuple after the `(`, e.g. `(g32, Slinger)`.
+    fn parse_tuple_type_specifier(&mut self, left_paren: FileRange) -> Ranged<TypeSpecifier> {
+        let mut elements = Vec::new();
 
-        let specifier = match Builtin::type_by_name(name.value()) {
-            Some(builtin) => TypeSpecifier::BuiltIn(Ranged::new(name.range(), builtin)),
-            None => {
-                let type_parameters = self.parse_type_generic_parameters_definitions();
-                TypeSpecifier::Custom { name, type_parameters }
+        while !self.is_at_end() && self.peek_punctuator() != Some(Punctuator::RightParenthesis) {
+            elements.push(self.parse_type());
+
+            if self.peek_punctuator() != Some(Punctuator::Comma) {
+                break;
             }
-        };
 
-        Ranged::new(name_token.range(), specifier)
+            _ = self.consume_token();
+        }
+
+        self.expect_right_paren("tupeltype");
+
+        let range = FileRange::new(left_paren.start(), self.previous_end());
+        Ranged::new(range, TypeSpecifier::Tuple(elements))
     }
 
     fn parse_type_generic_parameters_definitions(&mut self) -> Ranged<Vec<Ranged<Type>>> {
@@ -884,7 +1351,7 @@ impl<'tokens> Parser<'tokens> {
         Ranged::new(FileRange::new(start, self.previous_end()), types)
     }
 
-    fn parse_type_generic_parameters_declarations(&mut self) -> Vec<Ranged<BabString>> {
+    fn parse_type_generic_parameters_declarations(&mut self) -> Vec<GenericTypeDeclaration> {
         if self.peek_punctuator() != Some(Punctuator::LessThan) {
             return Vec::new();
         }
@@ -903,10 +1370,12 @@ impl<'tokens> Parser<'tokens> {
 
         let mut types = Vec::new();
         while !self.is_at_end() {
-            let Some(ty) = self.parse_generic_type_name() else {
+            let Some(name) = self.parse_generic_type_name() else {
                 break;
             };
-            types.push(ty);
+
+            let bounds = self.parse_generic_type_bounds();
+            types.push(GenericTypeDeclaration { name, bounds });
 
             match self.peek_punctuator() {
                 Some(Punctuator::Comma) => {
@@ -937,6 +1406,30 @@ impl<'tokens> Parser<'tokens> {
         types
     }
 
+    /// Parses the interfaces a generic type parameter is bound by, such as
+    /// the `: Vergelijkbaar + Weergeefbaar` in `<T: Vergelijkbaar + Weergeefbaar>`.
+    fn parse_generic_type_bounds(&mut self) -> Vec<Ranged<InterfaceSpecifier>> {
+        let mut bounds = Vec::new();
+        if self.peek_punctuator() != Some(Punctuator::Colon) {
+            return bounds;
+        }
+
+        _ = self.consume_token();
+
+        loop {
+            let bound = self.parse_interface_or_type_specifier("koppelvlak");
+            bounds.push(bound.map(|x| x.to_interface()));
+
+            if self.peek_punctuator() != Some(Punctuator::PlusSign) {
+                break;
+            }
+
+            _ = self.consume_token();
+        }
+
+        bounds
+    }
+
     fn parse_generic_type_name(&mut self) -> Option<Ranged<BabString>> {
         self.consume_identifier("generieke typenaam", self.tokens[self.cursor - 1].kind.to_string().into()).ok()
     }
@@ -1015,6 +1508,62 @@ impl<'tokens> Parser<'tokens> {
             self.expect_left_curly_bracket("als-conditie")?;
         }
 
+        let body = self.parse_block_statements("als")?;
+
+        let else_statement = if self.peek_keyword() == Some(Keyword::Anders) {
+            Some(self.parse_else_statement()?)
+        } else {
+            None
+        };
+
+        let range = FileRange::new(start, self.previous_end());
+
+        Ok(IfStatement { condition, body, else_statement, range })
+    }
+
+    fn parse_while_statement(&mut self) -> Result<WhileStatement, ParseError> {
+        let keyword = self.consume_token()?.range();
+
+        let condition = match self.parse_expression() {
+            Ok(expression) => expression,
+            Err(error) => {
+                self.handle_error(error);
+
+                Ranged::new(
+                    FileRange::new(self.token_end, self.token_end),
+                    Expression::Primary(PrimaryExpression::Boolean(false))
+                )
+            }
+        };
+
+        self.expect_left_curly_bracket("zolang-voorwaarde")?;
+
+        let body = self.parse_block_statements("zolang")?;
+
+        let range = FileRange::new(keyword.start(), self.previous_end());
+
+        Ok(WhileStatement { range, keyword, condition, body })
+    }
+
+    fn parse_else_statement(&mut self) -> Result<ElseStatement, ParseError> {
+        let keyword = self.consume_token()?.range();
+
+        let kind = if self.peek_keyword() == Some(Keyword::Als) {
+            _ = self.consume_token()?;
+            ElseStatementKind::ElseIf(Box::new(self.parse_if_statement()?))
+        } else {
+            self.expect_left_curly_bracket("anders")?;
+            ElseStatementKind::Else(self.parse_block_statements("anders")?)
+        };
+
+        let range = FileRange::new(keyword.start(), self.previous_end());
+
+        Ok(ElseStatement { range, keyword, kind })
+    }
+
+    /// Parses the statements of a block up to and including the closing `}`,
+    /// assuming the opening `{` is already consumed.
+    fn parse_block_statements(&mut self, after: &'static str) -> Result<Vec<Statement>, ParseError> {
         let mut body = Vec::new();
         let mut is_closed_correctly = false;
 
@@ -1037,12 +1586,10 @@ impl<'tokens> Parser<'tokens> {
         if !is_closed_correctly {
             let token = self.tokens.last().unwrap().clone();
             let range = token.range().end().as_zero_range();
-            self.emit_diagnostic(ParseDiagnostic::ExpectedRightCurlyBracket { token, range, after: "als" });
+            self.emit_diagnostic(ParseDiagnostic::ExpectedRightCurlyBracket { token, range, after });
         }
 
-        let range = FileRange::new(start, self.previous_end());
-
-        Ok(IfStatement { condition, body, range })
+        Ok(body)
     }
 
     fn parse_range(&mut self) -> Result<RangeExpression, ParseError> {
@@ -1051,6 +1598,10 @@ impl<'tokens> Parser<'tokens> {
             self.emit_diagnostic(ParseDiagnostic::RangeExpectedKeyword { token: range_keyword });
         }
 
+        self.parse_range_arguments()
+    }
+
+    fn parse_range_arguments(&mut self) -> Result<RangeExpression, ParseError> {
         self.expect_left_paren("reeks")?;
 
         let start = Box::new(self.parse_expression()?);
@@ -1059,9 +1610,48 @@ impl<'tokens> Parser<'tokens> {
 
         let end = Box::new(self.parse_expression()?);
 
+        let mut step = None;
+        let mut is_inclusive = false;
+
+        while self.peek_punctuator() == Some(Punctuator::Comma) {
+            _ = self.consume_token()?;
+
+            if self.peek_punctuator() == Some(Punctuator::RightParenthesis) {
+                break;
+            }
+
+            let name_token = self.consume_token()?;
+            let TokenKind::Identifier(name) = &name_token.kind else {
+                self.emit_diagnostic(ParseDiagnostic::RangeExpectedNamedArgument { token: name_token });
+                break;
+            };
+
+            self.expect_colon("benoemd argument van `reeks`");
+
+            match name.as_str() {
+                "stap" => {
+                    step = Some(Box::new(self.parse_expression()?));
+                }
+
+                "totEnMet" => {
+                    let token = self.consume_token()?;
+                    match token.kind {
+                        TokenKind::Keyword(Keyword::Waar) => is_inclusive = true,
+                        TokenKind::Keyword(Keyword::Onwaar) => is_inclusive = false,
+                        _ => self.emit_diagnostic(ParseDiagnostic::RangeInclusiveExpectedBoolean { token }),
+                    }
+                }
+
+                _ => {
+                    self.emit_diagnostic(ParseDiagnostic::RangeUnknownNamedArgument { token: name_token.clone() });
+                    _ = self.parse_expression()?;
+                }
+            }
+        }
+
         self.expect_right_paren("reeks");
 
-        Ok(RangeExpression { start, end })
+        Ok(RangeExpression { start, end, step, is_inclusive })
     }
 
     fn parse_primary_expression(&mut self) -> ParseResult<Ranged<PrimaryExpression>> {
@@ -1072,17 +1662,54 @@ impl<'tokens> Parser<'tokens> {
             TokenKind::CharacterLiteral(char) => Ok(PrimaryExpression::CharacterLiteral(char)),
             TokenKind::StringLiteral(literal) => Ok(PrimaryExpression::StringLiteral(literal)),
             TokenKind::Integer(integer) => Ok(PrimaryExpression::IntegerLiteral(integer)),
+            TokenKind::Float(float) => Ok(PrimaryExpression::FloatLiteral(float)),
             TokenKind::Identifier(ref identifier) => Ok(PrimaryExpression::Reference(Ranged::new(token.range(), identifier.clone()))),
             TokenKind::TemplateString(template_string) => self.parse_template_string(template_string),
             TokenKind::Keyword(Keyword::Waar) => Ok(PrimaryExpression::Boolean(true)),
             TokenKind::Keyword(Keyword::Onwaar) => Ok(PrimaryExpression::Boolean(false)),
             TokenKind::Keyword(Keyword::Nieuw) => self.parse_new_keyword(range.start()),
             TokenKind::Keyword(Keyword::Dit) => Ok(PrimaryExpression::ReferenceThis),
+            TokenKind::Keyword(Keyword::Kies) => self.parse_match_expression(range).map(PrimaryExpression::Match),
+            TokenKind::Keyword(Keyword::Werkwijze) => self.parse_closure(range).map(PrimaryExpression::Closure),
+            TokenKind::Keyword(Keyword::Reeks) => self.parse_range_arguments().map(PrimaryExpression::Range),
+            TokenKind::Keyword(Keyword::Iets) => {
+                self.expect_left_paren("Iets")?;
+                let expression = self.parse_expression()?;
+                self.expect_right_paren("Iets");
+                Ok(PrimaryExpression::OptionalSome(Box::new(expression)))
+            }
+            TokenKind::Keyword(Keyword::Niets) => Ok(PrimaryExpression::OptionalNone),
+            TokenKind::Keyword(Keyword::Goed) => {
+                self.expect_left_paren("Goed")?;
+                let expression = self.parse_expression()?;
+                self.expect_right_paren("Goed");
+                Ok(PrimaryExpression::ResultOk(Box::new(expression)))
+            }
+            TokenKind::Keyword(Keyword::Fout) => {
+                self.expect_left_paren("Fout")?;
+                let expression = self.parse_expression()?;
+                self.expect_right_paren("Fout");
+                Ok(PrimaryExpression::ResultError(Box::new(expression)))
+            }
+
+            TokenKind::Punctuator(Punctuator::LeftSquareBracket) => self.parse_array_literal(),
 
             TokenKind::Punctuator(Punctuator::LeftParenthesis) => {
                 let expression = self.parse_expression()?;
-                self.expect_right_paren("expressie binnen haakjes");
-                Ok(PrimaryExpression::Parenthesized(Box::new(expression)))
+
+                if self.peek_punctuator() == Some(Punctuator::Comma) {
+                    let mut elements = vec![expression];
+                    while self.peek_punctuator() == Some(Punctuator::Comma) {
+                        _ = self.consume_token();
+                        elements.push(self.parse_expression()?);
+                    }
+
+                    self.expect_right_paren("tupel");
+                    Ok(PrimaryExpression::Tuple(elements))
+                } else {
+                    self.expect_right_paren("expressie binnen haakjes");
+                    Ok(PrimaryExpression::Parenthesized(Box::new(expression)))
+                }
             }
 
             _ => {
@@ -1097,6 +1724,248 @@ impl<'tokens> Parser<'tokens> {
         Ok(Ranged::new(range, expression))
     }
 
+    /// Parses the elements of an array literal after the `[`, e.g. `[1, 2, 3]`.
+    fn parse_array_literal(&mut self) -> ParseResult<PrimaryExpression> {
+        let mut elements = Vec::new();
+
+        while !self.is_at_end() && self.peek_punctuator() != Some(Punctuator::RightSquareBracket) {
+            elements.push(self.parse_expression()?);
+
+            if self.peek_punctuator() != Some(Punctuator::Comma) {
+                break;
+            }
+
+            _ = self.consume_token();
+        }
+
+        let token = self.consume_token()?;
+        if token.kind != TokenKind::Punctuator(Punctuator::RightSquareBracket) {
+            self.emit_diagnostic(ParseDiagnostic::ExpectedRightSquareBracketForArrayLiteral { token });
+        }
+
+        Ok(PrimaryExpression::ArrayLiteral(elements))
+    }
+
+    fn parse_match_expression(&mut self, keyword: FileRange) -> ParseResult<MatchExpression> {
+        let scrutinee = Box::new(self.parse_expression()?);
+        let left_curly_range = self.expect_left_curly_bracket("kies")?;
+
+        let mut arms = Vec::new();
+        let mut right_curly_range = None;
+        while !self.is_at_end() {
+            if self.peek_punctuator() == Some(Punctuator::RightCurlyBracket) {
+                right_curly_range = Some(self.consume_token()?.range());
+                break;
+            }
+
+            let pattern = self.parse_pattern()?;
+            let arrow = self.expect_fat_arrow();
+            let expression = self.parse_expression()?;
+
+            let comma = if self.peek_punctuator() == Some(Punctuator::Comma) {
+                Some(self.consume_token()?.range())
+            } else {
+                None
+            };
+
+            arms.push(MatchArm {
+                pattern,
+                arrow,
+                expression,
+                comma,
+            });
+
+            if comma.is_none() && self.peek_punctuator() != Some(Punctuator::RightCurlyBracket) {
+                self.emit_diagnostic(ParseDiagnostic::ExpectedCommaAfterMatchArm { token: self.peek_token()?.clone(), location: self.token_end });
+            }
+        }
+
+        let right_curly_range = match right_curly_range {
+            Some(range) => range,
+            None => {
+                let token = self.tokens.last().unwrap().clone();
+                let range = token.range().end().as_zero_range();
+                self.emit_diagnostic(ParseDiagnostic::ExpectedRightCurlyBracket { token, range, after: "kies" });
+                range
+            }
+        };
+
+        Ok(MatchExpression {
+            keyword,
+            scrutinee,
+            left_curly_range,
+            arms,
+            right_curly_range,
+        })
+    }
+
+    fn expect_fat_arrow(&mut self) -> FileRange {
+        if self.peek_punctuator() == Some(Punctuator::FatArrow) {
+            if let Ok(token) = self.consume_token() {
+                return token.range();
+            }
+        }
+
+        let range = self.previous_end().as_zero_range();
+        let token = self.peek_current_or_last_token();
+        self.emit_diagnostic(ParseDiagnostic::ExpectedFatArrowAfterPattern { token, range });
+        range
+    }
+
+    fn parse_pattern(&mut self) -> ParseResult<Ranged<Pattern>> {
+        let token = self.consume_token()?;
+        let range = token.range();
+
+        let pattern = match token.kind {
+            TokenKind::Identifier(ref name) if name.as_str() == "_" => Pattern::Wildcard,
+
+            TokenKind::Identifier(ref name) => {
+                let name = self.parse_qualified_name(Ranged::new(range, name.clone()));
+                match self.peek_punctuator() {
+                    Some(Punctuator::LeftCurlyBracket) => self.parse_structure_pattern(name)?,
+                    Some(Punctuator::Period) => self.parse_enumeration_variant_pattern(name)?,
+                    _ => Pattern::Binding(name),
+                }
+            }
+
+            TokenKind::Keyword(Keyword::Waar) => Pattern::Boolean(true),
+            TokenKind::Keyword(Keyword::Onwaar) => Pattern::Boolean(false),
+            TokenKind::Keyword(Keyword::Iets) => {
+                self.expect_left_paren("Iets-patroon")?;
+                let pattern = self.parse_pattern()?;
+                self.expect_right_paren("Iets-patroon");
+                Pattern::OptionalSome(Box::new(pattern))
+            }
+            TokenKind::Keyword(Keyword::Niets) => Pattern::OptionalNone,
+            TokenKind::Keyword(Keyword::Goed) => {
+                self.expect_left_paren("Goed-patroon")?;
+                let pattern = self.parse_pattern()?;
+                self.expect_right_paren("Goed-patroon");
+                Pattern::ResultOk(Box::new(pattern))
+            }
+            TokenKind::Keyword(Keyword::Fout) => {
+                self.expect_left_paren("Fout-patroon")?;
+                let pattern = self.parse_pattern()?;
+                self.expect_right_paren("Fout-patroon");
+                Pattern::ResultError(Box::new(pattern))
+            }
+            TokenKind::CharacterLiteral(c) => Pattern::CharacterLiteral(c),
+            TokenKind::StringLiteral(ref s) => Pattern::StringLiteral(s.clone()),
+
+            TokenKind::Integer(..) | TokenKind::Punctuator(Punctuator::HyphenMinus) => {
+                self.cursor -= 1;
+                let start = self.parse_integer_pattern()?;
+                self.parse_integer_or_range_pattern(start)?
+            }
+
+            _ => {
+                self.emit_diagnostic(ParseDiagnostic::InvalidPattern { token });
+                Pattern::Wildcard
+            }
+        };
+
+        Ok(Ranged::new(FileRange::new(range.start(), self.token_end), pattern))
+    }
+
+    /// Parses an integer, optionally negated, e.g. `-1`.
+    fn parse_integer_pattern(&mut self) -> ParseResult<Ranged<i64>> {
+        let token = self.consume_token()?;
+        let start = token.begin;
+
+        let (is_negative, token) = match token.kind {
+            TokenKind::Punctuator(Punctuator::HyphenMinus) => (true, self.consume_token()?),
+            _ => (false, token),
+        };
+
+        let TokenKind::Integer(integer) = token.kind else {
+            self.emit_diagnostic(ParseDiagnostic::InvalidPattern { token });
+            return Ok(Ranged::new(FileRange::new(start, self.token_end), 0));
+        };
+
+        let integer = if is_negative { -integer } else { integer };
+        Ok(Ranged::new(FileRange::new(start, self.token_end), integer))
+    }
+
+    fn parse_integer_or_range_pattern(&mut self, start: Ranged<i64>) -> ParseResult<Pattern> {
+        if self.peek_punctuator() != Some(Punctuator::DoublePeriod) {
+            return Ok(Pattern::IntegerLiteral(*start.value()));
+        }
+
+        _ = self.consume_token()?;
+        let end = self.parse_integer_pattern()?;
+        Ok(Pattern::Range { start, end })
+    }
+
+    fn parse_structure_pattern(&mut self, name: Ranged<BabString>) -> ParseResult<Pattern> {
+        _ = self.expect_left_curly_bracket("structuurpatroon")?;
+
+        let mut fields = Vec::new();
+        let mut is_closed_correctly = false;
+        while !self.is_at_end() {
+            if self.peek_punctuator() == Some(Punctuator::RightCurlyBracket) {
+                _ = self.consume_token()?;
+                is_closed_correctly = true;
+                break;
+            }
+
+            let field_name = self.consume_identifier("Veldnaam", name.value().clone())?;
+            let pattern = if self.peek_punctuator() == Some(Punctuator::Colon) {
+                _ = self.consume_token()?;
+                self.parse_pattern()?
+            } else {
+                Ranged::new(field_name.range(), Pattern::Binding(field_name.clone()))
+            };
+
+            fields.push(FieldPattern {
+                name: field_name,
+                pattern,
+            });
+
+            if self.peek_punctuator() == Some(Punctuator::Comma) {
+                _ = self.consume_token()?;
+                continue;
+            }
+
+            if self.peek_punctuator() != Some(Punctuator::RightCurlyBracket) {
+                let token = self.peek_current_or_last_token();
+                self.emit_diagnostic(ParseDiagnostic::ExpectedComma { token, context: "veldpatroon" });
+                break;
+            }
+        }
+
+        if !is_closed_correctly {
+            let token = self.peek_current_or_last_token();
+            let range = self.previous_end().as_zero_range();
+            self.emit_diagnostic(ParseDiagnostic::ExpectedRightCurlyBracket { token, range, after: "structuurpatroon" });
+        }
+
+        Ok(Pattern::Structure { name, fields })
+    }
+
+    fn parse_enumeration_variant_pattern(&mut self, enumeration: Ranged<BabString>) -> ParseResult<Pattern> {
+        _ = self.consume_token()?;
+        let variant = self.consume_identifier("Variantnaam", enumeration.value().clone())?;
+
+        let mut fields = Vec::new();
+        if self.peek_punctuator() == Some(Punctuator::LeftParenthesis) {
+            _ = self.consume_token()?;
+
+            while self.peek_punctuator() != Some(Punctuator::RightParenthesis) && !self.is_at_end() {
+                fields.push(self.parse_pattern()?);
+
+                if self.peek_punctuator() == Some(Punctuator::Comma) {
+                    _ = self.consume_token()?;
+                } else {
+                    break;
+                }
+            }
+
+            self.expect_right_paren("opsommingsvariantpatroon");
+        }
+
+        Ok(Pattern::EnumerationVariant { enumeration, variant, fields })
+    }
+
     #[allow(unused)] // TODO evaluate further need of this function
     fn parse_ranged<F, T>(&mut self, f: F) -> Result<Ranged<T>, ParseError>
             where F: FnOnce(&mut Self) -> Result<T, ParseError> {
@@ -1143,12 +2012,17 @@ impl<'tokens> Parser<'tokens> {
     fn parse_equality_expression(&mut self) -> Result<Ranged<Expression>, ParseError> {
         self.parse_bi_expression(Self::parse_relational_expression, &[
             (Punctuator::Equals, Comparison::Equality.into()),
-            // TODO the rest
+            (Punctuator::NotEquals, Comparison::Inequality.into()),
         ])
     }
 
     fn parse_relational_expression(&mut self) -> Result<Ranged<Expression>, ParseError> {
-        self.parse_shift_expression()
+        self.parse_bi_expression(Self::parse_shift_expression, &[
+            (Punctuator::LessThan, Comparison::LessThan.into()),
+            (Punctuator::LessThanOrEqual, Comparison::LessThanOrEqual.into()),
+            (Punctuator::GreaterThan, Comparison::GreaterThan.into()),
+            (Punctuator::GreaterThanOrEqual, Comparison::GreaterThanOrEqual.into()),
+        ])
     }
 
     fn parse_shift_expression(&mut self) -> Result<Ranged<Expression>, ParseError> {
@@ -1216,6 +2090,11 @@ impl<'tokens> Parser<'tokens> {
                     _ = self.consume_token();
                 }
 
+                TokenKind::Punctuator(Punctuator::ExclamationMark) => {
+                    kinds.push(Ranged::new(token.range(), UnaryExpressionKind::Not));
+                    _ = self.consume_token();
+                }
+
                 _ => break,
             }
         }
@@ -1234,7 +2113,13 @@ impl<'tokens> Parser<'tokens> {
     }
 
     fn parse_postfix_expression(&mut self) -> Result<Ranged<Expression>, ParseError> {
-        let mut expression = self.parse_primary_expression()?.map(|x| Expression::Primary(x));
+        let mut primary = self.parse_primary_expression()?;
+        if let PrimaryExpression::Reference(name) = primary.value() {
+            let name = self.parse_qualified_name(name.clone());
+            primary = Ranged::new(name.range(), PrimaryExpression::Reference(name));
+        }
+
+        let mut expression = primary.map(|x| Expression::Primary(x));
         let start = expression.range().start();
 
         loop {
@@ -1317,6 +2202,14 @@ impl<'tokens> Parser<'tokens> {
                     })
                 }
 
+                Some(Punctuator::QuestionMark) => {
+                    let question_mark = self.consume_token()?.range();
+                    Expression::Postfix(PostfixExpression {
+                        lhs: Box::new(expression),
+                        kind: Ranged::new(question_mark, PostfixExpressionKind::Propagate),
+                    })
+                }
+
                 _ => break,
             };
 
@@ -1367,6 +2260,7 @@ impl<'tokens> Parser<'tokens> {
                 TemplateStringToken::Plain { str, .. } => TemplateStringExpressionPart::String(str),
                 TemplateStringToken::Expression(tokens) => {
                     let mut parser = Parser::new(self.path.clone(), &tokens);
+                    parser.imports = self.imports.clone();
                     match parser.parse_expression() {
                         Ok(expr) => {
                             if parser.cursor < tokens.len() {
@@ -1638,6 +2532,7 @@ impl<'tokens> Parser<'tokens> {
         };
 
         _ = self.consume_token();
+        let name = self.parse_qualified_name(name);
 
         if self.peek_punctuator() == Some(Punctuator::LeftSquareBracket) {
             self.parse_sized_array_initializer(name)
@@ -1774,24 +2669,39 @@ pub enum ParseDiagnostic {
     #[error("`,` of `>` verwacht in generieke typeverzameling")]
     ExpectedCommaOrGreaterThanInGenericTypePack { token: Token },
 
+    #[error("Na een opsommingsvariant hoort een komma `,`")]
+    ExpectedCommaAfterEnumerationVariant { token: Token, location: FileLocation },
+
+    #[error("Na een kies-tak hoort een komma `,`")]
+    ExpectedCommaAfterMatchArm { token: Token, location: FileLocation },
+
     #[error("Na een structuurlid hoort een komma `;`")]
     ExpectedCommaAfterStructureMember { token: Token, location: FileLocation },
 
     #[error("Onjuiste uitbreidingswerkwijze, deze hoort te starten met `werkwijze`")]
     ExpectedExtensionMethodPrefixWerkwijze { token: Token },
 
+    #[error("Pijl `=>` verwacht na patroon, maar kreeg: {token}")]
+    ExpectedFatArrowAfterPattern { token: Token, range: FileRange },
+
     #[error("Generieke typenaam verwacht, bijvoorbeeld `T`")]
     ExpectedGenericTypeName { token: Token },
 
     #[error("`>` verwacht na generieke parameters")]
     ExpectedGreaterThanForParameterPack { token: Token, location: FileLocation },
 
+    #[error("Sleutelwoord `door` verwacht na `ga`, maar kreeg: {token}")]
+    ExpectedKeywordDoor { token: Token },
+
     #[error("Sleutelwoord `op` verwacht tussen de naam van het koppelvlak en de type")]
     ExpectedKeywordOp { token: Token, range: FileRange },
 
     #[error("Naam van structuur verwacht, maar kreeg: {token}")]
     ExpectedNameAfterNieuw { token: Token },
 
+    #[error("Naam van vaste waarde verwacht, maar kreeg: {token}")]
+    ExpectedNameOfConstant { token: Token },
+
     #[error("Naam van structuurveld verwacht, maar kreeg: {token}")]
     ExpectedNameOfField { token: Token },
 
@@ -1810,12 +2720,27 @@ pub enum ParseDiagnostic {
     #[error("Is-teken `=` verwacht tussen naam van stelling en de toewijzing, maar kreeg: {token}")]
     ExpectedEqualsInsideVariable { token: Token },
 
+    #[error("Is-teken `=` verwacht tussen type en waarde van vaste waarde, maar kreeg: {token}")]
+    ExpectedEqualsInsideConstant { token: Token },
+
     #[error("Accolade `}}` verwacht om {after} af te sluiten")]
     ExpectedRightCurlyBracket { token: Token, after: &'static str, range: FileRange },
 
     #[error("`]` verwacht om opeenvolging af te sluiten")]
     ExpectedRightSquareBracketForArrayInitializer { token: Token },
 
+    #[error("`werkwijze` verwacht na `gedeeld`")]
+    ExpectedWerkwijzeAfterGedeeld { token: Token },
+
+    #[error("`werkwijze` verwacht na `{visibility}`")]
+    ExpectedWerkwijzeAfterVisibility { token: Token, visibility: &'static str },
+
+    #[error("`veld` of `werkwijze` verwacht na `{visibility}`")]
+    ExpectedMemberAfterVisibility { token: Token, visibility: &'static str },
+
+    #[error("`]` verwacht om de elementen van de opeenvolging af te sluiten")]
+    ExpectedRightSquareBracketForArrayLiteral { token: Token },
+
     #[error("`]` verwacht om opeenvolging af te sluiten")]
     ExpectedRightSquareBracketForArrayQualifier { token: Token },
 
@@ -1846,6 +2771,9 @@ pub enum ParseDiagnostic {
     #[error("Sleutelwoord 'in' verwacht na `volg {}`, maar kreeg: {token}", iterator_name.value())]
     ForStatementExpectedInKeyword { token: Token, iterator_name: Ranged<BabString> },
 
+    #[error("Ongeldig patroon: {token}")]
+    InvalidPattern { token: Token },
+
     #[error("Parameternaam verwacht, maar kreeg: {token}")]
     ParameterExpectedName { token: Token },
 
@@ -1858,6 +2786,15 @@ pub enum ParseDiagnostic {
     #[error("Sleutelwoord `reeks` verwacht, maar kreeg: {token}")]
     RangeExpectedKeyword { token: Token },
 
+    #[error("Benoemd argument `stap` of `totEnMet` verwacht binnen `reeks`, maar kreeg: {token}")]
+    RangeExpectedNamedArgument { token: Token },
+
+    #[error("`waar` of `onwaar` verwacht na `totEnMet:`, maar kreeg: {token}")]
+    RangeInclusiveExpectedBoolean { token: Token },
+
+    #[error("Onbekend benoemd argument `{token}` binnen `reeks`, verwacht `stap` of `totEnMet`")]
+    RangeUnknownNamedArgument { token: Token },
+
     #[error("Resterende token na expressie binnen sjabloonslinger: {token}")]
     ResidualTokensInTemplateString { token: Token, range: FileRange },
 
@@ -1878,6 +2815,12 @@ pub enum ParseDiagnostic {
 
     #[error("Werkwijze moet een definitie bevatten")]
     FunctionMustHaveDefinition { semicolon: Token, range: FileRange },
+
+    #[error("Dit bestand behoort al tot een module")]
+    DuplicateModuleDeclaration { token: Token },
+
+    #[error("`{}` hoort bovenaan het bestand te staan, vóór de andere statements", keyword.as_ref())]
+    ModuleHeaderNotAtTop { token: Token, keyword: Keyword },
 }
 
 impl ParseDiagnostic {
@@ -1890,16 +2833,21 @@ impl ParseDiagnostic {
             Self::ExpectedRightParen { token, .. } => token,
             Self::ExpectedColon { token, .. } => token,
             Self::ExpectedComma { token, .. } => token,
+            Self::ExpectedCommaAfterEnumerationVariant { token, .. } => token,
+            Self::ExpectedCommaAfterMatchArm { token, .. } => token,
             Self::ExpectedCommaAfterStructureMember { token, .. } => token,
             Self::ExpectedCommaOrGreaterThanInGenericTypePack { token, .. } => token,
             Self::ExpectedExtensionMethodPrefixWerkwijze { token, .. } => token,
+            Self::ExpectedFatArrowAfterPattern { token, .. } => token,
             Self::ExpectedGenericTypeName { token, .. } => token,
             Self::ExpectedGreaterThanForParameterPack { token, .. } => token,
             Self::ExpectedStructureMethodPrefixWerkwijze { token } => token,
             Self::ExpectedSemicolonAfterStatement { token, .. } => token,
             Self::ExpectedSemicolonOrCurlyBracketForFunction { token, .. } => token,
+            Self::ExpectedKeywordDoor { token } => token,
             Self::ExpectedKeywordOp { token, .. } => token,
             Self::ExpectedNameAfterNieuw { token } => token,
+            Self::ExpectedNameOfConstant { token } => token,
             Self::ExpectedNameOfField { token } => token,
             Self::ExpectedNameOfInterface { token } => token,
             Self::ExpectedNameOfInterfaceOrType { token, .. } => token,
@@ -1907,8 +2855,13 @@ impl ParseDiagnostic {
             Self::ExpectedNameOfVariable { token } => token,
             Self::ExpectedIdentifier { token, .. } => token,
             Self::ExpectedEqualsInsideVariable { token } => token,
+            Self::ExpectedEqualsInsideConstant { token } => token,
             Self::ExpectedRightCurlyBracket { token,  .. } => token,
             Self::ExpectedRightSquareBracketForArrayInitializer { token } => token,
+            Self::ExpectedRightSquareBracketForArrayLiteral { token } => token,
+            Self::ExpectedWerkwijzeAfterGedeeld { token } => token,
+            Self::ExpectedWerkwijzeAfterVisibility { token, .. } => token,
+            Self::ExpectedMemberAfterVisibility { token, .. } => token,
             Self::ExpectedRightSquareBracketForArrayQualifier { token } => token,
             Self::ExpectedRightSquareBracketForSubscript { token } => token,
             Self::ExpectedStructureMemberPrefixVeld { token } => token,
@@ -1916,10 +2869,16 @@ impl ParseDiagnostic {
             Self::ForStatementExpectedIteratorName { token } => token,
             Self::ForStatementExpectedInKeyword { token, .. } => token,
             Self::FunctionMustHaveDefinition { semicolon, .. } => semicolon,
+            Self::DuplicateModuleDeclaration { token } => token,
+            Self::ModuleHeaderNotAtTop { token, .. } => token,
+            Self::InvalidPattern { token } => token,
             Self::ParameterExpectedName { token } => token,
             Self::ParameterExpectedComma { token } => token,
             Self::PostfixMemberOrReferenceExpectedIdentifier { token, .. } => token,
             Self::RangeExpectedKeyword { token } => token,
+            Self::RangeExpectedNamedArgument { token } => token,
+            Self::RangeInclusiveExpectedBoolean { token } => token,
+            Self::RangeUnknownNamedArgument { token } => token,
             Self::ResidualTokensInTemplateString { token, .. } => token,
             Self::TypeExpectedSpecifierName { token } => token,
             Self::UnknownStartOfExpression { token } => token,
@@ -1932,7 +2891,10 @@ impl ParseDiagnostic {
     pub fn range(&self) -> FileRange {
         match self {
             Self::ExpectedColon { range, .. } => *range,
+            Self::ExpectedCommaAfterEnumerationVariant { location, .. } => location.as_zero_range(),
+            Self::ExpectedCommaAfterMatchArm { location, .. } => location.as_zero_range(),
             Self::ExpectedCommaAfterStructureMember { location, .. } => location.as_zero_range(),
+            Self::ExpectedFatArrowAfterPattern { range, .. } => *range,
             Self::ExpectedKeywordOp { range, .. } => *range,
             Self::ExpectedSemicolonAfterStatement { range, .. } => *range,
             Self::ExpectedSemicolonOrCurlyBracketForFunction { range, .. } => *range,
@@ -1961,12 +2923,13 @@ pub enum FunctionParsingContext {
     Function,
     Method,
     Interface,
+    Closure,
 }
 
 impl FunctionParsingContext {
     #[must_use]
     pub const fn require_body(&self) -> bool {
-        matches!(self, Self::Method)
+        matches!(self, Self::Method | Self::Closure)
     }
 }
 
@@ -2009,6 +2972,31 @@ mod tests {
     #[rstest]
     #[case("")]
     #[case("in")]
+    #[case("stel f = werkwijze(")]
+    #[case("werkwijze a(f: werkwijze(g32")]
+    #[case("stel a = Iets(")]
+    #[case("kies a { Iets(")]
+    #[case("stel a = b()?")]
+    #[case("kies a { Fout(")]
+    #[case("a +=")]
+    #[case("a[0] %= ")]
+    #[case("werkwijze a<T: ")]
+    #[case("structuur A<T: B + ")]
+    #[case("stel (a, ")]
+    #[case("stel (a, b) = (1, ")]
+    #[case("werkwijze a() -> (g32, ")]
+    #[case("stel a = [")]
+    #[case("stel a = [1, ")]
+    #[case("stel a: g32[] = ")]
+    #[case("structuur S { gedeeld ")]
+    #[case("structuur S { gedeeld werkwijze ")]
+    #[case("intern ")]
+    #[case("publiek werkwijze ")]
+    #[case("structuur S { intern ")]
+    #[case("structuur S { intern vast ")]
+    #[case("stel r = reeks(0, 10, ")]
+    #[case("stel r = reeks(0, 10, stap: ")]
+    #[case("volg i in reeks(0, 10, totEnMet: ")]
     fn ensure_not_crashing(#[case] input: &'static str) {
         let source_code = SourceCode::new_test(BabString::new_static(input));
         let tokens: Vec<Token> = Lexer::new(&source_code).collect();
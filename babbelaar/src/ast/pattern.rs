@@ -0,0 +1,163 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::fmt::{Display, Write};
+
+use crate::{BabString, Expression, FileRange, Ranged};
+
+/// `kies waarde { 1 => "een", _ => "iets anders" }`
+#[derive(Debug, Clone)]
+pub struct MatchExpression {
+    pub keyword: FileRange,
+    pub scrutinee: Box<Ranged<Expression>>,
+    pub left_curly_range: FileRange,
+    pub arms: Vec<MatchArm>,
+    pub right_curly_range: FileRange,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub pattern: Ranged<Pattern>,
+    pub arrow: FileRange,
+    pub expression: Ranged<Expression>,
+    pub comma: Option<FileRange>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// `_`
+    Wildcard,
+
+    /// A name that captures the value, e.g. `code`.
+    Binding(Ranged<BabString>),
+
+    Boolean(bool),
+    CharacterLiteral(char),
+    IntegerLiteral(i64),
+    StringLiteral(BabString),
+
+    /// `1..5`, where the `end` is excluded, just like `reeks(1, 5)`.
+    Range {
+        start: Ranged<i64>,
+        end: Ranged<i64>,
+    },
+
+    /// `Punt { x: 0, y }`
+    Structure {
+        name: Ranged<BabString>,
+        fields: Vec<FieldPattern>,
+    },
+
+    /// `Vorm.Cirkel(straal)`
+    EnumerationVariant {
+        enumeration: Ranged<BabString>,
+        variant: Ranged<BabString>,
+        fields: Vec<Ranged<Pattern>>,
+    },
+
+    /// `Iets(waarde)`
+    OptionalSome(Box<Ranged<Pattern>>),
+
+    /// `Niets`
+    OptionalNone,
+
+    /// `Goed(waarde)`
+    ResultOk(Box<Ranged<Pattern>>),
+
+    /// `Fout(fout)`
+    ResultError(Box<Ranged<Pattern>>),
+}
+
+impl Pattern {
+    /// Whether or not this pattern matches every value of the type it is
+    /// checked against.
+    #[must_use]
+    pub fn is_irrefutable(&self) -> bool {
+        match self {
+            Self::Wildcard | Self::Binding(..) => true,
+            Self::Structure { fields, .. } => fields.iter().all(|field| field.pattern.is_irrefutable()),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wildcard => f.write_char('_'),
+            Self::Binding(name) => f.write_str(name.value()),
+            Self::Boolean(b) => f.write_str(if *b { "waar" } else { "onwaar" }),
+            Self::CharacterLiteral(c) => {
+                f.write_char('\'')?;
+                f.write_char(*c)?;
+                f.write_char('\'')
+            }
+            Self::IntegerLiteral(i) => f.write_fmt(format_args!("{i}")),
+            Self::StringLiteral(s) => {
+                f.write_char('"')?;
+                f.write_str(s)?;
+                f.write_char('"')
+            }
+            Self::Range { start, end } => f.write_fmt(format_args!("{}..{}", start.value(), end.value())),
+            Self::Structure { name, fields } => {
+                f.write_str(name.value())?;
+                f.write_str(" { ")?;
+
+                for (idx, field) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    f.write_str(field.name.value())?;
+                    f.write_str(": ")?;
+                    field.pattern.value().fmt(f)?;
+                }
+
+                f.write_str(" }")
+            }
+            Self::EnumerationVariant { enumeration, variant, fields } => {
+                f.write_str(enumeration.value())?;
+                f.write_char('.')?;
+                f.write_str(variant.value())?;
+
+                if fields.is_empty() {
+                    return Ok(());
+                }
+
+                f.write_char('(')?;
+                for (idx, field) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    field.value().fmt(f)?;
+                }
+                f.write_char(')')
+            }
+            Self::OptionalSome(pattern) => {
+                f.write_str("Iets(")?;
+                pattern.value().fmt(f)?;
+                f.write_char(')')
+            }
+            Self::OptionalNone => f.write_str("Niets"),
+            Self::ResultOk(pattern) => {
+                f.write_str("Goed(")?;
+                pattern.value().fmt(f)?;
+                f.write_char(')')
+            }
+            Self::ResultError(pattern) => {
+                f.write_str("Fout(")?;
+                pattern.value().fmt(f)?;
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+/// `x: 0` or `y` inside of a [`Pattern::Structure`]. When the pattern is
+/// omitted, the field is bound to a local with the same name.
+#[derive(Debug, Clone)]
+pub struct FieldPattern {
+    pub name: Ranged<BabString>,
+    pub pattern: Ranged<Pattern>,
+}
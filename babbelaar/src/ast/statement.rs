@@ -1,7 +1,7 @@
 // Copyright (C) 2023 - 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::{AttributeList, BabString, Expression, FileRange, InterfaceSpecifier, InterfaceStatement, Method, Parameter, RangeExpression, Ranged, Structure, Type, TypeSpecifier};
+use crate::{AttributeList, BabString, Enumeration, Expression, FileRange, GenericTypeDeclaration, InterfaceSpecifier, InterfaceStatement, MathOperator, Method, Parameter, RangeExpression, Ranged, Structure, Type, TypeSpecifier, Visibility};
 
 #[derive(Debug, Clone)]
 pub struct Statement {
@@ -10,9 +10,23 @@ pub struct Statement {
     pub kind: StatementKind,
 }
 
+impl Statement {
+    #[must_use]
+    pub fn as_constant(&self) -> Option<&ConstantStatement> {
+        match &self.kind {
+            StatementKind::Constant(constant) => Some(constant),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum StatementKind {
     Assignment(Ranged<AssignStatement>),
+    Break(BreakStatement),
+    Constant(ConstantStatement),
+    Continue(ContinueStatement),
+    Enumeration(Enumeration),
     Expression(Ranged<Expression>),
     Extension(ExtensionStatement),
     Function(FunctionStatement),
@@ -22,6 +36,7 @@ pub enum StatementKind {
     Return(ReturnStatement),
     Structure(Structure),
     Variable(VariableStatement),
+    While(WhileStatement),
 }
 
 impl StatementKind {
@@ -37,13 +52,42 @@ impl StatementKind {
 pub struct AssignStatement {
     pub range: FileRange,
     pub equals_sign: FileRange,
+
+    /// The operator of a compound assignment like `som += i`, or `None` for a
+    /// plain `=`.
+    pub operator: Option<MathOperator>,
     pub destination: Ranged<Expression>,
     pub source: Ranged<Expression>,
 }
 
+/// `stop;`
+#[derive(Clone, Debug)]
+pub struct BreakStatement {
+    pub keyword: FileRange,
+}
+
+/// `vast MAX_GROOTTE: g32 = 16 * 1024;`
+///
+/// The value of a constant is evaluated at compile time, see
+/// [`ConstantEvaluator`](crate::ConstantEvaluator).
+#[derive(Clone, Debug)]
+pub struct ConstantStatement {
+    pub range: FileRange,
+    pub keyword: FileRange,
+    pub name: Ranged<BabString>,
+    pub ty: Ranged<Type>,
+    pub value: Ranged<Expression>,
+}
+
+/// `ga door;`
+#[derive(Clone, Debug)]
+pub struct ContinueStatement {
+    pub range: FileRange,
+}
+
 #[derive(Debug, Clone)]
 pub struct ExtensionStatement {
-    pub generic_types: Vec<Ranged<BabString>>,
+    pub generic_types: Vec<GenericTypeDeclaration>,
     pub interface_specifier: Option<Ranged<InterfaceSpecifier>>,
     pub type_specifier: Ranged<TypeSpecifier>,
     pub methods: Vec<Method>,
@@ -69,10 +113,27 @@ pub enum ForIterableKind {
 pub struct FunctionStatement {
     pub range: FileRange,
     pub name: Ranged<BabString>,
+
+    /// The type parameters of a generic `werkwijze`, e.g. the `T` in
+    /// `werkwijze eerste<T>(lijst: T[]) -> T`.
+    pub generic_types: Vec<GenericTypeDeclaration>,
     pub parameters: Vec<Parameter>,
     pub body: Option<Vec<Statement>>,
     pub parameters_right_paren_range: FileRange,
     pub return_type: Option<Ranged<Type>>,
+
+    /// The `publiek` or `intern` keyword in front of the `werkwijze`, if any.
+    pub visibility: Option<Ranged<Visibility>>,
+
+    /// The `///` comments in front of the `werkwijze`, if any.
+    pub documentation: Option<BabString>,
+}
+
+impl FunctionStatement {
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility.as_ref().map(|x| *x.value()).unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -80,6 +141,23 @@ pub struct IfStatement {
     pub range: FileRange,
     pub condition: Ranged<Expression>,
     pub body: Vec<Statement>,
+    pub else_statement: Option<ElseStatement>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ElseStatement {
+    pub range: FileRange,
+    pub keyword: FileRange,
+    pub kind: ElseStatementKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum ElseStatementKind {
+    /// `anders als <voorwaarde> { .. }`
+    ElseIf(Box<IfStatement>),
+
+    /// `anders { .. }`
+    Else(Vec<Statement>),
 }
 
 #[derive(Clone, Debug)]
@@ -91,6 +169,44 @@ pub struct ReturnStatement {
 #[derive(Clone, Debug)]
 pub struct VariableStatement {
     pub range: FileRange,
-    pub name: Ranged<BabString>,
+    pub name: VariableName,
+
+    /// The type given after the name, e.g. `stel lijst: g32[] = [];`
+    pub ty: Option<Ranged<Type>>,
     pub expression: Ranged<Expression>,
 }
+
+#[derive(Clone, Debug)]
+pub enum VariableName {
+    Single(Ranged<BabString>),
+
+    /// Destructures a tuple into its elements, e.g. `stel (q, r) = deel(a, b);`
+    Tuple(Vec<Ranged<BabString>>),
+}
+
+impl VariableName {
+    #[must_use]
+    pub fn names(&self) -> &[Ranged<BabString>] {
+        match self {
+            Self::Single(name) => std::slice::from_ref(name),
+            Self::Tuple(names) => names,
+        }
+    }
+
+    #[must_use]
+    pub fn range(&self) -> FileRange {
+        let names = self.names();
+        match (names.first(), names.last()) {
+            (Some(first), Some(last)) => FileRange::new(first.range().start(), last.range().end()),
+            _ => FileRange::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WhileStatement {
+    pub range: FileRange,
+    pub keyword: FileRange,
+    pub condition: Ranged<Expression>,
+    pub body: Vec<Statement>,
+}
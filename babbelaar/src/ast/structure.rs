@@ -1,39 +1,89 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::{AttributeList, BabString, BuiltinType, Expression, FileRange, FunctionStatement, Ranged, Type};
+use crate::{AttributeList, BabString, BuiltinType, ConstantStatement, Expression, FileRange, FunctionStatement, GenericTypeDeclaration, Parameter, Ranged, Type};
+
+/// `opsomming Vorm { Punt, Cirkel(straal: g32) }`
+#[derive(Debug, Clone)]
+pub struct Enumeration {
+    pub name: Ranged<BabString>,
+    pub left_curly_range: FileRange,
+    pub right_curly_range: FileRange,
+    pub variants: Vec<EnumerationVariant>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumerationVariant {
+    pub name: Ranged<BabString>,
+
+    /// The fields carried by the variant, e.g. `(straal: g32)`.
+    pub fields: Vec<Parameter>,
+}
 
 #[derive(Debug, Clone)]
 pub struct Field {
     pub attributes: AttributeList,
+
+    /// The `publiek` or `intern` keyword in front of the field, if any.
+    pub visibility: Option<Ranged<Visibility>>,
     pub name: Ranged<BabString>,
     pub ty: Ranged<Type>,
     pub default_value: Option<Ranged<Expression>>,
+
+    /// The `///` comments in front of the field, if any.
+    pub documentation: Option<BabString>,
+}
+
+impl Field {
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility.as_ref().map(|x| *x.value()).unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct InterfaceStatement {
     pub name: Ranged<BabString>,
-    pub generic_types: Vec<Ranged<BabString>>,
+    pub generic_types: Vec<GenericTypeDeclaration>,
     pub left_curly_range: FileRange,
     pub methods: Vec<Method>,
     pub right_curly_range: FileRange,
+
+    /// The `///` comments in front of the `koppelvlak`, if any.
+    pub documentation: Option<BabString>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Method {
     pub range: FileRange,
     pub function: FunctionStatement,
+
+    /// Whether this is a `gedeeld` method, which doesn't have a `dit` and is
+    /// called using the name of the structure, e.g. `Persoon.maak()`.
+    pub is_static: bool,
+}
+
+impl Method {
+    /// The visibility of the method, which is written in front of the
+    /// `werkwijze` (or `gedeeld`) keyword.
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.function.visibility()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Structure {
     pub name: Ranged<BabString>,
-    pub generic_types: Vec<Ranged<BabString>>,
+    pub generic_types: Vec<GenericTypeDeclaration>,
     pub left_curly_range: FileRange,
     pub right_curly_range: FileRange,
+    pub constants: Vec<ConstantStatement>,
     pub fields: Vec<Field>,
     pub methods: Vec<Method>,
+
+    /// The `///` comments in front of the `structuur`, if any.
+    pub documentation: Option<BabString>,
 }
 
 impl Structure {
@@ -45,8 +95,32 @@ impl Structure {
             generic_types: Vec::new(),
             left_curly_range: FileRange::INTERNAL,
             right_curly_range: FileRange::INTERNAL,
+            constants: Vec::new(),
             fields: Vec::new(),
             methods: Vec::new(),
+            documentation: None,
+        }
+    }
+}
+
+/// Who is allowed to use a field, method or function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// Usable from everywhere, which is the default.
+    #[default]
+    Publiek,
+
+    /// Only usable from within the same file, or from within the same
+    /// module if the declaration is part of one.
+    Intern,
+}
+
+impl Visibility {
+    #[must_use]
+    pub const fn keyword(&self) -> &'static str {
+        match self {
+            Self::Publiek => "publiek",
+            Self::Intern => "intern",
         }
     }
 }
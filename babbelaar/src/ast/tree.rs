@@ -3,11 +3,14 @@
 
 use std::{borrow::Cow, path::{Path, PathBuf}};
 
-use crate::{Statement, StatementKind};
+use crate::{BabString, ImportStatement, ModuleStatement, Statement, StatementKind};
 
 #[derive(Debug, Default, Clone)]
 pub struct ParseTree {
     pub(crate) path: PathBuf,
+    module: Option<ModuleStatement>,
+    imports: Vec<ImportStatement>,
+    constants: Vec<Statement>,
     functions: Vec<Statement>,
     statements: Vec<Statement>,
     structures: Vec<Statement>,
@@ -20,6 +23,9 @@ impl ParseTree {
     pub fn new(path: PathBuf) -> Self {
         Self {
             path,
+            module: None,
+            imports: Vec::new(),
+            constants: Vec::new(),
             functions: Vec::new(),
             statements: Vec::new(),
             structures: Vec::new(),
@@ -38,11 +44,42 @@ impl ParseTree {
         self.path.to_string_lossy()
     }
 
+    /// The `module` declaration of this file, if any.
+    #[must_use]
+    pub fn module(&self) -> Option<&ModuleStatement> {
+        self.module.as_ref()
+    }
+
+    /// The full name of the module this file is part of, or [`None`] if it
+    /// lives in the root namespace.
+    #[must_use]
+    pub fn module_path(&self) -> Option<BabString> {
+        self.module.as_ref().map(|module| module.path.name())
+    }
+
+    #[must_use]
+    pub fn imports(&self) -> &[ImportStatement] {
+        &self.imports
+    }
+
+    pub fn set_module(&mut self, module: ModuleStatement) {
+        self.module = Some(module);
+    }
+
+    pub fn push_import(&mut self, import: ImportStatement) {
+        self.imports.push(import);
+    }
+
     #[must_use]
     pub fn statements(&self) -> &[Statement] {
         &self.statements
     }
 
+    #[must_use]
+    pub fn constants(&self) -> &[Statement] {
+        &self.constants
+    }
+
     #[must_use]
     pub fn functions(&self) -> &[Statement] {
         &self.functions
@@ -64,7 +101,8 @@ impl ParseTree {
     }
 
     pub fn all(&self) -> impl Iterator<Item = &Statement> {
-        self.functions.iter()
+        self.constants.iter()
+            .chain(self.functions.iter())
             .chain(self.statements.iter())
             .chain(self.structures.iter())
             .chain(self.interfaces.iter())
@@ -73,8 +111,10 @@ impl ParseTree {
 
     pub fn push(&mut self, statement: Statement) {
         match &statement.kind {
+            StatementKind::Constant(..) => self.constants.push(statement),
             StatementKind::Function(..) => self.functions.push(statement),
             StatementKind::Structure(..) => self.structures.push(statement),
+            StatementKind::Enumeration(..) => self.structures.push(statement),
             StatementKind::Extension(..) => self.extensions.push(statement),
             StatementKind::Interface(..) => self.interfaces.push(statement),
             _ => self.statements.push(statement),
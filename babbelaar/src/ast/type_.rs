@@ -12,6 +12,15 @@ pub struct Parameter {
     pub ty: Ranged<Type>,
 }
 
+/// The declaration of a generic type parameter, e.g. the `T` in
+/// `structuur Lijst<T>`, optionally bound by one or more interfaces, as in
+/// `T: Vergelijkbaar + Weergeefbaar`.
+#[derive(Debug, Clone)]
+pub struct GenericTypeDeclaration {
+    pub name: Ranged<BabString>,
+    pub bounds: Vec<Ranged<InterfaceSpecifier>>,
+}
+
 #[derive(Debug, Clone)]
 pub struct InterfaceSpecifier {
     pub name: Ranged<BabString>,
@@ -43,6 +52,15 @@ pub enum TypeSpecifier {
         name: Ranged<BabString>,
         type_parameters: Ranged<Vec<Ranged<Type>>>,
     },
+
+    /// The type of a `werkwijze` as a value, e.g. `werkwijze(g32) -> bool`.
+    Function {
+        parameters: Vec<Ranged<Type>>,
+        return_type: Option<Box<Ranged<Type>>>,
+    },
+
+    /// Multiple values grouped together, e.g. `(g32, Slinger)`.
+    Tuple(Vec<Ranged<Type>>),
 }
 
 impl Display for TypeSpecifier {
@@ -67,6 +85,37 @@ impl Display for TypeSpecifier {
 
                 Ok(())
             }
+            Self::Function { parameters, return_type } => {
+                f.write_str("werkwijze(")?;
+
+                for (idx, param) in parameters.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+                    param.fmt(f)?;
+                }
+
+                f.write_char(')')?;
+
+                if let Some(return_type) = return_type {
+                    f.write_str(" -> ")?;
+                    return_type.fmt(f)?;
+                }
+
+                Ok(())
+            }
+            Self::Tuple(elements) => {
+                f.write_char('(')?;
+
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+                    element.fmt(f)?;
+                }
+
+                f.write_char(')')
+            }
         }
     }
 }
@@ -76,6 +125,7 @@ impl TypeSpecifier {
         match self {
             Self::BuiltIn(builtin) => builtin.name(),
             Self::Custom { name, .. } => BabString::clone(&name),
+            Self::Function { .. } | Self::Tuple(..) => BabString::new(self.to_string()),
         }
     }
 
@@ -83,7 +133,7 @@ impl TypeSpecifier {
     pub fn fully_qualified_name(&self) -> BabString {
         match self {
             Self::BuiltIn(ty) => ty.name(),
-            Self::Custom { .. } => {
+            Self::Custom { .. } | Self::Function { .. } | Self::Tuple(..) => {
                 BabString::new(self.to_string())
             }
         }
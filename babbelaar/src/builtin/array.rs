@@ -1,7 +1,7 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::{BabString, Interpreter, SemanticType, Value};
+use crate::{BabString, IntegerType, Interpreter, SemanticType, Value};
 
 use super::{functions::BuiltinFunctionSignature, BuiltinType};
 
@@ -131,15 +131,18 @@ fn array_lengte(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<V
         panic!("Invalid type given");
     };
     let values = values.borrow();
-    Value::Integer(values.len() as _)
+    Value::Integer {
+        value: values.len() as _,
+        ty: IntegerType::G32,
+    }
 }
 
-fn array_bevat(_: &mut dyn Interpreter, parameters: Vec<Value>, this: Option<Value>) -> Value {
+fn array_bevat(interpreter: &mut dyn Interpreter, parameters: Vec<Value>, this: Option<Value>) -> Value {
     let Value::Array { values, .. } = this.unwrap() else {
         panic!("Invalid type given");
     };
-    let values = values.borrow();
-    Value::Bool(values.contains(&parameters[0]))
+    let values = values.borrow().clone();
+    Value::Bool(values.iter().any(|value| interpreter.are_equal(value, &parameters[0])))
 }
 
 fn array_is_leeg(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<Value>) -> Value {
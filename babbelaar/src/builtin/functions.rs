@@ -3,7 +3,7 @@
 
 use std::{borrow::Cow, fmt::{Debug, Display}, io::stdin};
 
-use crate::{BuiltinType, Interpreter, Value};
+use crate::{BabString, BuiltinType, FileRange, Interpreter, SemanticGenericType, SemanticType, Value};
 
 pub type BuiltinFunctionSignature = &'static (dyn Fn(&mut dyn Interpreter, Vec<Value>, Option<Value>) -> Value + Send + Sync);
 
@@ -15,7 +15,7 @@ pub struct BuiltinFunction {
     pub function: BuiltinFunctionSignature,
     pub lsp_completion: Option<&'static str>,
     pub parameters: &'static [BuiltinFunctionParameter],
-    pub return_type: BuiltinType,
+    pub return_type: BuiltinFunctionReturnType,
     pub must_use: bool,
 }
 
@@ -41,6 +41,17 @@ impl BuiltinFunction {
         let params = if self.parameters.is_empty() { "" } else { "…" };
         format!("{}({params})", self.name)
     }
+
+    /// The generic types of this function, which is the single
+    /// [`BuiltinParameterType::Generic`] if any of the parameters uses it.
+    #[must_use]
+    pub fn generic_types(&self) -> Vec<SemanticGenericType> {
+        if self.parameters.iter().any(|parameter| parameter.typ == BuiltinParameterType::Generic) {
+            vec![BuiltinParameterType::generic_type()]
+        } else {
+            Vec::new()
+        }
+    }
 }
 
 impl Display for BuiltinFunction {
@@ -68,10 +79,82 @@ impl PartialEq for BuiltinFunction {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum BuiltinFunctionReturnType {
+    Builtin(BuiltinType),
+
+    /// `Resultaat<ok, error>`, for functions that can fail.
+    Result { ok: BuiltinType, error: BuiltinType },
+}
+
+impl BuiltinFunctionReturnType {
+    #[must_use]
+    pub fn resolve(&self) -> SemanticType {
+        match self {
+            Self::Builtin(ty) => SemanticType::Builtin(*ty),
+            Self::Result { ok, error } => SemanticType::Result {
+                ok: Box::new(SemanticType::Builtin(*ok)),
+                error: Box::new(SemanticType::Builtin(*error)),
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> BabString {
+        match self {
+            Self::Builtin(ty) => ty.name(),
+            Self::Result { ok, error } => BabString::new(format!("Resultaat<{}, {}>", ok.name(), error.name())),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct BuiltinFunctionParameter {
     pub name: &'static str,
-    pub typ: BuiltinType,
+    pub typ: BuiltinParameterType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuiltinParameterType {
+    Builtin(BuiltinType),
+
+    /// A value of any type, which is the same for all the generic parameters
+    /// of the function, like the `T` in `verwachtGelijk(a: T, b: T)`.
+    Generic,
+}
+
+impl BuiltinParameterType {
+    #[must_use]
+    pub fn generic_type() -> SemanticGenericType {
+        SemanticGenericType {
+            index: 0,
+            name: BabString::new_static("T"),
+            declaration_range: FileRange::INTERNAL,
+            bounds: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn resolve(&self) -> SemanticType {
+        match self {
+            Self::Builtin(ty) => SemanticType::Builtin(*ty),
+            Self::Generic => SemanticType::Generic(Self::generic_type()),
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> BabString {
+        match self {
+            Self::Builtin(ty) => ty.name(),
+            Self::Generic => Self::generic_type().name,
+        }
+    }
+}
+
+impl Display for BuiltinParameterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name())
+    }
 }
 
 pub fn schrijf(_: &mut dyn Interpreter, args: Vec<Value>, _this: Option<Value>) -> Value {
@@ -85,12 +168,35 @@ pub fn schrijf(_: &mut dyn Interpreter, args: Vec<Value>, _this: Option<Value>)
 
     println!();
 
-    Value::Null
+    Value::unit()
+}
+
+pub fn verwacht(interpreter: &mut dyn Interpreter, args: Vec<Value>, _this: Option<Value>) -> Value {
+    if !args[0].is_true() {
+        interpreter.report_failed_expectation(BabString::new_static("Verwacht dat de voorwaarde `waar` is, maar deze was `onwaar`"));
+    }
+
+    Value::unit()
+}
+
+pub fn verwacht_gelijk(interpreter: &mut dyn Interpreter, args: Vec<Value>, _this: Option<Value>) -> Value {
+    if !interpreter.are_equal(&args[0], &args[1]) {
+        interpreter.report_failed_expectation(format!("Verwacht dat `{}` gelijk is aan `{}`", args[0], args[1]).into());
+    }
+
+    Value::unit()
 }
 
 pub fn lees(_: &mut dyn Interpreter, _: Vec<Value>, _: Option<Value>) -> Value {
     let mut line = String::new();
-    stdin().read_line(&mut line).unwrap();
-    line.truncate(line.trim_end().len());
-    Value::String(line)
+    let result = match stdin().read_line(&mut line) {
+        Ok(0) => Err(String::from("Het einde van de invoer is bereikt")),
+        Ok(..) => {
+            line.truncate(line.trim_end().len());
+            Ok(line)
+        }
+        Err(e) => Err(format!("Kon niet lezen van de invoer: {e}")),
+    };
+
+    Value::Result(result.map(|x| Box::new(Value::String(x))).map_err(|x| Box::new(Value::String(x))))
 }
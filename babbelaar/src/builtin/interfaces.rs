@@ -0,0 +1,64 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{BiOperator, BuiltinType, Comparison, MathOperator};
+
+/// An interface that is always available, which allows structures to be used
+/// with an operator or to be shown as text, by implementing it in an
+/// `uitbreiding`, e.g. `uitbreiding Optelbaar<Geld> op Geld`.
+///
+/// The interfaces of operators have a single generic parameter `T`, which is
+/// the type of the right-hand side of the operator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuiltinInterface {
+    pub name: &'static str,
+    pub documentation: &'static str,
+    pub method_name: &'static str,
+    pub parameter_name: Option<&'static str>,
+    pub return_type: BuiltinInterfaceReturnType,
+}
+
+impl BuiltinInterface {
+    /// The name of the generic parameter of the interfaces of operators.
+    pub const GENERIC_TYPE_NAME: &'static str = "T";
+
+    /// Returns the interface that should be implemented for a structure to be
+    /// used as the left-hand side of the given `operator`.
+    #[must_use]
+    pub fn for_operator(operator: BiOperator) -> Option<&'static BuiltinInterface> {
+        let name = match operator {
+            BiOperator::Math(MathOperator::Add) => "Optelbaar",
+            BiOperator::Math(MathOperator::Subtract) => "Aftrekbaar",
+            BiOperator::Math(MathOperator::Multiply) => "Vermenigvuldigbaar",
+            BiOperator::Math(MathOperator::Divide) => "Deelbaar",
+            BiOperator::Comparison(Comparison::Equality | Comparison::Inequality) => "Gelijkwaardig",
+            BiOperator::Comparison(..) => "Vergelijkbaar",
+            BiOperator::Math(..) => return None,
+        };
+
+        super::Builtin::interface_by_name(name)
+    }
+
+    /// Returns whether the builtin type `ty` implements this interface by
+    /// itself, such that e.g. `grootste<T: Vergelijkbaar>(3, 9)` is allowed.
+    #[must_use]
+    pub fn is_implemented_by(&self, ty: BuiltinType) -> bool {
+        let is_number = ty.integer_type().is_some() || ty == BuiltinType::Kommagetal;
+
+        match self.name {
+            "Optelbaar" => is_number || ty == BuiltinType::Slinger,
+            "Aftrekbaar" | "Vermenigvuldigbaar" | "Deelbaar" => is_number,
+            "Vergelijkbaar" => is_number || ty == BuiltinType::Teken,
+            "Gelijkwaardig" | "Weergeefbaar" => !matches!(ty, BuiltinType::Null | BuiltinType::Reeks),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuiltinInterfaceReturnType {
+    /// The generic parameter `T` of the interface.
+    Generic,
+
+    Builtin(BuiltinType),
+}
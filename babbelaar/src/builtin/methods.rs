@@ -3,9 +3,9 @@
 
 use std::fmt::Debug;
 
-use crate::{BuiltinFunction, BuiltinType, Interpreter, Value};
+use crate::{BuiltinFunction, BuiltinFunctionReturnType, BuiltinType, IntegerType, Interpreter, Value};
 
-use super::{functions::BuiltinFunctionSignature, PointerMethod, ArrayMethod, BuiltinFunctionParameter};
+use super::{functions::BuiltinFunctionSignature, PointerMethod, ArrayMethod, BuiltinFunctionParameter, BuiltinParameterType};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum BuiltinMethodReference {
@@ -59,9 +59,68 @@ impl From<&'static PointerMethod> for BuiltinMethodReference {
 pub(super) static METHODS_BOOL: &'static [BuiltinFunction] = &[];
 pub(super) static METHODS_G8: &'static [BuiltinFunction] = &[];
 pub(super) static METHODS_G16: &'static [BuiltinFunction] = &[];
-pub(super) static METHODS_G32: &'static [BuiltinFunction] = &[];
+pub(super) static METHODS_G32: &'static [BuiltinFunction] = &[
+    BuiltinFunction {
+        name: "naarKommagetal",
+        documentation: "Zet dit getal om naar een kommagetal.\n## Voorbeeld\n```babbelaar\n3.naarKommagetal() // = 3,0\n```",
+        inline_detail: "Zet om naar een kommagetal.",
+        function: &g32_naar_kommagetal,
+        lsp_completion: None,
+        parameters: &[],
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Kommagetal),
+        must_use: true,
+    },
+];
+
+pub(super) static METHODS_G64: &[BuiltinFunction] = &[];
+
+pub(super) static METHODS_KOMMAGETAL: &[BuiltinFunction] = &[
+    BuiltinFunction {
+        name: "naarGetal",
+        documentation: "Zet dit kommagetal om naar een geheel getal, door de cijfers achter de komma weg te laten.\n## Voorbeeld\n```babbelaar\n3,7.naarGetal() // = 3\n```",
+        inline_detail: "Zet om naar een geheel getal.",
+        function: &kommagetal_naar_getal,
+        lsp_completion: None,
+        parameters: &[],
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::G32),
+        must_use: true,
+    },
+    BuiltinFunction {
+        name: "afronden",
+        documentation: "Rond dit kommagetal af naar het dichtstbijzijnde gehele getal.\n## Voorbeeld\n```babbelaar\n3,7.afronden() // = 4\n```",
+        inline_detail: "Rond af naar een geheel getal.",
+        function: &kommagetal_afronden,
+        lsp_completion: None,
+        parameters: &[],
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::G32),
+        must_use: true,
+    },
+];
+pub(super) static METHODS_N8: &[BuiltinFunction] = &[];
+pub(super) static METHODS_N16: &[BuiltinFunction] = &[];
+pub(super) static METHODS_N32: &[BuiltinFunction] = &[];
+pub(super) static METHODS_N64: &[BuiltinFunction] = &[];
+
 pub(super) static METHODS_NULL: &'static [BuiltinFunction] = &[];
 
+pub(super) static METHODS_REEKS: &[BuiltinFunction] = &[
+    BuiltinFunction {
+        name: "bevat",
+        documentation: "Controleer of dit getal één van de getallen van de reeks is.\n## Voorbeeld\n```babbelaar\nreeks(0, 10, stap: 2).bevat(4) // = waar\nreeks(0, 10, stap: 2).bevat(5) // = onwaar\n```",
+        inline_detail: "Controleer of het getal in de reeks zit.",
+        function: &reeks_bevat,
+        lsp_completion: None,
+        parameters: &[
+            BuiltinFunctionParameter {
+                name: "getal",
+                typ: BuiltinParameterType::Builtin(BuiltinType::G32),
+            },
+        ],
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Bool),
+        must_use: true,
+    },
+];
+
 pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
     BuiltinFunction {
         name: "bevat",
@@ -72,10 +131,10 @@ pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
         parameters: &[
             BuiltinFunctionParameter {
                 name: "naald",
-                typ: BuiltinType::Slinger,
+                typ: BuiltinParameterType::Builtin(BuiltinType::Slinger),
             },
         ],
-        return_type: BuiltinType::Bool,
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Bool),
         must_use: true,
     },
     BuiltinFunction {
@@ -85,7 +144,7 @@ pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
         function: &slinger_is_leeg,
         lsp_completion: None,
         parameters: &[],
-        return_type: BuiltinType::Bool,
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Bool),
         must_use: true,
     },
     BuiltinFunction {
@@ -97,10 +156,10 @@ pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
         parameters: &[
             BuiltinFunctionParameter {
                 name: "subtekst",
-                typ: BuiltinType::Slinger,
+                typ: BuiltinParameterType::Builtin(BuiltinType::Slinger),
             },
         ],
-        return_type: BuiltinType::Bool,
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Bool),
         must_use: true,
     },
     BuiltinFunction {
@@ -112,10 +171,10 @@ pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
         parameters: &[
             BuiltinFunctionParameter {
                 name: "subtekst",
-                typ: BuiltinType::Slinger,
+                typ: BuiltinParameterType::Builtin(BuiltinType::Slinger),
             },
         ],
-        return_type: BuiltinType::Bool,
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Bool),
         must_use: true,
     },
     BuiltinFunction {
@@ -125,7 +184,7 @@ pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
         function: &slinger_naar_kleine_letters,
         lsp_completion: None,
         parameters: &[],
-        return_type: BuiltinType::Slinger,
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Slinger),
         must_use: true,
     },
     BuiltinFunction {
@@ -135,7 +194,7 @@ pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
         function: &slinger_naar_hoofdletters,
         lsp_completion: None,
         parameters: &[],
-        return_type: BuiltinType::Slinger,
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Slinger),
         must_use: true,
     },
     BuiltinFunction {
@@ -145,15 +204,60 @@ pub(super) static METHODS_SLINGER: &'static [BuiltinFunction] = &[
         function: &slinger_lengte,
         lsp_completion: None,
         parameters: &[],
-        return_type: BuiltinType::G32,
+        return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::G32),
         must_use: true,
     },
 ];
 
 pub(super) static METHODS_TEKEN: &'static [BuiltinFunction] = &[];
 
+pub fn g32_naar_kommagetal(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<Value>) -> Value {
+    let Some(Value::Integer { value, ty }) = this else {
+        panic!("Ongeldige waarde voor `naarKommagetal`: {this:?}");
+    };
+
+    Value::Float(ty.widen(value) as f64)
+}
+
+pub fn kommagetal_naar_getal(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<Value>) -> Value {
+    let Some(Value::Float(float)) = this else {
+        panic!("Ongeldige waarde voor `naarGetal`: {this:?}");
+    };
+
+    Value::Integer {
+        value: float.trunc() as i32 as i64,
+        ty: IntegerType::G32,
+    }
+}
+
+pub fn kommagetal_afronden(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<Value>) -> Value {
+    let Some(Value::Float(float)) = this else {
+        panic!("Ongeldige waarde voor `afronden`: {this:?}");
+    };
+
+    Value::Integer {
+        value: float.round() as i32 as i64,
+        ty: IntegerType::G32,
+    }
+}
+
 pub fn slinger_lengte(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<Value>) -> Value {
-    Value::Integer(this.unwrap().to_string().len() as _)
+    Value::Integer {
+        value: this.unwrap().to_string().len() as _,
+        ty: IntegerType::G32,
+    }
+}
+
+pub fn reeks_bevat(_: &mut dyn Interpreter, parameters: Vec<Value>, this: Option<Value>) -> Value {
+    let Some(Value::Range(range)) = this else {
+        panic!("`bevat` verwacht een reeks, maar kreeg: {this:?}");
+    };
+
+    let Value::Integer { value, .. } = parameters[0] else {
+        panic!("`bevat` verwacht een getal, maar kreeg: {:?}", parameters[0]);
+    };
+
+    Value::Bool(range.contains(value))
 }
 
 pub fn slinger_bevat(_: &mut dyn Interpreter, parameters: Vec<Value>, this: Option<Value>) -> Value {
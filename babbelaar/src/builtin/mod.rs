@@ -3,16 +3,18 @@
 
 mod array;
 mod functions;
+mod interfaces;
 mod methods;
 mod pointer;
 mod types;
 
 pub use self::{
     array::{BuiltinArray, ArrayMethod, ArrayMethodParameter, ArrayTypeRef},
-    functions::{BuiltinFunction, BuiltinFunctionParameter},
+    functions::{BuiltinFunction, BuiltinFunctionParameter, BuiltinFunctionReturnType, BuiltinParameterType},
+    interfaces::{BuiltinInterface, BuiltinInterfaceReturnType},
     methods::BuiltinMethodReference,
     pointer::{BuiltinPointer, PointerMethod},
-    types::BuiltinType,
+    types::{BuiltinType, IntegerType},
 };
 
 pub struct Builtin;
@@ -23,6 +25,11 @@ impl Builtin {
         Self::TYPES.iter().find(|x| x.name() == name).copied()
     }
 
+    #[must_use]
+    pub fn interface_by_name(name: &str) -> Option<&'static BuiltinInterface> {
+        Self::INTERFACES.iter().find(|x| x.name == name)
+    }
+
     #[must_use]
     pub fn array() -> BuiltinArray {
         BuiltinArray
@@ -43,22 +50,56 @@ impl Builtin {
             parameters: &[
                 BuiltinFunctionParameter {
                     name: "uitvoer",
-                    typ: BuiltinType::Slinger,
+                    typ: BuiltinParameterType::Builtin(BuiltinType::Slinger),
                 }
             ],
-            return_type: BuiltinType::Null,
+            return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Null),
             must_use: true,
         },
         BuiltinFunction {
             name: "lees",
-            documentation: "Lees tekst vanuit de invoer",
+            documentation: "Lees een regel tekst vanuit de invoer. Geeft een `Fout` als er niets meer te lezen valt.",
             inline_detail: "Lees tekst",
             function: &functions::lees,
             lsp_completion: Some("lees();$0"),
             parameters: &[],
-            return_type: BuiltinType::Null,
+            return_type: BuiltinFunctionReturnType::Result { ok: BuiltinType::Slinger, error: BuiltinType::Slinger },
             must_use: true,
-        }
+        },
+        BuiltinFunction {
+            name: "verwacht",
+            documentation: "Verwacht dat de voorwaarde `waar` is. Zo niet, dan faalt de `@test` waarin dit gebeurt.\n## Voorbeeld\n```babbelaar\n@test\nwerkwijze optellen() {\n    verwacht(1 + 1 == 2);\n}\n```",
+            inline_detail: "Verwacht dat de voorwaarde waar is",
+            function: &functions::verwacht,
+            lsp_completion: Some("verwacht($1);$0"),
+            parameters: &[
+                BuiltinFunctionParameter {
+                    name: "voorwaarde",
+                    typ: BuiltinParameterType::Builtin(BuiltinType::Bool),
+                }
+            ],
+            return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Null),
+            must_use: false,
+        },
+        BuiltinFunction {
+            name: "verwachtGelijk",
+            documentation: "Verwacht dat de twee waarden gelijk zijn, zoals met `==`. Zo niet, dan faalt de `@test` waarin dit gebeurt.\n## Voorbeeld\n```babbelaar\n@test\nwerkwijze optellen() {\n    verwachtGelijk(1 + 1, 2);\n}\n```",
+            inline_detail: "Verwacht dat de waarden gelijk zijn",
+            function: &functions::verwacht_gelijk,
+            lsp_completion: Some("verwachtGelijk($1, $2);$0"),
+            parameters: &[
+                BuiltinFunctionParameter {
+                    name: "uitkomst",
+                    typ: BuiltinParameterType::Generic,
+                },
+                BuiltinFunctionParameter {
+                    name: "verwachting",
+                    typ: BuiltinParameterType::Generic,
+                },
+            ],
+            return_type: BuiltinFunctionReturnType::Builtin(BuiltinType::Null),
+            must_use: false,
+        },
     ];
 
     pub const TYPES: &'static [BuiltinType] = &[
@@ -66,7 +107,66 @@ impl Builtin {
         BuiltinType::G8,
         BuiltinType::G16,
         BuiltinType::G32,
+        BuiltinType::G64,
+        BuiltinType::Kommagetal,
+        BuiltinType::N8,
+        BuiltinType::N16,
+        BuiltinType::N32,
+        BuiltinType::N64,
+        BuiltinType::Reeks,
         BuiltinType::Slinger,
         BuiltinType::Teken,
     ];
+
+    pub const INTERFACES: &'static [BuiltinInterface] = &[
+        BuiltinInterface {
+            name: "Optelbaar",
+            documentation: "Maakt het mogelijk om waarden van dit type op te tellen met `+`.",
+            method_name: "optellen",
+            parameter_name: Some("ander"),
+            return_type: BuiltinInterfaceReturnType::Generic,
+        },
+        BuiltinInterface {
+            name: "Aftrekbaar",
+            documentation: "Maakt het mogelijk om waarden van dit type af te trekken met `-`.",
+            method_name: "aftrekken",
+            parameter_name: Some("ander"),
+            return_type: BuiltinInterfaceReturnType::Generic,
+        },
+        BuiltinInterface {
+            name: "Vermenigvuldigbaar",
+            documentation: "Maakt het mogelijk om waarden van dit type te vermenigvuldigen met `*`.",
+            method_name: "vermenigvuldigen",
+            parameter_name: Some("ander"),
+            return_type: BuiltinInterfaceReturnType::Generic,
+        },
+        BuiltinInterface {
+            name: "Deelbaar",
+            documentation: "Maakt het mogelijk om waarden van dit type te delen met `/`.",
+            method_name: "delen",
+            parameter_name: Some("ander"),
+            return_type: BuiltinInterfaceReturnType::Generic,
+        },
+        BuiltinInterface {
+            name: "Vergelijkbaar",
+            documentation: "Maakt het mogelijk om waarden van dit type te ordenen met `<`, `<=`, `>` en `>=`. De werkwijze `vergelijk` geeft een negatief getal als deze waarde kleiner is dan `ander`, nul als ze gelijk zijn en anders een positief getal.",
+            method_name: "vergelijk",
+            parameter_name: Some("ander"),
+            return_type: BuiltinInterfaceReturnType::Builtin(BuiltinType::G32),
+        },
+        BuiltinInterface {
+            name: "Gelijkwaardig",
+            documentation: "Maakt het mogelijk om waarden van dit type te vergelijken met `==` en `!=`.",
+            method_name: "isGelijkAan",
+            parameter_name: Some("ander"),
+            return_type: BuiltinInterfaceReturnType::Builtin(BuiltinType::Bool),
+        },
+        BuiltinInterface {
+            name: "Weergeefbaar",
+            documentation: "Bepaalt hoe waarden van dit type als tekst worden weergegeven, bijvoorbeeld in `€\"{waarde}\"` of met `schrijf(waarde)`. Zonder deze uitbreiding wordt een structuur weergegeven als `Persoon { naam: \"Jan\", leeftijd: 35 }`.",
+            method_name: "weergeven",
+            parameter_name: None,
+            return_type: BuiltinInterfaceReturnType::Builtin(BuiltinType::Slinger),
+        },
+    ];
 }
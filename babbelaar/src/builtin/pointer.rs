@@ -6,7 +6,7 @@ use std::ptr::null;
 
 use crate::{BabString, Interpreter, SemanticType, Value, ValueType};
 
-use super::{functions::BuiltinFunctionSignature, BuiltinType};
+use super::{functions::BuiltinFunctionSignature, BuiltinType, IntegerType};
 
 pub struct BuiltinPointer;
 
@@ -109,20 +109,27 @@ pub(super) static METHODS_ARRAY: &'static [PointerMethod] = &[
     },
 ];
 
-fn pointer_krijg(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<Value>) -> Value {
+fn pointer_krijg(interpreter: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<Value>) -> Value {
     let Value::Pointer { address, ty } = this.unwrap() else {
         panic!("Invalid type given");
     };
 
     match ty {
-        ValueType::Array(..) => todo!(),
+        ValueType::Array(..) => interpreter.report_unsupported(BabString::new_static("Een opeenvolging lezen achter een wijzer")),
 
         ValueType::Builtin(ty) => match ty {
             BuiltinType::Bool => Value::Bool(unsafe { *(address as *const bool) }),
-            BuiltinType::G8 => Value::Integer(unsafe { *(address as *const i8) } as i64),
-            BuiltinType::G16 => Value::Integer(unsafe { *(address as *const i16) } as i64),
-            BuiltinType::G32 => Value::Integer(unsafe { *(address as *const i32) } as i64),
-            BuiltinType::Null => Value::Null,
+            BuiltinType::G8 => Value::Integer { value: unsafe { *(address as *const i8) } as i64, ty: IntegerType::G8 },
+            BuiltinType::G16 => Value::Integer { value: unsafe { *(address as *const i16) } as i64, ty: IntegerType::G16 },
+            BuiltinType::G32 => Value::Integer { value: unsafe { *(address as *const i32) } as i64, ty: IntegerType::G32 },
+            BuiltinType::G64 => Value::Integer { value: unsafe { *(address as *const i64) }, ty: IntegerType::G64 },
+            BuiltinType::N8 => Value::Integer { value: unsafe { *(address as *const u8) } as i64, ty: IntegerType::N8 },
+            BuiltinType::N16 => Value::Integer { value: unsafe { *(address as *const u16) } as i64, ty: IntegerType::N16 },
+            BuiltinType::N32 => Value::Integer { value: unsafe { *(address as *const u32) } as i64, ty: IntegerType::N32 },
+            BuiltinType::N64 => Value::Integer { value: unsafe { *(address as *const u64) } as i64, ty: IntegerType::N64 },
+            BuiltinType::Kommagetal => Value::Float(unsafe { *(address as *const f64) }),
+            BuiltinType::Null => Value::unit(),
+            BuiltinType::Reeks => interpreter.report_unsupported(BabString::new_static("Een `Reeks` lezen achter een wijzer")),
             BuiltinType::Slinger => {
                 let start = address as *const u8;
 
@@ -152,6 +159,11 @@ fn pointer_krijg(_: &mut dyn Interpreter, _parameters: Vec<Value>, this: Option<
                 ty: *value_type,
             }
         }
-        ValueType::Structure(..) => todo!(),
+        ValueType::Structure(..) => interpreter.report_unsupported(BabString::new_static("Een structuur lezen achter een wijzer")),
+        ValueType::Enumeration(..) => interpreter.report_unsupported(BabString::new_static("Een opsomming lezen achter een wijzer")),
+        ValueType::Function => interpreter.report_unsupported(BabString::new_static("Een werkwijze lezen achter een wijzer")),
+        ValueType::Optional => interpreter.report_unsupported(BabString::new_static("Een `Misschien` lezen achter een wijzer")),
+        ValueType::Result => interpreter.report_unsupported(BabString::new_static("Een `Resultaat` lezen achter een wijzer")),
+        ValueType::Tuple(..) => interpreter.report_unsupported(BabString::new_static("Een tupel lezen achter een wijzer")),
     }
 }
@@ -5,7 +5,7 @@ use std::fmt::Display;
 
 use crate::{BabString, BuiltinFunction};
 
-use super::methods::{METHODS_BOOL, METHODS_G16, METHODS_G32, METHODS_G8, METHODS_NULL, METHODS_SLINGER, METHODS_TEKEN};
+use super::methods::{METHODS_BOOL, METHODS_G16, METHODS_G32, METHODS_G64, METHODS_G8, METHODS_KOMMAGETAL, METHODS_N16, METHODS_N32, METHODS_N64, METHODS_N8, METHODS_NULL, METHODS_REEKS, METHODS_SLINGER, METHODS_TEKEN};
 
 #[derive(Debug, Clone, Copy, PartialEq, Hash)]
 #[repr(u8)]
@@ -14,7 +14,14 @@ pub enum BuiltinType {
     G8,
     G16,
     G32,
+    G64,
+    Kommagetal,
+    N8,
+    N16,
+    N32,
+    N64,
     Null,
+    Reeks,
     Slinger,
     Teken,
 }
@@ -27,7 +34,14 @@ impl BuiltinType {
             Self::G8 => BabString::new_static("g8"),
             Self::G16 => BabString::new_static("g16"),
             Self::G32 => BabString::new_static("g32"),
+            Self::G64 => BabString::new_static("g64"),
+            Self::Kommagetal => BabString::new_static("kommagetal"),
+            Self::N8 => BabString::new_static("n8"),
+            Self::N16 => BabString::new_static("n16"),
+            Self::N32 => BabString::new_static("n32"),
+            Self::N64 => BabString::new_static("n64"),
             Self::Null => BabString::new_static("null"),
+            Self::Reeks => BabString::new_static("Reeks"),
             Self::Slinger => BabString::new_static("Slinger"),
             Self::Teken => BabString::new_static("teken"),
         }
@@ -81,7 +95,14 @@ impl BuiltinType {
             Self::G8 => "Een geheel getal met 8-bits precisie.",
             Self::G16 => "Een geheel getal met 16-bits precisie.",
             Self::G32 => "Een geheel getal met 32-bits precisie.",
+            Self::G64 => "Een geheel getal met 64-bits precisie.",
+            Self::Kommagetal => "Een kommagetal met 64-bits precisie, zoals `3,14`.",
+            Self::N8 => "Een natuurlijk (niet-negatief) getal met 8-bits precisie.",
+            Self::N16 => "Een natuurlijk (niet-negatief) getal met 16-bits precisie.",
+            Self::N32 => "Een natuurlijk (niet-negatief) getal met 32-bits precisie.",
+            Self::N64 => "Een natuurlijk (niet-negatief) getal met 64-bits precisie.",
             Self::Null => "Tijdelijk type, niet gebruiken",
+            Self::Reeks => "Een reeks van gehele getallen, zoals `reeks(0, 10, stap: 2)`.",
             Self::Slinger => "Een stuk tekst, schrijfbaar met bijvoorbeeld: \"Hallo, slinger!\"",
             Self::Teken => "Een letter, cijfer of speciaal teken.",
         })
@@ -94,11 +115,34 @@ impl BuiltinType {
             Self::G8 => METHODS_G8,
             Self::G16 => METHODS_G16,
             Self::G32 => METHODS_G32,
+            Self::G64 => METHODS_G64,
+            Self::Kommagetal => METHODS_KOMMAGETAL,
+            Self::N8 => METHODS_N8,
+            Self::N16 => METHODS_N16,
+            Self::N32 => METHODS_N32,
+            Self::N64 => METHODS_N64,
             Self::Null => METHODS_NULL,
+            Self::Reeks => METHODS_REEKS,
             Self::Slinger => METHODS_SLINGER,
             Self::Teken => METHODS_TEKEN,
         }
     }
+
+    /// The kind of integer, if this type is an integer.
+    #[must_use]
+    pub const fn integer_type(&self) -> Option<IntegerType> {
+        match self {
+            Self::G8 => Some(IntegerType::G8),
+            Self::G16 => Some(IntegerType::G16),
+            Self::G32 => Some(IntegerType::G32),
+            Self::G64 => Some(IntegerType::G64),
+            Self::N8 => Some(IntegerType::N8),
+            Self::N16 => Some(IntegerType::N16),
+            Self::N32 => Some(IntegerType::N32),
+            Self::N64 => Some(IntegerType::N64),
+            _ => None,
+        }
+    }
 }
 
 impl Display for BuiltinType {
@@ -106,3 +150,113 @@ impl Display for BuiltinType {
         f.write_str(&self.name())
     }
 }
+
+/// The integer types, with their width and whether they are signed (`gN`) or
+/// unsigned (`nN`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntegerType {
+    G8,
+    G16,
+    G32,
+    G64,
+    N8,
+    N16,
+    N32,
+    N64,
+}
+
+impl IntegerType {
+    #[must_use]
+    pub const fn bits(&self) -> u32 {
+        match self {
+            Self::G8 | Self::N8 => 8,
+            Self::G16 | Self::N16 => 16,
+            Self::G32 | Self::N32 => 32,
+            Self::G64 | Self::N64 => 64,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_signed(&self) -> bool {
+        matches!(self, Self::G8 | Self::G16 | Self::G32 | Self::G64)
+    }
+
+    #[must_use]
+    pub const fn min(&self) -> i128 {
+        if self.is_signed() {
+            -(1 << (self.bits() - 1))
+        } else {
+            0
+        }
+    }
+
+    #[must_use]
+    pub const fn max(&self) -> i128 {
+        if self.is_signed() {
+            (1 << (self.bits() - 1)) - 1
+        } else {
+            (1 << self.bits()) - 1
+        }
+    }
+
+    #[must_use]
+    pub const fn contains(&self, value: i128) -> bool {
+        self.min() <= value && value <= self.max()
+    }
+
+    /// Converts the value to the storage form of this type, or returns `None`
+    /// if the value doesn't fit in this type.
+    #[must_use]
+    pub const fn checked(&self, value: i128) -> Option<i64> {
+        if self.contains(value) {
+            Some(value as i64)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the value to the storage form of this type, discarding the
+    /// bits that don't fit (like a cast in C).
+    #[must_use]
+    pub const fn wrapping(&self, value: i128) -> i64 {
+        let bits = self.bits();
+        let truncated = value & ((1 << bits) - 1);
+
+        if self.is_signed() && truncated > self.max() {
+            (truncated - (1 << bits)) as i64
+        } else {
+            truncated as i64
+        }
+    }
+
+    /// The actual value of the storage form. This only differs for `n64`,
+    /// since it can hold values above `i64::MAX`.
+    #[must_use]
+    pub const fn widen(&self, stored: i64) -> i128 {
+        match self {
+            Self::N64 => stored as u64 as i128,
+            _ => stored as i128,
+        }
+    }
+}
+
+impl From<IntegerType> for BuiltinType {
+    fn from(value: IntegerType) -> Self {
+        match value {
+            IntegerType::G8 => Self::G8,
+            IntegerType::G16 => Self::G16,
+            IntegerType::G32 => Self::G32,
+            IntegerType::G64 => Self::G64,
+            IntegerType::N8 => Self::N8,
+            IntegerType::N16 => Self::N16,
+            IntegerType::N32 => Self::N32,
+            IntegerType::N64 => Self::N64,
+        }
+    }
+}
+
+impl Display for IntegerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        BuiltinType::from(*self).fmt(f)
+    }
+}
@@ -0,0 +1,485 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::{cmp::Ordering, fmt::Display};
+
+use strum::AsRefStr;
+use thiserror::Error;
+
+use crate::{BabString, BiExpression, BiOperator, BuiltinType, Comparison, ConstantStatement, Expression, FileRange, IntegerType, MathOperator, PostfixExpression, PostfixExpressionKind, PrimaryExpression, Ranged, Type, TypeSpecifier, UnaryExpression, UnaryExpressionKind, Value};
+
+/// A value that is known at compile time, e.g. the value of a
+/// `vast MAX_GROOTTE: g32 = 16 * 1024;`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Bool(bool),
+    Character(char),
+    Float(f64),
+    Integer {
+        value: i64,
+        ty: IntegerType,
+    },
+    String(BabString),
+}
+
+impl ConstantValue {
+    #[must_use]
+    pub fn ty(&self) -> BuiltinType {
+        match self {
+            Self::Bool(..) => BuiltinType::Bool,
+            Self::Character(..) => BuiltinType::Teken,
+            Self::Float(..) => BuiltinType::Kommagetal,
+            Self::Integer { ty, .. } => BuiltinType::from(*ty),
+            Self::String(..) => BuiltinType::Slinger,
+        }
+    }
+
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Bool(lhs), Self::Bool(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Character(lhs), Self::Character(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Float(lhs), Self::Float(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Integer { value: lhs, ty: lhs_ty }, Self::Integer { value: rhs, ty: rhs_ty }) => {
+                lhs_ty.widen(*lhs).partial_cmp(&rhs_ty.widen(*rhs))
+            }
+            (Self::String(lhs), Self::String(rhs)) => lhs.as_str().partial_cmp(rhs.as_str()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ConstantValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(true) => f.write_str("waar"),
+            Self::Bool(false) => f.write_str("onwaar"),
+            Self::Character(c) => write!(f, "'{c}'"),
+            Self::Float(value) => value.fmt(f),
+            Self::Integer { value, ty } => ty.widen(*value).fmt(f),
+            Self::String(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
+
+impl From<ConstantValue> for Value {
+    fn from(value: ConstantValue) -> Self {
+        match value {
+            ConstantValue::Bool(value) => Value::Bool(value),
+            ConstantValue::Character(value) => Value::Character(value),
+            ConstantValue::Float(value) => Value::Float(value),
+            ConstantValue::Integer { value, ty } => Value::Integer { value, ty },
+            ConstantValue::String(value) => Value::String(value.to_string()),
+        }
+    }
+}
+
+impl TryFrom<Value> for ConstantValue {
+    type Error = Value;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Bool(value) => Ok(Self::Bool(value)),
+            Value::Character(value) => Ok(Self::Character(value)),
+            Value::Float(value) => Ok(Self::Float(value)),
+            Value::Integer { value, ty } => Ok(Self::Integer { value, ty }),
+            Value::String(value) => Ok(Self::String(BabString::new(value))),
+            value => Err(value),
+        }
+    }
+}
+
+/// Evaluates the initializers of constants at compile time. Only literals,
+/// operators and references to other constants are allowed; the latter are
+/// looked up using the resolver, which receives the owner (e.g. the
+/// structure in `Buffer.GROOTTE`) and the name of the constant.
+pub struct ConstantEvaluator<R> {
+    resolver: R,
+    integer_type: IntegerType,
+}
+
+impl<R> ConstantEvaluator<R>
+        where R: FnMut(Option<&BabString>, &BabString) -> Option<ConstantValue> {
+    #[must_use]
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver,
+            integer_type: IntegerType::G32,
+        }
+    }
+
+    /// The type that integer literals and the resulting integer get, which
+    /// is the declared type of the constant, such that
+    /// `vast GROOT: g64 = 1024 * 1024 * 1024 * 1024` doesn't overflow.
+    #[must_use]
+    pub fn with_integer_type(self, integer_type: IntegerType) -> Self {
+        Self {
+            integer_type,
+            ..self
+        }
+    }
+
+    /// Uses the integer type of the declared `ty` of the constant, if it is
+    /// an integer type at all.
+    #[must_use]
+    pub fn with_declared_type(self, ty: &Type) -> Self {
+        match ty.specifier.value() {
+            TypeSpecifier::BuiltIn(builtin) => match builtin.integer_type() {
+                Some(integer_type) => self.with_integer_type(integer_type),
+                None => self,
+            },
+            _ => self,
+        }
+    }
+
+    pub fn evaluate(&mut self, expression: &Ranged<Expression>) -> Result<ConstantValue, ConstantEvaluationError> {
+        match self.evaluate_expression(expression)? {
+            ConstantValue::Integer { value, ty } if ty != self.integer_type => {
+                let value = ty.widen(value);
+                let ty = self.integer_type;
+                match ty.checked(value) {
+                    Some(value) => Ok(ConstantValue::Integer { value, ty }),
+                    None => Err(ConstantEvaluationError::IntegerOutOfRange { value, ty, range: expression.range() }),
+                }
+            }
+
+            value => Ok(value),
+        }
+    }
+
+    fn evaluate_expression(&mut self, expression: &Ranged<Expression>) -> Result<ConstantValue, ConstantEvaluationError> {
+        let range = expression.range();
+        match expression.value() {
+            Expression::BiExpression(expression) => self.evaluate_bi_expression(expression),
+            Expression::Postfix(postfix) => self.evaluate_postfix_expression(postfix, range),
+            Expression::Primary(primary) => self.evaluate_primary_expression(primary, range),
+            Expression::Unary(unary) => self.evaluate_unary_expression(unary, range),
+        }
+    }
+
+    fn evaluate_primary_expression(&mut self, expression: &PrimaryExpression, range: FileRange) -> Result<ConstantValue, ConstantEvaluationError> {
+        match expression {
+            PrimaryExpression::Boolean(value) => Ok(ConstantValue::Bool(*value)),
+            PrimaryExpression::CharacterLiteral(value) => Ok(ConstantValue::Character(*value)),
+            PrimaryExpression::FloatLiteral(value) => Ok(ConstantValue::Float(*value)),
+            PrimaryExpression::StringLiteral(value) => Ok(ConstantValue::String(value.clone())),
+
+            PrimaryExpression::IntegerLiteral(value) => {
+                let ty = self.integer_type;
+                match ty.checked(*value as i128) {
+                    Some(value) => Ok(ConstantValue::Integer { value, ty }),
+                    None => Err(ConstantEvaluationError::IntegerOutOfRange { value: *value as i128, ty, range }),
+                }
+            }
+
+            PrimaryExpression::Parenthesized(expression) => self.evaluate_expression(expression),
+
+            PrimaryExpression::Reference(name) => {
+                (self.resolver)(None, name.value())
+                    .ok_or_else(|| ConstantEvaluationError::UnknownConstant { name: name.value().clone(), range })
+            }
+
+            _ => Err(ConstantEvaluationError::NotConstant { range }),
+        }
+    }
+
+    fn evaluate_postfix_expression(&mut self, postfix: &PostfixExpression, range: FileRange) -> Result<ConstantValue, ConstantEvaluationError> {
+        let (PostfixExpressionKind::Member(name), Some(owner)) = (postfix.kind.value(), postfix.lhs.value().as_identifier()) else {
+            return Err(ConstantEvaluationError::NotConstant { range });
+        };
+
+        (self.resolver)(Some(owner), name.value())
+            .ok_or_else(|| ConstantEvaluationError::UnknownConstant { name: BabString::new(format!("{owner}.{}", name.value())), range })
+    }
+
+    fn evaluate_unary_expression(&mut self, expression: &UnaryExpression, range: FileRange) -> Result<ConstantValue, ConstantEvaluationError> {
+        let rhs = self.evaluate_expression(&expression.rhs)?;
+
+        match (expression.kind.value(), rhs) {
+            (UnaryExpressionKind::Negate, ConstantValue::Integer { value, ty }) => {
+                let value = ty.widen(value);
+                match ty.checked(-value) {
+                    Some(value) => Ok(ConstantValue::Integer { value, ty }),
+                    None => Err(ConstantEvaluationError::IntegerOverflow { lhs: 0, operator: "-", rhs: value, ty, range }),
+                }
+            }
+
+            (UnaryExpressionKind::Negate, ConstantValue::Float(value)) => Ok(ConstantValue::Float(-value)),
+            (UnaryExpressionKind::Not, ConstantValue::Bool(value)) => Ok(ConstantValue::Bool(!value)),
+
+            (UnaryExpressionKind::AddressOf, _) => Err(ConstantEvaluationError::NotConstant { range }),
+
+            (kind, _) => Err(ConstantEvaluationError::InvalidOperands {
+                operator: match kind {
+                    UnaryExpressionKind::Not => "!",
+                    _ => "-",
+                },
+                range,
+            }),
+        }
+    }
+
+    fn evaluate_bi_expression(&mut self, expression: &BiExpression) -> Result<ConstantValue, ConstantEvaluationError> {
+        let lhs = self.evaluate_expression(&expression.lhs)?;
+        let rhs = self.evaluate_expression(&expression.rhs)?;
+        let range = expression.operator.range();
+
+        match *expression.operator.value() {
+            BiOperator::Comparison(comparison) => {
+                let Some(ordering) = lhs.compare(&rhs) else {
+                    return Err(ConstantEvaluationError::InvalidOperands { operator: comparison.as_str(), range });
+                };
+
+                Ok(ConstantValue::Bool(match comparison {
+                    Comparison::Equality => ordering.is_eq(),
+                    Comparison::Inequality => ordering.is_ne(),
+                    Comparison::LessThan => ordering.is_lt(),
+                    Comparison::LessThanOrEqual => ordering.is_le(),
+                    Comparison::GreaterThan => ordering.is_gt(),
+                    Comparison::GreaterThanOrEqual => ordering.is_ge(),
+                }))
+            }
+
+            BiOperator::Math(operator) => evaluate_math_operator(operator, lhs, rhs, range),
+        }
+    }
+}
+
+/// Orders the constants such that each one comes after the constants its
+/// initializer refers to, which allows `vast A: g32 = B * 2;` to be declared
+/// before `vast B: g32 = 4;`. Constants that refer to each other keep their
+/// declaration order, so evaluating them reports the unknown constant. The
+/// `constant` function returns the constant of each item, if it is one.
+#[must_use]
+pub fn order_constants_by_dependency<'a, T>(items: &'a [T], constant: impl Fn(&'a T) -> Option<&'a ConstantStatement>) -> Vec<&'a T> {
+    let names: Vec<Option<&BabString>> = items.iter()
+        .map(|item| constant(item).map(|constant| constant.name.value()))
+        .collect();
+
+    let dependencies: Vec<Vec<usize>> = items.iter()
+        .map(|item| {
+            let mut references = Vec::new();
+            if let Some(constant) = constant(item) {
+                collect_references(&constant.value, &mut references);
+            }
+
+            references.iter()
+                .filter_map(|reference| names.iter().position(|name| *name == Some(*reference)))
+                .collect()
+        })
+        .collect();
+
+    // `None` when not visited yet, `Some(false)` while visiting the
+    // dependencies and `Some(true)` when it was ordered.
+    let mut visited = vec![None; items.len()];
+    let mut order = Vec::with_capacity(items.len());
+    for index in 0..items.len() {
+        visit_constant(index, &dependencies, &mut visited, &mut order);
+    }
+
+    order.into_iter().map(|index| &items[index]).collect()
+}
+
+fn visit_constant(index: usize, dependencies: &[Vec<usize>], visited: &mut [Option<bool>], order: &mut Vec<usize>) {
+    if visited[index].is_some() {
+        return;
+    }
+
+    visited[index] = Some(false);
+    for dependency in &dependencies[index] {
+        visit_constant(*dependency, dependencies, visited, order);
+    }
+
+    visited[index] = Some(true);
+    order.push(index);
+}
+
+/// Collects the names of the constants the expression refers to, in the
+/// forms that the [`ConstantEvaluator`] understands.
+fn collect_references<'a>(expression: &'a Expression, references: &mut Vec<&'a BabString>) {
+    match expression {
+        Expression::BiExpression(expression) => {
+            collect_references(&expression.lhs, references);
+            collect_references(&expression.rhs, references);
+        }
+
+        Expression::Unary(expression) => collect_references(&expression.rhs, references),
+        Expression::Primary(PrimaryExpression::Parenthesized(expression)) => collect_references(expression, references),
+        Expression::Primary(PrimaryExpression::Reference(name)) => references.push(name.value()),
+
+        Expression::Postfix(postfix) => {
+            if let PostfixExpressionKind::Member(name) = postfix.kind.value() {
+                references.push(name.value());
+            }
+        }
+
+        Expression::Primary(..) => (),
+    }
+}
+
+fn evaluate_math_operator(operator: MathOperator, lhs: ConstantValue, rhs: ConstantValue, range: FileRange) -> Result<ConstantValue, ConstantEvaluationError> {
+    match (lhs, rhs) {
+        (ConstantValue::Integer { value: lhs, ty }, ConstantValue::Integer { value: rhs, ty: rhs_ty }) => {
+            let lhs = ty.widen(lhs);
+            let rhs = rhs_ty.widen(rhs);
+
+            if rhs == 0 && matches!(operator, MathOperator::Divide | MathOperator::Modulo) {
+                return Err(ConstantEvaluationError::DivisionByZero { range });
+            }
+
+            let result = match operator {
+                MathOperator::Add => lhs.checked_add(rhs),
+                MathOperator::Subtract => lhs.checked_sub(rhs),
+                MathOperator::Multiply => lhs.checked_mul(rhs),
+                MathOperator::Divide => lhs.checked_div(rhs),
+                MathOperator::Modulo => lhs.checked_rem(rhs),
+                MathOperator::BitwiseAnd => Some(ty.wrapping(lhs & rhs) as i128),
+                MathOperator::BitwiseOr => Some(ty.wrapping(lhs | rhs) as i128),
+                MathOperator::BitwiseXor => Some(ty.wrapping(lhs ^ rhs) as i128),
+                MathOperator::LogicalAnd | MathOperator::LogicalOr => {
+                    return Err(ConstantEvaluationError::InvalidOperands { operator: operator.as_str(), range });
+                }
+            };
+
+            match result.and_then(|result| ty.checked(result)) {
+                Some(value) => Ok(ConstantValue::Integer { value, ty }),
+                None => Err(ConstantEvaluationError::IntegerOverflow { lhs, operator: operator.as_str(), rhs, ty, range }),
+            }
+        }
+
+        (ConstantValue::Float(lhs), ConstantValue::Float(rhs)) => {
+            Ok(ConstantValue::Float(match operator {
+                MathOperator::Add => lhs + rhs,
+                MathOperator::Subtract => lhs - rhs,
+                MathOperator::Multiply => lhs * rhs,
+                MathOperator::Divide => lhs / rhs,
+                MathOperator::Modulo => lhs % rhs,
+                _ => return Err(ConstantEvaluationError::InvalidOperands { operator: operator.as_str(), range }),
+            }))
+        }
+
+        (ConstantValue::Bool(lhs), ConstantValue::Bool(rhs)) => {
+            Ok(ConstantValue::Bool(match operator {
+                MathOperator::LogicalAnd => lhs && rhs,
+                MathOperator::LogicalOr => lhs || rhs,
+                _ => return Err(ConstantEvaluationError::InvalidOperands { operator: operator.as_str(), range }),
+            }))
+        }
+
+        (ConstantValue::String(lhs), ConstantValue::String(rhs)) if operator == MathOperator::Add => {
+            Ok(ConstantValue::String(BabString::new(format!("{lhs}{rhs}"))))
+        }
+
+        _ => Err(ConstantEvaluationError::InvalidOperands { operator: operator.as_str(), range }),
+    }
+}
+
+#[derive(Debug, Clone, Error, AsRefStr)]
+pub enum ConstantEvaluationError {
+    #[error("Deze expressie kan niet tijdens het vertalen uitgerekend worden")]
+    NotConstant { range: FileRange },
+
+    #[error("`{name}` is geen vaste waarde")]
+    UnknownConstant { name: BabString, range: FileRange },
+
+    #[error("Deling door nul")]
+    DivisionByZero { range: FileRange },
+
+    #[error("Getal {value} past niet in een `{ty}`")]
+    IntegerOutOfRange { value: i128, ty: IntegerType, range: FileRange },
+
+    #[error("Uitkomst van `{lhs} {operator} {rhs}` past niet in een `{ty}`")]
+    IntegerOverflow { lhs: i128, operator: &'static str, rhs: i128, ty: IntegerType, range: FileRange },
+
+    #[error("Operator `{operator}` kan niet gebruikt worden op deze waarden")]
+    InvalidOperands { operator: &'static str, range: FileRange },
+}
+
+impl ConstantEvaluationError {
+    #[must_use]
+    pub fn range(&self) -> FileRange {
+        match self {
+            Self::NotConstant { range } => *range,
+            Self::UnknownConstant { range, .. } => *range,
+            Self::DivisionByZero { range } => *range,
+            Self::IntegerOutOfRange { range, .. } => *range,
+            Self::IntegerOverflow { range, .. } => *range,
+            Self::InvalidOperands { range, .. } => *range,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    use crate::{Lexer, Parser, SourceCode, Statement};
+
+    fn evaluate(input: &'static str, integer_type: IntegerType) -> Result<ConstantValue, ConstantEvaluationError> {
+        let source_code = SourceCode::new_test(input);
+        let (tokens, errors) = Lexer::new(&source_code).collect_all();
+        assert!(errors.is_empty());
+
+        let mut parser = Parser::new(source_code.path().to_path_buf(), &tokens);
+        let expression = parser.parse_expression().unwrap();
+
+        ConstantEvaluator::new(|owner: Option<&BabString>, name: &BabString| {
+            match (owner.map(|x| x.as_str()), name.as_str()) {
+                (None, "BASIS") => Some(ConstantValue::Integer { value: 8, ty: IntegerType::G32 }),
+                (Some("Buffer"), "GROOTTE") => Some(ConstantValue::Integer { value: 64, ty: IntegerType::G32 }),
+                _ => None,
+            }
+        })
+        .with_integer_type(integer_type)
+        .evaluate(&expression)
+    }
+
+    #[rstest]
+    #[case("16 * 1024", ConstantValue::Integer { value: 16384, ty: IntegerType::G32 })]
+    #[case("(1 + 2) * 3", ConstantValue::Integer { value: 9, ty: IntegerType::G32 })]
+    #[case("-5 % 3", ConstantValue::Integer { value: -2, ty: IntegerType::G32 })]
+    #[case("BASIS * Buffer.GROOTTE", ConstantValue::Integer { value: 512, ty: IntegerType::G32 })]
+    #[case("BASIS >= 8 && !onwaar", ConstantValue::Bool(true))]
+    #[case("\"Hallo, \" + \"wereld\"", ConstantValue::String(BabString::new_static("Hallo, wereld")))]
+    #[case("1.5 * 2.0", ConstantValue::Float(3.0))]
+    fn evaluates(#[case] input: &'static str, #[case] expected: ConstantValue) {
+        assert_eq!(evaluate(input, IntegerType::G32).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("1000000 * 1000000", IntegerType::G64, "")]
+    #[case("1000000 * 1000000", IntegerType::G32, "IntegerOverflow")]
+    #[case("300", IntegerType::N8, "IntegerOutOfRange")]
+    #[case("Buffer.GROOTTE * 4", IntegerType::G8, "IntegerOutOfRange")]
+    #[case("4 / (BASIS - 8)", IntegerType::G32, "DivisionByZero")]
+    #[case("ONBEKEND", IntegerType::G32, "UnknownConstant")]
+    #[case("lees()", IntegerType::G32, "NotConstant")]
+    #[case("waar + 1", IntegerType::G32, "InvalidOperands")]
+    fn errors(#[case] input: &'static str, #[case] integer_type: IntegerType, #[case] expected: &str) {
+        let actual = evaluate(input, integer_type).err();
+        assert_eq!(actual.as_ref().map(|x| x.as_ref()).unwrap_or_default(), expected);
+    }
+
+    #[rstest]
+    #[case("vast A: g32 = 1; vast B: g32 = 2;", &["A", "B"])]
+    #[case("vast A: g32 = B * 2; vast B: g32 = 4;", &["B", "A"])]
+    #[case("vast A: g32 = B + C; vast B: g32 = C; vast C: g32 = 1;", &["C", "B", "A"])]
+    #[case("vast A: g32 = B; vast B: g32 = A;", &["B", "A"])]
+    fn orders_constants_by_dependency(#[case] input: &'static str, #[case] expected: &[&str]) {
+        let source_code = SourceCode::new_test(input);
+        let (tokens, errors) = Lexer::new(&source_code).collect_all();
+        assert!(errors.is_empty());
+
+        let mut parser = Parser::new(source_code.path().to_path_buf(), &tokens);
+        let tree = parser.parse_tree();
+
+        let order: Vec<&str> = order_constants_by_dependency(tree.constants(), Statement::as_constant)
+            .into_iter()
+            .filter_map(|statement| statement.as_constant())
+            .map(|constant| constant.name.value().as_str())
+            .collect();
+
+        assert_eq!(order, expected);
+    }
+}
@@ -1,6 +1,19 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{BabString, Value};
+
 pub trait Interpreter {
+    /// Compares two values for equality, like `==` does.
+    fn are_equal(&mut self, lhs: &Value, rhs: &Value) -> bool {
+        lhs.is_equal_to(rhs)
+    }
+
+    /// Called by `verwacht` and `verwachtGelijk` when the expectation wasn't
+    /// met.
+    fn report_failed_expectation(&mut self, message: BabString);
 
+    /// Called by a builtin when it can't handle the given value yet, e.g. a
+    /// `Reeks` behind a pointer. This stops the program with an error.
+    fn report_unsupported(&mut self, feature: BabString) -> Value;
 }
@@ -10,21 +10,42 @@ use crate::{DocumentationProvider, LspCompletion};
 #[strum(serialize_all = "snake_case")]
 pub enum Keyword {
     Als,
+    Anders,
     Bekeer,
     Dit,
+    Door,
+    #[strum(serialize = "Fout")]
+    Fout,
+    Ga,
+    Gebruik,
+    Gedeeld,
+    #[strum(serialize = "Goed")]
+    Goed,
+    #[strum(serialize = "Iets")]
+    Iets,
     In,
+    Intern,
+    Kies,
     Koppelvlak,
+    Module,
+    #[strum(serialize = "Niets")]
+    Niets,
     Nieuw,
     Onwaar,
     Op,
+    Opsomming,
+    Publiek,
     Reeks,
     Stel,
+    Stop,
     Structuur,
     Uitbreiding,
+    Vast,
     Veld,
     Volg,
     Waar,
     Werkwijze,
+    Zolang,
 }
 
 impl Keyword {
@@ -46,14 +67,30 @@ impl Keyword {
                 completion: "werkwijze ${1:naam}() {\n\t$0\n}",
                 inline_detail: "Een nieuwe werkwijze.",
             }),
+            Self::Kies => Some(LspCompletion {
+                completion: "kies ${1:waarde} {\n\t${2:patroon} => ${3:uitkomst},\n\t_ => ${0:anders},\n}",
+                inline_detail: "Kies een uitkomst op basis van patronen.",
+            }),
             Self::Koppelvlak => Some(LspCompletion {
                 completion: "koppelvlak ${1:naam} {\n\t$0\n}",
                 inline_detail: "Een nieuw koppelvlak",
             }),
+            Self::Gebruik => Some(LspCompletion {
+                completion: "gebruik ${1:module};\n$0",
+                inline_detail: "Gebruik een andere module",
+            }),
+            Self::Module => Some(LspCompletion {
+                completion: "module ${1:naam};\n$0",
+                inline_detail: "Maak van dit bestand een module",
+            }),
             Self::Nieuw => Some(LspCompletion {
                 completion: "nieuw ${1:structuurnaam} {\n\t${0:velden}\n}",
                 inline_detail: "Een nieuw object",
             }),
+            Self::Opsomming => Some(LspCompletion {
+                completion: "opsomming ${1:naam} {\n\t${0:varianten}\n}",
+                inline_detail: "Een nieuwe opsomming",
+            }),
             Self::Stel => Some(LspCompletion {
                 completion: "stel ${1:variabele} = ${2:waarde};\n${0}",
                 inline_detail: "Een nieuwe variabele",
@@ -62,6 +99,10 @@ impl Keyword {
                 completion: "structuur ${1:naam} {\n\t${0:velden}\n}",
                 inline_detail: "Een nieuwe structuur",
             }),
+            Self::Vast => Some(LspCompletion {
+                completion: "vast ${1:NAAM}: ${2:type} = ${3:waarde};\n${0}",
+                inline_detail: "Een nieuwe vaste waarde",
+            }),
             Self::Veld => Some(LspCompletion {
                 completion: "veld ${1:naam}: ${0:type},",
                 inline_detail: "Een nieuw veld",
@@ -70,6 +111,10 @@ impl Keyword {
                 completion: "volg ${1:element} in reeks(${2:start}, ${3:eind}) {\n\t$0\n}",
                 inline_detail: "Volg in reeks."
             }),
+            Self::Zolang => Some(LspCompletion {
+                completion: "zolang ${1:voorwaarde} {\n\t$0\n}",
+                inline_detail: "Herhaal zolang de voorwaarde geldt.",
+            }),
             Self::Dit => Some(LspCompletion {
                 completion: "dit.$0",
                 inline_detail: "De huidige waarde binnen een werkwijze van een structuur",
@@ -83,6 +128,17 @@ impl DocumentationProvider for Keyword {
     fn provide_documentation(&self) -> std::borrow::Cow<'_, str> {
         match self {
             Self::Als => "Evalueer sectie als een voorwaarde geldt.",
+            Self::Anders => r#"Evalueer sectie als de voorwaarde van de voorgaande `als` niet geldt.
+## Voorbeeld
+```babbelaar
+als getal == 0 {
+    schrijf("nul");
+} anders als getal == 1 {
+    schrijf("een");
+} anders {
+    schrijf("veel");
+}
+```"#,
             Self::Bekeer => "Geef een waarde terug aan de aanroeper van de werkwijze.",
             Self::Dit => r#"De huidige waarde binnen een werkwijze van een structuur.
 ## Voorbeeld
@@ -113,27 +169,201 @@ De uitvoer is als volgt:
 Hallo, ik ben John Doe
 ```
 "#,
+            Self::Door => r#"Sla de rest van de huidige herhaling over, met `ga door`.
+## Voorbeeld
+```babbelaar
+volg i in reeks(0, 10) {
+    als i == 5 {
+        ga door;
+    }
+
+    schrijf(€"{i}");
+}
+```"#,
+            Self::Fout => r#"Een `Resultaat` van een bewerking die mislukt is, met daarin de fout. Tegenovergestelde van `Goed`.
+## Voorbeeld
+```babbelaar
+werkwijze deel(a: g32, b: g32) -> Resultaat<g32, Slinger> {
+    als b == 0 {
+        bekeer Fout("Kan niet delen door nul");
+    }
+
+    bekeer Goed(a / b);
+}
+```"#,
+            Self::Ga => "Sla de rest van de huidige herhaling over, met `ga door`.",
+            Self::Gedeeld => r#"Een werkwijze van een structuur die geen waarde van de structuur nodig heeft, en dus geen `dit` heeft. Deze wordt aangeroepen via de naam van de structuur.
+## Voorbeeld
+```babbelaar
+structuur Persoon {
+    veld naam: Slinger,
+
+    gedeeld werkwijze maak(naam: Slinger) -> Persoon {
+        bekeer nieuw Persoon { naam: naam };
+    }
+}
+
+stel persoon = Persoon.maak("Jan");
+```"#,
+            Self::Gebruik => r#"Maak de werkwijzen, structuren, opsommingen en koppelvlakken van een andere module beschikbaar. Ze zijn bereikbaar via het laatste deel van de modulenaam.
+## Voorbeeld
+```babbelaar
+gebruik wiskunde.meetkunde;
+
+stel oppervlakte = meetkunde.oppervlakte(nieuw meetkunde.Vierkant { zijde: 3 });
+```"#,
+            Self::Goed => r#"Een `Resultaat` van een bewerking die gelukt is, met daarin de uitkomst. Tegenovergestelde van `Fout`.
+Met `?` achter een `Resultaat` wordt de uitkomst uitgepakt, of wordt de fout direct bekeerd vanuit de huidige werkwijze.
+## Voorbeeld
+```babbelaar
+werkwijze gemiddelde(som: g32, aantal: g32) -> Resultaat<g32, Slinger> {
+    stel uitkomst = deel(som, aantal)?;
+    bekeer Goed(uitkomst);
+}
+```"#,
+            Self::Iets => r#"Een `Misschien`-waarde die wél een waarde bevat. Tegenovergestelde van `Niets`.
+Om bij de waarde te komen moet deze eerst uitgepakt worden met `kies`.
+## Voorbeeld
+```babbelaar
+werkwijze zoek(naam: Slinger) -> Misschien<g32> {
+    als naam == "Jan" {
+        bekeer Iets(42);
+    }
+
+    bekeer Niets;
+}
+
+stel uitkomst = kies zoek("Jan") {
+    Iets(leeftijd) => €"Jan is {leeftijd} jaar",
+    Niets => "Niet gevonden",
+};
+```"#,
             Self::In => "Herhaal over een stel waardes met `volg`.",
+            Self::Intern => r#"Maak een veld, werkwijze of structuurwerkwijze alleen bruikbaar binnen hetzelfde bestand, of binnen dezelfde module als het bestand bij een module hoort. Tegenovergestelde van `publiek`.
+## Voorbeeld
+```babbelaar
+structuur Rekening {
+    intern veld saldo: g32,
+
+    werkwijze stort(bedrag: g32) {
+        dit.saldo += bedrag;
+    }
+}
+
+intern werkwijze hulpje() {}
+```"#,
+            Self::Kies => r#"Kies een uitkomst door een waarde met patronen te vergelijken. Het eerste patroon dat past wordt gekozen.
+Patronen kunnen letterlijke waardes zijn, reeksen zoals `1..5` (tot en met 4), structuren, opsommingsvarianten, of namen die de waarde vastleggen. Met `_` past alles.
+## Voorbeeld
+```babbelaar
+stel beschrijving = kies code {
+    0 => "Succes",
+    1..5 => "Klein probleem",
+    _ => "Onbekende fout",
+};
+
+stel oppervlakte = kies vorm {
+    Vorm.Punt => 0,
+    Vorm.Cirkel(straal) => 3 * straal * straal,
+    Vorm.Rechthoek(breedte, hoogte) => breedte * hoogte,
+};
+```"#,
             Self::Koppelvlak => "Definieer een nieuw koppelvlak, een soort contract voor structuren.",
+            Self::Module => r#"Geef aan tot welke module dit bestand behoort. Dit moet bovenaan het bestand staan. Bestanden zonder module horen bij de hoofdnaamruimte.
+## Voorbeeld
+```babbelaar
+module wiskunde.meetkunde;
+
+werkwijze kwadraat(x: g32) -> g32 {
+    bekeer x * x;
+}
+```"#,
+            Self::Niets => "Een `Misschien`-waarde zonder waarde. Tegenovergestelde van `Iets`.",
             Self::Nieuw => "Maak een nieuw object aan.",
             Self::Onwaar => "Een waarde van het type `booleaan`. Tegenovergestelde van `waar`",
             Self::Op => "Op welke structuur moet de het koppelvlak uitgebreid worden.",
+            Self::Opsomming => r#"Definieer een type dat precies één van een aantal varianten is. Een variant kan velden meedragen.
+## Voorbeeld
+```babbelaar
+opsomming Vorm {
+    Punt,
+    Cirkel(straal: g32),
+    Rechthoek(breedte: g32, hoogte: g32),
+}
+
+stel vorm = Vorm.Cirkel(5);
+```"#,
+            Self::Publiek => "Maak een veld, werkwijze of structuurwerkwijze overal bruikbaar. Dit is standaard zo, tenzij `intern` gebruikt wordt.",
             Self::Reeks => {
-                r#"Stel een reeks op van getallen.
+                r#"Stel een reeks op van getallen, vanaf de startwaarde tot de eindwaarde.
+Met `stap` tel je met andere stappen dan 1, ook aflopend, en met `totEnMet: waar` hoort de eindwaarde er ook bij.
+Een reeks kan ook in een variabele bewaard of aan een werkwijze meegegeven worden, als het type `Reeks`.
 ## Voorbeeld
 ```babbelaar
 volg i in reeks(0, 10) {
     // Gebruik het getal tussen 0 en 10.
 }
+
+volg i in reeks(10, 0, stap: -2, totEnMet: waar) {
+    // 10, 8, 6, 4, 2 en 0
+}
+
+stel even = reeks(0, 10, stap: 2);
+even.bevat(4) // = waar
 ```"#
             }
             Self::Stel => "Bepaal een aanpaswaarde in deze scoop",
+            Self::Stop => r#"Verlaat de huidige `volg`- of `zolang`-herhaling.
+## Voorbeeld
+```babbelaar
+volg i in reeks(0, 10) {
+    als i == 5 {
+        stop;
+    }
+
+    schrijf(€"{i}");
+}
+```"#,
             Self::Structuur => "Definieer een datastructuur.",
             Self::Uitbreiding => "Definieer een uitbreiding op een datastructuur.",
+            Self::Vast => r#"Definieer een vaste waarde, die al tijdens het vertalen uitgerekend wordt. Een vaste waarde kan niet aangepast worden.
+Vaste waardes kunnen bovenaan een bestand of binnen een `structuur` staan.
+## Voorbeeld
+```babbelaar
+vast MAX_GROOTTE: g32 = 16 * 1024;
+
+structuur Buffer {
+    vast STANDAARD_GROOTTE: g32 = MAX_GROOTTE / 4,
+
+    veld grootte: g32,
+}
+
+stel buffer = nieuw Buffer { grootte: Buffer.STANDAARD_GROOTTE };
+```"#,
             Self::Veld => "Een onderdeel van een `structuur`.",
             Self::Volg => "Herhaal de sectie per waarde van de reeks.",
             Self::Waar => "Een waarde van het type `booleaan`. Tegenovergestelde van `onwaar`",
-            Self::Werkwijze => "Definieer een nieuwe werkwijze.",
+            Self::Werkwijze => r#"Definieer een nieuwe werkwijze. Zonder naam is het een anonieme werkwijze, die als waarde meegegeven kan worden.
+## Voorbeeld
+```babbelaar
+werkwijze pasToe(x: g32, f: werkwijze(g32) -> g32) -> g32 {
+    bekeer f(x);
+}
+
+stel factor = 3;
+stel uitkomst = pasToe(2, werkwijze(x: g32) -> g32 {
+    bekeer x * factor;
+});
+```"#,
+            Self::Zolang => r#"Herhaal de sectie zolang de voorwaarde geldt.
+## Voorbeeld
+```babbelaar
+stel bezig = waar;
+zolang bezig {
+    schrijf("Nog één keer!");
+    bezig = onwaar;
+}
+```"#,
         }.into()
     }
 }
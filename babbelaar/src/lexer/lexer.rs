@@ -6,7 +6,7 @@ use std::{error::Error, fmt::Display, str::CharIndices};
 use strum::AsRefStr;
 use thiserror::Error;
 
-use crate::{FileLocation, Keyword, Punctuator, Slice, SourceCode, TemplateStringToken, Token, TokenKind};
+use crate::{BabString, FileLocation, Keyword, Punctuator, Slice, SourceCode, TemplateStringToken, Token, TokenKind};
 
 pub struct Lexer<'source_code> {
     input: &'source_code SourceCode,
@@ -16,6 +16,17 @@ pub struct Lexer<'source_code> {
     line: usize,
     column: usize,
     errors: Vec<LexerError>,
+
+    /// The lines of the `///` comments that were read since the last token.
+    documentation: Vec<String>,
+
+    /// The brackets that are currently open, which decide whether a comma
+    /// followed by a digit is a decimal comma (`3,14`) or a separator (`[1,2]`).
+    open_brackets: Vec<OpenBracket>,
+
+    /// The number of open brackets when the last `kies` was read, such that
+    /// the `{` following it can be recognized.
+    match_depth: Option<usize>,
 }
 
 impl<'source_code> Lexer<'source_code> {
@@ -27,6 +38,9 @@ impl<'source_code> Lexer<'source_code> {
             line: 0,
             column: 0,
             errors: Vec::new(),
+            documentation: Vec::new(),
+            open_brackets: Vec::new(),
+            match_depth: None,
         }
     }
 
@@ -34,7 +48,7 @@ impl<'source_code> Lexer<'source_code> {
         self.skip_whitespace();
 
         let ch = self.peek_char()?;
-        let tok = match ch {
+        let mut tok = match ch {
             '"' => self.consume_string(),
             '€' => self.consume_template_string(),
             '\'' => self.consume_character_literal(),
@@ -50,20 +64,22 @@ impl<'source_code> Lexer<'source_code> {
             ']' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::RightSquareBracket)),
             ';' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::Semicolon)),
             ',' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::Comma)),
-            '=' => self.consume_single_or_double_char_token(Punctuator::Assignment, Punctuator::Equals),
-            '+' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::PlusSign)),
+            '=' => self.consume_equals_or_fat_arrow(),
+            '+' => self.consume_single_or_equals_token(Punctuator::PlusSign, Punctuator::PlusEquals),
             '-' => self.consume_minus_or_arrow(),
             '/' => self.handle_solidus(),
-            '*' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::Asterisk)),
-            '%' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::PercentageSign)),
+            '*' => self.consume_single_or_equals_token(Punctuator::Asterisk, Punctuator::AsteriskEquals),
+            '%' => self.consume_single_or_equals_token(Punctuator::PercentageSign, Punctuator::PercentageEquals),
             ':' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::Colon)),
-            '.' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::Period)),
+            '.' => self.consume_single_or_double_char_token(Punctuator::Period, Punctuator::DoublePeriod),
             '@' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::AtSign)),
-            '<' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::LessThan)),
-            '>' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::GreaterThan)),
+            '!' => self.consume_single_or_equals_token(Punctuator::ExclamationMark, Punctuator::NotEquals),
+            '<' => self.consume_single_or_equals_token(Punctuator::LessThan, Punctuator::LessThanOrEqual),
+            '>' => self.consume_single_or_equals_token(Punctuator::GreaterThan, Punctuator::GreaterThanOrEqual),
             '&' => self.consume_single_or_double_char_token(Punctuator::BitwiseAnd, Punctuator::LogicalAnd),
             '|' => self.consume_single_or_double_char_token(Punctuator::BitwiseOr, Punctuator::LogicalOr),
             '^' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::BitwiseXor)),
+            '?' => self.consume_single_char_token(TokenKind::Punctuator(Punctuator::QuestionMark)),
 
             _ => {
                 let (begin, char) = self.current?;
@@ -78,13 +94,68 @@ impl<'source_code> Lexer<'source_code> {
                     begin,
                     end,
                     kind: TokenKind::IllegalCharacter(char),
+                    documentation: None,
                 })
             }
         };
 
+        if let Some(tok) = &mut tok {
+            tok.documentation = self.take_documentation();
+            self.track_brackets(&tok.kind);
+        }
+
         tok
     }
 
+    fn track_brackets(&mut self, kind: &TokenKind) {
+        match kind {
+            TokenKind::Keyword(Keyword::Kies) => {
+                self.match_depth = Some(self.open_brackets.len());
+            }
+
+            TokenKind::Punctuator(Punctuator::LeftParenthesis) => {
+                self.open_brackets.push(OpenBracket::Parenthesis);
+            }
+
+            TokenKind::Punctuator(Punctuator::LeftSquareBracket) => {
+                self.open_brackets.push(OpenBracket::Square);
+            }
+
+            TokenKind::Punctuator(Punctuator::LeftCurlyBracket) => {
+                if self.match_depth == Some(self.open_brackets.len()) {
+                    self.match_depth = None;
+                    self.open_brackets.push(OpenBracket::Match);
+                } else {
+                    self.open_brackets.push(OpenBracket::Curly);
+                }
+            }
+
+            TokenKind::Punctuator(Punctuator::RightParenthesis | Punctuator::RightSquareBracket | Punctuator::RightCurlyBracket) => {
+                self.open_brackets.pop();
+            }
+
+            _ => (),
+        }
+    }
+
+    /// A comma can only be a decimal comma where it can't separate arguments,
+    /// elements or the arms of a `kies`, so `som(1,2)` has two arguments.
+    #[must_use]
+    fn is_decimal_comma_allowed(&self) -> bool {
+        matches!(self.open_brackets.last(), None | Some(OpenBracket::Curly))
+    }
+
+    /// Takes the `///` comments in front of the current token, e.g. the
+    /// description of a `werkwijze`.
+    fn take_documentation(&mut self) -> Option<BabString> {
+        if self.documentation.is_empty() {
+            return None;
+        }
+
+        let lines = std::mem::take(&mut self.documentation);
+        Some(BabString::new(lines.join("\n")))
+    }
+
     fn consume_single_char_token(&mut self, kind: TokenKind) -> Option<Token> {
         let begin = self.current_location();
 
@@ -96,6 +167,7 @@ impl<'source_code> Lexer<'source_code> {
             kind,
             begin,
             end,
+            documentation: None,
         })
     }
 
@@ -117,6 +189,31 @@ impl<'source_code> Lexer<'source_code> {
             kind,
             begin,
             end,
+            documentation: None,
+        })
+    }
+
+    /// Consumes e.g. `<` or `<=`, depending on whether the character is
+    /// followed by an equals sign.
+    fn consume_single_or_equals_token(&mut self, single: Punctuator, with_equals: Punctuator) -> Option<Token> {
+        let begin = self.current_location();
+
+        _ = self.next_char()?;
+
+        let kind = if self.peek_char() == Some('=') {
+            self.consume_char();
+            TokenKind::Punctuator(with_equals)
+        } else {
+            TokenKind::Punctuator(single)
+        };
+
+        let end = self.current_location();
+
+        Some(Token {
+            kind,
+            begin,
+            end,
+            documentation: None,
         })
     }
 
@@ -140,6 +237,7 @@ impl<'source_code> Lexer<'source_code> {
             kind: TokenKind::CharacterLiteral(ch),
             begin,
             end: self.current_location(),
+            documentation: None,
         })
     }
 
@@ -173,6 +271,7 @@ impl<'source_code> Lexer<'source_code> {
             kind: TokenKind::StringLiteral(str),
             begin,
             end,
+            documentation: None,
         })
     }
 
@@ -191,6 +290,7 @@ impl<'source_code> Lexer<'source_code> {
                 kind: TokenKind::TemplateString(Vec::new()),
                 begin,
                 end: begin,
+                documentation: None,
             });
         }
 
@@ -214,9 +314,15 @@ impl<'source_code> Lexer<'source_code> {
                     }
                 }
 
+                // The expression isn't part of the brackets around the template string.
+                let open_brackets = std::mem::take(&mut self.open_brackets);
+
                 let mut tokens = Vec::new();
                 loop {
-                    let token = self.next()?;
+                    let Some(token) = self.next() else {
+                        self.open_brackets = open_brackets;
+                        return None;
+                    };
 
                     if matches!(token.kind, TokenKind::Punctuator(Punctuator::RightCurlyBracket)) {
                         break;
@@ -225,6 +331,8 @@ impl<'source_code> Lexer<'source_code> {
                     tokens.push(token);
                 }
 
+                self.open_brackets = open_brackets;
+
                 parts.push(TemplateStringToken::Expression(tokens));
                 continue;
             }
@@ -258,6 +366,7 @@ impl<'source_code> Lexer<'source_code> {
             kind: TokenKind::TemplateString(parts),
             begin,
             end,
+            documentation: None,
         })
     }
 
@@ -288,6 +397,7 @@ impl<'source_code> Lexer<'source_code> {
             kind,
             begin,
             end,
+            documentation: None,
         })
     }
 
@@ -306,6 +416,12 @@ impl<'source_code> Lexer<'source_code> {
             self.consume_char();
         }
 
+        let is_decimal_comma_allowed = self.is_decimal_comma_allowed();
+        let is_separator = |c| c == '.' || (c == ',' && is_decimal_comma_allowed);
+        if self.peek_char().is_some_and(is_separator) && self.peek_second_char().is_some_and(|c| c.is_ascii_digit()) {
+            return self.consume_float(begin);
+        }
+
         let end = self.current_location();
         let mut str = &self.input[begin.offset()..end.offset()];
         let mut radix = 10;
@@ -330,19 +446,63 @@ impl<'source_code> Lexer<'source_code> {
             kind: TokenKind::Integer(integer),
             begin,
             end,
+            documentation: None,
+        })
+    }
+
+    /// Consumes the fractional part of a number like `3,14` or `3.14`, the
+    /// integral part (starting at `begin`) has already been consumed.
+    fn consume_float(&mut self, begin: FileLocation) -> Option<Token> {
+        let separator = self.current_location();
+        self.consume_char();
+
+        while self.peek_char().is_some_and(|c| c.is_ascii_digit()) {
+            self.consume_char();
+        }
+
+        let end = self.current_location();
+        let integral = &self.input[begin.offset()..separator.offset()];
+        let fraction = &self.input[separator.offset() + 1..end.offset()];
+
+        let float = match format!("{integral}.{fraction}").parse() {
+            Ok(float) => float,
+            Err(..) => {
+                self.errors.push(LexerError {
+                    location: end,
+                    kind: LexerErrorKind::InvalidNumber,
+                });
+                0.0
+            }
+        };
+
+        Some(Token {
+            kind: TokenKind::Float(float),
+            begin,
+            end,
+            documentation: None,
         })
     }
 
     fn skip_whitespace(&mut self) {
         while let Some(c) = self.peek_char() {
             if c == '/' {
-                self.consume_char();
-                if self.peek_char() != Some('/') {
-                    self.consume_single_char_token(TokenKind::Punctuator(Punctuator::Solidus));
-                    continue;
+                // A single `/` is a punctuator, not the start of a comment.
+                if self.peek_second_char() != Some('/') {
+                    break;
                 }
 
-                self.consume_until_end_of_line();
+                self.consume_char();
+                self.consume_char();
+
+                // `///` is a documentation comment, but `////` isn't.
+                let is_documentation = self.peek_char() == Some('/') && self.peek_second_char() != Some('/');
+                let line = self.consume_until_end_of_line();
+
+                if is_documentation {
+                    let line = &line[1..];
+                    let line = line.strip_prefix(' ').unwrap_or(line);
+                    self.documentation.push(line.to_string());
+                }
                 continue;
             }
 
@@ -375,6 +535,12 @@ impl<'source_code> Lexer<'source_code> {
         Some(self.current?.1)
     }
 
+    /// Peeks at the character after the one returned by [`Self::peek_char()`].
+    fn peek_second_char(&mut self) -> Option<char> {
+        _ = self.peek_char();
+        self.chars.clone().next().map(|(_, c)| c)
+    }
+
     fn next_char(&mut self) -> Option<char> {
         let c = self.peek_char()?;
         self.consume_char();
@@ -395,10 +561,13 @@ impl<'source_code> Lexer<'source_code> {
     }
 
     fn handle_solidus(&mut self) -> Option<Token> {
-        self.consume_single_char_token(TokenKind::Punctuator(Punctuator::Solidus))
+        self.consume_single_or_equals_token(Punctuator::Solidus, Punctuator::SolidusEquals)
     }
 
-    fn consume_until_end_of_line(&mut self) {
+    /// Consumes the rest of the line, and returns it without the line ending.
+    fn consume_until_end_of_line(&mut self) -> String {
+        let mut line = String::new();
+
         while let Some(c) = self.next_char() {
             if c == '\n' {
                 break;
@@ -411,7 +580,11 @@ impl<'source_code> Lexer<'source_code> {
 
                 break;
             }
+
+            line.push(c);
         }
+
+        line
     }
 
     pub fn collect_all(mut self) -> (Vec<Token>, Vec<LexerError>) {
@@ -424,16 +597,52 @@ impl<'source_code> Lexer<'source_code> {
         (tokens, self.errors)
     }
 
+    fn consume_equals_or_fat_arrow(&mut self) -> Option<Token> {
+        let begin = self.current_location();
+
+        _ = self.next_char()?;
+
+        let kind = match self.peek_char() {
+            Some('=') => {
+                self.consume_char();
+                TokenKind::Punctuator(Punctuator::Equals)
+            }
+
+            Some('>') => {
+                self.consume_char();
+                TokenKind::Punctuator(Punctuator::FatArrow)
+            }
+
+            _ => TokenKind::Punctuator(Punctuator::Assignment),
+        };
+
+        let end = self.current_location();
+
+        Some(Token {
+            kind,
+            begin,
+            end,
+            documentation: None,
+        })
+    }
+
     fn consume_minus_or_arrow(&mut self) -> Option<Token> {
         let begin = self.current_location();
 
         _ = self.next_char()?;
 
-        let kind = if self.peek_char() == Some('>') {
-            self.consume_char();
-            TokenKind::Punctuator(Punctuator::Arrow)
-        } else {
-            TokenKind::Punctuator(Punctuator::HyphenMinus)
+        let kind = match self.peek_char() {
+            Some('>') => {
+                self.consume_char();
+                TokenKind::Punctuator(Punctuator::Arrow)
+            }
+
+            Some('=') => {
+                self.consume_char();
+                TokenKind::Punctuator(Punctuator::MinusEquals)
+            }
+
+            _ => TokenKind::Punctuator(Punctuator::HyphenMinus),
         };
 
         let end = self.current_location();
@@ -442,6 +651,7 @@ impl<'source_code> Lexer<'source_code> {
             kind,
             begin,
             end,
+            documentation: None,
         })
     }
 }
@@ -461,6 +671,16 @@ fn is_identifier_char(c: char) -> bool {
         || c == '_'
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpenBracket {
+    Parenthesis,
+    Square,
+    Curly,
+
+    /// The `{` of a `kies`, of which the arms are separated by commas.
+    Match,
+}
+
 #[derive(Clone, Debug)]
 pub struct LexerError {
     pub location: FileLocation,
@@ -506,11 +726,13 @@ mod tests {
         kind: TokenKind::Identifier(BabString::new_static("h")),
         begin: FileLocation::new(FileId::INTERNAL, 0, 0, 0),
         end: FileLocation::new(FileId::INTERNAL, 1, 0, 1),
+        documentation: None,
     })]
     #[case("s ", Token {
         kind: TokenKind::Identifier(BabString::new_static("s")),
         begin: FileLocation::new(FileId::INTERNAL, 0, 0, 0),
         end: FileLocation::new(FileId::INTERNAL, 1, 0, 1),
+        documentation: None,
     })]
     fn next_text(#[case] input: &'static str, #[case] expected: Token) {
         let source_code = SourceCode::new(PathBuf::new(), 0, input.to_string());
@@ -518,4 +740,40 @@ mod tests {
 
         assert_eq!(actual, Some(expected));
     }
+
+    #[rstest]
+    #[case("a / b", &[TokenKind::Identifier(BabString::new_static("a")), TokenKind::Punctuator(Punctuator::Solidus), TokenKind::Identifier(BabString::new_static("b"))])]
+    #[case("a /= b", &[TokenKind::Identifier(BabString::new_static("a")), TokenKind::Punctuator(Punctuator::SolidusEquals), TokenKind::Identifier(BabString::new_static("b"))])]
+    #[case("// a /= b\n-= +=", &[TokenKind::Punctuator(Punctuator::MinusEquals), TokenKind::Punctuator(Punctuator::PlusEquals)])]
+    #[case("*=%=->", &[TokenKind::Punctuator(Punctuator::AsteriskEquals), TokenKind::Punctuator(Punctuator::PercentageEquals), TokenKind::Punctuator(Punctuator::Arrow)])]
+    #[case("2,5", &[TokenKind::Float(2.5)])]
+    #[case("{3,5}", &[TokenKind::Punctuator(Punctuator::LeftCurlyBracket), TokenKind::Float(3.5), TokenKind::Punctuator(Punctuator::RightCurlyBracket)])]
+    #[case("(1,2)", &[TokenKind::Punctuator(Punctuator::LeftParenthesis), TokenKind::Integer(1), TokenKind::Punctuator(Punctuator::Comma), TokenKind::Integer(2), TokenKind::Punctuator(Punctuator::RightParenthesis)])]
+    #[case("[1,2]", &[TokenKind::Punctuator(Punctuator::LeftSquareBracket), TokenKind::Integer(1), TokenKind::Punctuator(Punctuator::Comma), TokenKind::Integer(2), TokenKind::Punctuator(Punctuator::RightSquareBracket)])]
+    #[case("(1.5,2)", &[TokenKind::Punctuator(Punctuator::LeftParenthesis), TokenKind::Float(1.5), TokenKind::Punctuator(Punctuator::Comma), TokenKind::Integer(2), TokenKind::Punctuator(Punctuator::RightParenthesis)])]
+    #[case("kies a {1 => 1,2 => 2}", &[
+        TokenKind::Keyword(Keyword::Kies), TokenKind::Identifier(BabString::new_static("a")), TokenKind::Punctuator(Punctuator::LeftCurlyBracket),
+        TokenKind::Integer(1), TokenKind::Punctuator(Punctuator::FatArrow), TokenKind::Integer(1), TokenKind::Punctuator(Punctuator::Comma),
+        TokenKind::Integer(2), TokenKind::Punctuator(Punctuator::FatArrow), TokenKind::Integer(2), TokenKind::Punctuator(Punctuator::RightCurlyBracket),
+    ])]
+    fn token_kinds(#[case] input: &'static str, #[case] expected: &[TokenKind]) {
+        let source_code = SourceCode::new_test(BabString::new_static(input));
+        let actual: Vec<TokenKind> = Lexer::new(&source_code).map(|token| token.kind).collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("h", &[None])]
+    #[case("/// a\nh", &[Some("a")])]
+    #[case("/// a\n///b\nh i", &[Some("a\nb"), None])]
+    #[case("// a\n//// b\nh", &[None])]
+    #[case("/// a\n\n// b\nh", &[Some("a")])]
+    fn documentation(#[case] input: &'static str, #[case] expected: &[Option<&'static str>]) {
+        let source_code = SourceCode::new_test(BabString::new_static(input));
+        let actual: Vec<Option<BabString>> = Lexer::new(&source_code).map(|token| token.documentation).collect();
+        let expected: Vec<Option<BabString>> = expected.iter().map(|x| x.map(BabString::new_static)).collect();
+
+        assert_eq!(actual, expected);
+    }
 }
@@ -13,6 +13,8 @@ pub enum Punctuator {
     AtSign,
     #[strum(serialize = "dubbele punt")]
     Colon,
+    #[strum(serialize = "dubbele pijl")]
+    FatArrow,
     #[strum(serialize = "komma")]
     Comma,
     #[strum(serialize = "open rond haakje")]
@@ -35,6 +37,10 @@ pub enum Punctuator {
     Assignment,
     #[strum(serialize = "vergelijking")]
     Equals,
+    #[strum(serialize = "ongelijkheid")]
+    NotEquals,
+    #[strum(serialize = "uitroepteken")]
+    ExclamationMark,
     #[strum(serialize = "min")]
     HyphenMinus,
     #[strum(serialize = "schuine streep")]
@@ -45,10 +51,16 @@ pub enum Punctuator {
     PercentageSign,
     #[strum(serialize = "punt")]
     Period,
+    #[strum(serialize = "twee punten")]
+    DoublePeriod,
     #[strum(serialize = "minder-dan")]
     LessThan,
     #[strum(serialize = "meer-dan")]
     GreaterThan,
+    #[strum(serialize = "minder-dan-of-gelijk")]
+    LessThanOrEqual,
+    #[strum(serialize = "meer-dan-of-gelijk")]
+    GreaterThanOrEqual,
     #[strum(serialize = "bitgewijs-en")]
     BitwiseAnd,
     #[strum(serialize = "bitgewijs-en")]
@@ -59,6 +71,18 @@ pub enum Punctuator {
     LogicalAnd,
     #[strum(serialize = "logische-of")]
     LogicalOr,
+    #[strum(serialize = "vraagteken")]
+    QuestionMark,
+    #[strum(serialize = "plus-aanwijzing")]
+    PlusEquals,
+    #[strum(serialize = "min-aanwijzing")]
+    MinusEquals,
+    #[strum(serialize = "keer-aanwijzing")]
+    AsteriskEquals,
+    #[strum(serialize = "deel-aanwijzing")]
+    SolidusEquals,
+    #[strum(serialize = "rest-aanwijzing")]
+    PercentageEquals,
 }
 
 impl Punctuator {
@@ -68,6 +92,7 @@ impl Punctuator {
             Self::Arrow => ".",
             Self::AtSign => "@",
             Self::Colon => ":",
+            Self::FatArrow => "=>",
             Self::Comma => ",",
             Self::LeftParenthesis => "(",
             Self::RightParenthesis => ")",
@@ -79,18 +104,29 @@ impl Punctuator {
             Self::PlusSign => "+",
             Self::Assignment => "=",
             Self::Equals => "==",
+            Self::NotEquals => "!=",
+            Self::ExclamationMark => "!",
             Self::HyphenMinus => "-",
             Self::Solidus => "/",
             Self::Asterisk => "*",
             Self::PercentageSign => "%",
             Self::Period => ".",
+            Self::DoublePeriod => "..",
             Self::LessThan => "<",
             Self::GreaterThan => ">",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThanOrEqual => ">=",
             Self::BitwiseAnd => "&",
             Self::BitwiseOr => "|",
             Self::BitwiseXor => "^",
             Self::LogicalAnd => "&&",
             Self::LogicalOr => "||",
+            Self::QuestionMark => "?",
+            Self::PlusEquals => "+=",
+            Self::MinusEquals => "-=",
+            Self::AsteriskEquals => "*=",
+            Self::SolidusEquals => "/=",
+            Self::PercentageEquals => "%=",
         }
     }
 }
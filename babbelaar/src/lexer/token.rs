@@ -12,6 +12,9 @@ pub struct Token {
     pub kind: TokenKind,
     pub begin: FileLocation,
     pub end: FileLocation,
+
+    /// The `///` comments in front of this token, if any.
+    pub documentation: Option<BabString>,
 }
 
 impl Token {
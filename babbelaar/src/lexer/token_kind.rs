@@ -17,6 +17,7 @@ pub enum TokenKind {
     TemplateString(Vec<TemplateStringToken>),
     // TODO change to unsigned
     Integer(i64),
+    Float(f64),
 
     Punctuator(Punctuator),
     IllegalCharacter(char),
@@ -32,6 +33,7 @@ impl TokenKind {
             Self::StringLiteral(..) => "slinger",
             Self::TemplateString(..) => "sjabloonslinger",
             Self::Integer(..) => "getal",
+            Self::Float(..) => "kommagetal",
 
             Self::Punctuator(punctuator) => punctuator.into(),
             Self::IllegalCharacter(..) => "ongeldig teken",
@@ -54,6 +56,7 @@ impl Display for TokenKind {
             Self::Identifier(ident) => ident.fmt(f),
             Self::IllegalCharacter(ch) => ch.fmt(f),
             Self::Integer(int) => int.fmt(f),
+            Self::Float(float) => float.fmt(f),
             Self::Keyword(keyword) => f.write_str(keyword.as_ref()),
             Self::Punctuator(punctuator) => punctuator.fmt(f),
             Self::CharacterLiteral(c) => f.write_fmt(format_args!("'{c}'")),
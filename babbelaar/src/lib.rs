@@ -6,6 +6,7 @@
 mod ast;
 mod builtin;
 mod constants;
+mod evaluator;
 mod interpreter;
 mod lexer;
 mod semantics;
@@ -19,29 +20,46 @@ pub use self::{
         AttributeList,
         BiExpression,
         BiOperator,
+        BreakStatement,
+        ClosureExpression,
         Comparison,
+        ConstantStatement,
+        ContinueStatement,
+        ElseStatement,
+        ElseStatementKind,
+        Enumeration,
+        EnumerationVariant,
         Expression,
         ExtensionStatement,
         Field,
         FieldInstantiation,
+        FieldPattern,
         ForIterableKind,
         ForStatement,
         FunctionCallExpression,
         FunctionStatement,
+        GenericTypeDeclaration,
         IfStatement,
+        ImportStatement,
         InterfaceSpecifier,
         InterfaceStatement,
+        MatchArm,
+        MatchExpression,
         MathOperator,
         Method,
         MethodCallExpression,
+        ModulePath,
+        ModuleStatement,
         Parameter,
         ParseDiagnostic,
         ParseError,
         Parser,
         ParseTree,
+        Pattern,
         PostfixExpression,
         PostfixExpressionKind,
         PrimaryExpression,
+        qualify_name,
         RangeExpression,
         ReturnStatement,
         Statement,
@@ -54,7 +72,10 @@ pub use self::{
         TypeSpecifier,
         UnaryExpression,
         UnaryExpressionKind,
-        VariableStatement
+        VariableName,
+        VariableStatement,
+        Visibility,
+        WhileStatement,
     },
     builtin::{
         ArrayMethod,
@@ -62,10 +83,21 @@ pub use self::{
         ArrayTypeRef,
         Builtin,
         BuiltinFunction,
+        BuiltinFunctionReturnType,
+        BuiltinInterface,
+        BuiltinInterfaceReturnType,
         BuiltinMethodReference,
+        BuiltinParameterType,
         BuiltinType,
+        IntegerType,
     },
     constants::Constants,
+    evaluator::{
+        ConstantEvaluationError,
+        ConstantEvaluator,
+        ConstantValue,
+        order_constants_by_dependency,
+    },
     interpreter::Interpreter,
     lexer::{
         Keyword,
@@ -78,11 +110,16 @@ pub use self::{
         TokenKind,
     },
     semantics::{
+        FunctionReference,
         SemanticAnalysisPhase,
         SemanticAnalyzer,
         SemanticDiagnostic,
         SemanticDiagnosticKind,
         SemanticDiagnosticSeverity,
+        SemanticEnumeration,
+        SemanticEnumerationVariant,
+        SemanticFunctionSignature,
+        SemanticGenericType,
         SemanticLocal,
         SemanticLocalKind,
         SemanticReference,
@@ -96,7 +133,9 @@ pub use self::{
         BabbelaarCommand,
         BabbelaarFixKind,
         BabString,
+        Closure,
         DocumentationProvider,
+        EnumerationId,
         ExtensionId,
         FileEdit,
         FileId,
@@ -109,6 +148,7 @@ pub use self::{
         MethodId,
         MethodOwnerId,
         OptionExt,
+        RangeValue,
         Ranged,
         Slice,
         SourceCode,
@@ -7,6 +7,7 @@ use log::warn;
 
 use crate::*;
 use super::*;
+use super::match_coverage::MatchCoverage;
 
 #[derive(Debug)]
 pub struct SemanticAnalyzer {
@@ -39,9 +40,17 @@ impl SemanticAnalyzer {
 
         match phase {
             SemanticAnalysisPhase::Phase1 => {
+                // Constants come first, since they can be used in the
+                // structures and their methods.
+                for statement in order_constants_by_dependency(tree.constants(), Statement::as_constant) {
+                    self.analyze_statement(statement);
+                }
+
                 for statement in tree.structures() {
-                    if let StatementKind::Structure(structure) = &statement.kind {
-                        self.analyze_structure(statement, structure);
+                    match &statement.kind {
+                        StatementKind::Structure(structure) => self.analyze_structure(statement, structure),
+                        StatementKind::Enumeration(enumeration) => self.analyze_enumeration(statement, enumeration),
+                        _ => (),
                     }
                 }
 
@@ -65,13 +74,32 @@ impl SemanticAnalyzer {
             }
 
             SemanticAnalysisPhase::Phase4 => {
+                self.analyze_imports(tree);
+
                 for statement in tree.all() {
+                    // Top-level constants were already analyzed in Phase1.
+                    if matches!(statement.kind, StatementKind::Constant(..)) {
+                        continue;
+                    }
+
                     self.analyze_statement(statement);
                 }
             }
         }
     }
 
+    fn analyze_imports(&mut self, tree: &ParseTree) {
+        for import in tree.imports() {
+            let name = import.path.name();
+            if !self.context.modules.contains_key(&name) {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    import.path.range(),
+                    SemanticDiagnosticKind::UnknownModule { name },
+                ));
+            }
+        }
+    }
+
     pub fn finish_analysis(&mut self) {
         if self.should_produce_diagnostics {
             self.analyze_usages();
@@ -80,8 +108,10 @@ impl SemanticAnalyzer {
 
     fn analyze_statements(&mut self, statements: &[Statement]) {
         for statement in statements {
-            if let StatementKind::Structure(structure) = &statement.kind {
-                self.analyze_structure(statement, structure);
+            match &statement.kind {
+                StatementKind::Structure(structure) => self.analyze_structure(statement, structure),
+                StatementKind::Enumeration(enumeration) => self.analyze_enumeration(statement, enumeration),
+                _ => (),
             }
         }
 
@@ -104,7 +134,8 @@ impl SemanticAnalyzer {
 
     /// Analyze a function declaration (signature) without analyzing the statements inside
     fn analyze_function_declaration(&mut self, function: &FunctionStatement, range: FileRange) {
-        if let Some(other) = self.context.current().get_function_mut(&function.name) {
+        let name = self.context.declaration_name(self.context.scope.len() - 1, &function.name);
+        if let Some(other) = self.context.current().get_function_mut(&name) {
             self.diagnostics.create(||
                 SemanticDiagnostic::new(
                     function.name.range(),
@@ -122,33 +153,8 @@ impl SemanticAnalyzer {
             return;
         }
 
-        let return_type = Box::new(
-            match &function.return_type {
-                Some(ty) => self.resolve_type(ty),
-                None => SemanticType::Builtin(BuiltinType::Null),
-            }
-        );
-
-        let parameters = function.parameters.iter()
-            .map(|param| {
-                let ty = self.resolve_type(&param.ty);
-                SemanticParameter {
-                    name: param.name.clone(),
-                    ty: Ranged::new(param.ty.range(), ty),
-                }
-            })
-            .collect();
-
-        self.context.push_function(
-            SemanticFunction {
-                name: function.name.clone(),
-                parameters,
-                parameters_right_paren_range: function.parameters_right_paren_range,
-                extern_function: None,
-                return_type,
-            },
-            range,
-        );
+        let function = self.create_semantic_function(function);
+        self.context.push_function(function, range);
     }
 
     fn analyze_expression(&mut self, expression: &Ranged<Expression>) -> SemanticValue {
@@ -168,6 +174,7 @@ impl SemanticAnalyzer {
 
     fn analyze_extension_statement(&mut self, extension: &ExtensionStatement, range: FileRange) {
         self.context.push_extension_scope(extension, range);
+        self.analyze_generic_bounds(&extension.generic_types);
 
         let ty = self.resolve_type_specifier(&extension.type_specifier);
 
@@ -179,10 +186,22 @@ impl SemanticAnalyzer {
         let interface = extension.interface_specifier.as_ref()
             .and_then(|specifier| self.resolve_interface(specifier));
 
+        let mut interface_arguments: Vec<SemanticType> = extension.interface_specifier.iter()
+            .flat_map(|specifier| specifier.type_parameters.iter())
+            .map(|ty| self.resolve_type(ty))
+            .collect();
+
+        // The builtin interfaces are usually implemented for the type itself,
+        // so `uitbreiding Optelbaar op Geld` means `Optelbaar<Geld>`.
+        if interface.as_ref().is_some_and(|x| x.is_builtin() && !x.generic_types.is_empty()) && interface_arguments.is_empty() {
+            interface_arguments.push(ty.clone());
+        }
+
         let mut ext = SemanticExtension {
             ty,
             interface,
-            generic_types: extension.generic_types.iter().map(|x| x.value().clone()).collect(),
+            interface_arguments,
+            generic_types: SemanticGenericType::from_declarations(&extension.generic_types, 0),
             methods: HashMap::new(),
             range,
             right_curly_bracket: extension.right_curly_bracket,
@@ -224,14 +243,14 @@ impl SemanticAnalyzer {
         }
 
         if let Some(interface) = &ext.interface {
-            self.is_invalid_method_in_interface_extension(name, method, interface, function)
+            self.is_invalid_method_in_interface_extension(name, method, interface, &ext.interface_generic_arguments(), function)
         } else {
             self.is_invalid_method_in_normal_extension(ext, name, method)
         }
     }
 
     /// Returns whether or not this method is invalid.
-    fn is_invalid_method_in_interface_extension(&mut self, name: &BabString, method: &Method, interface: &SemanticInterface, function: SemanticFunctionAnalysis) -> bool {
+    fn is_invalid_method_in_interface_extension(&mut self, name: &BabString, method: &Method, interface: &SemanticInterface, arguments: &HashMap<usize, SemanticType>, function: SemanticFunctionAnalysis) -> bool {
         let Some(expected_method) = interface.methods.iter().find(|x| x.name() == name) else {
             self.diagnostics.create(||
                 SemanticDiagnostic::new(
@@ -251,7 +270,7 @@ impl SemanticAnalyzer {
         };
 
         for (index, (interface_param, actual_param)) in expected_method.function.parameters.iter().zip(&method.function.parameters).enumerate() {
-            let expected_type = self.refine_type(&interface_param.ty);
+            let expected_type = self.refine_type(&interface_param.ty.value().clone().substitute_generic_types(arguments));
             let actual_type = &function.parameters[index];
             if expected_type != *actual_type {
                 self.diagnostics.create(||
@@ -298,7 +317,7 @@ impl SemanticAnalyzer {
         }
 
         let return_type = function.return_type.unwrap_or(SemanticType::null());
-        let expected_ty = self.refine_type(&expected_method.function.return_type);
+        let expected_ty = self.refine_type(&expected_method.return_type().substitute_generic_types(arguments));
 
         if return_type != expected_ty {
             let range = method.function.return_type.as_ref().map(|x| x.range()).unwrap_or(method.function.parameters_right_paren_range);
@@ -310,7 +329,7 @@ impl SemanticAnalyzer {
                 SemanticDiagnostic::new(
                     range,
                     SemanticDiagnosticKind::InterfaceDeclarationHasDifferentReturnType {
-                        expected: expected_method.return_type(),
+                        expected: expected_ty,
                         actual: return_type,
                     }
                 )
@@ -378,6 +397,7 @@ impl SemanticAnalyzer {
         let is_definition = function.body.is_some();
 
         self.context.push_function_scope(function, this);
+        self.analyze_generic_bounds(&function.generic_types);
 
         let mut analysis = SemanticFunctionAnalysis::default();
 
@@ -450,7 +470,9 @@ impl SemanticAnalyzer {
             StatementKind::Expression(expr) => {
                 let value = self.analyze_expression(expr);
 
-                if let SemanticUsage::Pure(pure) = value.usage {
+                if value.ty.is_result() {
+                    self.report_unhandled_result(expr, statement, value.ty);
+                } else if let SemanticUsage::Pure(pure) = value.usage {
                     let diag = SemanticDiagnostic::new(expr.range(), SemanticDiagnosticKind::UnusedPureValue { ty: value.ty.to_string().into() })
                         .warn()
                         .with_action(BabbelaarCodeAction::new(
@@ -506,23 +528,86 @@ impl SemanticAnalyzer {
 
                 self.context.statements_state.pop();
             }
+            StatementKind::Break(statement) => self.analyze_break_statement(statement),
+            StatementKind::Constant(constant) => self.analyze_constant_statement(constant, statement),
+            StatementKind::Continue(statement) => self.analyze_continue_statement(statement),
+            StatementKind::Enumeration(..) => (),
             StatementKind::For(statement) => self.analyze_for_statement(statement),
             StatementKind::Function(function) => {
                 self.analyze_function(function, None);
             }
             StatementKind::If(statement) => self.analyze_if_statement(statement),
-            StatementKind::Interface(..) => (),
+            StatementKind::Interface(interface) => self.analyze_generic_bounds(&interface.generic_types),
             StatementKind::Return(function) => self.analyze_return_statement(function),
-            StatementKind::Structure(..) => (),
+            StatementKind::Structure(structure) => self.analyze_structure_methods(structure),
             StatementKind::Variable(variable) => self.analyze_variable_statement(variable, statement),
+            StatementKind::While(statement) => self.analyze_while_statement(statement),
         }
     }
 
+    fn report_assignment_to_constant(&mut self, range: FileRange, name: &BabString, declaration_range: FileRange) {
+        self.diagnostics.create(|| SemanticDiagnostic::new(
+            range,
+            SemanticDiagnosticKind::AssignmentToConstant { name: name.clone() },
+        ).with_related(SemanticRelatedInformation::new(
+            declaration_range,
+            SemanticRelatedMessage::ConstantDefinedHere { name: name.clone() },
+        )));
+    }
+
+    fn report_unhandled_result(&mut self, expr: &Ranged<Expression>, statement: &Statement, ty: SemanticType) {
+        let returns_result = self.context.current().return_type.as_ref()
+            .is_some_and(|ty| ty.value().is_result());
+
+        let mut diag = SemanticDiagnostic::new(expr.range(), SemanticDiagnosticKind::UnhandledResult { ty: ty.clone() })
+            .warn();
+
+        if returns_result {
+            diag = diag.with_action(BabbelaarCodeAction::new(
+                BabbelaarCodeActionType::Insert { text: "?" },
+                vec![
+                    FileEdit::new(expr.range().end().as_zero_range(), "?".to_string())
+                ]
+            ));
+        }
+
+        let diag = diag.with_action(BabbelaarCodeAction::new(
+            BabbelaarCodeActionType::AssignToNewVariable,
+            vec![
+                FileEdit::new(
+                    statement.range.start().as_zero_range(),
+                    match self.find_canonical_name_for_variable(expr) {
+                        Some(name) => format!("stel {name}"),
+                        None => format!("stel {} = ", ty.value_or_field_name_hint()),
+                    }
+                )
+            ]
+        ));
+
+        self.diagnostics.create(|| diag);
+    }
+
     fn analyze_assignment_destination(&mut self, range: FileRange, expression: &Expression) {
         match expression {
-            Expression::Primary(PrimaryExpression::Reference(..)) => return,
+            Expression::Primary(PrimaryExpression::Reference(reference)) => {
+                let name = self.context.resolve_name(reference.value());
+                let constant = self.context.scope.iter().rev()
+                    .find_map(|scope| scope.locals.get(&name))
+                    .filter(|local| local.kind == SemanticLocalKind::Constant);
+
+                if let Some(constant) = constant {
+                    self.report_assignment_to_constant(range, reference.value(), constant.name_declaration_range);
+                }
+
+                return;
+            }
 
             Expression::Postfix(postfix) => {
+                if let Some((_, constant)) = self.resolve_structure_constant_of_postfix(postfix) {
+                    self.report_assignment_to_constant(range, constant.name.value(), constant.name.range());
+                    return;
+                }
+
                 match postfix.kind.value() {
                     PostfixExpressionKind::Member(..) => return,
                     PostfixExpressionKind::Subscript(..) => return,
@@ -541,6 +626,24 @@ impl SemanticAnalyzer {
         ));
     }
 
+    fn analyze_break_statement(&mut self, statement: &BreakStatement) {
+        if !self.context.is_inside_loop() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                statement.keyword,
+                SemanticDiagnosticKind::BreakOutsideLoop,
+            ));
+        }
+    }
+
+    fn analyze_continue_statement(&mut self, statement: &ContinueStatement) {
+        if !self.context.is_inside_loop() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                statement.range,
+                SemanticDiagnosticKind::ContinueOutsideLoop,
+            ));
+        }
+    }
+
     fn analyze_for_statement(&mut self, statement: &ForStatement) {
         let ty = match statement.iterable.value() {
             ForIterableKind::Expression(expression) => {
@@ -563,7 +666,7 @@ impl SemanticAnalyzer {
             });
         }
 
-        let scope = self.context.push_block_scope(statement.file_range);
+        let scope = self.context.push_loop_scope(statement.file_range);
         scope.locals.insert(statement.iterator_name.value().clone(), SemanticLocal::new(
             SemanticLocalKind::Iterator,
             ty,
@@ -581,10 +684,14 @@ impl SemanticAnalyzer {
                 return *item_type;
             }
 
+            SemanticType::Builtin(BuiltinType::Reeks) => {
+                return SemanticType::Builtin(BuiltinType::G32);
+            }
+
             SemanticType::Custom { ref base, ref parameters } => {
                 if let Some(interface) = self.resolve_interface_by_name(&BabString::new_static("Doorloper")) {
                     if let Some(extension) = self.get_interface_implementation_for(&ty, &interface) {
-                        let ty_name = interface.generic_types[0].value().clone();
+                        let ty_name = interface.generic_types[0].name.clone();
                         let ty_idx = base.index_of_generic_type(&ty_name).expect("Het vinden van de generieke parameter van `Doorloper`");
                         _ = extension; // Dit zou gebruikt moeten worden?
                         return parameters[ty_idx].clone();
@@ -614,6 +721,22 @@ impl SemanticAnalyzer {
     fn analyze_range(&mut self, range: &RangeExpression) {
         self.analyze_range_param("Startwaarde", &range.start);
         self.analyze_range_param("Eindwaarde", &range.end);
+
+        if let Some(step) = &range.step {
+            self.analyze_range_param("Stap", step);
+
+            let context = &self.context;
+            let mut evaluator = ConstantEvaluator::new(|owner: Option<&BabString>, name: &BabString| {
+                resolve_constant_value(context, owner, name)
+            });
+
+            if let Ok(ConstantValue::Integer { value: 0, .. }) = evaluator.evaluate(step) {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    step.range(),
+                    SemanticDiagnosticKind::RangeStepIsZero,
+                ));
+            }
+        }
     }
 
     fn analyze_range_param(&mut self, name: &'static str, expression: &Ranged<Expression>) {
@@ -633,26 +756,72 @@ impl SemanticAnalyzer {
     }
 
     fn analyze_if_statement(&mut self, statement: &IfStatement) {
-        self.context.push_block_scope(statement.range);
+        let range = match &statement.else_statement {
+            Some(else_statement) => FileRange::new(statement.range.start(), else_statement.range.start()),
+            None => statement.range,
+        };
+
+        self.context.push_block_scope(range);
+
+        self.analyze_condition(&statement.condition);
 
-        self.analyze_expression(&statement.condition);
+        self.analyze_statements(&statement.body);
+
+        self.context.pop_scope();
+
+        if let Some(else_statement) = &statement.else_statement {
+            self.analyze_else_statement(else_statement);
+        }
+    }
+
+    fn analyze_else_statement(&mut self, statement: &ElseStatement) {
+        match &statement.kind {
+            ElseStatementKind::ElseIf(if_statement) => self.analyze_if_statement(if_statement),
+
+            ElseStatementKind::Else(body) => {
+                self.context.push_block_scope(statement.range);
+                self.analyze_statements(body);
+                self.context.pop_scope();
+            }
+        }
+    }
+
+    fn analyze_while_statement(&mut self, statement: &WhileStatement) {
+        self.analyze_condition(&statement.condition);
+
+        self.context.push_loop_scope(statement.range);
 
         self.analyze_statements(&statement.body);
 
         self.context.pop_scope();
     }
 
+    fn analyze_condition(&mut self, condition: &Ranged<Expression>) {
+        let ty = self.analyze_expression(condition).ty;
+
+        if ty.is_null() || ty == SemanticType::Builtin(BuiltinType::Bool) {
+            return;
+        }
+
+        self.diagnostics.create(|| SemanticDiagnostic::new(
+            condition.range(),
+            SemanticDiagnosticKind::ConditionExpectsBoolean { ty },
+        ));
+    }
+
     fn analyze_return_statement(&mut self, statement: &ReturnStatement) {
         match (&statement.expression, self.context.current().return_type.clone()) {
             (Some(actual), Some(expected)) => {
-                let actual_type = self.analyze_expression(actual);
+                let actual_type = self.analyze_value_expression(actual);
                 let conversion_actions = self.try_create_conversion_actions(&expected, &actual_type.ty, actual);
 
                 if actual_type.ty == SemanticType::Builtin(BuiltinType::Null) {
                     return;
                 }
 
-                if actual_type.ty != *expected.value() {
+                self.check_integer_literal_range(actual, expected.value());
+
+                if !is_assignable(expected.value(), &actual_type.ty, actual.value()) {
                     self.diagnostics.create(||
                         SemanticDiagnostic::new(
                             actual.range(),
@@ -693,6 +862,7 @@ impl SemanticAnalyzer {
                         SemanticScopeKind::TopLevel => None,
                         SemanticScopeKind::Structure => None,
                         SemanticScopeKind::Werkwijze => None,
+                        SemanticScopeKind::Loop => None,
                         SemanticScopeKind::Function { right_parameter_range, .. } => Some(right_parameter_range.clone()),
                     })
                     .next();
@@ -738,6 +908,7 @@ impl SemanticAnalyzer {
                         SemanticScopeKind::TopLevel => None,
                         SemanticScopeKind::Structure => None,
                         SemanticScopeKind::Werkwijze => None,
+                        SemanticScopeKind::Loop => None,
                         SemanticScopeKind::Function { right_parameter_range, .. } => Some(right_parameter_range.clone()),
                     })
                     .next();
@@ -781,10 +952,11 @@ impl SemanticAnalyzer {
         let semantic_interface = Arc::new(SemanticInterface {
             attributes: statement.attributes.clone(),
             name: interface.name.clone(),
-            generic_types: interface.generic_types.clone(),
+            generic_types: SemanticGenericType::from_declarations(&interface.generic_types, 0),
             left_curly_range: interface.left_curly_range,
             right_curly_range: interface.right_curly_range,
             methods,
+            documentation: interface.documentation.clone(),
         });
 
         self.context.push_interface(Arc::clone(&semantic_interface));
@@ -815,25 +987,48 @@ impl SemanticAnalyzer {
     fn analyze_structure(&mut self, statement: &Statement, structure: &Structure) {
         self.context.push_structure_scope(structure);
 
+        let mut constants: Vec<SemanticConstant> = Vec::new();
+        for constant in order_constants_by_dependency(&structure.constants, Some) {
+            let (ty, value) = self.analyze_constant(constant, Some((structure.name.value(), &constants)));
+
+            let local = SemanticLocal::new(SemanticLocalKind::Constant, ty.clone(), constant.name.range())
+                .with_constant_value(value.clone());
+            self.context.push_local(&constant.name, local);
+
+            constants.push(SemanticConstant {
+                name: constant.name.clone(),
+                ty,
+                value,
+            });
+        }
+
         let fields: Vec<SemanticField> = structure.fields.iter().map(|x| SemanticField {
             attributes: x.attributes.clone(),
             name: x.name.clone(),
             ty: self.resolve_type(&x.ty),
             has_default_value: x.default_value.is_some(),
+            visibility: x.visibility,
+            documentation: x.documentation.clone(),
         }).collect();
 
-        let methods = structure.methods.iter().map(|x| self.create_semantic_method(x)).collect();
-
-        let semantic_structure = Arc::new(SemanticStructure {
+        let mut semantic_structure = SemanticStructure {
             attributes: statement.attributes.clone(),
             name: structure.name.clone(),
-            generic_types: structure.generic_types.clone(),
+            generic_types: SemanticGenericType::from_declarations(&structure.generic_types, 0),
             left_curly_range: structure.left_curly_range,
             right_curly_range: structure.right_curly_range,
+            constants,
             fields,
-            methods,
-        });
+            methods: Vec::new(),
+            documentation: structure.documentation.clone(),
+        };
+
+        // Methods can refer to their own structure, e.g. `gedeeld werkwijze
+        // maak() -> Persoon`, so the structure is declared before them.
+        self.context.declare_structure(Arc::new(semantic_structure.clone()));
+        semantic_structure.methods = structure.methods.iter().map(|x| self.create_semantic_method(x)).collect();
 
+        let semantic_structure = Arc::new(semantic_structure);
         self.context.push_structure(Arc::clone(&semantic_structure));
 
         for field in &structure.fields {
@@ -857,8 +1052,33 @@ impl SemanticAnalyzer {
             self.analyze_attributes_for_field(field);
         }
 
+        self.context.pop_scope();
+    }
+
+    /// Analyzes the bodies of the methods of a structure. Unlike the fields
+    /// and signatures, this is done after all interfaces, extensions and
+    /// functions are declared, since the methods can refer to them.
+    fn analyze_structure_methods(&mut self, structure: &Structure) {
+        let name = self.context.resolve_name(structure.name.value());
+        let Some(semantic_structure) = self.context.scope.iter().rev()
+            .filter_map(|scope| scope.structures.get(&name))
+            .find(|x| x.name.range() == structure.name.range())
+            .cloned() else {
+            return;
+        };
+
+        self.context.push_structure_scope(structure);
+
+        self.analyze_generic_bounds(&structure.generic_types);
+
+        for constant in &semantic_structure.constants {
+            let local = SemanticLocal::new(SemanticLocalKind::Constant, constant.ty.clone(), constant.name.range())
+                .with_constant_value(constant.value.clone());
+            self.context.current().locals.insert(constant.name.value().clone(), local);
+        }
+
         let this_type = Some(SemanticType::Custom {
-            base: Arc::clone(&semantic_structure),
+            base: semantic_structure,
             parameters: Vec::new(),
         });
 
@@ -874,22 +1094,80 @@ impl SemanticAnalyzer {
                 );
             }
 
-            self.analyze_function(&method.function, this_type.clone());
+            let this = if method.is_static { None } else { this_type.clone() };
+            self.analyze_function(&method.function, this);
         }
 
         self.context.pop_scope();
     }
 
+    fn analyze_enumeration(&mut self, statement: &Statement, enumeration: &Enumeration) {
+        let mut names: HashMap<&BabString, FileRange> = HashMap::new();
+        let mut variants = Vec::new();
+
+        for variant in &enumeration.variants {
+            if let Some(first) = names.get(variant.name.value()).copied() {
+                self.diagnostics.create(||
+                    SemanticDiagnostic::new(
+                        variant.name.range(),
+                        SemanticDiagnosticKind::DuplicateVariantName {
+                            name: variant.name.value().clone(),
+                            enumeration: enumeration.name.value().clone(),
+                        },
+                    )
+                    .with_related(SemanticRelatedInformation::new(
+                        first,
+                        SemanticRelatedMessage::DuplicateVariantFirstDefinedHere { name: variant.name.value().clone() },
+                    ))
+                );
+            } else {
+                names.insert(variant.name.value(), variant.name.range());
+            }
+
+            let mut field_names = HashSet::new();
+            for field in &variant.fields {
+                if !field_names.insert(field.name.value()) {
+                    self.diagnostics.create(||
+                        SemanticDiagnostic::new(
+                            field.name.range(),
+                            SemanticDiagnosticKind::DuplicateFieldName { name: field.name.value().clone() },
+                        )
+                    );
+                }
+            }
+
+            variants.push(SemanticEnumerationVariant {
+                name: variant.name.clone(),
+                fields: variant.fields.iter().map(|x| self.create_semantic_parameter(x)).collect(),
+            });
+        }
+
+        self.context.push_enumeration(Arc::new(SemanticEnumeration {
+            attributes: statement.attributes.clone(),
+            name: enumeration.name.clone(),
+            left_curly_range: enumeration.left_curly_range,
+            right_curly_range: enumeration.right_curly_range,
+            variants,
+        }));
+    }
+
     #[must_use]
     fn create_semantic_method(&mut self, method: &Method) -> SemanticMethod {
         SemanticMethod {
             range: method.range,
             function: self.create_semantic_function(&method.function),
+            is_static: method.is_static,
         }
     }
 
     #[must_use]
     fn create_semantic_function(&mut self, function: &FunctionStatement) -> SemanticFunction {
+        let generic_types = self.context.function_generic_types(function);
+
+        // The types of the parameters and return value can refer to the
+        // generic types of the function itself.
+        self.context.push_function_scope(function, None);
+
         let parameters = function.parameters.iter()
             .map(|param| self.create_semantic_parameter(param))
             .collect();
@@ -901,12 +1179,17 @@ impl SemanticAnalyzer {
             }
         );
 
+        self.context.pop_scope();
+
         SemanticFunction {
             name: function.name.clone(),
+            generic_types,
             parameters,
             parameters_right_paren_range: function.parameters_right_paren_range,
             extern_function: None,
             return_type,
+            visibility: function.visibility,
+            documentation: function.documentation.clone(),
         }
     }
 
@@ -918,29 +1201,240 @@ impl SemanticAnalyzer {
         }
     }
 
+    fn analyze_constant_statement(&mut self, constant: &ConstantStatement, statement: &Statement) {
+        let (typ, value) = self.analyze_constant(constant, None);
+
+        let local = SemanticLocal::new(
+            SemanticLocalKind::Constant,
+            typ,
+            constant.name.range(),
+        )
+        .with_declaration_range(statement.range)
+        .with_constant_value(value);
+
+        self.context.push_constant(&constant.name, local);
+    }
+
+    /// Analyzes the type and initializer of a `vast` constant, and evaluates
+    /// its value. The `structure` contains the constants that were declared
+    /// before this one in the same structure, if any.
+    fn analyze_constant(&mut self, constant: &ConstantStatement, structure: Option<(&BabString, &[SemanticConstant])>) -> (SemanticType, Option<ConstantValue>) {
+        let typ = self.resolve_type(&constant.ty);
+        let builtin = match &typ {
+            SemanticType::Builtin(ty) if *ty != BuiltinType::Null => Some(*ty),
+            _ => None,
+        };
+
+        let diagnostic_count = self.diagnostics.as_slice().len();
+        if builtin.is_none() && !typ.is_null() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                constant.ty.range(),
+                SemanticDiagnosticKind::InvalidConstantType { ty: typ.clone() },
+            ));
+        }
+
+        let context = &self.context;
+        let mut evaluator = ConstantEvaluator::new(|owner: Option<&BabString>, name: &BabString| {
+            let sibling = structure
+                .filter(|(structure, _)| owner.is_none_or(|owner| owner == *structure))
+                .and_then(|(_, constants)| constants.iter().find(|x| x.name.value() == name));
+            if let Some(sibling) = sibling {
+                return sibling.value.clone();
+            }
+
+            resolve_constant_value(context, owner, name)
+        });
+
+        if let Some(integer_type) = builtin.and_then(|ty| ty.integer_type()) {
+            evaluator = evaluator.with_integer_type(integer_type);
+        }
+
+        let result = evaluator.evaluate(&constant.value);
+
+        // Top-level constants are analyzed before the werkwijzen and
+        // structures are declared, so e.g. calls are reported here instead of
+        // as unknown references.
+        if let Err(ConstantEvaluationError::NotConstant { range }) = &result {
+            if self.diagnostics.as_slice().len() == diagnostic_count {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    *range,
+                    SemanticDiagnosticKind::NonConstantInitializer,
+                ));
+            }
+
+            return (typ, None);
+        }
+
+        let value_type = self.analyze_expression(&constant.value).ty;
+
+        // The evaluator converts integers to the type of the constant, and
+        // reports values that don't fit.
+        let is_integer_conversion = typ.is_primitive_number() && value_type.is_primitive_number();
+        if !value_type.is_null() && !typ.is_null() && !typ.is_compatible_with(&value_type) && !is_integer_conversion {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                constant.value.range(),
+                SemanticDiagnosticKind::IncompatibleConstantType {
+                    expected: typ.clone(),
+                    actual: value_type,
+                },
+            ));
+            return (typ, None);
+        }
+
+        match result {
+            Ok(value) => (typ, Some(value)),
+            Err(error) => {
+                // When other diagnostics were already reported, those are
+                // most likely the cause of this error.
+                if self.diagnostics.as_slice().len() == diagnostic_count {
+                    let range = error.range();
+                    let kind = match error {
+                        // Since the constants are analyzed in the order of
+                        // their dependencies, an unknown constant here is a
+                        // variable or werkwijze instead.
+                        ConstantEvaluationError::UnknownConstant { .. } => SemanticDiagnosticKind::NonConstantInitializer,
+                        error => SemanticDiagnosticKind::ConstantNotEvaluable { error },
+                    };
+
+                    self.diagnostics.create(|| SemanticDiagnostic::new(range, kind));
+                }
+
+                (typ, None)
+            }
+        }
+    }
+
     fn analyze_variable_statement(&mut self, statement: &VariableStatement, stmt: &Statement) {
-        let typ = self.analyze_expression(&statement.expression).ty;
+        let mut typ = self.analyze_value_expression(&statement.expression).ty;
+
+        if let Some(declared) = &statement.ty {
+            let declared_type = self.resolve_type(declared);
+
+            if !typ.is_null() && !declared_type.is_null() && !is_assignable(&declared_type, &typ, statement.expression.value()) {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    statement.expression.range(),
+                    SemanticDiagnosticKind::IncompatibleVariableType {
+                        expected: declared_type.clone(),
+                        actual: typ.clone(),
+                    },
+                ));
+            }
+
+            typ = declared_type;
+        } else if typ.is_empty_array() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                statement.expression.range(),
+                SemanticDiagnosticKind::EmptyArrayWithoutType,
+            ));
+        }
+
+        // Without an annotation, an integer literal is a `g32`.
+        self.check_integer_literal_range(&statement.expression, &typ);
+
+        match &statement.name {
+            VariableName::Single(name) => {
+                self.declare_variable(name, typ, stmt.range);
+            }
+
+            VariableName::Tuple(names) => {
+                let element_types = match typ {
+                    SemanticType::Tuple(elements) if elements.len() == names.len() => elements,
 
-        if statement.name.value() == &Constants::DISCARDING_IDENT {
+                    SemanticType::Tuple(ref elements) => {
+                        self.diagnostics.create(|| SemanticDiagnostic::new(
+                            statement.name.range(),
+                            SemanticDiagnosticKind::DestructuringCountMismatch {
+                                ty: typ.clone(),
+                                expected: names.len(),
+                                actual: elements.len(),
+                            },
+                        ));
+                        vec![SemanticType::null(); names.len()]
+                    }
+
+                    ty => {
+                        if !ty.is_null() {
+                            self.diagnostics.create(|| SemanticDiagnostic::new(
+                                statement.expression.range(),
+                                SemanticDiagnosticKind::DestructuringRequiresTuple { ty },
+                            ));
+                        }
+                        vec![SemanticType::null(); names.len()]
+                    }
+                };
+
+                for (name, typ) in names.iter().zip(element_types) {
+                    self.declare_variable(name, typ, stmt.range);
+                }
+            }
+        }
+    }
+
+    fn declare_variable(&mut self, name: &Ranged<BabString>, typ: SemanticType, declaration_range: FileRange) {
+        if name.value() == &Constants::DISCARDING_IDENT {
             return;
         }
 
         let local = SemanticLocal::new(
             SemanticLocalKind::Variable,
             typ,
-            statement.name.range(),
+            name.range(),
+        );
+
+        let local = local.with_declaration_range(declaration_range);
+
+        self.context.push_local(name, local);
+    }
+
+    /// Analyzes an expression of which the value is used, e.g. stored in a
+    /// variable. Calls to werkwijzen that don't return anything can't be used
+    /// there, since there is no `null` to fall back to.
+    fn analyze_value_expression(&mut self, expression: &Ranged<Expression>) -> SemanticValue {
+        let diagnostic_count = self.diagnostics.as_slice().len();
+        let value = self.analyze_expression(expression);
+
+        let is_call = matches!(
+            expression.value(),
+            Expression::Postfix(PostfixExpression { kind, .. })
+                if matches!(kind.value(), PostfixExpressionKind::Call(..) | PostfixExpressionKind::MethodCall(..))
         );
 
-        let local = local.with_declaration_range(stmt.range);
+        // When other diagnostics were already reported, the `null` is most
+        // likely caused by those.
+        if is_call && value.ty.is_null() && self.diagnostics.as_slice().len() == diagnostic_count {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                expression.range(),
+                SemanticDiagnosticKind::ExpressionHasNoValue,
+            ));
+        }
 
-        self.context.push_local(&statement.name, local);
+        value
     }
 
     fn analyze_bi_expression(&mut self, expression: &BiExpression) -> SemanticValue {
         let lhs_type = self.analyze_expression(&expression.lhs).ty;
         let rhs_type = self.analyze_expression(&expression.rhs).ty;
 
-        if !lhs_type.is_null() && !rhs_type.is_null() && !lhs_type.is_compatible_with(&rhs_type) {
+        if let Some(value) = self.analyze_overloaded_operator(expression, &lhs_type, &rhs_type) {
+            return value;
+        }
+
+        // An integer literal on one side takes the type of the other side, as
+        // long as it fits in there.
+        let lhs_is_literal = lhs_type.is_primitive_number() && expression.lhs.as_integer_literal().is_some();
+        let lhs_type = if lhs_is_literal && rhs_type.is_primitive_number() {
+            rhs_type.clone()
+        } else {
+            lhs_type
+        };
+
+        if !lhs_is_literal {
+            self.check_integer_literal_range(&expression.rhs, &lhs_type);
+        } else if !rhs_type.is_null() {
+            self.check_integer_literal_range(&expression.lhs, &rhs_type);
+        }
+
+        if !lhs_type.is_null() && !rhs_type.is_null() && !is_assignable(&lhs_type, &rhs_type, expression.rhs.value()) {
             self.diagnostics.create(|| SemanticDiagnostic::new(
                 expression.operator.range(),
                 SemanticDiagnosticKind::IncompatibleTypes {
@@ -950,30 +1444,172 @@ impl SemanticAnalyzer {
             ));
         }
 
+        if let BiOperator::Comparison(comparison) = expression.operator.value() {
+            let is_ordering = !matches!(comparison, Comparison::Equality | Comparison::Inequality);
+            let is_ordered = lhs_type.is_primitive_number()
+                || matches!(lhs_type, SemanticType::Builtin(BuiltinType::Kommagetal | BuiltinType::Teken));
+
+            if is_ordering && !is_ordered && !lhs_type.is_null() {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.operator.range(),
+                    SemanticDiagnosticKind::UnorderedComparison {
+                        operator: comparison.as_str(),
+                        ty: lhs_type.clone(),
+                    }
+                ));
+            }
+        }
+
+        if let BiOperator::Math(operator @ (MathOperator::BitwiseAnd | MathOperator::BitwiseOr | MathOperator::BitwiseXor)) = expression.operator.value() {
+            if lhs_type == SemanticType::Builtin(BuiltinType::Kommagetal) {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.operator.range(),
+                    SemanticDiagnosticKind::BitwiseOperationOnFloat {
+                        operator: operator.as_str(),
+                    }
+                ));
+            }
+        }
+
+        let ty = match expression.operator.value() {
+            BiOperator::Comparison(..) => SemanticType::Builtin(BuiltinType::Bool),
+            BiOperator::Math(MathOperator::LogicalAnd | MathOperator::LogicalOr) => SemanticType::Builtin(BuiltinType::Bool),
+            BiOperator::Math(..) => lhs_type,
+        };
+
         SemanticValue {
-            ty: lhs_type,
+            ty,
             usage: SemanticUsage::Pure(PureValue::Operator {
                 operator_range: expression.operator.range(),
             }),
         }
     }
 
-    fn analyze_function_call_expression(&mut self, lhs: SemanticType, expression: &FunctionCallExpression, postfix: &PostfixExpression) -> SemanticValue {
-        // TODO: why do we this again? we only need the function and function ref...
-        let function_name = match lhs {
+    /// Analyzes an operator used on a structure (or a generic type bound by
+    /// an interface), which calls the method of the builtin interface that
+    /// belongs to the operator, such as `optellen` of `Optelbaar` for `+`.
+    ///
+    /// Returns `None` when the operator should be analyzed as usual.
+    fn analyze_overloaded_operator(&mut self, expression: &BiExpression, lhs_type: &SemanticType, rhs_type: &SemanticType) -> Option<SemanticValue> {
+        if !matches!(lhs_type, SemanticType::Custom { .. } | SemanticType::Generic(..)) {
+            return None;
+        }
+
+        let builtin = BuiltinInterface::for_operator(*expression.operator.value())?;
+        let interface = Arc::clone(self.context.scope[0].interfaces.get(&BabString::new_static(builtin.name))?);
+
+        let ty = match expression.operator.value() {
+            BiOperator::Comparison(..) => SemanticType::Builtin(BuiltinType::Bool),
+            BiOperator::Math(..) => lhs_type.clone(),
+        };
+
+        let usage = SemanticUsage::Pure(PureValue::Operator {
+            operator_range: expression.operator.range(),
+        });
+
+        if let SemanticType::Generic(..) = lhs_type {
+            if !self.implements_interface(lhs_type, &interface) {
+                return None;
+            }
+
+            return Some(SemanticValue { ty, usage });
+        }
+
+        let Some(extension) = self.get_interface_implementation_for(lhs_type, &interface) else {
+            // Structures can always be compared for equality.
+            if matches!(expression.operator.value(), BiOperator::Comparison(Comparison::Equality | Comparison::Inequality)) {
+                return None;
+            }
+
+            let action = match lhs_type {
+                SemanticType::Custom { base, .. } => Some(self.create_actions_extend_structure_with_interface(base, expression.operator.range().file_id(), &interface)),
+                _ => None,
+            };
+
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                expression.operator.range(),
+                SemanticDiagnosticKind::OperatorNotImplemented {
+                    operator: expression.operator.as_str(),
+                    ty: lhs_type.clone(),
+                    interface: builtin.name,
+                },
+            ).with_action(action));
+
+            return Some(SemanticValue { ty, usage });
+        };
+
+        let method = extension.methods.get(&BabString::new_static(builtin.method_name))?.clone();
+
+        let parameter_type = method.function.parameters.first()?.ty.value().clone().resolve_against(lhs_type);
+        if !rhs_type.is_null() && !is_assignable(&parameter_type, rhs_type, expression.rhs.value()) {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                expression.operator.range(),
+                SemanticDiagnosticKind::IncompatibleTypes {
+                    lhs_type: parameter_type,
+                    rhs_type: rhs_type.clone(),
+                }
+            ));
+        }
+
+        if let Some(tracker) = &mut self.context.definition_tracker {
+            tracker.insert(expression.operator.range(), SemanticReference {
+                local_name: method.name().clone(),
+                local_kind: SemanticLocalKind::Method,
+                declaration_range: method.function.name.range(),
+                typ: SemanticType::FunctionReference(FunctionReference::Custom(method.function.clone())),
+            });
+        }
+
+        let ty = match expression.operator.value() {
+            BiOperator::Comparison(..) => ty,
+            BiOperator::Math(..) => method.return_type().resolve_against(lhs_type),
+        };
+
+        Some(SemanticValue { ty, usage })
+    }
+
+    fn analyze_function_call_expression(&mut self, lhs: SemanticType, expression: &FunctionCallExpression, postfix: &PostfixExpression) -> SemanticValue {
+        // TODO: why do we this again? we only need the function and function ref...
+        let function_name = match &lhs {
             SemanticType::Array(..) => postfix.lhs.value().to_string().into(),
             SemanticType::Builtin(BuiltinType::Null) => postfix.lhs.value().to_string().into(),
             SemanticType::Builtin(builtin) => builtin.name(),
             SemanticType::Custom { base, .. } => base.name.value().clone(),
-            SemanticType::Function(func) => func.name.value().clone(),
+            SemanticType::Enumeration(base) => base.name.value().clone(),
+            SemanticType::Function(func) => match postfix.lhs.value().as_identifier() {
+                Some(name) => name.clone(),
+                None => func.name.value().clone(),
+            },
+            SemanticType::FunctionReference(FunctionReference::Signature(..)) => postfix.lhs.value().to_string().into(),
             SemanticType::FunctionReference(func) => func.name(),
             SemanticType::IndexReference(ty) => ty.name().clone(),
             SemanticType::Interface { base, .. } => base.name.value().clone(),
             SemanticType::Generic(ty) => ty.name.clone(),
+            SemanticType::Optional(..) => postfix.lhs.value().to_string().into(),
+            SemanticType::Result { .. } => postfix.lhs.value().to_string().into(),
             SemanticType::Pointer(..) => postfix.lhs.value().to_string().into(),
+            SemanticType::Tuple(..) => postfix.lhs.value().to_string().into(),
         };
 
-        let Some(function) = self.find_and_use_function(&function_name) else {
+        let function = match &lhs {
+            // The callee was already resolved while analyzing the lhs, which
+            // might be a local containing an anonymous `werkwijze`.
+            SemanticType::Function(..) | SemanticType::FunctionReference(..) => Some(SemanticReference {
+                local_name: function_name.clone(),
+                local_kind: SemanticLocalKind::FunctionReference,
+                declaration_range: lhs.declaration_range(),
+                typ: lhs.clone(),
+            }),
+            _ => {
+                let function = self.find_and_use_function(&function_name);
+                if let Some(SemanticType::Function(func) | SemanticType::FunctionReference(FunctionReference::Custom(func))) = function.as_ref().map(|x| &x.typ) {
+                    self.check_accessible(postfix.lhs.range(), "werkwijze", &func.name, func.visibility.as_ref());
+                }
+                function
+            }
+        };
+
+        let Some(function) = function else {
             let diag = SemanticDiagnostic::new(
                 postfix.lhs.range(),
                 SemanticDiagnosticKind::InvalidFunctionReference { name: function_name.clone() }
@@ -987,10 +1623,18 @@ impl SemanticAnalyzer {
 
         let ret_typ = function.return_value();
 
-        self.analyze_function_parameters(function_name, function, expression, None);
+        // A `Resultaat` must always be handled, even if the builtin itself
+        // isn't marked as `must_use`.
+        let must_use = match &function.typ {
+            SemanticType::FunctionReference(FunctionReference::Builtin(builtin)) => builtin.must_use || ret_typ.is_result(),
+            _ => true,
+        };
+
+        let inferred = self.analyze_function_parameters(function_name, function, expression, None);
+        let ret_typ = ret_typ.substitute_generic_types(&inferred);
 
         SemanticValue {
-            usage: if ret_typ == SemanticType::Builtin(BuiltinType::Null) {
+            usage: if ret_typ == SemanticType::Builtin(BuiltinType::Null) || !must_use {
                 SemanticUsage::Indifferent
             } else {
                 SemanticUsage::Pure(PureValue::ReturnValue)
@@ -1000,13 +1644,15 @@ impl SemanticAnalyzer {
         }
     }
 
+    /// Checks the arguments against the parameters of the function, and
+    /// returns the types that were inferred for its generic types.
     fn analyze_function_parameters(
         &mut self,
         function_name: BabString,
         function: SemanticReference,
         expression: &FunctionCallExpression,
         this_structure: Option<&SemanticType>
-    ) {
+    ) -> HashMap<usize, SemanticType> {
         let function_hint = SemanticRelatedInformation::new(
             function.declaration_range,
             SemanticRelatedMessage::FunctionDefinedHere { name: function_name.clone() }
@@ -1061,15 +1707,25 @@ impl SemanticAnalyzer {
             self.diagnostics.create(||
                 SemanticDiagnostic::new(
                     expression.arguments[param_count].range(),
-                    SemanticDiagnosticKind::TooManyArguments { function_name: BabString::new(format!("{function:#?}")), param_count, arg_count },
+                    SemanticDiagnosticKind::TooManyArguments { function_name: function_name.clone(), param_count, arg_count },
                 )
                 .with_related(function_hint.clone())
                 .with_action(remove_parameter_action)
                 .with_action(add_parameter_action)
             );
-            return;
+            return HashMap::new();
         }
 
+        let generic_types = match &function.typ {
+            SemanticType::Function(func) => func.generic_types.clone(),
+            SemanticType::FunctionReference(FunctionReference::Custom(func)) => func.generic_types.clone(),
+            SemanticType::FunctionReference(FunctionReference::Builtin(func)) => func.generic_types(),
+            _ => Vec::new(),
+        };
+
+        let mut inferred = HashMap::new();
+        let mut inferred_ranges = HashMap::new();
+
         for (arg_idx, arg) in expression.arguments.iter().enumerate() {
             let argument_type = self.analyze_expression(arg).ty;
 
@@ -1083,7 +1739,22 @@ impl SemanticAnalyzer {
                 None => parameter_type,
             };
 
-            if !argument_type.is_compatible_with(&parameter_type) {
+            parameter_type.infer_generic_types(&argument_type, &generic_types, &mut inferred);
+            for index in inferred.keys() {
+                inferred_ranges.entry(*index).or_insert(arg.range());
+            }
+
+            let parameter_type = parameter_type.substitute_generic_types(&inferred);
+
+            self.check_integer_literal_range(arg, &parameter_type);
+
+            // Structures are given as text to builtin functions like `schrijf`,
+            // the same as in template strings.
+            let is_structure_as_text = matches!(function.typ, SemanticType::FunctionReference(FunctionReference::Builtin(..)))
+                && parameter_type == SemanticType::Builtin(BuiltinType::Slinger)
+                && matches!(argument_type, SemanticType::Custom { .. });
+
+            if !is_assignable(&parameter_type, &argument_type, arg.value()) && !is_structure_as_text {
                 let param_hint = self.resolve_parameter_name(&function, arg_idx)
                     .map(|x| SemanticRelatedInformation::new(
                         x.range(),
@@ -1099,6 +1770,27 @@ impl SemanticAnalyzer {
                 ).with_related(param_hint).with_related(function_hint.clone()))
             }
         }
+
+        for generic in generic_types {
+            if let Some(ty) = inferred.get(&generic.index) {
+                self.analyze_generic_bounds_satisfied(&generic, ty, inferred_ranges[&generic.index]);
+                continue;
+            }
+
+            if param_count != arg_count {
+                continue;
+            }
+
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                expression.token_right_paren,
+                SemanticDiagnosticKind::CannotInferGenericType { name: generic.name.clone() },
+            ).with_related(SemanticRelatedInformation::new(
+                generic.declaration_range,
+                SemanticRelatedMessage::GenericTypeDeclaredHere { name: generic.name.clone() },
+            )));
+        }
+
+        inferred
     }
 
     fn analyze_primary_expression(&mut self, expression: &PrimaryExpression, range: FileRange) -> SemanticValue {
@@ -1115,6 +1807,10 @@ impl SemanticAnalyzer {
                 SemanticType::Builtin(BuiltinType::G32)
             }
 
+            PrimaryExpression::FloatLiteral(..) => {
+                SemanticType::Builtin(BuiltinType::Kommagetal)
+            }
+
             PrimaryExpression::StringLiteral(..) => {
                 SemanticType::Builtin(BuiltinType::Slinger)
             }
@@ -1140,6 +1836,9 @@ impl SemanticAnalyzer {
                     }
 
                     this.clone()
+                } else if self.context.scope.iter().any(|scope| matches!(scope.kind, SemanticScopeKind::Structure)) {
+                    self.diagnostics.create(|| SemanticDiagnostic::new(range, SemanticDiagnosticKind::ThisInsideStaticMethod));
+                    SemanticType::null()
                 } else {
                     let diag = SemanticDiagnostic::new(range, SemanticDiagnosticKind::ThisOutsideStructure)
                         .with_related(SemanticRelatedInformation::new(scope.range, SemanticRelatedMessage::WerkwijzeNotInsideStructuur));
@@ -1150,6 +1849,41 @@ impl SemanticAnalyzer {
 
             PrimaryExpression::StructureInstantiation(structure) => self.analyze_structure_instantiation(structure).ty,
 
+            PrimaryExpression::Closure(closure) => {
+                let this = self.context.current_scope().this.clone();
+                let analysis = self.analyze_function(&closure.function, this);
+
+                SemanticType::FunctionReference(FunctionReference::Signature(SemanticFunctionSignature {
+                    parameters: analysis.parameters,
+                    return_type: Box::new(analysis.return_type.unwrap_or_else(SemanticType::null)),
+                }))
+            }
+
+            PrimaryExpression::OptionalSome(expr) => {
+                let ty = self.analyze_expression(expr).ty;
+                SemanticType::Optional(Box::new(ty))
+            }
+
+            PrimaryExpression::OptionalNone => {
+                SemanticType::Optional(Box::new(SemanticType::null()))
+            }
+
+            PrimaryExpression::ResultOk(expr) => {
+                let ty = self.analyze_expression(expr).ty;
+                SemanticType::Result {
+                    ok: Box::new(ty),
+                    error: Box::new(SemanticType::null()),
+                }
+            }
+
+            PrimaryExpression::ResultError(expr) => {
+                let ty = self.analyze_expression(expr).ty;
+                SemanticType::Result {
+                    ok: Box::new(SemanticType::null()),
+                    error: Box::new(ty),
+                }
+            }
+
             PrimaryExpression::TemplateString { parts } => {
                 for part in parts {
                     match part {
@@ -1176,7 +1910,8 @@ impl SemanticAnalyzer {
                     return SemanticValue::null();
                 }
 
-                let Some(local) = self.find_local_by_name(|name| name == reference.value()) else {
+                let name = self.context.resolve_name(reference.value());
+                let Some(local) = self.find_local_by_name(|local_name| *local_name == name) else {
                     self.diagnostics.create(|| SemanticDiagnostic::new(
                         reference.range(),
                         SemanticDiagnosticKind::InvalidIdentifierReference { identifier: reference.value().clone() }
@@ -1192,6 +1927,9 @@ impl SemanticAnalyzer {
                 };
 
                 let typ = local.typ.clone();
+                if let SemanticType::Function(function) | SemanticType::FunctionReference(FunctionReference::Custom(function)) = &typ {
+                    self.check_accessible(reference.range(), "werkwijze", &function.name, function.visibility.as_ref());
+                }
                 if let Some(tracker) = &mut self.context.definition_tracker {
                     tracker.insert(reference.range(), local_reference);
                 }
@@ -1201,6 +1939,45 @@ impl SemanticAnalyzer {
 
             PrimaryExpression::Parenthesized(expr) => return self.analyze_expression(expr),
 
+            PrimaryExpression::ArrayLiteral(elements) => {
+                let mut element_type = SemanticType::null();
+
+                for element in elements {
+                    let ty = self.analyze_value_expression(element).ty;
+
+                    if ty.is_null() {
+                        continue;
+                    }
+
+                    if element_type.is_null() {
+                        element_type = ty;
+                    } else if !is_assignable(&element_type, &ty, element.value()) {
+                        self.diagnostics.create(|| SemanticDiagnostic::new(
+                            element.range(),
+                            SemanticDiagnosticKind::ArrayElementTypeMismatch {
+                                expected: element_type.clone(),
+                                actual: ty,
+                            },
+                        ));
+                    } else if element_type.is_empty_array() || element_type == SemanticType::Optional(Box::new(SemanticType::null())) {
+                        // `[[], [1]]` and `[Niets, Iets(1)]` only know their element type later on.
+                        element_type = ty;
+                    }
+                }
+
+                SemanticType::Array(Box::new(element_type))
+            }
+
+            PrimaryExpression::Tuple(elements) => {
+                let elements = elements.iter()
+                    .map(|element| self.analyze_value_expression(element).ty)
+                    .collect();
+
+                SemanticType::Tuple(elements)
+            }
+
+            PrimaryExpression::Match(expression) => return self.analyze_match_expression(expression),
+
             PrimaryExpression::SizedArrayInitializer { typ, size } => {
                 let size_value = self.analyze_expression(&size);
                 if size_value.ty != SemanticType::Builtin(BuiltinType::G32) {
@@ -1211,9 +1988,20 @@ impl SemanticAnalyzer {
                 }
 
                 let ty = self.resolve_type(typ);
+                if !ty.has_default_value() {
+                    self.diagnostics.create(|| SemanticDiagnostic::new(
+                        typ.range(),
+                        SemanticDiagnosticKind::SizedArrayElementWithoutDefault { ty: ty.clone() },
+                    ));
+                }
 
                 SemanticType::Array(Box::new(ty))
             }
+
+            PrimaryExpression::Range(range) => {
+                self.analyze_range(range);
+                SemanticType::Builtin(BuiltinType::Reeks)
+            }
         };
 
         SemanticValue {
@@ -1222,12 +2010,402 @@ impl SemanticAnalyzer {
         }
     }
 
+    fn analyze_match_expression(&mut self, expression: &MatchExpression) -> SemanticValue {
+        let scrutinee = self.analyze_expression(&expression.scrutinee).ty;
+
+        let mut coverage = MatchCoverage::default();
+        let mut first_arm: Option<(FileRange, SemanticType)> = None;
+        let mut all_pure = true;
+
+        for arm in &expression.arms {
+            self.context.push_block_scope(arm.pattern.range());
+            self.analyze_pattern(&arm.pattern, &scrutinee);
+            let value = self.analyze_expression(&arm.expression);
+            self.context.pop_scope();
+
+            if value.usage == SemanticUsage::Indifferent {
+                all_pure = false;
+            }
+
+            if let Some(covering_arm) = coverage.find_covering_arm(arm.pattern.value()) {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    arm.pattern.range(),
+                    SemanticDiagnosticKind::UnreachableMatchArm,
+                ).warn().with_related(SemanticRelatedInformation::new(
+                    covering_arm,
+                    SemanticRelatedMessage::MatchArmAlreadyCovers,
+                )));
+            } else {
+                coverage.add(&arm.pattern, &scrutinee);
+            }
+
+            match &first_arm {
+                None => first_arm = Some((arm.expression.range(), value.ty)),
+                Some((first_range, expected)) => {
+                    if !expected.is_null() && !value.ty.is_null() && !is_assignable(expected, &value.ty, arm.expression.value()) {
+                        self.diagnostics.create(|| SemanticDiagnostic::new(
+                            arm.expression.range(),
+                            SemanticDiagnosticKind::IncompatibleMatchArmTypes {
+                                expected: expected.clone(),
+                                actual: value.ty.clone(),
+                            },
+                        ).with_related(SemanticRelatedInformation::new(
+                            *first_range,
+                            SemanticRelatedMessage::FirstMatchArmType { ty: expected.to_string().into() },
+                        )));
+                    } else if *expected == SemanticType::Optional(Box::new(SemanticType::null())) {
+                        // A `Niets` arm doesn't tell which `Misschien` this is,
+                        // but this arm does.
+                        first_arm = Some((*first_range, value.ty));
+                    } else if let (SemanticType::Result { ok, error }, SemanticType::Result { ok: other_ok, error: other_error }) = (expected, &value.ty) {
+                        // Likewise, a `Goed` arm doesn't tell the type of the
+                        // error, and a `Fout` arm doesn't tell the type of the
+                        // value.
+                        let pick = |this: &SemanticType, other: &SemanticType| {
+                            if this.is_null() { other.clone() } else { this.clone() }
+                        };
+
+                        let ty = SemanticType::Result {
+                            ok: Box::new(pick(ok, other_ok)),
+                            error: Box::new(pick(error, other_error)),
+                        };
+                        first_arm = Some((*first_range, ty));
+                    }
+                }
+            }
+        }
+
+        let ty = first_arm.map(|(_, ty)| ty).unwrap_or_else(SemanticType::null);
+
+        if !scrutinee.is_null() {
+            let missing = coverage.missing_patterns(&scrutinee);
+            if !missing.is_empty() {
+                let action = self.create_action_add_missing_match_arms(expression, &missing, &ty);
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.keyword,
+                    SemanticDiagnosticKind::NonExhaustiveMatch {
+                        missing: format!("`{}`", missing.join("`, `")),
+                    },
+                ).with_action(action));
+            }
+        }
+
+        SemanticValue {
+            ty,
+            usage: if all_pure {
+                SemanticUsage::Pure(PureValue::ConstantValue)
+            } else {
+                SemanticUsage::Indifferent
+            },
+        }
+    }
+
+    fn create_action_add_missing_match_arms(&self, expression: &MatchExpression, missing: &[String], ty: &SemanticType) -> BabbelaarCodeAction {
+        let mut edits = Vec::new();
+
+        if let Some(last) = expression.arms.last() {
+            if last.comma.is_none() {
+                edits.push(FileEdit::new(last.expression.range().end().as_zero_range(), ","));
+            }
+        }
+
+        let value = ty.default_value_hint();
+        let right_curly = expression.right_curly_range.start();
+
+        if expression.left_curly_range.start().line() == right_curly.line() {
+            let text = missing.iter()
+                .map(|pattern| format!("{pattern} => {value}, "))
+                .collect::<String>();
+
+            edits.push(FileEdit::new(right_curly.as_zero_range(), text));
+        } else {
+            let indentation = expression.arms.first()
+                .map(|arm| arm.pattern.range().start().column())
+                .unwrap_or(right_curly.column() + 4);
+
+            let line_start = FileLocation::new(
+                right_curly.file_id(),
+                right_curly.offset() - right_curly.column(),
+                right_curly.line(),
+                0,
+            );
+
+            let text = missing.iter()
+                .map(|pattern| format!("{}{pattern} => {value},\n", " ".repeat(indentation)))
+                .collect::<String>();
+
+            edits.push(FileEdit::new(line_start.as_zero_range(), text));
+        }
+
+        BabbelaarCodeAction::new(BabbelaarCodeActionType::AddMissingMatchArms { count: missing.len() }, edits)
+    }
+
+    fn analyze_pattern(&mut self, pattern: &Ranged<Pattern>, ty: &SemanticType) {
+        match pattern.value() {
+            Pattern::Wildcard => (),
+
+            Pattern::Binding(name) => {
+                self.context.push_local(name, SemanticLocal::new(
+                    SemanticLocalKind::Variable,
+                    ty.clone(),
+                    name.range(),
+                ));
+            }
+
+            Pattern::Boolean(..) => {
+                self.check_pattern_type(pattern, ty, &SemanticType::Builtin(BuiltinType::Bool));
+            }
+
+            Pattern::CharacterLiteral(..) => {
+                self.check_pattern_type(pattern, ty, &SemanticType::Builtin(BuiltinType::Teken));
+            }
+
+            Pattern::IntegerLiteral(..) | Pattern::Range { .. } => {
+                // Like other integer literals, these take the type of the value.
+                if !ty.is_primitive_number() {
+                    self.check_pattern_type(pattern, ty, &SemanticType::Builtin(BuiltinType::G32));
+                }
+            }
+
+            Pattern::StringLiteral(..) => {
+                self.check_pattern_type(pattern, ty, &SemanticType::Builtin(BuiltinType::Slinger));
+            }
+
+            Pattern::Structure { name, fields } => self.analyze_structure_pattern(pattern, name, fields, ty),
+
+            Pattern::EnumerationVariant { enumeration, variant, fields } => {
+                self.analyze_enumeration_variant_pattern(pattern, enumeration, variant, fields, ty);
+            }
+
+            Pattern::OptionalSome(inner) => {
+                let contained = match ty {
+                    SemanticType::Optional(contained) => contained.as_ref().clone(),
+                    _ => {
+                        self.check_pattern_type(pattern, ty, &SemanticType::Optional(Box::new(SemanticType::null())));
+                        SemanticType::null()
+                    }
+                };
+
+                self.analyze_pattern(inner, &contained);
+            }
+
+            Pattern::OptionalNone => {
+                self.check_pattern_type(pattern, ty, &SemanticType::Optional(Box::new(SemanticType::null())));
+            }
+
+            Pattern::ResultOk(inner) => {
+                let ok = match ty {
+                    SemanticType::Result { ok, .. } => ok.as_ref().clone(),
+                    _ => {
+                        let pattern_type = SemanticType::Result {
+                            ok: Box::new(SemanticType::null()),
+                            error: Box::new(SemanticType::null()),
+                        };
+                        self.check_pattern_type(pattern, ty, &pattern_type);
+                        SemanticType::null()
+                    }
+                };
+
+                self.analyze_pattern(inner, &ok);
+            }
+
+            Pattern::ResultError(inner) => {
+                let error = match ty {
+                    SemanticType::Result { error, .. } => error.as_ref().clone(),
+                    _ => {
+                        let pattern_type = SemanticType::Result {
+                            ok: Box::new(SemanticType::null()),
+                            error: Box::new(SemanticType::null()),
+                        };
+                        self.check_pattern_type(pattern, ty, &pattern_type);
+                        SemanticType::null()
+                    }
+                };
+
+                self.analyze_pattern(inner, &error);
+            }
+        }
+    }
+
+    fn check_pattern_type(&mut self, pattern: &Ranged<Pattern>, ty: &SemanticType, pattern_type: &SemanticType) {
+        if ty.is_null() || ty.is_compatible_with(pattern_type) {
+            return;
+        }
+
+        self.diagnostics.create(|| SemanticDiagnostic::new(
+            pattern.range(),
+            SemanticDiagnosticKind::PatternTypeMismatch {
+                pattern: pattern.value().to_string(),
+                ty: ty.clone(),
+            },
+        ));
+    }
+
+    fn analyze_structure_pattern(&mut self, pattern: &Ranged<Pattern>, name: &Ranged<BabString>, fields: &[FieldPattern], ty: &SemanticType) {
+        let base = match ty {
+            SemanticType::Custom { base, .. } if base.name.value() == name.value() => Arc::clone(base),
+            _ => {
+                if !ty.is_null() {
+                    self.diagnostics.create(|| SemanticDiagnostic::new(
+                        pattern.range(),
+                        SemanticDiagnosticKind::PatternTypeMismatch {
+                            pattern: pattern.value().to_string(),
+                            ty: ty.clone(),
+                        },
+                    ));
+                }
+
+                for field in fields {
+                    self.analyze_pattern(&field.pattern, &SemanticType::null());
+                }
+                return;
+            }
+        };
+
+        if let Some(tracker) = &mut self.context.definition_tracker {
+            tracker.insert(name.range(), SemanticReference {
+                local_name: name.value().clone(),
+                local_kind: SemanticLocalKind::StructureReference,
+                declaration_range: base.name.range(),
+                typ: ty.clone(),
+            });
+        }
+
+        for field_pattern in fields {
+            let Some(field) = base.fields.iter().find(|field| field.name.value() == field_pattern.name.value()) else {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    field_pattern.name.range(),
+                    SemanticDiagnosticKind::InvalidFieldInstantiation {
+                        struct_name: base.name.value().clone(),
+                        field_name: field_pattern.name.value().clone(),
+                    },
+                ).with_related(SemanticRelatedInformation::new(
+                    base.name.range(),
+                    SemanticRelatedMessage::StructureDefinedHere { name: base.name.value().clone() },
+                )));
+
+                self.analyze_pattern(&field_pattern.pattern, &SemanticType::null());
+                continue;
+            };
+
+            let field_type = field.ty.clone().resolve_against(ty);
+
+            if let Some(tracker) = &mut self.context.definition_tracker {
+                tracker.insert(field_pattern.name.range(), SemanticReference {
+                    local_name: field.name.value().clone(),
+                    local_kind: SemanticLocalKind::FieldReference,
+                    declaration_range: field.name.range(),
+                    typ: field_type.clone(),
+                });
+            }
+
+            self.analyze_pattern(&field_pattern.pattern, &field_type);
+        }
+    }
+
+    fn analyze_enumeration_variant_pattern(
+        &mut self,
+        pattern: &Ranged<Pattern>,
+        enumeration_name: &Ranged<BabString>,
+        variant_name: &Ranged<BabString>,
+        fields: &[Ranged<Pattern>],
+        ty: &SemanticType,
+    ) {
+        let name = self.context.resolve_name(enumeration_name.value());
+        let enumeration = self.context.scope.iter().rev()
+            .find_map(|scope| scope.enumerations.get(&name))
+            .cloned();
+
+        let Some(enumeration) = enumeration else {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                enumeration_name.range(),
+                SemanticDiagnosticKind::UnknownType { name: enumeration_name.value().clone() },
+            ));
+
+            for field in fields {
+                self.analyze_pattern(field, &SemanticType::null());
+            }
+            return;
+        };
+
+        let enumeration_type = SemanticType::Enumeration(Arc::clone(&enumeration));
+
+        if let Some(tracker) = &mut self.context.definition_tracker {
+            tracker.insert(enumeration_name.range(), SemanticReference {
+                local_name: enumeration.name.value().clone(),
+                local_kind: SemanticLocalKind::EnumerationReference,
+                declaration_range: enumeration.name.range(),
+                typ: enumeration_type.clone(),
+            });
+        }
+
+        if !ty.is_null() && !ty.is_compatible_with(&enumeration_type) {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                pattern.range(),
+                SemanticDiagnosticKind::PatternTypeMismatch {
+                    pattern: pattern.value().to_string(),
+                    ty: ty.clone(),
+                },
+            ));
+        }
+
+        let Some(variant) = enumeration.variant(variant_name.value()) else {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                variant_name.range(),
+                SemanticDiagnosticKind::InvalidEnumerationVariant {
+                    enumeration: enumeration.name.value().clone(),
+                    name: variant_name.value().clone(),
+                },
+            ).with_related(SemanticRelatedInformation::new(
+                enumeration.name.range(),
+                SemanticRelatedMessage::EnumerationDefinedHere { name: enumeration.name.value().clone() },
+            )));
+
+            for field in fields {
+                self.analyze_pattern(field, &SemanticType::null());
+            }
+            return;
+        };
+
+        if let Some(tracker) = &mut self.context.definition_tracker {
+            tracker.insert(variant_name.range(), SemanticReference {
+                local_name: variant.name.value().clone(),
+                local_kind: SemanticLocalKind::EnumerationVariant,
+                declaration_range: variant.name.range(),
+                typ: enumeration_type.clone(),
+            });
+        }
+
+        if fields.len() != variant.fields.len() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                pattern.range(),
+                SemanticDiagnosticKind::EnumerationVariantFieldCount {
+                    name: variant.name.value().clone(),
+                    field_count: variant.fields.len(),
+                    arg_count: fields.len(),
+                },
+            ));
+        }
+
+        for (idx, field) in fields.iter().enumerate() {
+            let field_type = variant.fields.get(idx)
+                .map(|field| field.ty.value().clone())
+                .unwrap_or_else(SemanticType::null);
+
+            self.analyze_pattern(field, &field_type);
+        }
+    }
+
     fn analyze_structure_instantiation(&mut self, instantiation: &StructureInstantiationExpression) -> SemanticValue {
         let ty = self.resolve_type_by_name(&instantiation.name, &instantiation.type_parameters, Some(instantiation));
-        let SemanticType::Custom { base, .. } = &ty else {
+        let SemanticType::Custom { base, parameters } = &ty else {
             return SemanticValue::null();
         };
 
+        for ((generic, parameter), specifier) in base.generic_types.iter().zip(parameters).zip(instantiation.type_parameters.iter()) {
+            self.analyze_generic_bounds_satisfied(generic, parameter, specifier.range());
+        }
+
         let struct_hint = SemanticRelatedInformation::new(
             base.name.range(),
             SemanticRelatedMessage::StructureDefinedHere { name: base.name.value().clone() }
@@ -1249,9 +2427,13 @@ impl SemanticAnalyzer {
             let name = &field_instantiation.name;
             match fields_left.remove(name.value()) {
                 Some(field) => {
+                    self.check_accessible(name.range(), "veld", &field.name, field.visibility.as_ref());
+
                     let declaration_type = field.ty.clone().resolve_against(&ty);
                     let definition_type = self.analyze_expression(&field_instantiation.value).ty;
-                    if !declaration_type.is_compatible_with(&definition_type) {
+                    self.check_integer_literal_range(&field_instantiation.value, &declaration_type);
+
+                    if !is_assignable(&declaration_type, &definition_type, field_instantiation.value.value()) {
                         let actions = self.try_create_conversion_actions(&declaration_type, &definition_type, &field_instantiation.value);
 
                         self.diagnostics.create(|| SemanticDiagnostic::new(
@@ -1355,7 +2537,7 @@ impl SemanticAnalyzer {
             }
 
             UnaryExpressionKind::Negate => {
-                if ty != SemanticType::Builtin(BuiltinType::G32) {
+                if !matches!(ty, SemanticType::Builtin(BuiltinType::G32 | BuiltinType::Kommagetal)) {
                     self.diagnostics.create(|| {
                         SemanticDiagnostic::new(operator_range, SemanticDiagnosticKind::CannotNegateNonInteger)
                     });
@@ -1363,6 +2545,16 @@ impl SemanticAnalyzer {
 
                 ty
             }
+
+            UnaryExpressionKind::Not => {
+                if !ty.is_null() && ty != SemanticType::Builtin(BuiltinType::Bool) {
+                    self.diagnostics.create(|| {
+                        SemanticDiagnostic::new(operator_range, SemanticDiagnosticKind::CannotInvertNonBoolean { ty })
+                    });
+                }
+
+                SemanticType::Builtin(BuiltinType::Bool)
+            }
         };
 
         SemanticValue {
@@ -1419,14 +2611,15 @@ impl SemanticAnalyzer {
         None
     }
 
-    fn find_and_use_function(&mut self, name: &str) -> Option<SemanticReference> {
+    fn find_and_use_function(&mut self, name: &BabString) -> Option<SemanticReference> {
+        let name = self.context.resolve_name(name);
         for scope in self.context.scope.iter_mut().rev() {
             for (func_name, func) in &mut scope.locals {
                 if !func.kind.is_function() {
                     continue;
                 }
 
-                if name == func_name {
+                if name == *func_name {
                     func.add_usage();
 
                     return Some(SemanticReference {
@@ -1442,6 +2635,32 @@ impl SemanticAnalyzer {
         None
     }
 
+    /// Reports the usage at `usage` of an `intern` field or `werkwijze` called
+    /// `name`, when it is declared outside of the current file or module.
+    fn check_accessible(&mut self, usage: FileRange, kind: &'static str, name: &Ranged<BabString>, visibility: Option<&Ranged<Visibility>>) {
+        let Some(visibility) = visibility else { return };
+        if self.context.is_accessible(*visibility.value(), name.range()) {
+            return;
+        }
+
+        let related = match kind {
+            "veld" => SemanticRelatedMessage::FieldDefinedHere { name: name.value().clone() },
+            _ => SemanticRelatedMessage::FunctionDefinedHere { name: name.value().clone() },
+        };
+
+        self.diagnostics.create(|| SemanticDiagnostic::new(
+            usage,
+            SemanticDiagnosticKind::NotAccessible { kind, name: name.value().clone() },
+        )
+        .with_related(SemanticRelatedInformation::new(name.range(), related))
+        .with_action(BabbelaarCodeAction::new(
+            BabbelaarCodeActionType::MakePublic { kind, name: name.value().clone() },
+            vec![
+                FileEdit::new(visibility.range(), Visibility::Publiek.keyword()),
+            ],
+        )));
+    }
+
     pub fn find_reference(&self, range: FileRange) -> Option<SemanticReference> {
         self.context.definition_tracker.as_ref()?.get(&range).cloned()
     }
@@ -1477,6 +2696,29 @@ impl SemanticAnalyzer {
         None
     }
 
+    /// Find the `///` documentation of the declaration the reference points
+    /// to. Fields aren't part of the type of the reference, so these are looked
+    /// up in the structures that were analyzed.
+    #[must_use]
+    pub fn find_documentation_of(&self, reference: &SemanticReference) -> Option<BabString> {
+        match reference.local_kind {
+            SemanticLocalKind::Function
+                | SemanticLocalKind::FunctionReference
+                | SemanticLocalKind::Method
+                | SemanticLocalKind::StructureReference => return reference.documentation(),
+
+            SemanticLocalKind::FieldReference => (),
+            _ => return None,
+        }
+
+        self.context.scope.iter()
+            .chain(self.context.previous_scopes.iter())
+            .flat_map(|scope| scope.structures.values())
+            .flat_map(|structure| structure.fields.iter())
+            .find(|field| field.name.range() == reference.declaration_range)
+            .and_then(|field| field.documentation.clone())
+    }
+
     #[must_use]
     pub fn find_references_of(&self, declaration_range: FileRange) -> Option<HashSet<FileRange>> {
         let mut result = HashSet::new();
@@ -1513,6 +2755,7 @@ impl SemanticAnalyzer {
     }
 
     fn resolve_interface_by_name(&self, name: &BabString) -> Option<Arc<SemanticInterface>> {
+        let name = self.context.resolve_name(name);
         for scope in self.context.scope.iter().rev() {
             if let Some(interface) = scope.interfaces.get(&name) {
                 return Some(Arc::clone(&interface));
@@ -1543,6 +2786,21 @@ impl SemanticAnalyzer {
             TypeSpecifier::Custom { name, type_parameters } => {
                 self.resolve_type_by_name(name, &type_parameters, None)
             }
+            TypeSpecifier::Function { parameters, return_type } => {
+                let parameters = parameters.iter().map(|ty| self.resolve_type(ty)).collect();
+                let return_type = match return_type {
+                    Some(ty) => self.resolve_type(ty),
+                    None => SemanticType::null(),
+                };
+
+                SemanticType::FunctionReference(FunctionReference::Signature(SemanticFunctionSignature {
+                    parameters,
+                    return_type: Box::new(return_type),
+                }))
+            }
+            TypeSpecifier::Tuple(elements) => {
+                SemanticType::Tuple(elements.iter().map(|ty| self.resolve_type(ty)).collect())
+            }
         }
     }
 
@@ -1579,11 +2837,24 @@ impl SemanticAnalyzer {
             SemanticType::IndexReference(ty) => {
                 SemanticType::IndexReference(Box::new(self.refine_type(&ty)))
             }
+            SemanticType::Optional(ty) => {
+                SemanticType::Optional(Box::new(self.refine_type(&ty)))
+            }
+            SemanticType::Result { ok, error } => {
+                SemanticType::Result {
+                    ok: Box::new(self.refine_type(ok)),
+                    error: Box::new(self.refine_type(error)),
+                }
+            }
             SemanticType::Pointer(ty) => {
                 SemanticType::Pointer(Box::new(self.refine_type(&ty)))
             }
+            SemanticType::Tuple(elements) => {
+                SemanticType::Tuple(elements.iter().map(|x| self.refine_type(x)).collect())
+            }
 
             SemanticType::Builtin(..) => ty.clone(),
+            SemanticType::Enumeration(..) => ty.clone(),
             SemanticType::Function(..) => ty.clone(),
             SemanticType::FunctionReference(..) => ty.clone(),
         }
@@ -1591,12 +2862,21 @@ impl SemanticAnalyzer {
 
     #[must_use]
     fn resolve_type_by_name(&mut self, name: &Ranged<BabString>, params: &Ranged<Vec<Ranged<Type>>>, instantiation: Option<&StructureInstantiationExpression>) -> SemanticType {
+        if name.value() == "Misschien" {
+            return self.resolve_optional_type(name, params);
+        }
+
+        if name.value() == "Resultaat" {
+            return self.resolve_result_type(name, params);
+        }
+
+        let resolved_name = self.context.resolve_name(name.value());
         for scope in self.context.scope.iter().rev() {
             if let Some(generic) = scope.generic_types.get(&name) {
                 return SemanticType::Generic(generic.clone());
             }
 
-            if let Some(structure) = scope.structures.get(name.value()) {
+            if let Some(structure) = scope.structures.get(&resolved_name) {
                 let structure = Arc::clone(structure);
 
                 let mut parameters = Vec::new();
@@ -1632,6 +2912,21 @@ impl SemanticAnalyzer {
                     parameters,
                 };
             }
+
+            if let Some(enumeration) = scope.enumerations.get(&resolved_name) {
+                let enumeration = Arc::clone(enumeration);
+
+                if !params.is_empty() {
+                    self.diagnostics.create(||
+                        SemanticDiagnostic::new(params.range(), SemanticDiagnosticKind::TypeParametersUnexpected { ty: enumeration.name.value().clone() })
+                            .with_action(BabbelaarCodeAction::new(BabbelaarCodeActionType::RemoveGenericParameters, [
+                                FileEdit::new(params.range(), String::new())
+                            ].to_vec()))
+                    );
+                }
+
+                return SemanticType::Enumeration(enumeration);
+            }
         }
 
         self.emit_diagnostic(|this| {
@@ -1646,6 +2941,55 @@ impl SemanticAnalyzer {
         SemanticType::Builtin(BuiltinType::Null)
     }
 
+    #[must_use]
+    fn resolve_optional_type(&mut self, name: &Ranged<BabString>, params: &Ranged<Vec<Ranged<Type>>>) -> SemanticType {
+        let Some(contained) = params.first() else {
+            self.diagnostics.create(||
+                SemanticDiagnostic::new(name.range(), SemanticDiagnosticKind::TooFewGenericTypes { ty: name.value().clone() })
+            );
+            return SemanticType::Optional(Box::new(SemanticType::null()));
+        };
+
+        if params.len() > 1 {
+            let range = FileRange::new(contained.range().end(), params.last().unwrap().range().end());
+            self.diagnostics.create(||
+                SemanticDiagnostic::new(range, SemanticDiagnosticKind::TooManyGenericTypes { ty: name.value().clone() })
+                    .with_action(BabbelaarCodeAction::new(BabbelaarCodeActionType::RemoveExtraneousGenericTypes, [
+                        FileEdit::new(range, String::new())
+                    ].to_vec()))
+            );
+        }
+
+        SemanticType::Optional(Box::new(self.resolve_type(contained)))
+    }
+
+    fn resolve_result_type(&mut self, name: &Ranged<BabString>, params: &Ranged<Vec<Ranged<Type>>>) -> SemanticType {
+        let (Some(ok), Some(error)) = (params.first(), params.get(1)) else {
+            self.diagnostics.create(||
+                SemanticDiagnostic::new(name.range(), SemanticDiagnosticKind::TooFewGenericTypes { ty: name.value().clone() })
+            );
+            return SemanticType::Result {
+                ok: Box::new(SemanticType::null()),
+                error: Box::new(SemanticType::null()),
+            };
+        };
+
+        if params.len() > 2 {
+            let range = FileRange::new(error.range().end(), params.last().unwrap().range().end());
+            self.diagnostics.create(||
+                SemanticDiagnostic::new(range, SemanticDiagnosticKind::TooManyGenericTypes { ty: name.value().clone() })
+                    .with_action(BabbelaarCodeAction::new(BabbelaarCodeActionType::RemoveExtraneousGenericTypes, [
+                        FileEdit::new(range, String::new())
+                    ].to_vec()))
+            );
+        }
+
+        SemanticType::Result {
+            ok: Box::new(self.resolve_type(ok)),
+            error: Box::new(self.resolve_type(error)),
+        }
+    }
+
     pub fn scopes_surrounding<F>(&self, location: FileLocation, mut f: F)
             where F: FnMut(&SemanticScope) {
         for scope in &self.context.previous_scopes {
@@ -1654,66 +2998,391 @@ impl SemanticAnalyzer {
             }
         }
 
-        for scope in &self.context.scope {
-            if scope.is_location_inside(location) {
-                f(scope);
-            }
+        for scope in &self.context.scope {
+            if scope.is_location_inside(location) {
+                f(scope);
+            }
+        }
+    }
+
+    fn resolve_parameter_name<'this>(&'this mut self, function: &SemanticReference, arg_idx: usize) -> Option<Ranged<BabString>> {
+        match &function.typ {
+            SemanticType::Function(func) => {
+                Some(func.parameters.get(arg_idx)?.name.clone())
+            }
+            SemanticType::FunctionReference(FunctionReference::Builtin(..) | FunctionReference::Signature(..)) => {
+                None
+            }
+            SemanticType::FunctionReference(FunctionReference::Custom(func)) => {
+                Some(func.name.clone())
+            }
+
+            // Values of these types can't be called, which is reported elsewhere.
+            SemanticType::Array(..)
+                | SemanticType::Builtin(..)
+                | SemanticType::Custom { .. }
+                | SemanticType::Enumeration(..)
+                | SemanticType::IndexReference(..)
+                | SemanticType::Interface { .. }
+                | SemanticType::Generic(..)
+                | SemanticType::Optional(..)
+                | SemanticType::Result { .. }
+                | SemanticType::Pointer(..)
+                | SemanticType::Tuple(..) => None,
+        }
+    }
+
+    fn resolve_parameter_type<'this>(&'this mut self, function: &SemanticReference, arg_idx: usize) -> Option<SemanticType> {
+        Some(match &function.typ {
+            SemanticType::Function(func) => {
+                func.parameters.get(arg_idx)?.ty.value().clone()
+            }
+            SemanticType::FunctionReference(FunctionReference::Builtin(func)) => {
+                func.parameters.get(arg_idx)?.typ.resolve()
+            }
+            SemanticType::FunctionReference(FunctionReference::Custom(func)) => {
+                func.parameters.get(arg_idx)?.ty.value().clone()
+            }
+            SemanticType::FunctionReference(FunctionReference::Signature(signature)) => {
+                signature.parameters.get(arg_idx)?.clone()
+            }
+
+            SemanticType::Array(..)
+                | SemanticType::Builtin(..)
+                | SemanticType::Custom { .. }
+                | SemanticType::Enumeration(..)
+                | SemanticType::IndexReference(..)
+                | SemanticType::Interface { .. }
+                | SemanticType::Generic(..)
+                | SemanticType::Optional(..)
+                | SemanticType::Result { .. }
+                | SemanticType::Pointer(..)
+                | SemanticType::Tuple(..) => return None,
+        })
+    }
+
+    fn analyze_postfix_expression(&mut self, postfix: &PostfixExpression) -> SemanticValue {
+        if let Some((structure, constant)) = self.resolve_structure_constant_of_postfix(postfix) {
+            if let Some(tracker) = &mut self.context.definition_tracker {
+                tracker.insert(postfix.lhs.range(), SemanticReference {
+                    local_name: structure.name.value().clone(),
+                    local_kind: SemanticLocalKind::StructureReference,
+                    declaration_range: structure.name.range(),
+                    typ: SemanticType::Custom { base: Arc::clone(&structure), parameters: Vec::new() },
+                });
+
+                tracker.insert(postfix.kind.range(), SemanticReference {
+                    local_name: constant.name.value().clone(),
+                    local_kind: SemanticLocalKind::Constant,
+                    declaration_range: constant.name.range(),
+                    typ: constant.ty.clone(),
+                });
+            }
+
+            return SemanticValue {
+                ty: constant.ty,
+                usage: SemanticUsage::Pure(PureValue::ConstantValue),
+            };
+        }
+
+        if let Some(enumeration) = self.resolve_enumeration_of_postfix(postfix) {
+            return self.analyze_enumeration_variant_expression(enumeration, postfix);
+        }
+
+        if let Some(structure) = self.resolve_structure_of_postfix(postfix) {
+            if let Some(tracker) = &mut self.context.definition_tracker {
+                tracker.insert(postfix.lhs.range(), SemanticReference {
+                    local_name: structure.name.value().clone(),
+                    local_kind: SemanticLocalKind::StructureReference,
+                    declaration_range: structure.name.range(),
+                    typ: SemanticType::Custom { base: Arc::clone(&structure), parameters: Vec::new() },
+                });
+            }
+
+            if let PostfixExpressionKind::MethodCall(method) = postfix.kind.value() {
+                return self.analyze_static_method_expression(structure, method);
+            }
+        }
+
+        let lhs = self.analyze_expression(&postfix.lhs).ty;
+        match postfix.kind.value() {
+            PostfixExpressionKind::Call(call) => self.analyze_function_call_expression(lhs, call, postfix),
+            PostfixExpressionKind::Member(member) => self.analyze_member_expression(lhs, member),
+            PostfixExpressionKind::MethodCall(method) => self.analyze_method_expression(lhs, method),
+            PostfixExpressionKind::Subscript(expr) => self.analyze_subscript_expression(lhs, &expr, postfix.kind.range()),
+            PostfixExpressionKind::Propagate => self.analyze_propagate_expression(lhs, postfix.kind.range()),
+        }
+    }
+
+    fn analyze_propagate_expression(&mut self, lhs: SemanticType, range: FileRange) -> SemanticValue {
+        let SemanticType::Result { ok, error } = lhs else {
+            if !lhs.is_null() {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    range,
+                    SemanticDiagnosticKind::PropagateRequiresResult { ty: lhs },
+                ));
+            }
+
+            return SemanticValue::null();
+        };
+
+        match self.context.current().return_type.clone() {
+            Some(expected) => match expected.value() {
+                SemanticType::Result { error: expected_error, .. } => {
+                    if !error.is_null() && !expected_error.is_null() && !error.is_compatible_with(expected_error) {
+                        self.diagnostics.create(|| SemanticDiagnostic::new(
+                            range,
+                            SemanticDiagnosticKind::PropagateIncompatibleError {
+                                actual: error.as_ref().clone(),
+                                expected: expected_error.as_ref().clone(),
+                            },
+                        ).with_related(SemanticRelatedInformation::new(
+                            expected.range(),
+                            SemanticRelatedMessage::ReturnTypeDefinedHere {
+                                typ: expected.name(),
+                            }
+                        )));
+                    }
+                }
+
+                _ => {
+                    self.diagnostics.create(|| SemanticDiagnostic::new(
+                        range,
+                        SemanticDiagnosticKind::PropagateOutsideResultFunction,
+                    ).with_related(SemanticRelatedInformation::new(
+                        expected.range(),
+                        SemanticRelatedMessage::ReturnTypeDefinedHere {
+                            typ: expected.name(),
+                        }
+                    )));
+                }
+            },
+
+            None => {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    range,
+                    SemanticDiagnosticKind::PropagateOutsideResultFunction,
+                ));
+            }
+        }
+
+        SemanticValue {
+            ty: *ok,
+            usage: SemanticUsage::Indifferent,
+        }
+    }
+
+    /// Expressions like `Buffer.GROOTTE` refer to a constant of a structure,
+    /// but only when `Buffer` isn't shadowed by a local.
+    fn resolve_structure_constant_of_postfix(&self, postfix: &PostfixExpression) -> Option<(Arc<SemanticStructure>, SemanticConstant)> {
+        let PostfixExpressionKind::Member(member) = postfix.kind.value() else {
+            return None;
+        };
+
+        let structure = self.resolve_structure_of_postfix(postfix)?;
+        let constant = structure.constant(member.value())?.clone();
+        Some((structure, constant))
+    }
+
+    /// Returns the structure the left-hand side of e.g. `Persoon.maak()`
+    /// refers to, when `Persoon` isn't shadowed by a local.
+    fn resolve_structure_of_postfix(&self, postfix: &PostfixExpression) -> Option<Arc<SemanticStructure>> {
+        let name = postfix.lhs.value().as_identifier()?;
+        if self.find_type_of_local(name).is_some() {
+            return None;
+        }
+
+        let name = self.context.resolve_name(name);
+        self.context.scope.iter().rev()
+            .find_map(|scope| scope.structures.get(&name))
+            .cloned()
+    }
+
+    /// Analyzes a call to a `gedeeld` method, e.g. `Persoon.maak("Jan")`.
+    fn analyze_static_method_expression(&mut self, structure: Arc<SemanticStructure>, expression: &MethodCallExpression) -> SemanticValue {
+        let typ = SemanticType::Custom { base: Arc::clone(&structure), parameters: Vec::new() };
+
+        let Some(method) = structure.methods.iter().find(|method| *method.name() == *expression.method_name) else {
+            for argument in &expression.call.arguments {
+                self.analyze_expression(argument);
+            }
+
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                expression.method_name.range(),
+                SemanticDiagnosticKind::InvalidMethod { typ, name: expression.method_name.value().clone() }
+            ).with_related(SemanticRelatedInformation::new(
+                structure.name.range(),
+                SemanticRelatedMessage::StructureDefinedHere { name: structure.name.value().clone() }
+            )));
+
+            return SemanticValue::null();
+        };
+
+        if !method.is_static {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                expression.method_name.range(),
+                SemanticDiagnosticKind::MethodRequiresValue {
+                    structure: structure.name.value().clone(),
+                    name: method.name().clone(),
+                },
+            ));
+        }
+
+        self.check_accessible(expression.method_name.range(), "werkwijze", &method.function.name, method.function.visibility.as_ref());
+
+        let local_reference = SemanticReference {
+            local_name: method.name().clone(),
+            local_kind: SemanticLocalKind::Method,
+            declaration_range: method.function.name.range(),
+            typ: SemanticType::FunctionReference(FunctionReference::Custom(method.function.clone())),
+        };
+
+        if let Some(tracker) = &mut self.context.definition_tracker {
+            tracker.insert(expression.method_name.range(), local_reference.clone());
+        }
+
+        let inferred = self.analyze_function_parameters(method.name().clone(), local_reference, &expression.call, Some(&typ));
+
+        let ty = method.return_type().resolve_against(&typ).substitute_generic_types(&inferred);
+        if ty.is_null() {
+            return SemanticValue::null();
+        }
+
+        SemanticValue {
+            ty,
+            usage: method.return_type_usage(),
+        }
+    }
+
+    /// Expressions like `Vorm.Punt` or `Vorm.Cirkel(5)` refer to a variant of
+    /// an enumeration, but only when `Vorm` isn't shadowed by a local.
+    fn resolve_enumeration_of_postfix(&self, postfix: &PostfixExpression) -> Option<Arc<SemanticEnumeration>> {
+        if !matches!(postfix.kind.value(), PostfixExpressionKind::Member(..) | PostfixExpressionKind::MethodCall(..)) {
+            return None;
+        }
+
+        let name = postfix.lhs.value().as_identifier()?;
+        if self.find_type_of_local(name).is_some() {
+            return None;
+        }
+
+        let name = self.context.resolve_name(name);
+        self.context.scope.iter().rev()
+            .find_map(|scope| scope.enumerations.get(&name))
+            .cloned()
+    }
+
+    fn analyze_enumeration_variant_expression(&mut self, enumeration: Arc<SemanticEnumeration>, postfix: &PostfixExpression) -> SemanticValue {
+        let ty = SemanticType::Enumeration(Arc::clone(&enumeration));
+
+        if let Some(tracker) = &mut self.context.definition_tracker {
+            tracker.insert(postfix.lhs.range(), SemanticReference {
+                local_name: enumeration.name.value().clone(),
+                local_kind: SemanticLocalKind::EnumerationReference,
+                declaration_range: enumeration.name.range(),
+                typ: ty.clone(),
+            });
+        }
+
+        let (name, call) = match postfix.kind.value() {
+            PostfixExpressionKind::Member(name) => (name, None),
+            PostfixExpressionKind::MethodCall(method) => (&method.method_name, Some(&method.call)),
+            _ => unreachable!("resolve_enumeration_of_postfix only accepts members and method calls"),
+        };
+
+        let arguments = call.map(|call| call.arguments.as_slice()).unwrap_or_default();
+
+        let Some(variant) = enumeration.variant(name.value()) else {
+            for argument in arguments {
+                self.analyze_expression(argument);
+            }
+
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                name.range(),
+                SemanticDiagnosticKind::InvalidEnumerationVariant {
+                    enumeration: enumeration.name.value().clone(),
+                    name: name.value().clone(),
+                },
+            ).with_related(SemanticRelatedInformation::new(
+                enumeration.name.range(),
+                SemanticRelatedMessage::EnumerationDefinedHere { name: enumeration.name.value().clone() },
+            )));
+
+            return SemanticValue::null();
+        };
+
+        if let Some(tracker) = &mut self.context.definition_tracker {
+            tracker.insert(name.range(), SemanticReference {
+                local_name: variant.name.value().clone(),
+                local_kind: SemanticLocalKind::EnumerationVariant,
+                declaration_range: variant.name.range(),
+                typ: ty.clone(),
+            });
+        }
+
+        if arguments.len() != variant.fields.len() {
+            let range = call.map(|call| call.token_right_paren).unwrap_or_else(|| name.range());
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                range,
+                SemanticDiagnosticKind::EnumerationVariantFieldCount {
+                    name: variant.name.value().clone(),
+                    field_count: variant.fields.len(),
+                    arg_count: arguments.len(),
+                },
+            ));
+        }
+
+        for (idx, argument) in arguments.iter().enumerate() {
+            let argument_type = self.analyze_expression(argument).ty;
+
+            let Some(field) = variant.fields.get(idx) else {
+                continue;
+            };
+
+            let field_type = field.ty.value();
+            self.check_integer_literal_range(argument, field_type);
+
+            if argument_type.is_null() || is_assignable(field_type, &argument_type, argument.value()) {
+                continue;
+            }
+
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                argument.range(),
+                SemanticDiagnosticKind::IncompatibleArgumentParameterType {
+                    argument_type,
+                    parameter_type: field_type.clone(),
+                },
+            ).with_related(SemanticRelatedInformation::new(
+                field.name.range(),
+                SemanticRelatedMessage::FieldDefinedHere { name: field.name.value().clone() },
+            )));
+        }
+
+        SemanticValue {
+            ty,
+            usage: SemanticUsage::Pure(PureValue::ConstantValue),
         }
     }
 
-    fn resolve_parameter_name<'this>(&'this mut self, function: &SemanticReference, arg_idx: usize) -> Option<Ranged<BabString>> {
-        match &function.typ {
-            SemanticType::Array(..) => todo!(),
-            SemanticType::Builtin(..) => todo!(),
-            SemanticType::Custom { .. } => todo!(),
-            SemanticType::Function(func) => {
-                Some(func.parameters[arg_idx].name.clone())
-            }
-            SemanticType::FunctionReference(FunctionReference::Builtin(..)) => {
-                None
-            }
-            SemanticType::FunctionReference(FunctionReference::Custom(func)) => {
-                Some(func.name.clone())
-            }
-            SemanticType::IndexReference(..) => todo!(),
-            SemanticType::Interface { .. } => todo!(),
-            SemanticType::Generic(..) => todo!(),
-            SemanticType::Pointer(..) => todo!(),
+    fn analyze_member_expression(&mut self, typ: SemanticType, member: &Ranged<BabString>) -> SemanticValue {
+        if typ.is_optional() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                member.range(),
+                SemanticDiagnosticKind::OptionalNotUnpacked { ty: typ },
+            ));
+
+            return SemanticValue::null();
         }
-    }
 
-    fn resolve_parameter_type<'this>(&'this mut self, function: &SemanticReference, arg_idx: usize) -> Option<SemanticType> {
-        Some(match &function.typ {
-            SemanticType::Array(..) => todo!(),
-            SemanticType::Builtin(..) => todo!(),
-            SemanticType::Custom { .. } => todo!(),
-            SemanticType::Function(func) => {
-                func.parameters.get(arg_idx)?.ty.value().clone()
-            }
-            SemanticType::FunctionReference(FunctionReference::Builtin(func)) => {
-                SemanticType::Builtin(func.parameters.get(arg_idx)?.typ)
-            }
-            SemanticType::FunctionReference(FunctionReference::Custom(func)) => {
-                func.parameters.get(arg_idx)?.ty.value().clone()
-            }
-            SemanticType::IndexReference(..) => todo!(),
-            SemanticType::Interface { .. } => todo!(),
-            SemanticType::Generic(..) => todo!(),
-            SemanticType::Pointer(..) => todo!(),
-        })
-    }
+        if typ.is_result() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                member.range(),
+                SemanticDiagnosticKind::ResultNotUnpacked { ty: typ },
+            ));
 
-    fn analyze_postfix_expression(&mut self, postfix: &PostfixExpression) -> SemanticValue {
-        let lhs = self.analyze_expression(&postfix.lhs).ty;
-        match postfix.kind.value() {
-            PostfixExpressionKind::Call(call) => self.analyze_function_call_expression(lhs, call, postfix),
-            PostfixExpressionKind::Member(member) => self.analyze_member_expression(lhs, member),
-            PostfixExpressionKind::MethodCall(method) => self.analyze_method_expression(lhs, method),
-            PostfixExpressionKind::Subscript(expr) => self.analyze_subscript_expression(lhs, &expr, postfix.kind.range()),
+            return SemanticValue::null();
         }
-    }
 
-    fn analyze_member_expression(&mut self, typ: SemanticType, member: &Ranged<BabString>) -> SemanticValue {
         let SemanticType::Custom { base, .. } = &typ else {
             self.diagnostics.create(|| SemanticDiagnostic::new(
                 member.range(),
@@ -1725,6 +3394,8 @@ impl SemanticAnalyzer {
 
         for field in &base.fields {
             if field.name.value() == member.value() {
+                self.check_accessible(member.range(), "veld", &field.name, field.visibility.as_ref());
+
                 let ty = field.ty.clone().resolve_against(&typ);
 
                 if let Some(tracker) = &mut self.context.definition_tracker {
@@ -1792,7 +3463,7 @@ impl SemanticAnalyzer {
                 for method in builtin.methods() {
                     if *expression.method_name == method.name {
                         return SemanticValue {
-                            ty: SemanticType::Builtin(method.return_type),
+                            ty: method.return_type.resolve(),
                             usage: if method.must_use { SemanticUsage::Pure(PureValue::ReturnValue) } else { SemanticUsage::Indifferent },
                         };
                     }
@@ -1814,8 +3485,21 @@ impl SemanticAnalyzer {
             }
 
             SemanticType::Custom { ref base, .. } => {
+                let base = self.context.complete_structure(base);
                 for method in &base.methods {
                     if *method.name() == *expression.method_name {
+                        if method.is_static {
+                            self.diagnostics.create(|| SemanticDiagnostic::new(
+                                expression.method_name.range(),
+                                SemanticDiagnosticKind::StaticMethodCalledOnValue {
+                                    structure: base.name.value().clone(),
+                                    name: method.name().clone(),
+                                },
+                            ));
+                        }
+
+                        self.check_accessible(expression.method_name.range(), "werkwijze", &method.function.name, method.function.visibility.as_ref());
+
                         let local_reference = SemanticReference {
                             local_name: method.name().clone(),
                             local_kind: SemanticLocalKind::Method,
@@ -1827,9 +3511,9 @@ impl SemanticAnalyzer {
                             tracker.insert(expression.method_name.range(), local_reference.clone());
                         }
 
-                        self.analyze_function_parameters(method.name().clone(), local_reference, &expression.call, Some(&typ));
+                        let inferred = self.analyze_function_parameters(method.name().clone(), local_reference, &expression.call, Some(&typ));
 
-                        let ty = method.return_type().resolve_against(&typ);
+                        let ty = method.return_type().resolve_against(&typ).substitute_generic_types(&inferred);
                         if ty.is_null() {
                             return SemanticValue::null();
                         }
@@ -1845,6 +3529,36 @@ impl SemanticAnalyzer {
                     return value;
                 }
 
+                // A field containing a `werkwijze` can be called like a method.
+                if let Some(field) = base.fields.iter().find(|field| *field.name == *expression.method_name) {
+                    if let Some(signature) = field.ty.function_signature() {
+                        self.check_accessible(expression.method_name.range(), "veld", &field.name, field.visibility.as_ref());
+
+                        let local_reference = SemanticReference {
+                            local_name: field.name.value().clone(),
+                            local_kind: SemanticLocalKind::FieldReference,
+                            declaration_range: field.name.range(),
+                            typ: field.ty.clone(),
+                        };
+
+                        if let Some(tracker) = &mut self.context.definition_tracker {
+                            tracker.insert(expression.method_name.range(), local_reference.clone());
+                        }
+
+                        self.analyze_function_parameters(field.name.value().clone(), local_reference, &expression.call, Some(&typ));
+
+                        let ty = signature.return_type.resolve_against(&typ);
+                        if ty.is_null() {
+                            return SemanticValue::null();
+                        }
+
+                        return SemanticValue {
+                            ty,
+                            usage: SemanticUsage::Pure(PureValue::ReturnValue),
+                        };
+                    }
+                }
+
                 let struct_hint = SemanticRelatedInformation::new(
                     base.name.range(),
                     SemanticRelatedMessage::StructureDefinedHere { name: base.name.value().clone() }
@@ -1863,6 +3577,19 @@ impl SemanticAnalyzer {
                 SemanticValue::null()
             }
 
+            SemanticType::Enumeration(..) => {
+                if let Some(value) = self.analyze_method_expression_with_extensions(&typ, expression) {
+                    return value;
+                }
+
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.method_name.range(),
+                    SemanticDiagnosticKind::InvalidMethod { typ, name: expression.method_name.value().clone() }
+                ));
+
+                SemanticValue::null()
+            }
+
             SemanticType::Function(..) | SemanticType::FunctionReference(..) => {
                 self.diagnostics.create(|| SemanticDiagnostic::new(
                     expression.method_name.range(),
@@ -1891,9 +3618,9 @@ impl SemanticAnalyzer {
                             tracker.insert(expression.method_name.range(), local_reference.clone());
                         }
 
-                        self.analyze_function_parameters(method.name().clone(), local_reference, &expression.call, Some(&typ));
+                        let inferred = self.analyze_function_parameters(method.name().clone(), local_reference, &expression.call, Some(&typ));
 
-                        let ty = method.return_type().resolve_against(&typ);
+                        let ty = method.return_type().resolve_against(&typ).substitute_generic_types(&inferred);
                         if ty.is_null() {
                             return SemanticValue::null();
                         }
@@ -1927,7 +3654,60 @@ impl SemanticAnalyzer {
                 SemanticValue::null()
             }
 
-            SemanticType::Generic(..) => todo!(),
+            SemanticType::Generic(ref generic) => {
+                for bound in &generic.bounds {
+                    let Some(interface) = self.resolve_interface_by_name(bound) else {
+                        continue;
+                    };
+
+                    if interface.methods.iter().any(|method| *method.name() == *expression.method_name) {
+                        let ty = SemanticType::Interface { base: interface, parameters: Vec::new() };
+                        return self.analyze_method_expression(ty, expression);
+                    }
+                }
+
+                for argument in &expression.call.arguments {
+                    self.analyze_expression(argument);
+                }
+
+                let generic_hint = SemanticRelatedInformation::new(
+                    generic.declaration_range,
+                    SemanticRelatedMessage::GenericTypeDeclaredHere { name: generic.name.clone() },
+                );
+
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.method_name.range(),
+                    SemanticDiagnosticKind::InvalidMethod { typ: typ.clone(), name: expression.method_name.value().clone() }
+                ).with_related(generic_hint));
+
+                SemanticValue::null()
+            }
+
+            SemanticType::Optional(..) => {
+                for argument in &expression.call.arguments {
+                    self.analyze_expression(argument);
+                }
+
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.method_name.range(),
+                    SemanticDiagnosticKind::OptionalNotUnpacked { ty: typ },
+                ));
+
+                SemanticValue::null()
+            }
+
+            SemanticType::Result { .. } => {
+                for argument in &expression.call.arguments {
+                    self.analyze_expression(argument);
+                }
+
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.method_name.range(),
+                    SemanticDiagnosticKind::ResultNotUnpacked { ty: typ },
+                ));
+
+                SemanticValue::null()
+            }
 
             SemanticType::Pointer(ref element_type) => {
                 for method in Builtin::pointer().methods() {
@@ -1946,6 +3726,23 @@ impl SemanticAnalyzer {
 
                 SemanticValue::null()
             }
+
+            SemanticType::Tuple(..) => {
+                if let Some(value) = self.analyze_method_expression_with_extensions(&typ, expression) {
+                    return value;
+                }
+
+                for argument in &expression.call.arguments {
+                    self.analyze_expression(argument);
+                }
+
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    expression.method_name.range(),
+                    SemanticDiagnosticKind::InvalidMethod { typ, name: expression.method_name.value().clone()}
+                ));
+
+                SemanticValue::null()
+            }
         }
     }
 
@@ -1972,8 +3769,17 @@ impl SemanticAnalyzer {
 
             let return_type = method.return_type().resolve_against(typ);
             let usage = method.return_type_usage();
+            let generic_arguments: Vec<(SemanticGenericType, SemanticType)> = extension.generic_arguments(typ)
+                .into_iter()
+                .map(|(generic, ty)| (generic.clone(), ty.clone()))
+                .collect();
 
-            self.analyze_function_parameters(method.name().clone(), local_reference, &expression.call, Some(&typ));
+            let inferred = self.analyze_function_parameters(method.name().clone(), local_reference, &expression.call, Some(&typ));
+            let return_type = return_type.substitute_generic_types(&inferred);
+
+            for (generic, ty) in generic_arguments {
+                self.analyze_generic_bounds_satisfied(&generic, &ty, expression.method_name.range());
+            }
 
             if return_type.is_null() {
                 return Some(SemanticValue::null());
@@ -1995,6 +3801,11 @@ impl SemanticAnalyzer {
                 continue;
             }
 
+            if attribute.name.value() == Attribute::NAME_TEST {
+                self.analyze_attribute_test(statement, attribute);
+                continue;
+            }
+
             self.diagnostics.create(|| SemanticDiagnostic::new(
                 attribute.name.range(),
                 SemanticDiagnosticKind::UnknownAttribute { name: attribute.name.value().clone(), range: attribute.range() },
@@ -2043,11 +3854,17 @@ impl SemanticAnalyzer {
             return;
         };
 
-        let Some(func) = self.context.current().get_function_mut(&function.name) else {
+        let name = self.context.declaration_name(self.context.scope.len() - 1, &function.name);
+        let Some(func) = self.context.current().get_function_mut(&name) else {
             log::warn!("Expected function '{}' to be defined earlier", function.name.value());
             return;
         };
 
+        let unsupported_parameters: Vec<_> = func.parameters.iter()
+            .filter(|parameter| !parameter.ty.can_be_passed_to_extern_function())
+            .map(|parameter| (parameter.ty.range(), parameter.ty.value().to_string()))
+            .collect();
+
         if func.extern_function.is_some() {
             let diag = SemanticDiagnostic::new(
                 attr.name.range().as_full_line(),
@@ -2062,6 +3879,56 @@ impl SemanticAnalyzer {
         }
 
         func.extern_function = Some(extern_func);
+
+        for (range, typ) in unsupported_parameters {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                range,
+                SemanticDiagnosticKind::AttributeExternUnsupportedParameterType { typ: typ.into() },
+            ));
+        }
+    }
+
+    fn analyze_attribute_test(&mut self, statement: &Statement, attr: &Attribute) {
+        let StatementKind::Function(function) = &statement.kind else {
+            let diag = SemanticDiagnostic::new(
+                attr.name.range().as_full_line(),
+                SemanticDiagnosticKind::AttributeTestOnlyOnFunctions,
+            );
+            let diag = diag.with_action(BabbelaarCodeAction::new(
+                BabbelaarCodeActionType::RemoveAttribute { name: attr.name.value().clone() },
+                vec![FileEdit::new(attr.name.range().as_full_line(), "")],
+            ));
+            self.diagnostics.create(|| diag);
+            return;
+        };
+
+        if let Some(argument) = attr.arguments.value().first() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                argument.name.range(),
+                SemanticDiagnosticKind::AttributeCannotHaveArguments { name: Attribute::NAME_TEST },
+            ));
+        }
+
+        if function.body.is_none() {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                function.name.range(),
+                SemanticDiagnosticKind::AttributeTestOnlyOnFunctionsWithBody,
+            ));
+        }
+
+        if let Some(parameter) = function.parameters.first() {
+            let range = FileRange::new(parameter.name.range().start(), function.parameters_right_paren_range.start());
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                range,
+                SemanticDiagnosticKind::AttributeTestFunctionHasParameters,
+            ));
+        }
+
+        // Tests are called by `babbelaar test`, so they aren't unused.
+        let name = self.context.declaration_name(self.context.scope.len() - 1, &function.name);
+        if let Some(local) = self.context.current().locals.get_mut(&name) {
+            local.add_usage();
+        }
     }
 
     fn attribute_extern_evaluate(&mut self, attr: &Attribute) -> Option<SemanticExternFunction> {
@@ -2126,6 +3993,47 @@ impl SemanticAnalyzer {
         }
     }
 
+    /// Checks that an integer literal fits in the integer type it ends up
+    /// in, e.g. `300` doesn't fit in a `g8`.
+    fn check_integer_literal_range(&mut self, expression: &Ranged<Expression>, expected: &SemanticType) {
+        match (expected, expression.value()) {
+            (SemanticType::Array(expected), Expression::Primary(PrimaryExpression::ArrayLiteral(elements))) => {
+                for element in elements {
+                    self.check_integer_literal_range(element, expected);
+                }
+                return;
+            }
+
+            (SemanticType::Tuple(expected), Expression::Primary(PrimaryExpression::Tuple(elements))) => {
+                for (element, expected) in elements.iter().zip(expected) {
+                    self.check_integer_literal_range(element, expected);
+                }
+                return;
+            }
+
+            _ => (),
+        }
+
+        let SemanticType::Builtin(expected) = expected else {
+            return;
+        };
+
+        let Some(ty) = expected.integer_type() else {
+            return;
+        };
+
+        let Some(value) = expression.value().as_integer_literal() else {
+            return;
+        };
+
+        if !ty.contains(value) {
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                expression.range(),
+                SemanticDiagnosticKind::IntegerLiteralOutOfRange { value, ty },
+            ));
+        }
+    }
+
     fn try_create_conversion_actions(
         &self,
         expected_type: &SemanticType,
@@ -2175,7 +4083,7 @@ impl SemanticAnalyzer {
 
         for (generic_index,generic_parameter_type) in generic_parameters.iter().enumerate() {
             if generic_parameter_type == &ty {
-                let ty = structure.generic_types[generic_index].value().clone();
+                let ty = structure.generic_types[generic_index].name.clone();
                 let add_text = format!("\n{indent}veld {name}: {ty},");
 
                 items.push(BabbelaarCodeAction::new(
@@ -2275,8 +4183,12 @@ impl SemanticAnalyzer {
         let location = self.calculate_new_extension_location(file_id);
         let indent = self.indentation_at(location).unwrap_or_default();
 
-        let mut generics = structure.generic_types.iter().map(|x| x.as_str())
-            .chain(interface.generic_types.iter().map(|x| x.as_str()))
+        // The generic parameter of the builtin interfaces is the structure
+        // itself, e.g. `uitbreiding Optelbaar<Geld> op Geld`.
+        let interface_generics = if interface.is_builtin() { &[][..] } else { &interface.generic_types[..] };
+
+        let mut generics = structure.generic_types.iter().map(|x| x.name.as_str())
+            .chain(interface_generics.iter().map(|x| x.name.as_str()))
             .collect::<HashSet<&str>>()
             .into_iter()
             .join(", ");
@@ -2285,11 +4197,21 @@ impl SemanticAnalyzer {
             generics = format!("<{generics}>");
         }
 
+        let structure_type = format!("{}{generics}", structure.name.value());
+        let type_to_string = |ty: &SemanticType| match ty {
+            SemanticType::Generic(..) if interface.is_builtin() => structure_type.clone(),
+            _ => ty.to_string(),
+        };
+
         let mut add_text = format!(
-            "{new_line}{indent}uitbreiding{generics} {interface_name}{generics} op {structure_name}{generics} {{\n",
+            "{new_line}{indent}uitbreiding{generics} {interface_name}{interface_arguments} op {structure_type} {{\n",
             new_line = if location.line() == 0 { "" } else { "\n" },
             interface_name = interface.name.value(),
-            structure_name = structure.name.value(),
+            interface_arguments = match interface.is_builtin() {
+                true if interface.generic_types.is_empty() => String::new(),
+                true => format!("<{structure_type}>"),
+                false => generics.clone(),
+            },
         );
 
         for (idx, method) in interface.methods.iter().enumerate() {
@@ -2307,14 +4229,14 @@ impl SemanticAnalyzer {
                     add_text += ", ";
                 }
 
-                add_text += &format!("{}: {}", param.name.value(), param.ty.value());
+                add_text += &format!("{}: {}", param.name.value(), type_to_string(param.ty.value()));
             }
 
             add_text += ")";
 
             let ret = method.return_type();
             if !ret.is_null() {
-                write!(&mut add_text, " -> {ret}").unwrap();
+                write!(&mut add_text, " -> {}", type_to_string(&ret)).unwrap();
             }
 
             add_text += " {\n\n";
@@ -2488,7 +4410,7 @@ impl SemanticAnalyzer {
 
         for scope in &self.context.scope {
             location = scope.range.end();
-            if !matches!(scope.kind, SemanticScopeKind::Default) {
+            if !matches!(scope.kind, SemanticScopeKind::Default | SemanticScopeKind::Loop) {
                 break;
             }
         }
@@ -2504,7 +4426,9 @@ impl SemanticAnalyzer {
         match value {
             Expression::Primary(PrimaryExpression::CharacterLiteral(..)) => None,
             Expression::Primary(PrimaryExpression::Boolean(..)) => None,
+            Expression::Primary(PrimaryExpression::Closure(..)) => None,
             Expression::Primary(PrimaryExpression::IntegerLiteral(..)) => Some(BabString::new_static("getal")),
+            Expression::Primary(PrimaryExpression::FloatLiteral(..)) => Some(BabString::new_static("kommagetal")),
             Expression::Primary(PrimaryExpression::Parenthesized(expr)) => self.find_canonical_name_for_variable(expr.value()),
             Expression::Primary(PrimaryExpression::Reference(reference)) => Some(reference.value().clone()),
             Expression::Primary(PrimaryExpression::ReferenceThis) => {
@@ -2516,6 +4440,14 @@ impl SemanticAnalyzer {
             }
             Expression::Primary(PrimaryExpression::TemplateString { .. }) => None,
             Expression::Primary(PrimaryExpression::SizedArrayInitializer { .. }) => None,
+            Expression::Primary(PrimaryExpression::ArrayLiteral(..)) => None,
+            Expression::Primary(PrimaryExpression::Match(..)) => None,
+            Expression::Primary(PrimaryExpression::Tuple(..)) => None,
+            Expression::Primary(PrimaryExpression::Range(..)) => Some(BabString::new_static("reeks")),
+            Expression::Primary(PrimaryExpression::OptionalSome(expr)) => self.find_canonical_name_for_variable(expr.value()),
+            Expression::Primary(PrimaryExpression::OptionalNone) => None,
+            Expression::Primary(PrimaryExpression::ResultOk(expr)) => self.find_canonical_name_for_variable(expr.value()),
+            Expression::Primary(PrimaryExpression::ResultError(..)) => Some(BabString::new_static("fout")),
             Expression::Unary(expr) => self.find_canonical_name_for_variable(&expr.rhs),
             Expression::Postfix(..) => None, // TODO
             Expression::BiExpression(..) => None, // TODO
@@ -2529,6 +4461,12 @@ impl SemanticAnalyzer {
                     continue;
                 }
 
+                // Constants of a structure can also be used as `Structuur.NAAM`
+                // outside of it, which isn't tracked by the structure scope.
+                if local.kind == SemanticLocalKind::Constant && matches!(scope.kind, SemanticScopeKind::Structure) {
+                    continue;
+                }
+
                 if local.name_declaration_range.file_id() == FileId::INTERNAL {
                     continue;
                 }
@@ -2546,6 +4484,7 @@ impl SemanticAnalyzer {
 
     fn create_diagnostic_unused_local(&self, name: &BabString, local: &SemanticLocal) -> SemanticDiagnostic {
         let kind = match &local.kind {
+            SemanticLocalKind::Constant => SemanticDiagnosticKind::UnusedConstant { name: name.clone() },
             SemanticLocalKind::Function | SemanticLocalKind::FunctionReference =>
                 SemanticDiagnosticKind::UnusedFunction { name: name.clone() },
             SemanticLocalKind::Iterator => SemanticDiagnosticKind::UnusedIterator { name: name.clone() },
@@ -2605,7 +4544,23 @@ impl SemanticAnalyzer {
     }
 
     fn analyze_assignment_source_dest(&mut self, assign: &AssignStatement, destination_type: SemanticType, source_type: SemanticType) {
-        if source_type != destination_type {
+        if let Some(operator) = assign.operator {
+            let is_number = destination_type.is_primitive_number() || destination_type == SemanticType::Builtin(BuiltinType::Kommagetal);
+            let is_string_concatenation = operator == MathOperator::Add && destination_type == SemanticType::Builtin(BuiltinType::Slinger);
+            if !destination_type.is_null() && !is_number && !is_string_concatenation {
+                self.diagnostics.create(|| SemanticDiagnostic::new(
+                    assign.equals_sign,
+                    SemanticDiagnosticKind::InvalidCompoundAssignment {
+                        operator: operator.as_str(),
+                        ty: destination_type.clone(),
+                    },
+                ));
+                return;
+            }
+        }
+
+        let is_empty_array_assignment = source_type.is_empty_array() && matches!(destination_type, SemanticType::Array(..));
+        if source_type != destination_type && !is_empty_array_assignment {
             self.emit_diagnostic(|this|
                 SemanticDiagnostic::new(assign.equals_sign, SemanticDiagnosticKind::IncompatibleAssignmentTypes)
                     .with_related(SemanticRelatedInformation::new(assign.destination.range(), SemanticRelatedMessage::DestinationOfType { ty: destination_type.clone() }))
@@ -2691,6 +4646,130 @@ impl SemanticAnalyzer {
             .flat_map(|scope| scope.extensions.iter())
             .filter(|extension| extension.is_for_type(typ))
             .filter(|extension| extension.interface.as_ref().is_some_and(|i| i.as_ref() == interface))
-            .next()
+            .find(|extension| self.are_extension_bounds_satisfied(extension, typ))
+    }
+
+    /// Returns whether `typ` implements the `interface`, either by an
+    /// `uitbreiding`, because it is a generic type bound by it, or because it
+    /// is a builtin type that supports the operator of a builtin interface.
+    #[must_use]
+    fn implements_interface(&self, typ: &SemanticType, interface: &SemanticInterface) -> bool {
+        match typ {
+            SemanticType::Generic(generic) => generic.bounds.iter()
+                .filter_map(|bound| self.resolve_interface_by_name(bound))
+                .any(|bound| bound.as_ref() == interface),
+
+            SemanticType::Interface { base, .. } if base.as_ref() == interface => true,
+
+            SemanticType::Builtin(ty) if interface.is_builtin() && Builtin::interface_by_name(interface.name.value())
+                .is_some_and(|builtin| builtin.is_implemented_by(*ty)) => true,
+
+            _ => self.get_interface_implementation_for(typ, interface).is_some(),
+        }
+    }
+
+    /// Returns whether the types given for the generic parameters of the
+    /// `extension`, such as the `T` in `uitbreiding<T: Vergelijkbaar> .. op Lijst<T>`,
+    /// implement the interfaces they are bound by.
+    #[must_use]
+    fn are_extension_bounds_satisfied(&self, extension: &SemanticExtension, typ: &SemanticType) -> bool {
+        extension.generic_arguments(typ)
+            .into_iter()
+            .all(|(generic, ty)| {
+                generic.bounds.iter()
+                    .filter_map(|bound| self.resolve_interface_by_name(bound))
+                    .all(|interface| self.implements_interface(ty, &interface))
+            })
+    }
+
+    /// Reports the bounds of generic type parameters that don't refer to an
+    /// interface.
+    fn analyze_generic_bounds(&mut self, declarations: &[GenericTypeDeclaration]) {
+        for declaration in declarations {
+            for bound in &declaration.bounds {
+                _ = self.resolve_interface(bound);
+            }
+        }
+    }
+
+    /// Checks that the type `ty` given for the `generic` type parameter
+    /// implements the interfaces it is bound by.
+    fn analyze_generic_bounds_satisfied(&mut self, generic: &SemanticGenericType, ty: &SemanticType, range: FileRange) {
+        if ty.is_null() {
+            return;
+        }
+
+        for bound in &generic.bounds {
+            let Some(interface) = self.resolve_interface_by_name(bound) else {
+                continue;
+            };
+
+            if self.implements_interface(ty, &interface) {
+                continue;
+            }
+
+            let action = match ty {
+                SemanticType::Custom { base, .. } => Some(self.create_actions_extend_structure_with_interface(base, range.file_id(), &interface)),
+                _ => None,
+            };
+
+            self.diagnostics.create(|| SemanticDiagnostic::new(
+                range,
+                SemanticDiagnosticKind::GenericBoundNotSatisfied {
+                    ty: ty.clone(),
+                    interface: interface.name.value().clone(),
+                    name: generic.name.clone(),
+                },
+            ).with_related(SemanticRelatedInformation::new(
+                generic.declaration_range,
+                SemanticRelatedMessage::GenericTypeDeclaredHere { name: generic.name.clone() },
+            )).with_action(action));
+        }
+    }
+}
+
+/// Whether a value of type `actual` can be used where `expected` is expected.
+/// Integers of a different width or signedness aren't converted implicitly;
+/// only an integer literal takes the type of its destination, after which its
+/// range is checked separately. The same goes for the elements of an array or
+/// tuple literal, such as `stel xs: g8[] = [1, 2];`.
+fn is_assignable(expected: &SemanticType, actual: &SemanticType, expression: &Expression) -> bool {
+    if expected.is_compatible_with(actual) {
+        return true;
+    }
+
+    match (expected, actual, expression) {
+        (SemanticType::Array(expected), SemanticType::Array(actual), Expression::Primary(PrimaryExpression::ArrayLiteral(elements))) => {
+            elements.iter().all(|element| is_assignable(expected, actual, element.value()))
+        }
+
+        (SemanticType::Tuple(expected), SemanticType::Tuple(actual), Expression::Primary(PrimaryExpression::Tuple(elements))) => {
+            expected.len() == elements.len()
+                && actual.len() == elements.len()
+                && elements.iter().zip(expected.iter().zip(actual)).all(|(element, (expected, actual))| is_assignable(expected, actual, element.value()))
+        }
+
+        _ => expected.is_primitive_number() && actual.is_primitive_number() && expression.as_integer_literal().is_some(),
+    }
+}
+
+/// Looks up the value of a constant, such as `GROOTTE` or `Buffer.GROOTTE`,
+/// for the [`ConstantEvaluator`].
+fn resolve_constant_value(context: &SemanticContext, owner: Option<&BabString>, name: &BabString) -> Option<ConstantValue> {
+    match owner {
+        Some(owner) => {
+            let owner = context.resolve_name(owner);
+            context.scope.iter().rev()
+                .find_map(|scope| scope.structures.get(&owner))
+                .and_then(|structure| structure.constant(name))
+                .and_then(|constant| constant.value.clone())
+        }
+
+        None => {
+            let name = context.resolve_name(name);
+            context.scope.iter().rev()
+                .find_map(|scope| scope.locals.get(&name))
+                .and_then(|local| local.constant_value.clone())
+        }
     }
 }
@@ -3,9 +3,9 @@
 
 use std::{collections::HashMap, sync::Arc};
 
-use crate::{BabString, Constants, ExtensionStatement, FileId, FileLocation, FileRange, FunctionStatement, InterfaceStatement, ParseTree, Ranged, Structure};
+use crate::{qualify_name, BabString, Constants, ExtensionStatement, FileId, FileLocation, FileRange, FunctionStatement, InterfaceStatement, ParseTree, Ranged, Structure, Visibility};
 
-use super::{SemanticLocal, SemanticReference, scope::SemanticScope, FunctionReference, SemanticFunction, SemanticGenericType, SemanticInterface, SemanticLocalKind, SemanticScopeKind, SemanticStructure, SemanticType, StatementAnalysisState};
+use super::{SemanticLocal, SemanticReference, scope::SemanticScope, FunctionReference, SemanticEnumeration, SemanticFunction, SemanticGenericType, SemanticInterface, SemanticLocalKind, SemanticScopeKind, SemanticStructure, SemanticType, StatementAnalysisState};
 
 #[derive(Debug)]
 pub struct SemanticContext {
@@ -17,6 +17,17 @@ pub struct SemanticContext {
     pub value_type_tracker: Option<HashMap<FileRange, SemanticType>>,
 
     pub statements_state: Vec<StatementAnalysisState>,
+
+    /// The module of the file currently being analyzed, see [`Self::announce_file`].
+    pub current_module: Option<BabString>,
+
+    /// All modules declared by the analyzed files, with the range of (one of)
+    /// their `module` declarations.
+    pub modules: HashMap<BabString, FileRange>,
+
+    /// The module each announced file belongs to, used to decide whether an
+    /// `intern` declaration is accessible, see [`Self::is_accessible`].
+    pub file_modules: HashMap<FileId, Option<BabString>>,
 }
 
 impl SemanticContext {
@@ -30,6 +41,9 @@ impl SemanticContext {
             declaration_tracker: Some(Vec::new()),
             value_type_tracker: Some(HashMap::new()),
             statements_state: Vec::new(),
+            current_module: None,
+            modules: HashMap::new(),
+            file_modules: HashMap::new(),
         }
     }
 
@@ -52,14 +66,96 @@ impl SemanticContext {
         let location_start = FileLocation::new(location_end.file_id(), 0, 0, 0);
 
         self.scope[0].range = FileRange::new(location_start, location_end);
+
+        self.current_module = tree.module_path();
+        self.file_modules.insert(location_start.file_id(), self.current_module.clone());
+        if let (Some(module), Some(statement)) = (&self.current_module, tree.module()) {
+            self.modules.entry(module.clone()).or_insert(statement.path.range());
+        }
+    }
+
+    /// Whether a declaration with the given `visibility`, declared at
+    /// `declaration`, can be used from the file currently being analyzed.
+    #[must_use]
+    pub fn is_accessible(&self, visibility: Visibility, declaration: FileRange) -> bool {
+        self.is_accessible_from(self.scope[0].range.file_id(), visibility, declaration)
+    }
+
+    /// Whether a declaration with the given `visibility`, declared at
+    /// `declaration`, can be used from the file `file_id`. Declarations that
+    /// are `intern` are only accessible from within the same file, or from
+    /// within the same module.
+    #[must_use]
+    pub fn is_accessible_from(&self, file_id: FileId, visibility: Visibility, declaration: FileRange) -> bool {
+        if visibility == Visibility::Publiek {
+            return true;
+        }
+
+        let declared_in = declaration.file_id();
+        if declared_in == file_id || declared_in == FileId::INTERNAL {
+            return true;
+        }
+
+        match (self.file_modules.get(&file_id), self.file_modules.get(&declared_in)) {
+            (Some(Some(module)), Some(Some(declared_module))) => module == declared_module,
+            _ => false,
+        }
+    }
+
+    /// The name under which a declaration called `name` is stored in the scope
+    /// at `index`. Top-level declarations inside a module are prefixed with the
+    /// name of that module, e.g. `wiskunde.kwadraat`.
+    #[must_use]
+    pub fn declaration_name(&self, index: usize, name: &BabString) -> BabString {
+        if index == 0 {
+            qualify_name(self.current_module.as_ref(), name)
+        } else {
+            name.clone()
+        }
+    }
+
+    /// Resolves a `name` as written in the current file to the name it was
+    /// declared with. Inside a module, the declarations of that module take
+    /// precedence over those in the root namespace, but locals still shadow
+    /// both of them.
+    #[must_use]
+    pub fn resolve_name(&self, name: &BabString) -> BabString {
+        if self.current_module.is_none() {
+            return name.clone();
+        }
+
+        let is_declared_in = |scope: &SemanticScope, name: &BabString| {
+            scope.locals.contains_key(name)
+                || scope.structures.contains_key(name)
+                || scope.enumerations.contains_key(name)
+                || scope.interfaces.contains_key(name)
+                || scope.generic_types.contains_key(name)
+        };
+
+        if self.scope[1..].iter().any(|scope| is_declared_in(scope, name)) {
+            return name.clone();
+        }
+
+        let qualified = self.declaration_name(0, name);
+        if is_declared_in(&self.scope[0], &qualified) {
+            qualified
+        } else {
+            name.clone()
+        }
     }
 
     pub fn push_function_scope(&mut self, function: &FunctionStatement, this: Option<SemanticType>) -> &mut SemanticScope {
+        let generic_types = self.function_generic_types(function)
+            .into_iter()
+            .map(|ty| (ty.name.clone(), ty))
+            .collect();
+
         self.scope.push(SemanticScope {
             range: function.range,
             locals: HashMap::new(),
             structures: HashMap::new(),
-            generic_types: HashMap::new(),
+            enumerations: HashMap::new(),
+            generic_types,
             this,
             return_type: None,
             kind: SemanticScopeKind::Function {
@@ -71,6 +167,15 @@ impl SemanticContext {
         self.scope.last_mut().expect("we just pushed a scope")
     }
 
+    /// The type parameters of a generic `werkwijze`. Their indices come after
+    /// those of the enclosing scopes, such that the `U` of a method
+    /// `werkwijze zet<U>` inside `structuur Paar<T>` doesn't collide with `T`.
+    #[must_use]
+    pub fn function_generic_types(&self, function: &FunctionStatement) -> Vec<SemanticGenericType> {
+        let offset: usize = self.scope.iter().map(|scope| scope.generic_types.len()).sum();
+        SemanticGenericType::from_declarations(&function.generic_types, offset)
+    }
+
     pub fn push_block_scope(&mut self, range: FileRange) -> &mut SemanticScope {
         let this = self.scope.last().and_then(|x| x.this.clone());
         let return_type = self.scope.last().and_then(|x| x.return_type.clone());
@@ -78,6 +183,7 @@ impl SemanticContext {
             range,
             locals: HashMap::new(),
             structures: HashMap::new(),
+            enumerations: HashMap::new(),
             generic_types: HashMap::new(),
             this,
             return_type,
@@ -88,6 +194,12 @@ impl SemanticContext {
         self.scope.last_mut().expect("we just pushed a scope")
     }
 
+    pub fn push_loop_scope(&mut self, range: FileRange) -> &mut SemanticScope {
+        let scope = self.push_block_scope(range);
+        scope.kind = SemanticScopeKind::Loop;
+        scope
+    }
+
     pub fn push_structure_scope(&mut self, structure: &Structure) {
         let this = self.scope.last().and_then(|x| x.this.clone());
         let return_type = self.scope.last().and_then(|x| x.return_type.clone());
@@ -95,17 +207,10 @@ impl SemanticContext {
             range: FileRange::new(structure.left_curly_range.start(), structure.right_curly_range.end()),
             locals: HashMap::new(),
             structures: HashMap::new(),
-            generic_types: structure.generic_types
-                .iter()
-                .enumerate()
-                .map(|(index, x)| {
-                    let ty = SemanticGenericType {
-                        index,
-                        name: x.value().clone(),
-                        declaration_range: x.range(),
-                    };
-                    (x.value().clone(), ty)
-                })
+            enumerations: HashMap::new(),
+            generic_types: SemanticGenericType::from_declarations(&structure.generic_types, 0)
+                .into_iter()
+                .map(|ty| (ty.name.clone(), ty))
                 .collect(),
             this,
             return_type,
@@ -122,17 +227,10 @@ impl SemanticContext {
             range: FileRange::new(interface.left_curly_range.start(), interface.right_curly_range.end()),
             locals: HashMap::new(),
             structures: HashMap::new(),
-            generic_types: interface.generic_types
-                .iter()
-                .enumerate()
-                .map(|(index, x)| {
-                    let ty = SemanticGenericType {
-                        index,
-                        name: x.value().clone(),
-                        declaration_range: x.range(),
-                    };
-                    (x.value().clone(), ty)
-                })
+            enumerations: HashMap::new(),
+            generic_types: SemanticGenericType::from_declarations(&interface.generic_types, 0)
+                .into_iter()
+                .map(|ty| (ty.name.clone(), ty))
                 .collect(),
             this,
             return_type,
@@ -149,17 +247,10 @@ impl SemanticContext {
             range,
             locals: HashMap::new(),
             structures: HashMap::new(),
-            generic_types: extension.generic_types
-                .iter()
-                .enumerate()
-                .map(|(index, x)| {
-                    let ty = SemanticGenericType {
-                        index,
-                        name: x.value().clone(),
-                        declaration_range: x.range(),
-                    };
-                    (x.value().clone(), ty)
-                })
+            enumerations: HashMap::new(),
+            generic_types: SemanticGenericType::from_declarations(&extension.generic_types, 0)
+                .into_iter()
+                .map(|ty| (ty.name.clone(), ty))
                 .collect(),
             this,
             return_type,
@@ -181,8 +272,9 @@ impl SemanticContext {
             });
         }
 
+        let name = self.declaration_name(self.scope.len() - 1, function.name.value());
         self.scope.last_mut().unwrap().locals.insert(
-            function.name.value().clone(),
+            name,
             SemanticLocal::new(
                 SemanticLocalKind::Function,
                 SemanticType::Function(function),
@@ -222,8 +314,51 @@ impl SemanticContext {
             }
         }
 
+        self.declare_structure(structure);
+    }
+
+    /// Makes the `structure` available by name in the scope around the one
+    /// of the structure itself, without tracking its declarations. This is
+    /// used to refer to a structure in the signatures of its own methods,
+    /// before the structure is complete.
+    pub fn declare_structure(&mut self, structure: Arc<SemanticStructure>) {
         let previous_idx = self.scope.len() - 2;
-        self.scope[previous_idx].structures.insert(structure.name.value().clone(), structure);
+        let name = self.declaration_name(previous_idx, structure.name.value());
+        self.scope[previous_idx].structures.insert(name, structure);
+    }
+
+    /// Returns the complete version of the `structure`, which might be the
+    /// one that was declared before its methods were known, when it is used
+    /// inside the signatures of its own methods.
+    #[must_use]
+    pub fn complete_structure(&self, structure: &Arc<SemanticStructure>) -> Arc<SemanticStructure> {
+        self.scope.iter().rev()
+            .flat_map(|scope| scope.structures.values())
+            .find(|candidate| *candidate == structure)
+            .map_or_else(|| Arc::clone(structure), Arc::clone)
+    }
+
+    pub fn push_enumeration(&mut self, enumeration: Arc<SemanticEnumeration>) {
+        if let Some(tracker) = &mut self.declaration_tracker {
+            tracker.push(SemanticReference {
+                local_name: enumeration.name.value().clone(),
+                local_kind: SemanticLocalKind::EnumerationReference,
+                declaration_range: enumeration.name.range(),
+                typ: SemanticType::Enumeration(Arc::clone(&enumeration)),
+            });
+
+            for variant in &enumeration.variants {
+                tracker.push(SemanticReference {
+                    local_name: variant.name.value().clone(),
+                    local_kind: SemanticLocalKind::EnumerationVariant,
+                    declaration_range: variant.name.range(),
+                    typ: SemanticType::Enumeration(Arc::clone(&enumeration)),
+                });
+            }
+        }
+
+        let name = self.declaration_name(self.scope.len() - 1, enumeration.name.value());
+        self.current().enumerations.insert(name, enumeration);
     }
 
     pub fn push_interface(&mut self, interface: Arc<SemanticInterface>) {
@@ -249,7 +384,23 @@ impl SemanticContext {
         }
 
         let previous_idx = self.scope.len() - 2;
-        self.scope[previous_idx].interfaces.insert(interface.name.value().clone(), interface);
+        let name = self.declaration_name(previous_idx, interface.name.value());
+        self.scope[previous_idx].interfaces.insert(name, interface);
+    }
+
+    /// Whether the current scope is inside a loop, without crossing the
+    /// boundary of a werkwijze or structure.
+    #[must_use]
+    pub fn is_inside_loop(&self) -> bool {
+        for scope in self.scope.iter().rev() {
+            match scope.kind {
+                SemanticScopeKind::Default => continue,
+                SemanticScopeKind::Loop => return true,
+                _ => return false,
+            }
+        }
+
+        false
     }
 
     pub fn pop_scope(&mut self) {
@@ -259,6 +410,22 @@ impl SemanticContext {
         self.previous_scopes.push(scope);
     }
 
+    /// Declares a `vast` constant. Unlike other locals, constants at the top
+    /// level are part of the module they are declared in.
+    pub fn push_constant(&mut self, name: &Ranged<BabString>, local: SemanticLocal) {
+        if let Some(tracker) = &mut self.declaration_tracker {
+            tracker.push(SemanticReference {
+                local_name: name.value().clone(),
+                local_kind: local.kind,
+                declaration_range: name.range(),
+                typ: local.typ.clone(),
+            });
+        }
+
+        let name = self.declaration_name(self.scope.len() - 1, name.value());
+        self.scope.last_mut().unwrap().locals.insert(name, local);
+    }
+
     pub fn push_local(&mut self, name: &Ranged<BabString>, local: SemanticLocal) {
         if name.value() == &Constants::DISCARDING_IDENT {
             return;
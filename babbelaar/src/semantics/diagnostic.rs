@@ -6,7 +6,7 @@ use std::{error::Error, fmt::Display};
 use strum::AsRefStr;
 use thiserror::Error;
 
-use crate::{BabString, BabbelaarCodeAction, Expression, FileRange};
+use crate::{BabString, BabbelaarCodeAction, ConstantEvaluationError, Expression, FileRange, IntegerType};
 
 use super::{SemanticRelatedInformation, SemanticType};
 
@@ -132,6 +132,11 @@ pub enum SemanticDiagnosticKind {
         name: BabString,
     },
 
+    #[error("Module `{name}` bestaat niet")]
+    UnknownModule {
+        name: BabString,
+    },
+
     #[error("Types `{lhs_type}` en `{rhs_type}` zijn niet gelijksoortig.")]
     IncompatibleTypes {
         lhs_type: SemanticType,
@@ -144,6 +149,16 @@ pub enum SemanticDiagnosticKind {
         parameter_type: SemanticType,
     },
 
+    #[error("Type van generieke parameter `{name}` kan niet afgeleid worden uit de argumenten.")]
+    CannotInferGenericType { name: BabString },
+
+    #[error("Type `{ty}` implementeert koppelvlak `{interface}` niet, wat vereist is voor generieke parameter `{name}`. Voeg een `uitbreiding {interface} op {ty}` toe.")]
+    GenericBoundNotSatisfied {
+        ty: SemanticType,
+        interface: BabString,
+        name: BabString,
+    },
+
     #[error("Lid `{name}` bestaat niet binnen type `{typ}`")]
     InvalidMember {
         typ: SemanticType,
@@ -174,6 +189,15 @@ pub enum SemanticDiagnosticKind {
     #[error("Werkwijzenaam `{name}` in structuur `{structure}` wordt meerdere keren gebruikt")]
     DuplicateMethodNameInStructure { name: BabString, structure: BabString },
 
+    #[error("Variantnaam `{name}` in opsomming `{enumeration}` wordt meerdere keren gebruikt")]
+    DuplicateVariantName { name: BabString, enumeration: BabString },
+
+    #[error("Opsomming `{enumeration}` heeft geen variant genaamd `{name}`")]
+    InvalidEnumerationVariant { enumeration: BabString, name: BabString },
+
+    #[error("Variant `{name}` draagt {field_count} veld(en) mee, maar kreeg er {arg_count}")]
+    EnumerationVariantFieldCount { name: BabString, field_count: usize, arg_count: usize },
+
     #[error("Veld met naam `{name}` wordt meerdere keren een waarde toegekend")]
     DuplicateFieldInstantiation { name: BabString },
 
@@ -231,6 +255,18 @@ pub enum SemanticDiagnosticKind {
     #[error("Attribuut `@uitheems` kan maar één keer gebruikt worden per werkwijzen.")]
     AttributeExternOnlyOnce,
 
+    #[error("Een waarde van type `{typ}` kan nog niet doorgegeven worden aan een uitheemse werkwijze, alleen getallen, `bool`, `teken`, `Slinger` en wijzers.")]
+    AttributeExternUnsupportedParameterType { typ: BabString },
+
+    #[error("Het attribuut `@test` kan alleen gebruikt worden op werkwijzen.")]
+    AttributeTestOnlyOnFunctions,
+
+    #[error("Het attribuut `@test` kan alleen gebruikt worden op werkwijzen met een lichaam {{ .. }}")]
+    AttributeTestOnlyOnFunctionsWithBody,
+
+    #[error("Een `@test`-werkwijze kan geen parameters hebben, want deze wordt zonder argumenten aangeroepen.")]
+    AttributeTestFunctionHasParameters,
+
     #[error("De werkwijze genaamd `{name}` is meerdere keren gedefinieerd.")]
     DuplicateFunction { name: BabString },
 
@@ -251,6 +287,9 @@ pub enum SemanticDiagnosticKind {
         name: BabString,
     },
 
+    #[error("Vaste waarde `{name}` wordt nergens gebruikt.")]
+    UnusedConstant { name: BabString },
+
     #[error("Werkwijze `{name}` wordt nergens gebruikt.")]
     UnusedFunction { name: BabString },
 
@@ -266,6 +305,9 @@ pub enum SemanticDiagnosticKind {
     #[error("Expressie resulteert niet in een getal, wat nodig is om de grootte van de opeenvolging te bepalen.")]
     SizedArrayInitializerInvalidSize,
 
+    #[error("Een `{ty}` heeft geen standaardwaarde, dus kan `nieuw {ty}[...]` niet gevuld worden. Gebruik een lijst zoals `[a, b]`.")]
+    SizedArrayElementWithoutDefault { ty: SemanticType },
+
     #[error("Een `{ty}` kan niet worden geïndexeerd. Types zoals `Slinger` en opeenvolgingen wel.")]
     CannotSubscriptNonArray { ty: SemanticType },
 
@@ -278,6 +320,18 @@ pub enum SemanticDiagnosticKind {
     #[error("{name} moet van het type `g32` zijn, maar dit is een `{ty}`")]
     RangeExpectsInteger { name: &'static str, ty: SemanticType },
 
+    #[error("De stap van een `reeks` mag niet `0` zijn, want dan houdt de reeks nooit op")]
+    RangeStepIsZero,
+
+    #[error("Voorwaarde moet van het type `bool` zijn, maar dit is een `{ty}`")]
+    ConditionExpectsBoolean { ty: SemanticType },
+
+    #[error("`stop` kan alleen binnen een `volg`- of `zolang`-lus gebruikt worden.")]
+    BreakOutsideLoop,
+
+    #[error("`ga door` kan alleen binnen een `volg`- of `zolang`-lus gebruikt worden.")]
+    ContinueOutsideLoop,
+
     #[error("Kan deze waarde niet gebruiken als een doorloper, gebruik een opeenvolging of `reeks`.")]
     ExpressionNotIterable,
 
@@ -329,8 +383,101 @@ pub enum SemanticDiagnosticKind {
     #[error("Kan alleen getallen negatief keren")]
     CannotNegateNonInteger,
 
+    #[error("Kan alleen `waar` of `onwaar` omkeren met `!`, maar kreeg een `{ty}`")]
+    CannotInvertNonBoolean { ty: SemanticType },
+
+    #[error("Waarden van het type `{ty}` kunnen niet vergeleken worden met `{operator}`")]
+    UnorderedComparison { operator: &'static str, ty: SemanticType },
+
+    #[error("Operator `{operator}` kan niet gebruikt worden op `{ty}`, want het koppelvlak `{interface}` is niet geïmplementeerd. Voeg een `uitbreiding {interface} op {ty}` toe.")]
+    OperatorNotImplemented { operator: &'static str, ty: SemanticType, interface: &'static str },
+
+    #[error("De bitoperatie `{operator}` kan niet gebruikt worden op een `kommagetal`")]
+    BitwiseOperationOnFloat { operator: &'static str },
+
+    #[error("Getal {value} past niet in een `{ty}`, dat loopt van {} tot en met {}", ty.min(), ty.max())]
+    IntegerLiteralOutOfRange { value: i128, ty: IntegerType },
+
     #[error("Kan alleen het adres nemen van een lokale variabele")]
     CannotTakeAddressOfNonIdentifier,
+
+    #[error("`kies` dekt niet alle mogelijke waarden af, ontbrekend: {missing}")]
+    NonExhaustiveMatch { missing: String },
+
+    #[error("Deze tak wordt nooit gekozen, want eerdere takken dekken deze waarden al af")]
+    UnreachableMatchArm,
+
+    #[error("Patroon `{pattern}` kan nooit passen bij een waarde van het type `{ty}`")]
+    PatternTypeMismatch { pattern: String, ty: SemanticType },
+
+    #[error("Deze tak geeft een `{actual}`, maar de eerste tak van `kies` geeft een `{expected}`")]
+    IncompatibleMatchArmTypes { expected: SemanticType, actual: SemanticType },
+
+    #[error("Waarde van type `{ty}` kan ontbreken, en moet eerst uitgepakt worden met `kies`")]
+    OptionalNotUnpacked { ty: SemanticType },
+
+    #[error("Deze expressie geeft geen waarde terug. Gebruik `Misschien` als een waarde kan ontbreken.")]
+    ExpressionHasNoValue,
+
+    #[error("Waarde van type `{ty}` kan een fout bevatten, en moet eerst uitgepakt worden met `kies` of `?`")]
+    ResultNotUnpacked { ty: SemanticType },
+
+    #[error("Resultaat van type `{ty}` wordt niet afgehandeld")]
+    UnhandledResult { ty: SemanticType },
+
+    #[error("`?` kan alleen gebruikt worden op een `Resultaat`, maar dit is een `{ty}`")]
+    PropagateRequiresResult { ty: SemanticType },
+
+    #[error("`?` kan alleen gebruikt worden in een werkwijze die een `Resultaat` bekeert")]
+    PropagateOutsideResultFunction,
+
+    #[error("Fout van type `{actual}` kan niet doorgegeven worden, want de werkwijze bekeert fouten van type `{expected}`")]
+    PropagateIncompatibleError { actual: SemanticType, expected: SemanticType },
+
+    #[error("Operator `{operator}=` kan niet gebruikt worden op een waarde van type `{ty}`")]
+    InvalidCompoundAssignment { operator: &'static str, ty: SemanticType },
+
+    #[error("Vaste waarde `{name}` kan niet aangepast worden")]
+    AssignmentToConstant { name: BabString },
+
+    #[error("Waarde van vaste waarde kan niet tijdens het vertalen bepaald worden: {error}")]
+    ConstantNotEvaluable { error: ConstantEvaluationError },
+
+    #[error("Een vaste waarde moet tijdens het vertalen uitgerekend kunnen worden. Gebruik `stel` voor waarden die pas bij het uitvoeren bekend zijn, zoals de uitkomst van een werkwijze.")]
+    NonConstantInitializer,
+
+    #[error("Type `{ty}` kan niet gebruikt worden voor een vaste waarde")]
+    InvalidConstantType { ty: SemanticType },
+
+    #[error("Vaste waarde van type `{expected}` kan geen waarde van type `{actual}` krijgen")]
+    IncompatibleConstantType { expected: SemanticType, actual: SemanticType },
+
+    #[error("Alleen een tupel kan uitgepakt worden in meerdere variabelen, maar dit is een `{ty}`")]
+    DestructuringRequiresTuple { ty: SemanticType },
+
+    #[error("Tupel van type `{ty}` heeft {actual} elementen, maar wordt uitgepakt in {expected} variabelen")]
+    DestructuringCountMismatch { ty: SemanticType, expected: usize, actual: usize },
+
+    #[error("Element van type `{actual}` past niet in een opeenvolging van `{expected}`, het type van het eerste element")]
+    ArrayElementTypeMismatch { expected: SemanticType, actual: SemanticType },
+
+    #[error("Het type van de elementen van een lege opeenvolging is onbekend, geef het type aan, bijvoorbeeld `stel lijst: g32[] = [];`")]
+    EmptyArrayWithoutType,
+
+    #[error("Variabele van type `{expected}` kan geen waarde van type `{actual}` krijgen")]
+    IncompatibleVariableType { expected: SemanticType, actual: SemanticType },
+
+    #[error("`dit` kan niet gebruikt worden binnen een gedeelde werkwijze")]
+    ThisInsideStaticMethod,
+
+    #[error("Werkwijze `{name}` is gedeeld, en wordt aangeroepen via de structuur: `{structure}.{name}()`")]
+    StaticMethodCalledOnValue { structure: BabString, name: BabString },
+
+    #[error("Werkwijze `{name}` is niet gedeeld, en heeft daarom een waarde van `{structure}` nodig om aangeroepen te worden")]
+    MethodRequiresValue { structure: BabString, name: BabString },
+
+    #[error("{kind} `{name}` is intern en kan alleen binnen hetzelfde bestand of dezelfde module gebruikt worden")]
+    NotAccessible { kind: &'static str, name: BabString },
 }
 
 impl SemanticDiagnosticKind {
@@ -0,0 +1,66 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::fmt::{Display, Write};
+
+use crate::{AttributeList, BabString, FileRange, Ranged};
+
+use super::SemanticParameter;
+
+#[derive(Debug)]
+pub struct SemanticEnumeration {
+    pub attributes: AttributeList,
+    pub name: Ranged<BabString>,
+    pub left_curly_range: FileRange,
+    pub right_curly_range: FileRange,
+    pub variants: Vec<SemanticEnumerationVariant>,
+}
+
+impl SemanticEnumeration {
+    #[must_use]
+    pub fn variant(&self, name: &BabString) -> Option<&SemanticEnumerationVariant> {
+        self.variants.iter().find(|variant| variant.name.value() == name)
+    }
+}
+
+impl Display for SemanticEnumeration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl PartialEq for SemanticEnumeration {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.range() == other.name.range() && self.name.value() == other.name.value()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SemanticEnumerationVariant {
+    pub name: Ranged<BabString>,
+    pub fields: Vec<SemanticParameter>,
+}
+
+impl Display for SemanticEnumerationVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)?;
+
+        if self.fields.is_empty() {
+            return Ok(());
+        }
+
+        f.write_char('(')?;
+
+        for (idx, field) in self.fields.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_str(&field.name)?;
+            f.write_str(": ")?;
+            field.ty.value().fmt(f)?;
+        }
+
+        f.write_char(')')
+    }
+}
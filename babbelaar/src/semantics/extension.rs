@@ -1,17 +1,21 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::{collections::{HashMap, HashSet}, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{BabString, FileRange};
 
-use super::{SemanticInterface, SemanticMethod, SemanticType};
+use super::{SemanticGenericType, SemanticInterface, SemanticMethod, SemanticType};
 
 #[derive(Debug, Clone)]
 pub struct SemanticExtension {
     pub ty: SemanticType,
-    pub generic_types: HashSet<BabString>,
+    pub generic_types: Vec<SemanticGenericType>,
     pub interface: Option<Arc<SemanticInterface>>,
+
+    /// The types given for the generic parameters of the `interface`, such as
+    /// the `Geld` in `uitbreiding Optelbaar<Geld> op Geld`.
+    pub interface_arguments: Vec<SemanticType>,
     pub methods: HashMap<BabString, SemanticMethod>,
     pub range: FileRange,
     pub right_curly_bracket: FileRange,
@@ -42,7 +46,7 @@ impl SemanticExtension {
                 continue;
             }
 
-            if self.generic_types.contains(&ext_param.name()) {
+            if self.generic_types.iter().any(|generic| generic.name == ext_param.name()) {
                 continue;
             }
 
@@ -52,4 +56,36 @@ impl SemanticExtension {
 
         true
     }
+
+    /// Returns the types given for the generic type parameters of the
+    /// interface, keyed by the index of the generic type in the interface.
+    #[must_use]
+    pub fn interface_generic_arguments(&self) -> HashMap<usize, SemanticType> {
+        let Some(interface) = &self.interface else {
+            return HashMap::new();
+        };
+
+        interface.generic_types.iter()
+            .zip(self.interface_arguments.iter())
+            .map(|(generic, ty)| (generic.index, ty.clone()))
+            .collect()
+    }
+
+    /// Returns the types of `typ` that are given for the generic type
+    /// parameters of this extension, e.g. `g32` for the `T` in
+    /// `uitbreiding<T> .. op Lijst<T>` when `typ` is `Lijst<g32>`.
+    #[must_use]
+    pub fn generic_arguments<'a>(&'a self, typ: &'a SemanticType) -> Vec<(&'a SemanticGenericType, &'a SemanticType)> {
+        let (SemanticType::Custom { parameters: req_params, .. }, SemanticType::Custom { parameters: ext_params, .. }) = (typ, &self.ty) else {
+            return Vec::new();
+        };
+
+        req_params.iter()
+            .zip(ext_params.iter())
+            .filter_map(|(req_param, ext_param)| {
+                let generic = self.generic_types.iter().find(|generic| generic.name == ext_param.name())?;
+                Some((generic, req_param))
+            })
+            .collect()
+    }
 }
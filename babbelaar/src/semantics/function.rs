@@ -1,11 +1,11 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::fmt::Display;
+use std::fmt::{Display, Write};
 
-use crate::{BabString, FileRange, Ranged};
+use crate::{BabString, FileRange, Ranged, Visibility};
 
-use super::SemanticType;
+use super::{SemanticGenericType, SemanticType};
 
 #[derive(Debug, Clone)]
 pub struct SemanticExternFunction {
@@ -16,10 +16,17 @@ pub struct SemanticExternFunction {
 #[derive(Debug, Clone)]
 pub struct SemanticFunction {
     pub name: Ranged<BabString>,
+    pub generic_types: Vec<SemanticGenericType>,
     pub parameters: Vec<SemanticParameter>,
     pub parameters_right_paren_range: FileRange,
     pub extern_function: Option<SemanticExternFunction>,
     pub return_type: Box<SemanticType>,
+
+    /// See [`crate::FunctionStatement::visibility`].
+    pub visibility: Option<Ranged<Visibility>>,
+
+    /// See [`crate::FunctionStatement::documentation`].
+    pub documentation: Option<BabString>,
 }
 
 impl Display for SemanticFunction {
@@ -30,6 +37,21 @@ impl Display for SemanticFunction {
     }
 }
 
+impl SemanticFunction {
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility.as_ref().map(|x| *x.value()).unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn signature(&self) -> SemanticFunctionSignature {
+        SemanticFunctionSignature {
+            parameters: self.parameters.iter().map(|x| x.ty.value().clone()).collect(),
+            return_type: self.return_type.clone(),
+        }
+    }
+}
+
 impl PartialEq for SemanticFunction {
     fn eq(&self, other: &Self) -> bool {
         self.name.value() == other.name.value()
@@ -41,3 +63,34 @@ pub struct SemanticParameter {
     pub name: Ranged<BabString>,
     pub ty: Ranged<SemanticType>,
 }
+
+/// The type of a `werkwijze` that is used as a value, such as an anonymous
+/// `werkwijze` or a parameter of type `werkwijze(g32) -> bool`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticFunctionSignature {
+    pub parameters: Vec<SemanticType>,
+    pub return_type: Box<SemanticType>,
+}
+
+impl Display for SemanticFunctionSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("werkwijze(")?;
+
+        for (idx, param) in self.parameters.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ")?;
+            }
+
+            param.fmt(f)?;
+        }
+
+        f.write_char(')')?;
+
+        if !self.return_type.is_null() {
+            f.write_str(" -> ")?;
+            self.return_type.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -5,12 +5,15 @@ use std::fmt::Display;
 
 use crate::{BabString, BuiltinFunction, FileRange};
 
-use super::SemanticFunction;
+use super::{SemanticFunction, SemanticFunctionSignature};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum FunctionReference {
     Builtin(&'static BuiltinFunction),
     Custom(SemanticFunction),
+
+    /// An anonymous `werkwijze`, or a value of a function type.
+    Signature(SemanticFunctionSignature),
 }
 
 impl Display for FunctionReference {
@@ -18,6 +21,7 @@ impl Display for FunctionReference {
         match self {
             Self::Builtin(func) => func.fmt(f),
             Self::Custom(func) => func.fmt(f),
+            Self::Signature(signature) => signature.fmt(f),
         }
     }
 }
@@ -28,6 +32,21 @@ impl FunctionReference {
         match self {
             Self::Builtin(func) => func.parameters.len(),
             Self::Custom(func) => func.parameters.len(),
+            Self::Signature(signature) => signature.parameters.len(),
+        }
+    }
+
+    /// The types of the parameters and the return value, used to check
+    /// whether this function can be used as a value of a function type.
+    #[must_use]
+    pub fn signature(&self) -> SemanticFunctionSignature {
+        match self {
+            Self::Builtin(func) => SemanticFunctionSignature {
+                parameters: func.parameters.iter().map(|x| x.typ.resolve()).collect(),
+                return_type: Box::new(func.return_type.resolve()),
+            },
+            Self::Custom(func) => func.signature(),
+            Self::Signature(signature) => signature.clone(),
         }
     }
 
@@ -36,6 +55,7 @@ impl FunctionReference {
         match self {
             Self::Builtin(func) => BabString::new_static(func.name),
             Self::Custom(func) => func.name.value().to_owned(),
+            Self::Signature(signature) => BabString::new(signature.to_string()),
         }
     }
 
@@ -43,7 +63,8 @@ impl FunctionReference {
     pub fn documentation(&self) -> Option<BabString> {
         match self {
             Self::Builtin(func) => Some(BabString::new_static(func.documentation)),
-            Self::Custom(..) => None,
+            Self::Custom(func) => func.documentation.clone(),
+            Self::Signature(..) => None,
         }
     }
 
@@ -68,14 +89,14 @@ impl FunctionReference {
     pub fn lsp_completion_raw(&self) -> Option<BabString> {
         match self {
             Self::Builtin(func) => func.lsp_completion.map(|x| BabString::new_static(x)),
-            Self::Custom(..) => None,
+            Self::Custom(..) | Self::Signature(..) => None,
         }
     }
 
     #[must_use]
     pub fn declaration_range(&self) -> FileRange {
         match self {
-            Self::Builtin(..) => FileRange::default(),
+            Self::Builtin(..) | Self::Signature(..) => FileRange::default(),
             Self::Custom(func) => func.name.range(),
         }
     }
@@ -84,7 +105,7 @@ impl FunctionReference {
     pub fn inline_detail(&self) -> Option<BabString> {
         match self {
             Self::Builtin(func) => Some(BabString::new_static(func.inline_detail)),
-            Self::Custom(..) => None,
+            Self::Custom(..) | Self::Signature(..) => None,
         }
     }
 }
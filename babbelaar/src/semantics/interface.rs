@@ -3,18 +3,81 @@
 
 use std::fmt::Display;
 
-use crate::{AttributeList, BabString, FileRange, Ranged};
+use crate::{AttributeList, BabString, BuiltinInterface, BuiltinInterfaceReturnType, FileRange, Ranged};
 
-use super::SemanticMethod;
+use super::{SemanticFunction, SemanticGenericType, SemanticMethod, SemanticParameter, SemanticType};
 
 #[derive(Debug)]
 pub struct SemanticInterface {
     pub attributes: AttributeList,
     pub name: Ranged<BabString>,
-    pub generic_types: Vec<Ranged<BabString>>,
+    pub generic_types: Vec<SemanticGenericType>,
     pub left_curly_range: FileRange,
     pub right_curly_range: FileRange,
     pub methods: Vec<SemanticMethod>,
+
+    /// See [`crate::InterfaceStatement::documentation`].
+    pub documentation: Option<BabString>,
+}
+
+impl SemanticInterface {
+    #[must_use]
+    pub fn from_builtin(builtin: &BuiltinInterface) -> Self {
+        let generic = SemanticGenericType {
+            index: 0,
+            name: BabString::new_static(BuiltinInterface::GENERIC_TYPE_NAME),
+            declaration_range: FileRange::INTERNAL,
+            bounds: Vec::new(),
+        };
+
+        let return_type = match builtin.return_type {
+            BuiltinInterfaceReturnType::Generic => SemanticType::Generic(generic.clone()),
+            BuiltinInterfaceReturnType::Builtin(ty) => SemanticType::Builtin(ty),
+        };
+
+        let parameters = builtin.parameter_name.iter()
+            .map(|name| SemanticParameter {
+                name: Ranged::new(FileRange::INTERNAL, BabString::new_static(name)),
+                ty: Ranged::new(FileRange::INTERNAL, SemanticType::Generic(generic.clone())),
+            })
+            .collect();
+
+        // Only the interfaces of operators have a parameter, which is `T`.
+        let generic_types = if builtin.parameter_name.is_some() { vec![generic] } else { Vec::new() };
+
+        let function = SemanticFunction {
+            name: Ranged::new(FileRange::INTERNAL, BabString::new_static(builtin.method_name)),
+            generic_types: Vec::new(),
+            parameters,
+            parameters_right_paren_range: FileRange::INTERNAL,
+            extern_function: None,
+            return_type: Box::new(return_type),
+            visibility: None,
+            documentation: None,
+        };
+
+        Self {
+            attributes: AttributeList::new(),
+            name: Ranged::new(FileRange::INTERNAL, BabString::new_static(builtin.name)),
+            generic_types,
+            left_curly_range: FileRange::INTERNAL,
+            right_curly_range: FileRange::INTERNAL,
+            methods: vec![
+                SemanticMethod {
+                    range: FileRange::INTERNAL,
+                    function,
+                    is_static: false,
+                },
+            ],
+            documentation: Some(BabString::new_static(builtin.documentation)),
+        }
+    }
+
+    /// Returns whether this is one of the [`crate::Builtin::INTERFACES`].
+    #[must_use]
+    pub fn is_builtin(&self) -> bool {
+        self.name.range() == FileRange::INTERNAL
+    }
 }
 
 impl Display for SemanticInterface {
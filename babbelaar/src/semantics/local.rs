@@ -1,7 +1,7 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::FileRange;
+use crate::{ConstantValue, FileRange};
 
 use super::SemanticType;
 
@@ -12,6 +12,9 @@ pub struct SemanticLocal {
     pub typ: SemanticType,
     pub usage_count: usize,
     pub full_declaration_range: Option<FileRange>,
+
+    /// The value of a `vast` constant, if it could be evaluated.
+    pub constant_value: Option<ConstantValue>,
 }
 
 impl SemanticLocal {
@@ -23,6 +26,7 @@ impl SemanticLocal {
             typ,
             usage_count: 0,
             full_declaration_range: None,
+            constant_value: None,
         }
     }
 
@@ -33,6 +37,13 @@ impl SemanticLocal {
         }
     }
 
+    pub fn with_constant_value(self, value: Option<ConstantValue>) -> Self {
+        Self {
+            constant_value: value,
+            ..self
+        }
+    }
+
     pub fn add_usage(&mut self) {
         self.usage_count += 1;
     }
@@ -40,9 +51,12 @@ impl SemanticLocal {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SemanticLocalKind {
+    Constant,
     Parameter,
     FieldReference,
     StructureReference,
+    EnumerationReference,
+    EnumerationVariant,
     Iterator,
     Function,
     FunctionReference,
@@ -55,9 +69,12 @@ impl SemanticLocalKind {
     #[must_use]
     pub const fn is_function(&self) -> bool {
         match self {
+            Self::Constant => false,
             Self::Parameter => false,
             Self::FieldReference => false,
             Self::StructureReference => true,
+            Self::EnumerationReference => false,
+            Self::EnumerationVariant => false,
             Self::Iterator => false,
             Self::Function => true,
             Self::FunctionReference => true,
@@ -70,9 +87,12 @@ impl SemanticLocalKind {
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
+            Self::Constant => "vaste waarde",
             Self::Parameter => "parameter",
             Self::FieldReference => "veld",
             Self::StructureReference => "structuur",
+            Self::EnumerationReference => "opsomming",
+            Self::EnumerationVariant => "opsommingsvariant",
             Self::Iterator => "iterator",
             Self::Function => "werkwijze",
             Self::FunctionReference => "werkwijze",
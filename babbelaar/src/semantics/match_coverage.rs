@@ -0,0 +1,186 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::{BabString, BuiltinType, FileRange, Pattern, Ranged};
+
+use super::SemanticType;
+
+/// Keeps track of the values that are already covered by the arms of a `kies`,
+/// which is used to find unreachable arms and missing patterns.
+#[derive(Debug, Default)]
+pub struct MatchCoverage {
+    everything: Option<FileRange>,
+    booleans: HashMap<bool, FileRange>,
+    literals: HashMap<String, FileRange>,
+    ranges: Vec<(i64, i64, FileRange)>,
+    variants: HashMap<BabString, FileRange>,
+
+    /// Keyed by whether or not the `Misschien` contains a value.
+    optionals: HashMap<bool, FileRange>,
+
+    /// Keyed by whether or not the `Resultaat` is `Goed`.
+    results: HashMap<bool, FileRange>,
+}
+
+impl MatchCoverage {
+    /// Returns the range of the earlier arm that already matches all the values
+    /// the given pattern can match.
+    #[must_use]
+    pub fn find_covering_arm(&self, pattern: &Pattern) -> Option<FileRange> {
+        if let Some(range) = self.everything {
+            return Some(range);
+        }
+
+        match pattern {
+            Pattern::Boolean(b) => self.booleans.get(b).copied(),
+
+            Pattern::IntegerLiteral(i) => {
+                self.literals.get(&pattern.to_string()).copied()
+                    .or_else(|| self.find_covering_range(*i, i + 1))
+            }
+
+            Pattern::CharacterLiteral(..) | Pattern::StringLiteral(..) => {
+                self.literals.get(&pattern.to_string()).copied()
+            }
+
+            Pattern::Range { start, end } => self.find_covering_range(*start.value(), *end.value()),
+
+            Pattern::EnumerationVariant { variant, .. } => self.variants.get(variant.value()).copied(),
+
+            Pattern::OptionalSome(..) => self.optionals.get(&true).copied(),
+            Pattern::OptionalNone => self.optionals.get(&false).copied(),
+
+            Pattern::ResultOk(..) => self.results.get(&true).copied(),
+            Pattern::ResultError(..) => self.results.get(&false).copied(),
+
+            _ => None,
+        }
+    }
+
+    pub fn add(&mut self, pattern: &Ranged<Pattern>, ty: &SemanticType) {
+        let range = pattern.range();
+
+        match pattern.value() {
+            value if value.is_irrefutable() => {
+                self.everything = Some(range);
+            }
+
+            Pattern::Boolean(b) => {
+                self.booleans.insert(*b, range);
+
+                if self.booleans.len() == 2 {
+                    self.everything = Some(range);
+                }
+            }
+
+            Pattern::IntegerLiteral(..) | Pattern::CharacterLiteral(..) | Pattern::StringLiteral(..) => {
+                self.literals.insert(pattern.value().to_string(), range);
+            }
+
+            Pattern::Range { start, end } => {
+                self.ranges.push((*start.value(), *end.value(), range));
+            }
+
+            Pattern::EnumerationVariant { variant, fields, .. } => {
+                // Only when the fields can be anything, the variant is fully covered.
+                if !fields.iter().all(|field| field.is_irrefutable()) {
+                    return;
+                }
+
+                self.variants.insert(variant.value().clone(), range);
+
+                if let SemanticType::Enumeration(enumeration) = ty {
+                    if enumeration.variants.iter().all(|variant| self.variants.contains_key(variant.name.value())) {
+                        self.everything = Some(range);
+                    }
+                }
+            }
+
+            Pattern::OptionalSome(inner) => {
+                if inner.value().is_irrefutable() {
+                    self.add_optional(true, range);
+                }
+            }
+
+            Pattern::OptionalNone => self.add_optional(false, range),
+
+            Pattern::ResultOk(inner) | Pattern::ResultError(inner) if inner.value().is_irrefutable() => {
+                let is_ok = matches!(pattern.value(), Pattern::ResultOk(..));
+                self.results.insert(is_ok, range);
+
+                if self.results.len() == 2 {
+                    self.everything = Some(range);
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    fn add_optional(&mut self, has_value: bool, range: FileRange) {
+        self.optionals.insert(has_value, range);
+
+        if self.optionals.len() == 2 {
+            self.everything = Some(range);
+        }
+    }
+
+    /// The patterns that should be added to make the `kies` exhaustive, empty
+    /// if every value is already covered.
+    #[must_use]
+    pub fn missing_patterns(&self, ty: &SemanticType) -> Vec<String> {
+        if self.everything.is_some() {
+            return Vec::new();
+        }
+
+        match ty {
+            SemanticType::Builtin(BuiltinType::Bool) => {
+                [true, false].into_iter()
+                    .filter(|b| !self.booleans.contains_key(b))
+                    .map(|b| Pattern::Boolean(b).to_string())
+                    .collect()
+            }
+
+            SemanticType::Enumeration(enumeration) => {
+                enumeration.variants.iter()
+                    .filter(|variant| !self.variants.contains_key(variant.name.value()))
+                    .map(|variant| {
+                        let mut pattern = format!("{}.{}", enumeration.name.value(), variant.name.value());
+
+                        if !variant.fields.is_empty() {
+                            let wildcards = vec!["_"; variant.fields.len()];
+                            pattern += &format!("({})", wildcards.join(", "));
+                        }
+
+                        pattern
+                    })
+                    .collect()
+            }
+
+            SemanticType::Optional(..) => {
+                [(true, "Iets(_)"), (false, "Niets")].into_iter()
+                    .filter(|(has_value, _)| !self.optionals.contains_key(has_value))
+                    .map(|(_, pattern)| pattern.to_string())
+                    .collect()
+            }
+
+            SemanticType::Result { .. } => {
+                [(true, "Goed(_)"), (false, "Fout(_)")].into_iter()
+                    .filter(|(is_ok, _)| !self.results.contains_key(is_ok))
+                    .map(|(_, pattern)| pattern.to_string())
+                    .collect()
+            }
+
+            _ => vec![Pattern::Wildcard.to_string()],
+        }
+    }
+
+    #[must_use]
+    fn find_covering_range(&self, start: i64, end: i64) -> Option<FileRange> {
+        self.ranges.iter()
+            .find(|(range_start, range_end, _)| *range_start <= start && end <= *range_end)
+            .map(|(_, _, range)| *range)
+    }
+}
@@ -9,6 +9,9 @@ use super::{PureValue, SemanticFunction, SemanticType, SemanticUsage};
 pub struct SemanticMethod {
     pub range: FileRange,
     pub function: SemanticFunction,
+
+    /// See [`crate::Method::is_static`].
+    pub is_static: bool,
 }
 
 impl SemanticMethod {
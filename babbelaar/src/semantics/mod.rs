@@ -4,11 +4,13 @@
 mod analysis;
 mod context;
 mod diagnostic;
+mod enumeration;
 mod extension;
 mod function_reference;
 mod function;
 mod interface;
 mod local;
+mod match_coverage;
 mod method;
 mod reference;
 mod related;
@@ -33,11 +35,16 @@ pub use self::{
         SemanticDiagnosticSeverity,
         SemanticDiagnosticsList,
     },
+    enumeration::{
+        SemanticEnumeration,
+        SemanticEnumerationVariant,
+    },
     extension::SemanticExtension,
     function_reference::FunctionReference,
     function::{
         SemanticExternFunction,
         SemanticFunction,
+        SemanticFunctionSignature,
         SemanticParameter,
     },
     interface::SemanticInterface,
@@ -57,6 +64,7 @@ pub use self::{
     },
     structure_or_interface::StructureOrInterface,
     structure::{
+        SemanticConstant,
         SemanticField,
         SemanticStructure,
     },
@@ -16,15 +16,21 @@ pub struct SemanticReference {
 impl SemanticReference {
     pub fn function_name(&self) -> BabString {
         match &self.typ {
-            SemanticType::Array(..) => todo!(),
-            SemanticType::Builtin(..) => todo!(),
-            SemanticType::Custom { .. } => todo!(),
             SemanticType::Function(func) => BabString::clone(&func.name),
             SemanticType::FunctionReference(func) => func.name(),
-            SemanticType::IndexReference(..) => todo!(),
-            SemanticType::Interface { .. } => todo!(),
-            SemanticType::Generic(..) => todo!(),
-            SemanticType::Pointer(..) => todo!(),
+
+            // Other references aren't werkwijzen, so their own name is used.
+            SemanticType::Array(..)
+                | SemanticType::Builtin(..)
+                | SemanticType::Custom { .. }
+                | SemanticType::Enumeration(..)
+                | SemanticType::IndexReference(..)
+                | SemanticType::Interface { .. }
+                | SemanticType::Generic(..)
+                | SemanticType::Optional(..)
+                | SemanticType::Result { .. }
+                | SemanticType::Pointer(..)
+                | SemanticType::Tuple(..) => self.local_name.clone(),
         }
     }
 
@@ -32,13 +38,17 @@ impl SemanticReference {
         match &self.typ {
             SemanticType::Array(..) => None,
             SemanticType::Builtin(builtin) => Some(builtin.documentation().into_bab_string()),
-            SemanticType::Custom { .. } => None,
-            SemanticType::Function(..) => None,
+            SemanticType::Custom { base, .. } => base.documentation.clone(),
+            SemanticType::Enumeration(..) => None,
+            SemanticType::Function(func) => func.documentation.clone(),
             SemanticType::FunctionReference(func) => func.documentation(),
             SemanticType::IndexReference(..) => None,
-            SemanticType::Interface { .. } => None,
+            SemanticType::Interface { base, .. } => base.documentation.clone(),
             SemanticType::Generic(..) => None,
+            SemanticType::Optional(..) => None,
+            SemanticType::Result { .. } => None,
             SemanticType::Pointer(..) => None,
+            SemanticType::Tuple(..) => None,
         }
     }
 
@@ -47,12 +57,16 @@ impl SemanticReference {
             SemanticType::Array(..) => None,
             SemanticType::Builtin(builtin) => Some(builtin.inline_detail()),
             SemanticType::Custom { .. } => None,
+            SemanticType::Enumeration(..) => None,
             SemanticType::Function(..) => None,
             SemanticType::FunctionReference(func) => func.inline_detail(),
             SemanticType::IndexReference(..) => None,
             SemanticType::Interface { .. } => None,
             SemanticType::Generic(..) => None,
+            SemanticType::Optional(..) => None,
+            SemanticType::Result { .. } => None,
             SemanticType::Pointer(..) => None,
+            SemanticType::Tuple(..) => None,
         }
     }
 
@@ -61,12 +75,16 @@ impl SemanticReference {
             SemanticType::Array(ty) => format!("{}[]", ty.name()).into(),
             SemanticType::Builtin(builtin) => builtin.name(),
             SemanticType::Custom { .. } => self.typ.to_string().into(),
+            SemanticType::Enumeration(..) => self.typ.to_string().into(),
             SemanticType::Function(func) => BabString::new(format!("{}($1);$0", func.name.value())),
             SemanticType::FunctionReference(func) => func.lsp_completion(),
             SemanticType::IndexReference(..) => BabString::empty(),
             SemanticType::Interface { .. } => BabString::empty(),
             SemanticType::Generic(..) => BabString::empty(),
+            SemanticType::Optional(..) => self.typ.to_string().into(),
+            SemanticType::Result { .. } => self.typ.to_string().into(),
             SemanticType::Pointer(ty) => format!("{}*", ty.name()).into(),
+            SemanticType::Tuple(..) => self.typ.to_string().into(),
         }
     }
 
@@ -91,7 +109,7 @@ impl SemanticReference {
                                 str += &param.ty.to_string();
                             }
                         }
-                        FunctionReference::Builtin(..) => str += "..",
+                        FunctionReference::Builtin(..) | FunctionReference::Signature(..) => str += "..",
                     }
                 }
 
@@ -119,6 +137,27 @@ impl SemanticReference {
                 format!("structuur {} {{{fields}\n}}", self.typ.to_string())
             }
 
+            SemanticLocalKind::EnumerationReference => {
+                let mut variants = String::new();
+
+                if let SemanticType::Enumeration(typ) = &self.typ {
+                    for variant in &typ.variants {
+                        variants += &format!("\n    {variant},");
+                    }
+                }
+
+                format!("opsomming {} {{{variants}\n}}", self.typ)
+            }
+
+            SemanticLocalKind::EnumerationVariant => {
+                let variant = match &self.typ {
+                    SemanticType::Enumeration(typ) => typ.variant(&self.local_name).map(|x| x.to_string()),
+                    _ => None,
+                };
+
+                format!("{}.{}", self.typ, variant.unwrap_or_else(|| self.local_name.to_string()))
+            }
+
             SemanticLocalKind::Variable => {
                 format!("stel {}: {}", self.local_name, self.typ)
             }
@@ -137,13 +176,17 @@ impl SemanticReference {
             }
 
             SemanticType::FunctionReference(FunctionReference::Builtin(builtin)) => {
-                SemanticType::Builtin(builtin.return_type)
+                builtin.return_type.resolve()
             }
 
             SemanticType::FunctionReference(FunctionReference::Custom(f)) => {
                 SemanticType::clone(&f.return_type)
             }
 
+            SemanticType::FunctionReference(FunctionReference::Signature(signature)) => {
+                SemanticType::clone(&signature.return_type)
+            }
+
             _ => SemanticType::null()
         }
     }
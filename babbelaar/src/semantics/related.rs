@@ -42,6 +42,18 @@ pub enum SemanticRelatedMessage {
     #[error("`{name}` is hier voor het eerst geïnitialiseerd")]
     DuplicateFieldFirstUse { name: BabString },
 
+    #[error("variant `{name}` is hier voor het eerst gedefinieerd")]
+    DuplicateVariantFirstDefinedHere { name: BabString },
+
+    #[error("opsomming `{name}` is hier gedefinieerd")]
+    EnumerationDefinedHere { name: BabString },
+
+    #[error("deze tak dekt de waarden al af")]
+    MatchArmAlreadyCovers,
+
+    #[error("eerste tak geeft een `{ty}`")]
+    FirstMatchArmType { ty: BabString },
+
     #[error("werkwijze `{name}` is hier voor het eerst aangemaakt")]
     DuplicateMethodFirstDefinedHere { name: BabString },
 
@@ -54,9 +66,15 @@ pub enum SemanticRelatedMessage {
     #[error("werkwijze `{name}` is hier gedefinieerd")]
     FunctionDefinedHere { name: BabString },
 
+    #[error("generieke parameter `{name}` is hier gedeclareerd")]
+    GenericTypeDeclaredHere { name: BabString },
+
     #[error("koppelvlak `{name}` is hier gedefinieerd")]
     InterfaceDefinedHere { name: BabString },
 
+    #[error("vaste waarde `{name}` is hier gedefinieerd")]
+    ConstantDefinedHere { name: BabString },
+
     #[error("parameter `{name}` is hier gedeclareerd")]
     ParameterDeclaredHere { name: BabString },
 
@@ -5,13 +5,14 @@ use std::{collections::HashMap, sync::Arc};
 
 use crate::{BabString, Builtin, FileId, FileLocation, FileRange, Ranged};
 
-use super::{FunctionReference, SemanticExtension, SemanticFunction, SemanticGenericType, SemanticInterface, SemanticLocal, SemanticLocalKind, SemanticStructure, SemanticType};
+use super::{FunctionReference, SemanticEnumeration, SemanticExtension, SemanticFunction, SemanticGenericType, SemanticInterface, SemanticLocal, SemanticLocalKind, SemanticStructure, SemanticType};
 
 #[derive(Debug)]
 pub struct SemanticScope {
     pub range: FileRange,
     pub locals: HashMap<BabString, SemanticLocal>,
     pub structures: HashMap<BabString, Arc<SemanticStructure>>,
+    pub enumerations: HashMap<BabString, Arc<SemanticEnumeration>>,
     pub generic_types: HashMap<BabString, SemanticGenericType>,
     pub this: Option<SemanticType>,
     pub return_type: Option<Ranged<SemanticType>>,
@@ -28,6 +29,7 @@ impl SemanticScope {
                 FileLocation::new(FileId::INTERNAL, usize::MAX, usize::MAX, usize::MAX),
             ),
             structures: HashMap::new(),
+            enumerations: HashMap::new(),
             locals: HashMap::default(),
             this: None,
             return_type: None,
@@ -45,6 +47,10 @@ impl SemanticScope {
             ));
         }
 
+        for interface in Builtin::INTERFACES {
+            this.interfaces.insert(BabString::new_static(interface.name), Arc::new(SemanticInterface::from_builtin(interface)));
+        }
+
         this
     }
 
@@ -75,6 +81,9 @@ pub enum SemanticScopeKind {
     Structure,
     Werkwijze,
 
+    /// The body of a `volg` or `zolang` loop.
+    Loop,
+
     Function {
         right_parameter_range: FileRange,
     },
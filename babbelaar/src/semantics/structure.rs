@@ -3,9 +3,19 @@
 
 use std::fmt::Display;
 
-use crate::{AttributeList, BabString, FileRange, Ranged};
+use crate::{AttributeList, BabString, ConstantValue, FileRange, Ranged, Visibility};
 
-use super::{SemanticMethod, SemanticType};
+use super::{SemanticGenericType, SemanticMethod, SemanticType};
+
+/// A `vast` constant declared inside a structure, e.g. `Buffer.GROOTTE`.
+#[derive(Debug, Clone)]
+pub struct SemanticConstant {
+    pub name: Ranged<BabString>,
+    pub ty: SemanticType,
+
+    /// The evaluated value, or [`None`] if the initializer wasn't constant.
+    pub value: Option<ConstantValue>,
+}
 
 #[derive(Debug, Clone)]
 pub struct SemanticField {
@@ -13,23 +23,45 @@ pub struct SemanticField {
     pub name: Ranged<BabString>,
     pub ty: SemanticType,
     pub has_default_value: bool,
+
+    /// See [`crate::Field::visibility`].
+    pub visibility: Option<Ranged<Visibility>>,
+
+    /// See [`crate::Field::documentation`].
+    pub documentation: Option<BabString>,
+}
+
+impl SemanticField {
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility.as_ref().map(|x| *x.value()).unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct SemanticStructure {
     pub attributes: AttributeList,
     pub name: Ranged<BabString>,
-    pub generic_types: Vec<Ranged<BabString>>,
+    pub generic_types: Vec<SemanticGenericType>,
     pub left_curly_range: FileRange,
     pub right_curly_range: FileRange,
+    pub constants: Vec<SemanticConstant>,
     pub fields: Vec<SemanticField>,
     pub methods: Vec<SemanticMethod>,
+
+    /// See [`crate::Structure::documentation`].
+    pub documentation: Option<BabString>,
 }
 
 impl SemanticStructure {
+    #[must_use]
+    pub fn constant(&self, name: &str) -> Option<&SemanticConstant> {
+        self.constants.iter().find(|constant| constant.name.value() == name)
+    }
+
     pub fn index_of_generic_type(&self, name: &BabString) -> Option<usize> {
-        for (idx, generic_name) in self.generic_types.iter().enumerate() {
-            if generic_name.value() == name {
+        for (idx, generic) in self.generic_types.iter().enumerate() {
+            if generic.name == *name {
                 return Some(idx);
             }
         }
@@ -1,17 +1,40 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::{fmt::{Display, Write}, sync::Arc};
+use std::{collections::HashMap, fmt::{Display, Write}, sync::Arc};
 
-use crate::{BabString, BuiltinType, FileRange};
+use crate::{BabString, BuiltinType, FileRange, GenericTypeDeclaration};
 
-use super::{FunctionReference, SemanticFunction, SemanticInterface, SemanticStructure};
+use super::{FunctionReference, SemanticEnumeration, SemanticFunction, SemanticFunctionSignature, SemanticInterface, SemanticStructure};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SemanticGenericType {
     pub index: usize,
     pub name: BabString,
     pub declaration_range: FileRange,
+
+    /// The names of the interfaces this type must implement, e.g. the
+    /// `Vergelijkbaar` in `T: Vergelijkbaar`. These are resolved when used,
+    /// since structures are declared before the interfaces are.
+    pub bounds: Vec<BabString>,
+}
+
+impl SemanticGenericType {
+    /// Creates the generic types for the given declarations, with their
+    /// indices starting at `offset`.
+    #[must_use]
+    pub fn from_declarations(declarations: &[GenericTypeDeclaration], offset: usize) -> Vec<Self> {
+        declarations
+            .iter()
+            .enumerate()
+            .map(|(index, declaration)| Self {
+                index: offset + index,
+                name: declaration.name.value().clone(),
+                declaration_range: declaration.name.range(),
+                bounds: declaration.bounds.iter().map(|bound| bound.name.value().clone()).collect(),
+            })
+            .collect()
+    }
 }
 
 impl Display for SemanticGenericType {
@@ -25,12 +48,26 @@ pub enum SemanticType {
     Array(Box<SemanticType>),
     Builtin(BuiltinType),
     Custom { base: Arc<SemanticStructure>, parameters: Vec<SemanticType> },
+    Enumeration(Arc<SemanticEnumeration>),
     Function(SemanticFunction),
     FunctionReference(FunctionReference),
     Interface { base: Arc<SemanticInterface>, parameters: Vec<SemanticType> },
     Generic(SemanticGenericType),
     IndexReference(Box<SemanticType>),
+
+    /// `Misschien<T>`, a value that may or may not be present. The contained
+    /// type is `null` for a bare `Niets`, which is compatible with any
+    /// `Misschien`.
+    Optional(Box<SemanticType>),
     Pointer(Box<SemanticType>),
+
+    /// `Resultaat<T, F>`, the outcome of an operation that can fail. Like
+    /// with [`Self::Optional`], the side that a bare `Goed` or `Fout` doesn't
+    /// tell is `null`.
+    Result { ok: Box<SemanticType>, error: Box<SemanticType> },
+
+    /// Multiple values grouped together, e.g. `(g32, Slinger)`.
+    Tuple(Vec<SemanticType>),
 }
 
 impl SemanticType {
@@ -49,12 +86,16 @@ impl SemanticType {
             Self::Array(ty) => ty.declaration_range(),
             Self::Builtin(..) => FileRange::default(),
             Self::Custom { base, .. } => base.name.range(),
+            Self::Enumeration(base) => base.name.range(),
             Self::Function(func) => func.name.range(),
             Self::FunctionReference(func) => func.declaration_range(),
             Self::IndexReference(ty) => ty.declaration_range(),
             Self::Interface { base, .. } => base.name.range(),
             Self::Generic(ty) => ty.declaration_range,
+            Self::Optional(ty) => ty.declaration_range(),
             Self::Pointer(ty) => ty.declaration_range(),
+            Self::Result { ok, .. } => ok.declaration_range(),
+            Self::Tuple(..) => FileRange::default(),
         }
     }
 
@@ -63,12 +104,16 @@ impl SemanticType {
             Self::Array(..) => None,
             Self::Builtin(..) => None,
             Self::Custom { .. } => None,
+            Self::Enumeration(..) => None,
             Self::Function(func) => Some(func.parameters.len()),
             Self::FunctionReference(func) => Some(func.parameter_count()),
             Self::IndexReference(..) => None,
             Self::Interface { .. } => None,
             Self::Generic(..) => None,
+            Self::Optional(..) => None,
             Self::Pointer(..) => None,
+            Self::Result { .. } => None,
+            Self::Tuple(..) => None,
         }
     }
 
@@ -81,13 +126,17 @@ impl SemanticType {
             Self::Builtin(BuiltinType::G32) => BabString::new_static("getal"),
             Self::Builtin(builtin) => builtin.name().to_lowercase().into(),
             Self::Custom { base, .. } => base.name.value().to_lowercase().into(),
+            Self::Enumeration(base) => base.name.value().to_lowercase().into(),
             Self::Interface { base, .. } => base.name.value().to_lowercase().into(),
 
             Self::Function(..) => BabString::empty(),
             Self::FunctionReference(..) => BabString::empty(),
             Self::IndexReference(ty) => ty.value_or_field_name_hint(),
             Self::Generic(ty) => ty.name.clone(),
+            Self::Optional(ty) => ty.value_or_field_name_hint(),
             Self::Pointer(..) => BabString::new_static("wijzer"),
+            Self::Result { .. } => BabString::new_static("resultaat"),
+            Self::Tuple(..) => BabString::new_static("tupel"),
         }
     }
 
@@ -96,8 +145,10 @@ impl SemanticType {
     pub fn default_value_hint(&self) -> &str {
         match self {
             Self::Builtin(BuiltinType::Slinger) => "\"\"",
-            Self::Builtin(BuiltinType::G32) => "0",
+            Self::Builtin(ty) if ty.integer_type().is_some() => "0",
+            Self::Builtin(BuiltinType::Kommagetal) => "0,0",
             Self::Builtin(BuiltinType::Bool) => "onwaar",
+            Self::Optional(..) => "Niets",
 
             _ => "",
         }
@@ -109,12 +160,16 @@ impl SemanticType {
             Self::Array(..) => BabString::new_static("opeenvolging-naam"),
             Self::Builtin(builtin) => builtin.name().into(),
             Self::Custom { base, .. } => base.name.value().clone(),
+            Self::Enumeration(base) => base.name.value().clone(),
             Self::Function(func) => func.name.value().clone(),
             Self::FunctionReference(func) => func.name(),
             Self::IndexReference(ty) => ty.name(),
             Self::Interface { base, .. } => base.name.value().clone(),
             Self::Generic(ty) => ty.name.clone(),
+            Self::Optional(..) => BabString::new_static("Misschien"),
             Self::Pointer(..) => BabString::new_static("wijzer-naam"),
+            Self::Result { .. } => BabString::new_static("Resultaat"),
+            Self::Tuple(..) => BabString::new(self.to_string()),
         }
     }
 
@@ -127,6 +182,112 @@ impl SemanticType {
         }
     }
 
+    /// Infers the types of the `generics` of a function, by matching this type
+    /// of a parameter against the type of the `argument` that was passed to
+    /// it. Generics that were already inferred by an earlier argument are
+    /// kept, such that a conflicting argument is reported as incompatible.
+    pub fn infer_generic_types(&self, argument: &SemanticType, generics: &[SemanticGenericType], inferred: &mut HashMap<usize, SemanticType>) {
+        match (self, argument) {
+            (Self::Generic(generic), argument) => {
+                if !argument.is_null() && generics.iter().any(|x| x.index == generic.index) {
+                    inferred.entry(generic.index).or_insert_with(|| argument.clone());
+                }
+            }
+
+            (Self::Array(this), Self::Array(argument))
+                | (Self::Optional(this), Self::Optional(argument))
+                | (Self::Pointer(this), Self::Pointer(argument)) => {
+                this.infer_generic_types(argument, generics, inferred);
+            }
+
+            (Self::Result { ok, error }, Self::Result { ok: argument_ok, error: argument_error }) => {
+                ok.infer_generic_types(argument_ok, generics, inferred);
+                error.infer_generic_types(argument_error, generics, inferred);
+            }
+
+            (Self::Tuple(elements), Self::Tuple(argument_elements)) => {
+                for (this, argument) in elements.iter().zip(argument_elements) {
+                    this.infer_generic_types(argument, generics, inferred);
+                }
+            }
+
+            (Self::Custom { base, parameters }, Self::Custom { base: argument_base, parameters: argument_parameters })
+                    if base.name.value() == argument_base.name.value() => {
+                for (this, argument) in parameters.iter().zip(argument_parameters) {
+                    this.infer_generic_types(argument, generics, inferred);
+                }
+            }
+
+            (Self::Interface { base, parameters }, Self::Interface { base: argument_base, parameters: argument_parameters })
+                    if base.name.value() == argument_base.name.value() => {
+                for (this, argument) in parameters.iter().zip(argument_parameters) {
+                    this.infer_generic_types(argument, generics, inferred);
+                }
+            }
+
+            _ => {
+                if let (Some(this), Some(argument)) = (self.function_signature(), argument.function_signature()) {
+                    for (this, argument) in this.parameters.iter().zip(&argument.parameters) {
+                        this.infer_generic_types(argument, generics, inferred);
+                    }
+
+                    this.return_type.infer_generic_types(&argument.return_type, generics, inferred);
+                }
+            }
+        }
+    }
+
+    /// Replaces the generic types that were inferred by
+    /// [`Self::infer_generic_types`] with their actual types.
+    #[must_use]
+    pub fn substitute_generic_types(self, inferred: &HashMap<usize, SemanticType>) -> Self {
+        if inferred.is_empty() {
+            return self;
+        }
+
+        let substitute_all = |types: Vec<SemanticType>| {
+            types.into_iter().map(|ty| ty.substitute_generic_types(inferred)).collect()
+        };
+
+        match self {
+            Self::Generic(ref generic) => match inferred.get(&generic.index) {
+                Some(ty) => ty.clone(),
+                None => self,
+            },
+
+            Self::Array(ty) => Self::Array(Box::new(ty.substitute_generic_types(inferred))),
+            Self::Optional(ty) => Self::Optional(Box::new(ty.substitute_generic_types(inferred))),
+            Self::Pointer(ty) => Self::Pointer(Box::new(ty.substitute_generic_types(inferred))),
+            Self::IndexReference(ty) => Self::IndexReference(Box::new(ty.substitute_generic_types(inferred))),
+
+            Self::Result { ok, error } => Self::Result {
+                ok: Box::new(ok.substitute_generic_types(inferred)),
+                error: Box::new(error.substitute_generic_types(inferred)),
+            },
+
+            Self::Tuple(elements) => Self::Tuple(substitute_all(elements)),
+
+            Self::Custom { base, parameters } => Self::Custom {
+                base,
+                parameters: substitute_all(parameters),
+            },
+
+            Self::Interface { base, parameters } => Self::Interface {
+                base,
+                parameters: substitute_all(parameters),
+            },
+
+            Self::FunctionReference(FunctionReference::Signature(signature)) => {
+                Self::FunctionReference(FunctionReference::Signature(SemanticFunctionSignature {
+                    parameters: substitute_all(signature.parameters),
+                    return_type: Box::new(signature.return_type.substitute_generic_types(inferred)),
+                }))
+            }
+
+            other => other,
+        }
+    }
+
     pub fn resolve_against(self, ty: &SemanticType) -> Self {
         let generic_index = match self {
             Self::Array(element_type) => {
@@ -135,6 +296,23 @@ impl SemanticType {
                 return Self::Array(Box::new(element_type));
             }
 
+            Self::Optional(contained) => {
+                let contained = *contained;
+                let contained = contained.resolve_against(ty);
+                return Self::Optional(Box::new(contained));
+            }
+
+            Self::Result { ok, error } => {
+                return Self::Result {
+                    ok: Box::new(ok.resolve_against(ty)),
+                    error: Box::new(error.resolve_against(ty)),
+                };
+            }
+
+            Self::Tuple(elements) => {
+                return Self::Tuple(elements.into_iter().map(|element| element.resolve_against(ty)).collect());
+            }
+
             Self::Generic(ref generic) => generic.index,
 
             other => return other,
@@ -161,20 +339,97 @@ impl SemanticType {
     #[must_use]
     pub fn is_primitive_number(&self) -> bool {
         match self {
-            Self::Builtin(BuiltinType::G8) => true,
-            Self::Builtin(BuiltinType::G16) => true,
-            Self::Builtin(BuiltinType::G32) => true,
+            Self::Builtin(ty) => ty.integer_type().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Whether or not values of this type start out with a default value, like
+    /// `0` or `onwaar`, which is needed to fill `nieuw T[n]`. Generic types are
+    /// only known when the werkwijze is called, so they're assumed to have one.
+    #[must_use]
+    pub fn has_default_value(&self) -> bool {
+        match self {
+            Self::Builtin(ty) => *ty != BuiltinType::Reeks,
+            Self::Generic(..) => true,
+            Self::Tuple(elements) => elements.iter().all(|element| element.has_default_value()),
             _ => false,
         }
     }
 
+    /// Whether or not values of this type can be passed to an `@uitheems`
+    /// werkwijze, which receives each argument as a single integer.
+    #[must_use]
+    pub fn can_be_passed_to_extern_function(&self) -> bool {
+        match self {
+            Self::Builtin(ty) => ty.integer_type().is_some() || matches!(ty, BuiltinType::Bool | BuiltinType::Slinger | BuiltinType::Teken),
+            Self::Pointer(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether or not this is a `Misschien`, which has to be unpacked before the
+    /// value inside can be used.
+    #[must_use]
+    pub fn is_optional(&self) -> bool {
+        matches!(self, Self::Optional(..))
+    }
+
+    /// Whether or not this is a `Resultaat`, which must always be handled.
+    #[must_use]
+    pub fn is_result(&self) -> bool {
+        matches!(self, Self::Result { .. })
+    }
+
+    /// The signature of this type, if values of this type can be called.
+    #[must_use]
+    pub fn function_signature(&self) -> Option<SemanticFunctionSignature> {
+        match self {
+            Self::Function(func) => Some(func.signature()),
+            Self::FunctionReference(func) => Some(func.signature()),
+            _ => None,
+        }
+    }
+
+    /// Whether this is the type of an empty array literal `[]`, of which the
+    /// element type is only known from the place it is used.
+    #[must_use]
+    pub fn is_empty_array(&self) -> bool {
+        matches!(self, Self::Array(element) if element.is_null())
+    }
+
     #[must_use]
     pub fn is_compatible_with(&self, other: &SemanticType) -> bool {
         if self == other {
             return true;
         }
 
-        self.is_primitive_number() && other.is_primitive_number()
+        if let (Some(this), Some(other)) = (self.function_signature(), other.function_signature()) {
+            return this == other;
+        }
+
+        if let (Self::Optional(this), Self::Optional(other)) = (self, other) {
+            return this.is_null() || other.is_null() || this.is_compatible_with(other);
+        }
+
+        if let (Self::Result { ok, error }, Self::Result { ok: other_ok, error: other_error }) = (self, other) {
+            let is_compatible = |this: &SemanticType, other: &SemanticType| {
+                this.is_null() || other.is_null() || this.is_compatible_with(other)
+            };
+
+            return is_compatible(ok, other_ok) && is_compatible(error, other_error);
+        }
+
+        if let (Self::Array(this), Self::Array(other)) = (self, other) {
+            return this.is_null() || other.is_null() || this.is_compatible_with(other);
+        }
+
+        if let (Self::Tuple(this), Self::Tuple(other)) = (self, other) {
+            return this.len() == other.len()
+                && this.iter().zip(other).all(|(this, other)| this.is_compatible_with(other));
+        }
+
+        false
     }
 }
 
@@ -205,6 +460,7 @@ impl Display for SemanticType {
 
                 f.write_char('>')
             }
+            Self::Enumeration(base) => base.fmt(f),
             Self::Function(func) => func.fmt(f),
             Self::FunctionReference(func) => func.fmt(f),
             Self::IndexReference(ty) => ty.fmt(f),
@@ -228,10 +484,35 @@ impl Display for SemanticType {
                 f.write_char('>')
             }
             Self::Generic(ty) => ty.fmt(f),
+            Self::Optional(ty) => {
+                f.write_str("Misschien<")?;
+                ty.fmt(f)?;
+                f.write_char('>')
+            }
             Self::Pointer(ty) => {
                 ty.fmt(f)?;
                 f.write_char('*')
             }
+            Self::Result { ok, error } => {
+                f.write_str("Resultaat<")?;
+                ok.fmt(f)?;
+                f.write_str(", ")?;
+                error.fmt(f)?;
+                f.write_char('>')
+            }
+            Self::Tuple(elements) => {
+                f.write_char('(')?;
+
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    element.fmt(f)?;
+                }
+
+                f.write_char(')')
+            }
         }
     }
 }
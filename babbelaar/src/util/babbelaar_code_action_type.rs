@@ -55,6 +55,9 @@ pub enum BabbelaarCodeActionType {
     #[error("Maak werkwijze `{name}` aan")]
     CreateFunction { name: BabString },
 
+    #[error("Voeg ontbrekende {} toe aan `kies`", if *count == 1 { "tak" } else { "takken" })]
+    AddMissingMatchArms { count: usize },
+
     #[error("Vul structuurvelden van `{structure}`")]
     FillStructureFields { structure: String },
 
@@ -105,6 +108,9 @@ pub enum BabbelaarCodeActionType {
     #[error("Maak structuur `{name}` aan")]
     CreateStructure { name: BabString },
 
+    #[error("Maak {kind} `{name}` publiek")]
+    MakePublic { kind: &'static str, name: BabString },
+
     #[error("Maak structuur `{name}` aan in nieuw bestand")]
     CreateStructureInNewFile { name: BabString },
 
@@ -41,11 +41,14 @@ pub use self::{
         Slice,
     },
     value::{
+        Closure,
+        EnumerationId,
         ExtensionId,
         FunctionId,
         InterfaceId,
         MethodId,
         MethodOwnerId,
+        RangeValue,
         StructureId,
         Value,
         ValueType,
@@ -1,7 +1,7 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::{ops::Deref, path::{Path, PathBuf}, sync::Arc};
+use std::{fs::read_dir, io, ops::Deref, path::{Path, PathBuf}, sync::Arc};
 
 use crate::BabString;
 
@@ -16,6 +16,25 @@ pub struct SourceCode {
 }
 
 impl SourceCode {
+    /// Finds all Babbelaar files (`.bab`) inside the given `directory`,
+    /// including those in subdirectories, which are used to group modules.
+    pub fn find_in_directory(directory: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+
+        for entry in read_dir(directory)?.flatten() {
+            let path = entry.path();
+
+            if path.is_dir() {
+                files.extend(Self::find_in_directory(&path)?);
+            } else if path.extension().is_some_and(|extension| extension == "bab") {
+                files.push(path);
+            }
+        }
+
+        files.sort();
+        Ok(files)
+    }
+
     #[must_use]
     #[cfg(test)]
     pub fn new_test(contents: impl Into<BabString>) -> Self {
@@ -1,15 +1,12 @@
 // Copyright (C) 2023 - 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::{borrow::Cow, cell::RefCell, cmp::Ordering, collections::HashMap, fmt::{Display, Write}, hash::{DefaultHasher, Hash, Hasher}, rc::Rc};
+use std::{borrow::Cow, cell::RefCell, cmp::Ordering, collections::HashMap, fmt::{Display, Write}, hash::{DefaultHasher, Hash, Hasher}, rc::Rc, sync::Arc};
 
-use crate::{BabString, BuiltinMethodReference, BuiltinType, Comparison, FunctionStatement, InterfaceStatement, Structure};
+use crate::{BabString, BuiltinInterface, BuiltinMethodReference, BuiltinType, Comparison, FunctionStatement, IntegerType};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Value {
-    /// hehe 5 billion dollar problem
-    Null,
-
     Array {
         ty: ValueType,
         values: Rc<RefCell<Vec<Value>>>,
@@ -21,7 +18,14 @@ pub enum Value {
     },
 
     Bool(bool),
-    Integer(i64),
+
+    /// An integer, stored as an `i64` but always within the range of `ty`.
+    /// See [`IntegerType::widen`] for the actual value.
+    Integer {
+        value: i64,
+        ty: IntegerType,
+    },
+    Float(f64),
     String(String),
     Character(char),
     MethodReference {
@@ -36,11 +40,28 @@ pub enum Value {
         name: String,
         id: FunctionId,
     },
+    /// An anonymous `werkwijze`, with the values it captured.
+    Closure(Rc<Closure>),
     Object {
         structure: StructureId,
         fields: Rc<RefCell<HashMap<String, Value>>>,
         generic_types: HashMap<BabString, ValueType>,
     },
+    /// A variant of an `opsomming`, with the values of the fields it carries.
+    Enumeration {
+        enumeration: EnumerationId,
+        variant: usize,
+        name: BabString,
+        fields: Vec<Value>,
+    },
+    /// A `Misschien`: `Iets(waarde)` if it contains a value, `Niets` otherwise.
+    Optional(Option<Box<Value>>),
+    /// A `Resultaat`: `Goed(waarde)` on success, `Fout(fout)` otherwise.
+    Result(Result<Box<Value>, Box<Value>>),
+    /// A tuple, e.g. `(5, "vijf")`.
+    Tuple(Vec<Value>),
+    /// A `reeks` of integers, e.g. `reeks(10, 0, stap: -2)`.
+    Range(RangeValue),
     Pointer {
         address: usize,
         ty: ValueType,
@@ -48,6 +69,13 @@ pub enum Value {
 }
 
 impl Value {
+    /// The result of a werkwijze that doesn't return anything: the empty
+    /// tuple `()`. A value that can be absent is a `Misschien` instead.
+    #[must_use]
+    pub const fn unit() -> Self {
+        Self::Tuple(Vec::new())
+    }
+
     #[must_use]
     pub fn is_true(&self) -> bool {
         matches!(self, Self::Bool(true))
@@ -55,6 +83,12 @@ impl Value {
 
     #[must_use]
     pub fn compare(&self, other: &Self, comparison: Comparison) -> bool {
+        match comparison {
+            Comparison::Equality => return self.is_equal_to(other),
+            Comparison::Inequality => return !self.is_equal_to(other),
+            _ => (),
+        }
+
         let Some(ordering) = self.partial_cmp(other) else {
             return false;
         };
@@ -69,19 +103,66 @@ impl Value {
         }
     }
 
+    /// Structural equality: arrays, tuples and objects are equal when their
+    /// elements or fields are.
+    #[must_use]
+    pub fn is_equal_to(&self, other: &Self) -> bool {
+        self.is_structurally_equal_to(other, &mut |this, that| this.is_equal_to(that))
+    }
+
+    /// Compares arrays, tuples and objects by comparing their elements or
+    /// fields using `are_equal`, such that an interpreter can use the
+    /// `Gelijkwaardig` implementations of these. Other values are compared
+    /// as usual.
+    #[must_use]
+    pub fn is_structurally_equal_to(&self, other: &Self, are_equal: &mut dyn FnMut(&Value, &Value) -> bool) -> bool {
+        let this = self.actual_value();
+        let that = other.actual_value();
+
+        match (this.as_ref(), that.as_ref()) {
+            (Self::Array { values: this, .. }, Self::Array { values: that, .. }) => {
+                let this = this.borrow();
+                let that = that.borrow();
+                this.len() == that.len() && this.iter().zip(that.iter()).all(|(this, that)| are_equal(this, that))
+            }
+
+            (Self::Tuple(this), Self::Tuple(that)) => {
+                this.len() == that.len() && this.iter().zip(that.iter()).all(|(this, that)| are_equal(this, that))
+            }
+
+            (Self::Object { structure: this_structure, fields: this, .. }, Self::Object { structure: that_structure, fields: that, .. }) => {
+                if this_structure != that_structure {
+                    return false;
+                }
+
+                let this = this.borrow();
+                let that = that.borrow();
+                this.iter().all(|(name, this)| that.get(name).is_some_and(|that| are_equal(this, that)))
+            }
+
+            (this, that) => this.partial_cmp(that) == Some(Ordering::Equal),
+        }
+    }
+
     pub fn typ(&self) -> ValueType {
         match self {
             Self::Array{ ty, .. } => ValueType::Array(Box::new(ty.clone())),
             Self::ArrayElementReference { array, index } => array.borrow()[*index].typ(),
             Self::Bool(..) => BuiltinType::Bool.into(),
-            Self::Integer(..) => BuiltinType::G32.into(),
-            Self::Null => BuiltinType::Null.into(),
+            Self::Integer { ty, .. } => BuiltinType::from(*ty).into(),
+            Self::Float(..) => BuiltinType::Kommagetal.into(),
             Self::String(..) => BuiltinType::Slinger.into(),
             Self::Character(..) => BuiltinType::Teken.into(),
-            Self::MethodReference { .. } => todo!(),
-            Self::MethodIdReference { .. } => todo!(),
-            Self::Function { .. } => todo!(),
+            Self::MethodReference { .. } => ValueType::Function,
+            Self::MethodIdReference { .. } => ValueType::Function,
+            Self::Function { .. } => ValueType::Function,
+            Self::Closure(..) => ValueType::Function,
             Self::Object { structure, generic_types, .. } => ValueType::Structure(*structure, generic_types.clone()),
+            Self::Enumeration { enumeration, .. } => ValueType::Enumeration(*enumeration),
+            Self::Optional(..) => ValueType::Optional,
+            Self::Result(..) => ValueType::Result,
+            Self::Tuple(values) => ValueType::Tuple(values.iter().map(|value| value.typ()).collect()),
+            Self::Range(..) => BuiltinType::Reeks.into(),
             Self::Pointer { ty, .. } => ValueType::Pointer(Box::new(ty.clone())),
         }
     }
@@ -103,10 +184,38 @@ impl PartialOrd for Value {
         let that = other.actual_value();
 
         match (this.as_ref(), that.as_ref()) {
-            (Self::Null, Self::Null) => Some(Ordering::Equal),
             (Self::Bool(this), Self::Bool(that)) => Some(this.cmp(that)),
-            (Self::Integer(this), Self::Integer(that)) => Some(this.cmp(that)),
+            (Self::Character(this), Self::Character(that)) => Some(this.cmp(that)),
+            (Self::Integer { value: this, ty: this_ty }, Self::Integer { value: that, ty: that_ty }) => {
+                Some(this_ty.widen(*this).cmp(&that_ty.widen(*that)))
+            }
+            (Self::Float(this), Self::Float(that)) => this.partial_cmp(that),
             (Self::String(this), Self::String(that)) => Some(this.cmp(that)),
+            (
+                Self::Enumeration { enumeration: this_enumeration, variant: this_variant, fields: this_fields, .. },
+                Self::Enumeration { enumeration: that_enumeration, variant: that_variant, fields: that_fields, .. },
+            ) => {
+                if this_enumeration != that_enumeration {
+                    return None;
+                }
+
+                if this_variant != that_variant {
+                    return Some(this_variant.cmp(that_variant));
+                }
+
+                this_fields.partial_cmp(that_fields)
+            }
+            (Self::Optional(this), Self::Optional(that)) => match (this, that) {
+                (Some(this), Some(that)) => this.partial_cmp(that),
+                (this, that) => Some(this.is_some().cmp(&that.is_some())),
+            },
+            (Self::Result(this), Self::Result(that)) => match (this, that) {
+                (Ok(this), Ok(that)) => this.partial_cmp(that),
+                (Err(this), Err(that)) => this.partial_cmp(that),
+                (this, that) => Some(this.is_ok().cmp(&that.is_ok())),
+            },
+            (Self::Tuple(this), Self::Tuple(that)) => this.partial_cmp(that),
+            (Self::Range(this), Self::Range(that)) if this == that => Some(Ordering::Equal),
             _ => None,
         }
     }
@@ -129,27 +238,163 @@ impl Display for Value {
                 f.write_str("]")
             }
             Self::ArrayElementReference { array, index } => array.borrow()[*index].fmt(f),
-            Self::Null => f.write_str("null"),
             Self::Bool(false) => f.write_str("onwaar"),
             Self::Bool(true) => f.write_str("waar"),
-            Self::Integer(i) => i.fmt(f),
+            Self::Integer { value, ty } => ty.widen(*value).fmt(f),
+            Self::Float(float) => f.write_str(&format!("{float:?}").replace('.', ",")),
             Self::String(str) => f.write_str(str),
             Self::Character(c) => f.write_char(*c),
             Self::MethodReference { lhs, method } => f.write_fmt(format_args!("{lhs}.{}()", method.name())),
             Self::MethodIdReference { .. } => f.write_str("werkwijze"),
             Self::Function { name, .. } => f.write_fmt(format_args!("werkwijze {name}() {{ .. }}")),
-            Self::Object { .. } => f.write_str("te-doen(object-waarde-formatteren)"),
+            Self::Closure(..) => f.write_str("werkwijze() { .. }"),
+            // The interpreter knows the name and the order of the fields of
+            // the structure, so this is only a fallback.
+            Self::Object { fields, .. } => {
+                let fields = fields.borrow();
+                let mut names: Vec<&String> = fields.keys().collect();
+                names.sort();
+
+                f.write_str("{ ")?;
+
+                for (idx, name) in names.into_iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    f.write_fmt(format_args!("{name}: {}", fields[name]))?;
+                }
+
+                f.write_str(" }")
+            }
+            Self::Enumeration { name, fields, .. } => {
+                f.write_str(name)?;
+
+                if fields.is_empty() {
+                    return Ok(());
+                }
+
+                f.write_char('(')?;
+
+                for (idx, field) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    field.fmt(f)?;
+                }
+
+                f.write_char(')')
+            }
+            Self::Optional(Some(value)) => f.write_fmt(format_args!("Iets({value})")),
+            Self::Optional(None) => f.write_str("Niets"),
+            Self::Result(Ok(value)) => f.write_fmt(format_args!("Goed({value})")),
+            Self::Result(Err(error)) => f.write_fmt(format_args!("Fout({error})")),
+            Self::Tuple(values) => {
+                f.write_char('(')?;
+
+                for (idx, value) in values.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    value.fmt(f)?;
+                }
+
+                f.write_char(')')
+            }
             Self::Pointer { address, .. } => f.write_fmt(format_args!("{address:p}")),
+            Self::Range(range) => range.fmt(f),
         }
     }
 }
 
+/// The value of a `reeks`, which counts from `start` up to `end` (inclusive
+/// if `is_inclusive` is set), in steps of `step`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeValue {
+    pub start: i64,
+    pub end: i64,
+    pub step: i64,
+    pub is_inclusive: bool,
+}
+
+impl RangeValue {
+    /// Whether `value` is one of the numbers the range goes through.
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        if self.step == 0 || !self.is_before_end(value) {
+            return false;
+        }
+
+        let distance = value - self.start;
+        distance.signum() != -self.step.signum() && distance % self.step == 0
+    }
+
+    /// Whether `value` is still before the end of the range, in the direction
+    /// of the step.
+    #[must_use]
+    pub fn is_before_end(&self, value: i64) -> bool {
+        match (self.step.is_negative(), self.is_inclusive) {
+            (false, false) => value < self.end,
+            (false, true) => value <= self.end,
+            (true, false) => value > self.end,
+            (true, true) => value >= self.end,
+        }
+    }
+
+    /// All numbers of the range, in order. Note that this never ends when the
+    /// step is `0`.
+    pub fn values(self) -> impl Iterator<Item = i64> {
+        std::iter::successors(Some(self.start), move |value| value.checked_add(self.step))
+            .take_while(move |value| self.is_before_end(*value))
+    }
+}
+
+impl Display for RangeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("reeks({}, {}", self.start, self.end))?;
+
+        if self.step != 1 {
+            f.write_fmt(format_args!(", stap: {}", self.step))?;
+        }
+
+        if self.is_inclusive {
+            f.write_str(", totEnMet: waar")?;
+        }
+
+        f.write_char(')')
+    }
+}
+
+/// An anonymous `werkwijze`, together with the variables of the scope it was
+/// created in. These are captured by value, so assigning to a captured
+/// variable inside the closure doesn't change the variable outside of it.
+#[derive(Debug)]
+pub struct Closure {
+    pub function: Arc<FunctionStatement>,
+    pub captures: HashMap<BabString, Rc<RefCell<Value>>>,
+    pub this: Option<Value>,
+    pub module: Option<BabString>,
+}
+
+impl PartialEq for Closure {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValueType {
     Array(Box<ValueType>),
     Builtin(BuiltinType),
     Pointer(Box<ValueType>),
     Structure(StructureId, HashMap<BabString, ValueType>),
+    Enumeration(EnumerationId),
+    Function,
+    Optional,
+    Result,
+    Tuple(Vec<ValueType>),
 }
 
 impl From<BuiltinType> for ValueType {
@@ -164,6 +409,27 @@ impl From<&BuiltinType> for ValueType {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumerationId {
+    pub namespace: usize,
+    pub id: usize,
+}
+
+impl EnumerationId {
+    /// The `qualified_name` includes the module the declaration is part of,
+    /// such that equally named declarations in different modules don't clash.
+    #[must_use]
+    pub fn new(qualified_name: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        "Enumeration-".hash(&mut hasher);
+        qualified_name.hash(&mut hasher);
+        Self {
+            namespace: 0,
+            id: hasher.finish() as usize,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ExtensionId {
     pub namespace: usize,
@@ -176,11 +442,14 @@ pub struct FunctionId {
     pub id: usize,
 }
 
-impl From<&FunctionStatement> for FunctionId {
-    fn from(value: &FunctionStatement) -> Self {
+impl FunctionId {
+    /// The `qualified_name` includes the module the declaration is part of,
+    /// such that equally named declarations in different modules don't clash.
+    #[must_use]
+    pub fn new(qualified_name: &str) -> Self {
         let mut hasher = DefaultHasher::new();
         "Function-".hash(&mut hasher);
-        value.name.value().hash(&mut hasher);
+        qualified_name.hash(&mut hasher);
         Self {
             namespace: 0,
             id: hasher.finish() as usize,
@@ -194,11 +463,27 @@ pub struct InterfaceId {
     pub id: usize,
 }
 
-impl From<&InterfaceStatement> for InterfaceId {
-    fn from(value: &InterfaceStatement) -> Self {
+impl InterfaceId {
+    /// The `qualified_name` includes the module the declaration is part of,
+    /// such that equally named declarations in different modules don't clash.
+    #[must_use]
+    pub fn new(qualified_name: &str) -> Self {
         let mut hasher = DefaultHasher::new();
         "Interface-".hash(&mut hasher);
-        value.name.value().hash(&mut hasher);
+        qualified_name.hash(&mut hasher);
+        Self {
+            namespace: 1,
+            id: hasher.finish() as usize,
+        }
+    }
+
+    /// The identifier of one of the [`crate::Builtin::INTERFACES`], which
+    /// can't clash with a user-defined interface of the same name.
+    #[must_use]
+    pub fn for_builtin(interface: &BuiltinInterface) -> Self {
+        let mut hasher = DefaultHasher::new();
+        "BuiltinInterface-".hash(&mut hasher);
+        interface.name.hash(&mut hasher);
         Self {
             namespace: 1,
             id: hasher.finish() as usize,
@@ -212,11 +497,14 @@ pub struct StructureId {
     pub id: usize,
 }
 
-impl From<&Structure> for StructureId {
-    fn from(value: &Structure) -> Self {
+impl StructureId {
+    /// The `qualified_name` includes the module the declaration is part of,
+    /// such that equally named declarations in different modules don't clash.
+    #[must_use]
+    pub fn new(qualified_name: &str) -> Self {
         let mut hasher = DefaultHasher::new();
         "Structure-".hash(&mut hasher);
-        value.name.value().hash(&mut hasher);
+        qualified_name.hash(&mut hasher);
         Self {
             namespace: 0,
             id: hasher.finish() as usize,
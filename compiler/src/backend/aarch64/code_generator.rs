@@ -5,9 +5,9 @@ use std::{collections::HashMap, mem::take};
 
 use babbelaar::BabString;
 
-use crate::{CodeGenerator, CompiledFunction, Function, FunctionLink, FunctionLinkMethod, Instruction, Label, MathOperation, Operand, Register, RegisterAllocator};
+use crate::{CodeGenerator, CompiledFunction, FloatingPointOperation, Function, FunctionLink, FunctionLinkMethod, Instruction, JumpCondition, Label, MathOperation, Operand, Register, RegisterAllocator};
 
-use super::{AArch64FunctionCharacteristics, ArmBranchLocation, ArmConditionCode, ArmInstruction, ArmRegister, ArmShift2, ArmSignedAddressingMode, ArmUnsignedAddressingMode};
+use super::{AArch64FunctionCharacteristics, ArmBranchLocation, ArmConditionCode, ArmFloatRegister, ArmInstruction, ArmRegister, ArmShift2, ArmSignedAddressingMode, ArmUnsignedAddressingMode};
 
 const SPACE_NEEDED_FOR_FP_AND_LR: usize = 2 * 8;
 
@@ -21,6 +21,11 @@ pub struct AArch64CodeGenerator {
     stack_size: usize,
     space_used_on_stack: usize,
     link_locations: Vec<FunctionLink>,
+
+    /// `fcmp` sets the N flag for "less than" but also sets the V flag for
+    /// unordered operands, so the jumps following it use other condition codes.
+    last_comparison_was_floating_point: bool,
+    last_comparison_was_unsigned: bool,
 }
 
 impl AArch64CodeGenerator {
@@ -36,6 +41,8 @@ impl AArch64CodeGenerator {
             stack_size: 0,
             space_used_on_stack: 0,
             link_locations: Vec::new(),
+            last_comparison_was_floating_point: false,
+            last_comparison_was_unsigned: false,
         };
 
         this.add_prologue(function.instructions());
@@ -67,10 +74,23 @@ impl AArch64CodeGenerator {
 
     fn add_instruction(&mut self, instruction: &Instruction) {
         match instruction {
-            Instruction::Compare { lhs, rhs } => {
+            Instruction::Compare { lhs, rhs, unsigned } => {
+                self.last_comparison_was_floating_point = false;
+                self.last_comparison_was_unsigned = *unsigned;
                 self.add_instruction_cmp(lhs, rhs);
             }
 
+            Instruction::CompareFloatingPoint { lhs, rhs } => {
+                self.last_comparison_was_floating_point = true;
+
+                let lhs = self.allocate_register(lhs);
+                let rhs = self.allocate_register(rhs);
+
+                self.instructions.push(ArmInstruction::FmovFromGeneral { dst: ArmFloatRegister::D0, src: lhs });
+                self.instructions.push(ArmInstruction::FmovFromGeneral { dst: ArmFloatRegister::D1, src: rhs });
+                self.instructions.push(ArmInstruction::Fcmp { lhs: ArmFloatRegister::D0, rhs: ArmFloatRegister::D1 });
+            }
+
             Instruction::Increment { register } => {
                 let dst = self.allocate_register(register);
                 let src = dst;
@@ -80,8 +100,8 @@ impl AArch64CodeGenerator {
             }
 
             Instruction::LoadImmediate { immediate, destination_reg } => {
-                let arm_register = self.allocate_register(destination_reg);
-                self.instructions.push(ArmInstruction::MovZ { register: arm_register, imm16: immediate.as_i64() as _ });
+                let register = self.allocate_register(destination_reg);
+                self.add_instruction_load_immediate(register, immediate.as_i64());
             }
 
             Instruction::Move { source, destination } => {
@@ -133,7 +153,15 @@ impl AArch64CodeGenerator {
             }
 
             Instruction::JumpConditional { condition, location } => {
-                let cond = ArmConditionCode::from(*condition);
+                let cond = match (self.last_comparison_was_floating_point, condition) {
+                    (true, JumpCondition::Less) => ArmConditionCode::MI,
+                    (true, JumpCondition::LessOrEqual) => ArmConditionCode::LS,
+                    (false, JumpCondition::Greater) if self.last_comparison_was_unsigned => ArmConditionCode::HI,
+                    (false, JumpCondition::GreaterOrEqual) if self.last_comparison_was_unsigned => ArmConditionCode::CS,
+                    (false, JumpCondition::Less) if self.last_comparison_was_unsigned => ArmConditionCode::CC,
+                    (false, JumpCondition::LessOrEqual) if self.last_comparison_was_unsigned => ArmConditionCode::LS,
+                    _ => ArmConditionCode::from(*condition),
+                };
                 let location = ArmBranchLocation::Label(*location);
                 self.instructions.push(ArmInstruction::BCond { cond, location });
             }
@@ -160,9 +188,71 @@ impl AArch64CodeGenerator {
                 match operation {
                     MathOperation::Add => self.add_instruction_add(dst, lhs, rhs),
                     MathOperation::Subtract => self.add_instruction_sub(dst, lhs, rhs),
+                    MathOperation::Multiply => {
+                        let lhs = self.operand_to_register(lhs, ArmRegister::X16);
+                        let rhs = self.operand_to_register(rhs, ArmRegister::X17);
+                        self.instructions.push(ArmInstruction::Mul { dst, lhs, rhs });
+                    }
+                    MathOperation::Divide | MathOperation::DivideUnsigned => {
+                        let lhs = self.operand_to_register(lhs, ArmRegister::X16);
+                        let rhs = self.operand_to_register(rhs, ArmRegister::X17);
+                        self.instructions.push(match operation {
+                            MathOperation::Divide => ArmInstruction::Sdiv { dst, lhs, rhs },
+                            _ => ArmInstruction::Udiv { dst, lhs, rhs },
+                        });
+                    }
+                    MathOperation::Modulo | MathOperation::ModuloUnsigned => {
+                        let lhs = self.operand_to_register(lhs, ArmRegister::X16);
+                        let rhs = self.operand_to_register(rhs, ArmRegister::X17);
+
+                        // There is no remainder instruction, so compute it as
+                        // `lhs - (lhs / rhs) * rhs`. The quotient goes into a
+                        // scratch register, since `dst` may be `lhs` or `rhs`.
+                        let quotient = ArmRegister::X16;
+                        self.instructions.push(match operation {
+                            MathOperation::Modulo => ArmInstruction::Sdiv { dst: quotient, lhs, rhs },
+                            _ => ArmInstruction::Udiv { dst: quotient, lhs, rhs },
+                        });
+                        self.instructions.push(ArmInstruction::Msub { dst, lhs: quotient, rhs, minuend: lhs });
+                    }
                 }
             }
 
+            Instruction::FloatingPointMath { operation, destination, lhs, rhs } => {
+                let destination = self.allocate_register(destination);
+                let lhs = self.allocate_register(lhs);
+                let rhs = self.allocate_register(rhs);
+
+                let dst = ArmFloatRegister::D0;
+                let (lhs_float, rhs_float) = (ArmFloatRegister::D0, ArmFloatRegister::D1);
+
+                self.instructions.push(ArmInstruction::FmovFromGeneral { dst: lhs_float, src: lhs });
+                self.instructions.push(ArmInstruction::FmovFromGeneral { dst: rhs_float, src: rhs });
+                self.instructions.push(match operation {
+                    FloatingPointOperation::Add => ArmInstruction::Fadd { dst, lhs: lhs_float, rhs: rhs_float },
+                    FloatingPointOperation::Subtract => ArmInstruction::Fsub { dst, lhs: lhs_float, rhs: rhs_float },
+                    FloatingPointOperation::Multiply => ArmInstruction::Fmul { dst, lhs: lhs_float, rhs: rhs_float },
+                    FloatingPointOperation::Divide => ArmInstruction::Fdiv { dst, lhs: lhs_float, rhs: rhs_float },
+                });
+                self.instructions.push(ArmInstruction::FmovToGeneral { dst: destination, src: dst });
+            }
+
+            Instruction::ConvertIntegerToFloatingPoint { destination, source } => {
+                let dst = self.allocate_register(destination);
+                let src = self.allocate_register(source);
+
+                self.instructions.push(ArmInstruction::Scvtf { dst: ArmFloatRegister::D0, src });
+                self.instructions.push(ArmInstruction::FmovToGeneral { dst, src: ArmFloatRegister::D0 });
+            }
+
+            Instruction::ConvertFloatingPointToInteger { destination, source } => {
+                let dst = self.allocate_register(destination);
+                let src = self.allocate_register(source);
+
+                self.instructions.push(ArmInstruction::FmovFromGeneral { dst: ArmFloatRegister::D0, src });
+                self.instructions.push(ArmInstruction::Fcvtzs { dst, src: ArmFloatRegister::D0 });
+            }
+
             Instruction::StackAlloc { dst, size } => {
                 let dst = self.allocate_register(dst);
 
@@ -178,12 +268,6 @@ impl AArch64CodeGenerator {
             }
 
             Instruction::LoadPtr { destination, base_ptr, offset, typ } => {
-                let is_64_bit = match typ.bytes() {
-                    4 => false,
-                    8 => true,
-                    _ => todo!("We ondersteunen alleen 4 en 8 byte Laad ARM-instructies")
-                };
-
                 let Operand::Immediate(offset) = offset else {
                     todo!("ondersteun register offset {offset}")
                 };
@@ -191,6 +275,32 @@ impl AArch64CodeGenerator {
                 let dst = self.allocate_register(destination);
                 let base_ptr = self.allocate_register(base_ptr);
 
+                let is_64_bit = match typ.bytes() {
+                    1 => {
+                        self.instructions.push(ArmInstruction::LdrbImmediate {
+                            signed: typ.is_signed(),
+                            dst,
+                            base_ptr,
+                            offset: offset.as_i16() as u16,
+                        });
+                        return;
+                    }
+
+                    2 => {
+                        self.instructions.push(ArmInstruction::LdrhImmediate {
+                            signed: typ.is_signed(),
+                            dst,
+                            base_ptr,
+                            offset: offset.as_i16() as u16,
+                        });
+                        return;
+                    }
+
+                    4 => false,
+                    8 => true,
+                    _ => todo!("We ondersteunen alleen 1, 2, 4 en 8 byte Laad ARM-instructies")
+                };
+
                 self.instructions.push(ArmInstruction::LdrImmediate {
                     is_64_bit,
                     mode: ArmUnsignedAddressingMode::UnsignedOffset,
@@ -201,12 +311,6 @@ impl AArch64CodeGenerator {
             }
 
             Instruction::StorePtr { base_ptr, offset, value, typ } => {
-                let is_64_bit = match typ.bytes() {
-                    4 => false,
-                    8 => true,
-                    _ => todo!("We ondersteunen alleen 4 en 8 byte Laad ARM-instructies")
-                };
-
                 let Operand::Immediate(offset) = offset else {
                     todo!("ondersteun register offset {offset}")
                 };
@@ -215,6 +319,22 @@ impl AArch64CodeGenerator {
 
                 let src = self.allocate_register(value);
 
+                let is_64_bit = match typ.bytes() {
+                    1 => {
+                        self.instructions.push(ArmInstruction::StrbImmediate { src, base_ptr, offset: offset.as_i16() as u16 });
+                        return;
+                    }
+
+                    2 => {
+                        self.instructions.push(ArmInstruction::StrhImmediate { src, base_ptr, offset: offset.as_i16() as u16 });
+                        return;
+                    }
+
+                    4 => false,
+                    8 => true,
+                    _ => todo!("We ondersteunen alleen 1, 2, 4 en 8 byte Sla-op ARM-instructies")
+                };
+
                 self.instructions.push(ArmInstruction::StrImmediate {
                     is_64_bit,
                     mode: ArmUnsignedAddressingMode::UnsignedOffset,
@@ -264,6 +384,31 @@ impl AArch64CodeGenerator {
         }
     }
 
+    fn add_instruction_load_immediate(&mut self, register: ArmRegister, value: i64) {
+        let value = value as u64;
+
+        self.instructions.push(ArmInstruction::MovZ { register, imm16: value as u16 });
+
+        for shift in [16, 32, 48] {
+            let imm16 = (value >> shift) as u16;
+            if imm16 != 0 {
+                self.instructions.push(ArmInstruction::MovK { register, imm16, shift });
+            }
+        }
+    }
+
+    /// Returns the register of `operand`, loading it into `scratch` first if
+    /// it is an immediate.
+    fn operand_to_register(&mut self, operand: &Operand, scratch: ArmRegister) -> ArmRegister {
+        match operand {
+            Operand::Immediate(immediate) => {
+                self.add_instruction_load_immediate(scratch, immediate.as_i64());
+                scratch
+            }
+            Operand::Register(register) => self.allocate_register(register),
+        }
+    }
+
     fn add_instruction_cmp(&mut self, lhs: &Register, rhs: &Operand) {
         match rhs {
             Operand::Immediate(immediate) => {
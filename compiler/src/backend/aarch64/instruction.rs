@@ -10,6 +10,7 @@ use crate::Label;
 use super::{
     ArmBranchLocation,
     ArmConditionCode,
+    ArmFloatRegister,
     ArmRegister, ArmSignedAddressingMode, ArmUnsignedAddressingMode,
 };
 
@@ -56,6 +57,58 @@ pub enum ArmInstruction {
         rhs: ArmRegister,
     },
 
+    /// Floating-point add (scalar, double-precision)
+    Fadd {
+        dst: ArmFloatRegister,
+        lhs: ArmFloatRegister,
+        rhs: ArmFloatRegister,
+    },
+
+    /// Floating-point compare (scalar, double-precision), sets the NZCV flags
+    Fcmp {
+        lhs: ArmFloatRegister,
+        rhs: ArmFloatRegister,
+    },
+
+    /// Floating-point convert to signed integer, rounding toward zero
+    Fcvtzs {
+        dst: ArmRegister,
+        src: ArmFloatRegister,
+    },
+
+    /// Floating-point divide (scalar, double-precision)
+    Fdiv {
+        dst: ArmFloatRegister,
+        lhs: ArmFloatRegister,
+        rhs: ArmFloatRegister,
+    },
+
+    /// Floating-point move the bits of a general-purpose register
+    FmovFromGeneral {
+        dst: ArmFloatRegister,
+        src: ArmRegister,
+    },
+
+    /// Floating-point move the bits to a general-purpose register
+    FmovToGeneral {
+        dst: ArmRegister,
+        src: ArmFloatRegister,
+    },
+
+    /// Floating-point multiply (scalar, double-precision)
+    Fmul {
+        dst: ArmFloatRegister,
+        lhs: ArmFloatRegister,
+        rhs: ArmFloatRegister,
+    },
+
+    /// Floating-point subtract (scalar, double-precision)
+    Fsub {
+        dst: ArmFloatRegister,
+        lhs: ArmFloatRegister,
+        rhs: ArmFloatRegister,
+    },
+
     /// Load pair
     Ldp {
         is_64_bit: bool,
@@ -75,15 +128,64 @@ pub enum ArmInstruction {
         offset: i16,
     },
 
+    /// Load register byte (unsigned offset). When `signed` is set, this is
+    /// `ldrsb`, which sign-extends the byte into the 64-bit register.
+    LdrbImmediate {
+        signed: bool,
+        dst: ArmRegister,
+        base_ptr: ArmRegister,
+        offset: u16,
+    },
+
+    /// Load register halfword (unsigned offset). When `signed` is set, this
+    /// is `ldrsh`, which sign-extends the halfword into the 64-bit register.
+    LdrhImmediate {
+        signed: bool,
+        dst: ArmRegister,
+        base_ptr: ArmRegister,
+        offset: u16,
+    },
+
     #[allow(unused)]
     MovRegister32 { dst: ArmRegister, src: ArmRegister },
 
     MovRegister64 { dst: ArmRegister, src: ArmRegister },
 
+    /// Move wide with keep, `shift` is the amount of bits to shift `imm16` to
+    /// the left (0, 16, 32 or 48).
+    MovK { register: ArmRegister, imm16: u16, shift: u8 },
+
     MovZ { register: ArmRegister, imm16: u16 },
 
+    /// Multiply-subtract: `dst = minuend - lhs * rhs`
+    Msub {
+        dst: ArmRegister,
+        lhs: ArmRegister,
+        rhs: ArmRegister,
+        minuend: ArmRegister,
+    },
+
+    Mul {
+        dst: ArmRegister,
+        lhs: ArmRegister,
+        rhs: ArmRegister,
+    },
+
     Ret,
 
+    /// Signed integer convert to floating-point
+    Scvtf {
+        dst: ArmFloatRegister,
+        src: ArmRegister,
+    },
+
+    /// Signed divide, rounding towards zero
+    Sdiv {
+        dst: ArmRegister,
+        lhs: ArmRegister,
+        rhs: ArmRegister,
+    },
+
     /// Store Pair of Registers calculates an address from a base register
     /// value and an immediate offset, and stores two 32-bit words or two
     /// 64-bit double words to the calculated address, from two registers.
@@ -104,6 +206,20 @@ pub enum ArmInstruction {
         offset: i16,
     },
 
+    /// Store register byte (unsigned offset)
+    StrbImmediate {
+        src: ArmRegister,
+        base_ptr: ArmRegister,
+        offset: u16,
+    },
+
+    /// Store register halfword (unsigned offset)
+    StrhImmediate {
+        src: ArmRegister,
+        base_ptr: ArmRegister,
+        offset: u16,
+    },
+
     SubImmediate {
         dst: ArmRegister,
         lhs: ArmRegister,
@@ -118,6 +234,13 @@ pub enum ArmInstruction {
         shift: u8,
         shift_mode: ArmShift2,
     },
+
+    /// Unsigned divide
+    Udiv {
+        dst: ArmRegister,
+        lhs: ArmRegister,
+        rhs: ArmRegister,
+    },
 }
 
 impl ArmInstruction {
@@ -218,6 +341,50 @@ impl ArmInstruction {
                 instruction
             }
 
+            Self::Fadd { dst, lhs, rhs } => {
+                encode_floating_point_data_processing(0x1E602800, dst, lhs, rhs)
+            }
+
+            Self::Fcmp { lhs, rhs } => {
+                let mut instruction = 0x1E602000;
+                instruction |= (rhs.number as u32) << 16;
+                instruction |= (lhs.number as u32) << 5;
+                instruction
+            }
+
+            Self::Fcvtzs { dst, src } => {
+                let mut instruction = 0x9E780000;
+                instruction |= (src.number as u32) << 5;
+                instruction |= dst.number as u32;
+                instruction
+            }
+
+            Self::Fdiv { dst, lhs, rhs } => {
+                encode_floating_point_data_processing(0x1E601800, dst, lhs, rhs)
+            }
+
+            Self::FmovFromGeneral { dst, src } => {
+                let mut instruction = 0x9E670000;
+                instruction |= (src.number as u32) << 5;
+                instruction |= dst.number as u32;
+                instruction
+            }
+
+            Self::FmovToGeneral { dst, src } => {
+                let mut instruction = 0x9E660000;
+                instruction |= (src.number as u32) << 5;
+                instruction |= dst.number as u32;
+                instruction
+            }
+
+            Self::Fmul { dst, lhs, rhs } => {
+                encode_floating_point_data_processing(0x1E600800, dst, lhs, rhs)
+            }
+
+            Self::Fsub { dst, lhs, rhs } => {
+                encode_floating_point_data_processing(0x1E603800, dst, lhs, rhs)
+            }
+
             Self::Ldp { mode, is_64_bit, src, mut offset, first, second } => {
                 let mut instruction = match mode {
                     ArmSignedAddressingMode::PostIndex    => 0x28C00000,
@@ -308,6 +475,17 @@ impl ArmInstruction {
                 instruction
             }
 
+            Self::LdrbImmediate { signed, dst, base_ptr, offset } => {
+                let instruction = if signed { 0x39800000 } else { 0x39400000 };
+                encode_sub_word_load_store(instruction, dst, base_ptr, offset as u32)
+            }
+
+            Self::LdrhImmediate { signed, dst, base_ptr, offset } => {
+                debug_assert!(offset % 2 == 0);
+                let instruction = if signed { 0x79800000 } else { 0x79400000 };
+                encode_sub_word_load_store(instruction, dst, base_ptr, offset as u32 / 2)
+            }
+
             Self::MovRegister32 { dst, src } => {
                 let mut instruction = 0x2A0003E0;
                 instruction |= (src.number as u32) << 16;
@@ -322,6 +500,16 @@ impl ArmInstruction {
                 instruction
             }
 
+            Self::MovK { register, imm16, shift } => {
+                debug_assert!(shift % 16 == 0 && shift <= 48);
+
+                let mut instruction = 0xF2800000;
+                instruction |= register.number as u32;
+                instruction |= (imm16 as u32) << 5;
+                instruction |= ((shift / 16) as u32) << 21;
+                instruction
+            }
+
             Self::MovZ { register, imm16 } => {
                 let mut instruction = 0xD2800000;
                 instruction |= register.number as u32;
@@ -329,6 +517,24 @@ impl ArmInstruction {
                 instruction
             }
 
+            Self::Msub { dst, lhs, rhs, minuend } => {
+                let mut instruction = 0x9B008000;
+                instruction |= dst.number as u32;
+                instruction |= (lhs.number as u32) << 5;
+                instruction |= (minuend.number as u32) << 10;
+                instruction |= (rhs.number as u32) << 16;
+                instruction
+            }
+
+            Self::Mul { dst, lhs, rhs } => {
+                // MADD with the zero register as the addend
+                let mut instruction = 0x9B007C00;
+                instruction |= dst.number as u32;
+                instruction |= (lhs.number as u32) << 5;
+                instruction |= (rhs.number as u32) << 16;
+                instruction
+            }
+
             Self::Ret => {
                 let rn = ArmRegister::X30;
                 let mut instruction = 0xD65F0000;
@@ -336,6 +542,21 @@ impl ArmInstruction {
                 instruction
             }
 
+            Self::Sdiv { dst, lhs, rhs } => {
+                let mut instruction = 0x9AC00C00;
+                instruction |= dst.number as u32;
+                instruction |= (lhs.number as u32) << 5;
+                instruction |= (rhs.number as u32) << 16;
+                instruction
+            }
+
+            Self::Scvtf { dst, src } => {
+                let mut instruction = 0x9E620000;
+                instruction |= (src.number as u32) << 5;
+                instruction |= dst.number as u32;
+                instruction
+            }
+
             Self::Stp { mode, is_64_bit, dst, mut offset, first, second } => {
                 let mut instruction = match mode {
                     ArmSignedAddressingMode::PostIndex    => 0x28800000,
@@ -426,6 +647,15 @@ impl ArmInstruction {
                 instruction
             }
 
+            Self::StrbImmediate { src, base_ptr, offset } => {
+                encode_sub_word_load_store(0x39000000, src, base_ptr, offset as u32)
+            }
+
+            Self::StrhImmediate { src, base_ptr, offset } => {
+                debug_assert!(offset % 2 == 0);
+                encode_sub_word_load_store(0x79000000, src, base_ptr, offset as u32 / 2)
+            }
+
             Self::SubImmediate { dst, lhs, rhs_imm12 } => {
                 debug_assert!(rhs_imm12 < (1 << 12));
 
@@ -456,6 +686,14 @@ impl ArmInstruction {
 
                 instruction
             }
+
+            Self::Udiv { dst, lhs, rhs } => {
+                let mut instruction = 0x9AC00800;
+                instruction |= dst.number as u32;
+                instruction |= (lhs.number as u32) << 5;
+                instruction |= (rhs.number as u32) << 16;
+                instruction
+            }
         }
     }
 }
@@ -502,6 +740,38 @@ impl Display for ArmInstruction {
                 f.write_fmt(format_args!("cmp {lhs}, {rhs}"))
             }
 
+            Self::Fadd { dst, lhs, rhs } => {
+                f.write_fmt(format_args!("fadd {dst}, {lhs}, {rhs}"))
+            }
+
+            Self::Fcmp { lhs, rhs } => {
+                f.write_fmt(format_args!("fcmp {lhs}, {rhs}"))
+            }
+
+            Self::Fcvtzs { dst, src } => {
+                f.write_fmt(format_args!("fcvtzs {dst}, {src}"))
+            }
+
+            Self::Fdiv { dst, lhs, rhs } => {
+                f.write_fmt(format_args!("fdiv {dst}, {lhs}, {rhs}"))
+            }
+
+            Self::FmovFromGeneral { dst, src } => {
+                f.write_fmt(format_args!("fmov {dst}, {src}"))
+            }
+
+            Self::FmovToGeneral { dst, src } => {
+                f.write_fmt(format_args!("fmov {dst}, {src}"))
+            }
+
+            Self::Fmul { dst, lhs, rhs } => {
+                f.write_fmt(format_args!("fmul {dst}, {lhs}, {rhs}"))
+            }
+
+            Self::Fsub { dst, lhs, rhs } => {
+                f.write_fmt(format_args!("fsub {dst}, {lhs}, {rhs}"))
+            }
+
             Self::Ldp { is_64_bit, mode, first, second, src, offset } => {
                 _ = is_64_bit;
                 match mode {
@@ -536,6 +806,22 @@ impl Display for ArmInstruction {
                 }
             }
 
+            Self::LdrbImmediate { signed, dst, base_ptr, offset } => {
+                if *signed {
+                    f.write_fmt(format_args!("ldrsb {dst}, [{base_ptr}, #0x{offset:x}]"))
+                } else {
+                    f.write_fmt(format_args!("ldrb w{}, [{base_ptr}, #0x{offset:x}]", dst.number))
+                }
+            }
+
+            Self::LdrhImmediate { signed, dst, base_ptr, offset } => {
+                if *signed {
+                    f.write_fmt(format_args!("ldrsh {dst}, [{base_ptr}, #0x{offset:x}]"))
+                } else {
+                    f.write_fmt(format_args!("ldrh w{}, [{base_ptr}, #0x{offset:x}]", dst.number))
+                }
+            }
+
             Self::MovRegister32 { dst, src } => {
                 let dst = dst.number;
                 let src = src.number;
@@ -546,14 +832,34 @@ impl Display for ArmInstruction {
                 f.write_fmt(format_args!("mov {dst}, {src}"))
             }
 
+            Self::MovK { register, imm16, shift } => {
+                f.write_fmt(format_args!("movk {register}, #{imm16}, lsl #{shift}"))
+            }
+
             Self::MovZ { register, imm16 } => {
                 f.write_fmt(format_args!("mov {register}, #{imm16}"))
             }
 
+            Self::Msub { dst, lhs, rhs, minuend } => {
+                f.write_fmt(format_args!("msub {dst}, {lhs}, {rhs}, {minuend}"))
+            }
+
+            Self::Mul { dst, lhs, rhs } => {
+                f.write_fmt(format_args!("mul {dst}, {lhs}, {rhs}"))
+            }
+
             Self::Ret => {
                 f.write_str("ret")
             }
 
+            Self::Scvtf { dst, src } => {
+                f.write_fmt(format_args!("scvtf {dst}, {src}"))
+            }
+
+            Self::Sdiv { dst, lhs, rhs } => {
+                f.write_fmt(format_args!("sdiv {dst}, {lhs}, {rhs}"))
+            }
+
             Self::Stp { is_64_bit, dst, offset, first, second, mode } => {
                 _ = is_64_bit;
                 match mode {
@@ -588,6 +894,14 @@ impl Display for ArmInstruction {
                 }
             }
 
+            Self::StrbImmediate { src, base_ptr, offset } => {
+                f.write_fmt(format_args!("strb w{}, [{base_ptr}, #0x{offset:x}]", src.number))
+            }
+
+            Self::StrhImmediate { src, base_ptr, offset } => {
+                f.write_fmt(format_args!("strh w{}, [{base_ptr}, #0x{offset:x}]", src.number))
+            }
+
             Self::SubImmediate { dst, lhs, rhs_imm12 } => {
                 f.write_fmt(format_args!("sub {dst}, {lhs}, #{rhs_imm12}"))
             }
@@ -603,10 +917,38 @@ impl Display for ArmInstruction {
 
                 Ok(())
             }
+
+            Self::Udiv { dst, lhs, rhs } => {
+                f.write_fmt(format_args!("udiv {dst}, {lhs}, {rhs}"))
+            }
         }
     }
 }
 
+/// Encodes the "Floating-point data-processing (2 source)" instruction class,
+/// e.g. `fadd d0, d1, d2`.
+#[must_use]
+fn encode_floating_point_data_processing(opcode: u32, dst: ArmFloatRegister, lhs: ArmFloatRegister, rhs: ArmFloatRegister) -> u32 {
+    let mut instruction = opcode;
+    instruction |= (rhs.number as u32) << 16;
+    instruction |= (lhs.number as u32) << 5;
+    instruction |= dst.number as u32;
+    instruction
+}
+
+/// Encodes the unsigned-offset forms of `ldrb`, `ldrh`, `strb`, etc., where
+/// `imm12` is the byte offset already divided by the access size.
+#[must_use]
+fn encode_sub_word_load_store(instruction: u32, rt: ArmRegister, rn: ArmRegister, imm12: u32) -> u32 {
+    debug_assert!(imm12 < (1 << 12));
+
+    let mut instruction = instruction;
+    instruction |= take_bits(imm12, 12) << 10;
+    instruction |= (rn.number as u32) << 5;
+    instruction |= rt.number as u32;
+    instruction
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 #[allow(unused)]
@@ -701,6 +1043,124 @@ mod tests {
         },
         0xb9000be0,
     )]
+    #[case(
+        ArmInstruction::MovK {
+            register: ArmRegister::X0,
+            imm16: 1,
+            shift: 16,
+        },
+        0xf2a00020,
+    )]
+    #[case(
+        ArmInstruction::FmovFromGeneral {
+            dst: ArmFloatRegister::D0,
+            src: ArmRegister::X8,
+        },
+        0x9e670100,
+    )]
+    #[case(
+        ArmInstruction::FmovToGeneral {
+            dst: ArmRegister::X0,
+            src: ArmFloatRegister::D1,
+        },
+        0x9e660020,
+    )]
+    #[case(
+        ArmInstruction::Fadd {
+            dst: ArmFloatRegister::D0,
+            lhs: ArmFloatRegister::D0,
+            rhs: ArmFloatRegister::D1,
+        },
+        0x1e612800,
+    )]
+    #[case(
+        ArmInstruction::Fcmp {
+            lhs: ArmFloatRegister::D0,
+            rhs: ArmFloatRegister::D1,
+        },
+        0x1e612000,
+    )]
+    #[case(
+        ArmInstruction::Scvtf {
+            dst: ArmFloatRegister::D0,
+            src: ArmRegister::X0,
+        },
+        0x9e620000,
+    )]
+    #[case(
+        ArmInstruction::Fcvtzs {
+            dst: ArmRegister::X0,
+            src: ArmFloatRegister::D0,
+        },
+        0x9e780000,
+    )]
+    #[case(
+        ArmInstruction::LdrbImmediate {
+            signed: false,
+            dst: ArmRegister::X0,
+            base_ptr: ArmRegister::X8,
+            offset: 4,
+        },
+        0x39401100,
+    )]
+    #[case(
+        ArmInstruction::LdrhImmediate {
+            signed: true,
+            dst: ArmRegister::X0,
+            base_ptr: ArmRegister::X8,
+            offset: 4,
+        },
+        0x79800900,
+    )]
+    #[case(
+        ArmInstruction::StrbImmediate {
+            src: ArmRegister::X8,
+            base_ptr: ArmRegister::SP,
+            offset: 0,
+        },
+        0x390003e8,
+    )]
+    #[case(
+        ArmInstruction::StrhImmediate {
+            src: ArmRegister::X0,
+            base_ptr: ArmRegister::SP,
+            offset: 6,
+        },
+        0x79000fe0,
+    )]
+    #[case(
+        ArmInstruction::Mul {
+            dst: ArmRegister::X0,
+            lhs: ArmRegister::X1,
+            rhs: ArmRegister { number: 2 },
+        },
+        0x9b027c20,
+    )]
+    #[case(
+        ArmInstruction::Sdiv {
+            dst: ArmRegister::X0,
+            lhs: ArmRegister::X1,
+            rhs: ArmRegister { number: 2 },
+        },
+        0x9ac20c20,
+    )]
+    #[case(
+        ArmInstruction::Udiv {
+            dst: ArmRegister::X8,
+            lhs: ArmRegister::X8,
+            rhs: ArmRegister::X0,
+        },
+        0x9ac00908,
+    )]
+    #[case(
+        ArmInstruction::Msub {
+            dst: ArmRegister::X0,
+            lhs: ArmRegister::X16,
+            rhs: ArmRegister { number: 2 },
+            minuend: ArmRegister::X1,
+        },
+        0x9b028600,
+    )]
     fn encode_instruction(#[case] input: ArmInstruction, #[case] expected: u32) {
         let actual = input.encode(0, &HashMap::new());
         assert_eq!(expected, actual, "actual was: 0x{actual:x}");
@@ -27,5 +27,8 @@ pub use self::{
         ArmInstruction,
         ArmShift2,
     },
-    register::ArmRegister,
+    register::{
+        ArmFloatRegister,
+        ArmRegister,
+    },
 };
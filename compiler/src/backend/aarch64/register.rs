@@ -13,7 +13,14 @@ pub struct ArmRegister {
 #[allow(unused)]
 impl ArmRegister {
     pub const X0: Self = Self { number: 0 };
+    pub const X1: Self = Self { number: 1 };
     pub const X8: Self = Self { number: 8 };
+
+    /// Intra-procedure-call scratch registers, these are never allocated, so
+    /// they can be used for temporaries within a single IR instruction.
+    pub const X16: Self = Self { number: 16 };
+    pub const X17: Self = Self { number: 17 };
+
     pub const X30: Self = Self { number: 30 };
 
     /// Frame Pointer
@@ -96,3 +103,21 @@ impl Display for ArmRegister {
         }
     }
 }
+
+/// The 64-bit SIMD & floating-point registers `d0` to `d31`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmFloatRegister {
+    pub(super) number: u8,
+}
+
+impl ArmFloatRegister {
+    pub const D0: Self = Self { number: 0 };
+    pub const D1: Self = Self { number: 1 };
+}
+
+impl Display for ArmFloatRegister {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("d")?;
+        self.number.fmt(f)
+    }
+}
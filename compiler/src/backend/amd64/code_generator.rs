@@ -5,9 +5,9 @@ use std::{collections::HashMap, mem::take};
 
 use babbelaar::BabString;
 
-use crate::{AllocatableRegister, CodeGenerator, CompiledFunction, Function, FunctionLink, FunctionLinkMethod, Immediate, Instruction, JumpCondition, Label, MathOperation, Operand, Register, RegisterAllocator};
+use crate::{AllocatableRegister, CodeGenerator, CompiledFunction, FloatingPointOperation, Function, FunctionLink, FunctionLinkMethod, Immediate, Instruction, JumpCondition, Label, MathOperation, Operand, Register, RegisterAllocator};
 
-use super::{Amd64FunctionCharacteristics, Amd64Instruction, Amd64Register};
+use super::{Amd64FloatRegister, Amd64FunctionCharacteristics, Amd64Instruction, Amd64Register};
 
 #[derive(Debug)]
 pub struct Amd64CodeGenerator {
@@ -19,6 +19,15 @@ pub struct Amd64CodeGenerator {
     stack_size: usize,
     space_used_on_stack: usize,
     link_locations: Vec<FunctionLink>,
+
+    /// `ucomisd` sets the flags like an unsigned comparison, so the jumps
+    /// following it must use the unsigned condition codes.
+    last_comparison_was_floating_point: bool,
+    last_comparison_was_unsigned: bool,
+
+    /// The id of the last label that was created by the code generator
+    /// itself, which are numbered after the labels of the IR.
+    last_label_id: usize,
 }
 
 impl Amd64CodeGenerator {
@@ -34,6 +43,9 @@ impl Amd64CodeGenerator {
             stack_size: 0,
             space_used_on_stack: 0,
             link_locations: Vec::new(),
+            last_label_id: function.highest_label_id(),
+            last_comparison_was_floating_point: false,
+            last_comparison_was_unsigned: false,
         };
 
         this.add_prologue(function.instructions());
@@ -65,7 +77,9 @@ impl Amd64CodeGenerator {
 
     fn add_instruction(&mut self, instruction: &Instruction) {
         match instruction {
-            Instruction::Compare { lhs, rhs } => {
+            Instruction::Compare { lhs, rhs, unsigned } => {
+                self.last_comparison_was_floating_point = false;
+                self.last_comparison_was_unsigned = *unsigned;
                 let lhs = self.allocate_register(lhs);
 
                 match rhs {
@@ -86,6 +100,8 @@ impl Amd64CodeGenerator {
                             }
 
                             Immediate::Integer64(..) => todo!(),
+
+                            Immediate::Float64(..) => panic!("ICE: kommagetallen moeten met VergelijkKomma vergeleken worden"),
                         }
                     }
 
@@ -97,6 +113,20 @@ impl Amd64CodeGenerator {
                 }
             }
 
+            Instruction::CompareFloatingPoint { lhs, rhs } => {
+                self.last_comparison_was_floating_point = true;
+
+                let lhs = self.allocate_register(lhs);
+                let rhs = self.allocate_register(rhs);
+
+                self.instructions.push(Amd64Instruction::MovqXmmReg64 { dst: Amd64FloatRegister::Xmm0, src: lhs });
+                self.instructions.push(Amd64Instruction::MovqXmmReg64 { dst: Amd64FloatRegister::Xmm1, src: rhs });
+                self.instructions.push(Amd64Instruction::UcomisdXmmXmm {
+                    lhs: Amd64FloatRegister::Xmm0,
+                    rhs: Amd64FloatRegister::Xmm1,
+                });
+            }
+
             Instruction::Increment { register } => {
                 let reg = self.allocate_register(register);
                 self.instructions.push(Amd64Instruction::Inc32 { reg });
@@ -112,8 +142,11 @@ impl Amd64CodeGenerator {
                         });
                     }
 
-                    Immediate::Integer64(qword) => {
-                        todo!("Support mov64 (value is 0x{qword:x})")
+                    Immediate::Integer64(..) | Immediate::Float64(..) => {
+                        self.instructions.push(Amd64Instruction::MovReg64Imm64 {
+                            dst,
+                            src: immediate.as_i64(),
+                        });
                     }
                 }
             }
@@ -162,16 +195,47 @@ impl Amd64CodeGenerator {
 
             Instruction::JumpConditional { condition, location } => {
                 let location = *location;
+                if self.last_comparison_was_floating_point {
+                    self.add_floating_point_jump(*condition, location);
+                    return;
+                }
+
                 match condition {
                     JumpCondition::Equal => {
                         self.instructions.push(Amd64Instruction::JeShort { location });
                     }
 
+                    JumpCondition::Greater => {
+                        self.instructions.push(match self.last_comparison_was_unsigned {
+                            true => Amd64Instruction::JaShort { location },
+                            false => Amd64Instruction::JgShort { location },
+                        });
+                    }
+
+                    JumpCondition::GreaterOrEqual => {
+                        self.instructions.push(match self.last_comparison_was_unsigned {
+                            true => Amd64Instruction::JaeShort { location },
+                            false => Amd64Instruction::JgeShort { location },
+                        });
+                    }
+
+                    JumpCondition::Less => {
+                        self.instructions.push(match self.last_comparison_was_unsigned {
+                            true => Amd64Instruction::JbShort { location },
+                            false => Amd64Instruction::JlShort { location },
+                        });
+                    }
+
+                    JumpCondition::LessOrEqual => {
+                        self.instructions.push(match self.last_comparison_was_unsigned {
+                            true => Amd64Instruction::JbeShort { location },
+                            false => Amd64Instruction::JleShort { location },
+                        });
+                    }
+
                     JumpCondition::NotEqual => {
                         self.instructions.push(Amd64Instruction::JneShort { location });
                     }
-
-                    _ => todo!("Ondersteun conditie {condition} voor Spring-locatie {location}"),
                 }
             }
 
@@ -200,9 +264,49 @@ impl Amd64CodeGenerator {
                 match operation {
                     MathOperation::Add => self.add_instruction_add(dst, lhs, rhs),
                     MathOperation::Subtract => self.add_instruction_sub(dst, lhs, rhs),
+                    MathOperation::Multiply => self.add_instruction_imul(dst, lhs, rhs),
+                    MathOperation::Divide => self.add_instruction_div(dst, lhs, rhs, true, Amd64Register::Rax),
+                    MathOperation::DivideUnsigned => self.add_instruction_div(dst, lhs, rhs, false, Amd64Register::Rax),
+                    MathOperation::Modulo => self.add_instruction_div(dst, lhs, rhs, true, Amd64Register::Rdx),
+                    MathOperation::ModuloUnsigned => self.add_instruction_div(dst, lhs, rhs, false, Amd64Register::Rdx),
                 }
             }
 
+            Instruction::FloatingPointMath { operation, destination, lhs, rhs } => {
+                let dst = self.allocate_register(destination);
+                let lhs = self.allocate_register(lhs);
+                let rhs = self.allocate_register(rhs);
+
+                let xmm_dst = Amd64FloatRegister::Xmm0;
+                let xmm_src = Amd64FloatRegister::Xmm1;
+
+                self.instructions.push(Amd64Instruction::MovqXmmReg64 { dst: xmm_dst, src: lhs });
+                self.instructions.push(Amd64Instruction::MovqXmmReg64 { dst: xmm_src, src: rhs });
+                self.instructions.push(match operation {
+                    FloatingPointOperation::Add => Amd64Instruction::AddsdXmmXmm { dst: xmm_dst, src: xmm_src },
+                    FloatingPointOperation::Subtract => Amd64Instruction::SubsdXmmXmm { dst: xmm_dst, src: xmm_src },
+                    FloatingPointOperation::Multiply => Amd64Instruction::MulsdXmmXmm { dst: xmm_dst, src: xmm_src },
+                    FloatingPointOperation::Divide => Amd64Instruction::DivsdXmmXmm { dst: xmm_dst, src: xmm_src },
+                });
+                self.instructions.push(Amd64Instruction::MovqReg64Xmm { dst, src: xmm_dst });
+            }
+
+            Instruction::ConvertIntegerToFloatingPoint { destination, source } => {
+                let dst = self.allocate_register(destination);
+                let src = self.allocate_register(source);
+
+                self.instructions.push(Amd64Instruction::Cvtsi2sdXmmReg64 { dst: Amd64FloatRegister::Xmm0, src });
+                self.instructions.push(Amd64Instruction::MovqReg64Xmm { dst, src: Amd64FloatRegister::Xmm0 });
+            }
+
+            Instruction::ConvertFloatingPointToInteger { destination, source } => {
+                let dst = self.allocate_register(destination);
+                let src = self.allocate_register(source);
+
+                self.instructions.push(Amd64Instruction::MovqXmmReg64 { dst: Amd64FloatRegister::Xmm0, src });
+                self.instructions.push(Amd64Instruction::Cvttsd2siReg64Xmm { dst, src: Amd64FloatRegister::Xmm0 });
+            }
+
             Instruction::StackAlloc { dst, size } => {
                 let offset = self.space_used_on_stack;
                 self.space_used_on_stack += size;
@@ -237,6 +341,36 @@ impl Amd64CodeGenerator {
                         }
                     }
 
+                    (Operand::Immediate(Immediate::Integer8(offset)), 1) => {
+                        self.instructions.push(match typ.is_signed() {
+                            true => Amd64Instruction::MovsxReg64FromPtr8Reg64Off8 { dst, base, offset },
+                            false => Amd64Instruction::MovzxReg32FromPtr8Reg64Off8 { dst, base, offset },
+                        });
+                    }
+
+                    (Operand::Immediate(Immediate::Integer8(offset)), 2) => {
+                        self.instructions.push(match typ.is_signed() {
+                            true => Amd64Instruction::MovsxReg64FromPtr16Reg64Off8 { dst, base, offset },
+                            false => Amd64Instruction::MovzxReg32FromPtr16Reg64Off8 { dst, base, offset },
+                        });
+                    }
+
+                    (Operand::Immediate(offset @ (Immediate::Integer16(..) | Immediate::Integer32(..))), 1) => {
+                        let offset = offset.as_i32();
+                        self.instructions.push(match typ.is_signed() {
+                            true => Amd64Instruction::MovsxReg64FromPtr8Reg64Off32 { dst, base, offset },
+                            false => Amd64Instruction::MovzxReg32FromPtr8Reg64Off32 { dst, base, offset },
+                        });
+                    }
+
+                    (Operand::Immediate(offset @ (Immediate::Integer16(..) | Immediate::Integer32(..))), 2) => {
+                        let offset = offset.as_i32();
+                        self.instructions.push(match typ.is_signed() {
+                            true => Amd64Instruction::MovsxReg64FromPtr16Reg64Off32 { dst, base, offset },
+                            false => Amd64Instruction::MovzxReg32FromPtr16Reg64Off32 { dst, base, offset },
+                        });
+                    }
+
                     _ => todo!("Ondersteun register-offset {offset} met typegrootte {}", typ.bytes()),
                 }
             }
@@ -281,12 +415,72 @@ impl Amd64CodeGenerator {
                         }
                     }
 
+                    (Operand::Register(src), Operand::Immediate(Immediate::Integer8(offset)), 1) => {
+                        let src = self.allocate_register(src);
+                        self.instructions.push(Amd64Instruction::MovReg8ToPtrReg64Off8 { base, offset, src });
+                    }
+
+                    (Operand::Register(src), Operand::Immediate(Immediate::Integer8(offset)), 2) => {
+                        let src = self.allocate_register(src);
+                        self.instructions.push(Amd64Instruction::MovReg16ToPtrReg64Off8 { base, offset, src });
+                    }
+
+                    (Operand::Register(src), Operand::Immediate(offset @ (Immediate::Integer16(..) | Immediate::Integer32(..))), 1) => {
+                        let src = self.allocate_register(src);
+                        let offset = offset.as_i32();
+                        self.instructions.push(Amd64Instruction::MovReg8ToPtrReg64Off32 { base, offset, src });
+                    }
+
+                    (Operand::Register(src), Operand::Immediate(offset @ (Immediate::Integer16(..) | Immediate::Integer32(..))), 2) => {
+                        let src = self.allocate_register(src);
+                        let offset = offset.as_i32();
+                        self.instructions.push(Amd64Instruction::MovReg16ToPtrReg64Off32 { base, offset, src });
+                    }
+
                     _ => todo!("Ondersteun {instruction}"),
                 }
             }
         }
     }
 
+    /// When either operand is NaN, `ucomisd` sets the zero, parity and carry
+    /// flags, so the conditions that would hold for these flags must also
+    /// check the parity flag, such that `NaN == NaN` and `NaN < 1` are false.
+    fn add_floating_point_jump(&mut self, condition: JumpCondition, location: Label) {
+        let ordered_jump = match condition {
+            JumpCondition::Greater => {
+                self.instructions.push(Amd64Instruction::JaShort { location });
+                return;
+            }
+
+            JumpCondition::GreaterOrEqual => {
+                self.instructions.push(Amd64Instruction::JaeShort { location });
+                return;
+            }
+
+            JumpCondition::NotEqual => {
+                self.instructions.push(Amd64Instruction::JneShort { location });
+                self.instructions.push(Amd64Instruction::JpShort { location });
+                return;
+            }
+
+            JumpCondition::Equal => Amd64Instruction::JeShort { location },
+            JumpCondition::Less => Amd64Instruction::JbShort { location },
+            JumpCondition::LessOrEqual => Amd64Instruction::JbeShort { location },
+        };
+
+        let unordered = self.create_label();
+        self.instructions.push(Amd64Instruction::JpShort { location: unordered });
+        self.instructions.push(ordered_jump);
+        self.label_offsets.insert(unordered, self.instructions.len());
+    }
+
+    #[must_use]
+    fn create_label(&mut self) -> Label {
+        self.last_label_id += 1;
+        Label::new(self.last_label_id)
+    }
+
     fn add_prologue(&mut self, instructions: &[Instruction]) {
         self.stack_size = 0;
 
@@ -457,6 +651,100 @@ impl Amd64CodeGenerator {
             _ => todo!("Support sub of {lhs}, {rhs}"),
         }
     }
+
+    fn add_instruction_imul(&mut self, dst: Amd64Register, lhs: &Operand, rhs: &Operand) {
+        match (lhs, rhs) {
+            (Operand::Immediate(lhs), Operand::Immediate(rhs)) => {
+                self.instructions.push(Amd64Instruction::MovReg32Imm32 {
+                    dst,
+                    src: lhs.as_i32().wrapping_mul(rhs.as_i32()),
+                });
+            }
+
+            (Operand::Register(reg), Operand::Immediate(imm)) |
+                (Operand::Immediate(imm), Operand::Register(reg)) => {
+                let src = self.allocate_register(reg);
+                self.instructions.push(Amd64Instruction::ImulReg32Reg32Imm32 { dst, src, imm: imm.as_i32() });
+            }
+
+            (Operand::Register(lhs), Operand::Register(rhs)) => {
+                let lhs = self.allocate_register(lhs);
+                let rhs = self.allocate_register(rhs);
+
+                // Multiplication is commutative, so the operand that already
+                // lives in `dst` is kept there.
+                let src = if rhs == dst {
+                    lhs
+                } else {
+                    if lhs != dst {
+                        self.instructions.push(Amd64Instruction::MovReg32Reg32 { dst, src: lhs });
+                    }
+                    rhs
+                };
+
+                self.instructions.push(Amd64Instruction::ImulReg32Reg32 { dst, src });
+            }
+        }
+    }
+
+    /// Divides `lhs` by `rhs`, where `result` is `eax` for the quotient and
+    /// `edx` for the remainder.
+    fn add_instruction_div(&mut self, dst: Amd64Register, lhs: &Operand, rhs: &Operand, signed: bool, result: Amd64Register) {
+        let lhs_register = match lhs {
+            Operand::Register(lhs) => Some(self.allocate_register(lhs)),
+            Operand::Immediate(..) => None,
+        };
+
+        // The dividend goes into edx:eax, so the divisor needs a register
+        // that isn't one of these, nor the one holding the dividend.
+        let divisor = [Amd64Register::Rcx, Amd64Register::Rbx, Amd64Register::Rsi].into_iter()
+            .find(|reg| *reg != dst && Some(*reg) != lhs_register)
+            .unwrap();
+
+        // These are overwritten by the division, but may hold other values.
+        let saved_registers: Vec<Amd64Register> = [Amd64Register::Rax, Amd64Register::Rdx, divisor].into_iter()
+            .filter(|reg| *reg != dst)
+            .collect();
+
+        for reg in &saved_registers {
+            self.instructions.push(Amd64Instruction::PushReg64 { reg: *reg });
+        }
+
+        // The divisor is moved first, since it may live in eax or edx.
+        self.add_instruction_mov_operand(divisor, rhs);
+        self.add_instruction_mov_operand(Amd64Register::Rax, lhs);
+
+        if signed {
+            self.instructions.push(Amd64Instruction::Cdq);
+            self.instructions.push(Amd64Instruction::IdivReg32 { src: divisor });
+        } else {
+            self.instructions.push(Amd64Instruction::MovReg32Imm32 { dst: Amd64Register::Rdx, src: 0 });
+            self.instructions.push(Amd64Instruction::DivReg32 { src: divisor });
+        }
+
+        if dst != result {
+            self.instructions.push(Amd64Instruction::MovReg32Reg32 { dst, src: result });
+        }
+
+        for reg in saved_registers.iter().rev() {
+            self.instructions.push(Amd64Instruction::PopReg64 { reg: *reg });
+        }
+    }
+
+    fn add_instruction_mov_operand(&mut self, dst: Amd64Register, src: &Operand) {
+        match src {
+            Operand::Immediate(immediate) => {
+                self.instructions.push(Amd64Instruction::MovReg32Imm32 { dst, src: immediate.as_i32() });
+            }
+
+            Operand::Register(src) => {
+                let src = self.allocate_register(src);
+                if src != dst {
+                    self.instructions.push(Amd64Instruction::MovReg32Reg32 { dst, src });
+                }
+            }
+        }
+    }
 }
 
 impl CodeGenerator for Amd64CodeGenerator {
@@ -488,4 +776,54 @@ mod tests {
         assert_eq!(actual_bytecode, expected_bytecode);
     }
 
+    #[rstest]
+    #[case(JumpCondition::Equal, &[0x7a, 0x02, 0x74, 0x00])]
+    #[case(JumpCondition::NotEqual, &[0x75, 0x02, 0x7a, 0x00])]
+    #[case(JumpCondition::Less, &[0x7a, 0x02, 0x72, 0x00])]
+    #[case(JumpCondition::Greater, &[0x77, 0x00])]
+    fn floating_point_jumps_check_for_nan(#[case] condition: JumpCondition, #[case] expected_jumps: &[u8]) {
+        let location = Label::new(1);
+        let function = Function {
+            name: BabString::new_static("testFunctie"),
+            argument_registers: vec![Register::new(0), Register::new(1)],
+            instructions: vec![
+                Instruction::CompareFloatingPoint { lhs: Register::new(0), rhs: Register::new(1) },
+                Instruction::JumpConditional { condition, location },
+                Instruction::Label(location),
+                Instruction::Return { value_reg: None },
+            ],
+            label_names: HashMap::from([(location, BabString::new_static("na"))]),
+        };
+
+        let actual_bytecode = Amd64CodeGenerator::compile(&function).byte_code;
+        let jumps = &actual_bytecode[actual_bytecode.len() - expected_jumps.len() - 1..actual_bytecode.len() - 1];
+        assert_eq!(jumps, expected_jumps);
+    }
+
+    #[rstest]
+    #[case(false, JumpCondition::Greater, &[0x7f, 0x00])]
+    #[case(false, JumpCondition::Less, &[0x7c, 0x00])]
+    #[case(true, JumpCondition::Greater, &[0x77, 0x00])]
+    #[case(true, JumpCondition::GreaterOrEqual, &[0x73, 0x00])]
+    #[case(true, JumpCondition::Less, &[0x72, 0x00])]
+    #[case(true, JumpCondition::LessOrEqual, &[0x76, 0x00])]
+    fn unsigned_comparisons_use_unsigned_jumps(#[case] unsigned: bool, #[case] condition: JumpCondition, #[case] expected_jump: &[u8]) {
+        let location = Label::new(1);
+        let function = Function {
+            name: BabString::new_static("testFunctie"),
+            argument_registers: vec![Register::new(0), Register::new(1)],
+            instructions: vec![
+                Instruction::Compare { lhs: Register::new(0), rhs: Operand::Register(Register::new(1)), unsigned },
+                Instruction::JumpConditional { condition, location },
+                Instruction::Label(location),
+                Instruction::Return { value_reg: None },
+            ],
+            label_names: HashMap::from([(location, BabString::new_static("na"))]),
+        };
+
+        let actual_bytecode = Amd64CodeGenerator::compile(&function).byte_code;
+        let jump = &actual_bytecode[actual_bytecode.len() - expected_jump.len() - 1..actual_bytecode.len() - 1];
+        assert_eq!(jump, expected_jump);
+    }
+
 }
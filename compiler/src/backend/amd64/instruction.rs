@@ -7,7 +7,7 @@ use babbelaar::BabString;
 
 use crate::Label;
 
-use super::register::Amd64Register;
+use super::register::{Amd64FloatRegister, Amd64Register};
 
 
 /// Intel glossary:
@@ -25,13 +25,37 @@ use super::register::Amd64Register;
 pub enum Amd64Instruction {
     AddReg32Imm8 { dst: Amd64Register, src: i8 },
     AddReg32Reg32 { dst: Amd64Register, src: Amd64Register },
+    /// Add scalar double-precision floating-point value
+    AddsdXmmXmm { dst: Amd64FloatRegister, src: Amd64FloatRegister },
 
     CallNearRelative { symbol_name: BabString },
 
+    /// Convert doubleword to quadword: sign-extends eax into edx, for `idiv`.
+    Cdq,
+
     CmpReg32Imm8 { lhs: Amd64Register, rhs: i8 },
     CmpReg32Imm32 { lhs: Amd64Register, rhs: i32 },
     CmpReg32Reg32 { lhs: Amd64Register, rhs: Amd64Register },
 
+    /// Convert signed 64-bit integer to scalar double-precision floating-point value
+    Cvtsi2sdXmmReg64 { dst: Amd64FloatRegister, src: Amd64Register },
+    /// Convert with truncation scalar double-precision floating-point value to signed 64-bit integer
+    Cvttsd2siReg64Xmm { dst: Amd64Register, src: Amd64FloatRegister },
+
+    /// Divide scalar double-precision floating-point value
+    DivsdXmmXmm { dst: Amd64FloatRegister, src: Amd64FloatRegister },
+
+    /// Unsigned divide edx:eax by the register, with the quotient in eax and
+    /// the remainder in edx.
+    DivReg32 { src: Amd64Register },
+
+    /// Signed divide edx:eax by the register, with the quotient in eax and
+    /// the remainder in edx.
+    IdivReg32 { src: Amd64Register },
+
+    ImulReg32Reg32 { dst: Amd64Register, src: Amd64Register },
+    ImulReg32Reg32Imm32 { dst: Amd64Register, src: Amd64Register, imm: i32 },
+
     Inc32 { reg: Amd64Register },
 
     Jmp { location: Label },
@@ -39,6 +63,24 @@ pub enum Amd64Instruction {
     JneShort { location: Label },
     /// Jump if equal (short = 8-bit offset)
     JeShort { location: Label },
+    /// Jump if greater (short = 8-bit offset)
+    JgShort { location: Label },
+    /// Jump if greater or equal (short = 8-bit offset)
+    JgeShort { location: Label },
+    /// Jump if less (short = 8-bit offset)
+    JlShort { location: Label },
+    /// Jump if less or equal (short = 8-bit offset)
+    JleShort { location: Label },
+    /// Jump if above, i.e. unsigned greater (short = 8-bit offset)
+    JaShort { location: Label },
+    /// Jump if above or equal, i.e. unsigned greater or equal (short = 8-bit offset)
+    JaeShort { location: Label },
+    /// Jump if below, i.e. unsigned less (short = 8-bit offset)
+    JbShort { location: Label },
+    /// Jump if below or equal, i.e. unsigned less or equal (short = 8-bit offset)
+    JbeShort { location: Label },
+    /// Jump if parity, i.e. the operands of `ucomisd` were unordered (short = 8-bit offset)
+    JpShort { location: Label },
 
     LeaReg32FromReg32 {
         dst: Amd64Register,
@@ -67,6 +109,19 @@ pub enum Amd64Instruction {
     MovReg64FromPtrReg64 { dst: Amd64Register, base: Amd64Register },
     MovReg64FromPtrReg64Off8 { dst: Amd64Register, base: Amd64Register, offset: i8 },
 
+    /// Move byte with zero-extension, used for loading unsigned sub-word integers
+    MovzxReg32FromPtr8Reg64Off8 { dst: Amd64Register, base: Amd64Register, offset: i8 },
+    /// Move word with zero-extension, used for loading unsigned sub-word integers
+    MovzxReg32FromPtr16Reg64Off8 { dst: Amd64Register, base: Amd64Register, offset: i8 },
+    /// Move byte with sign-extension, used for loading signed sub-word integers
+    MovsxReg64FromPtr8Reg64Off8 { dst: Amd64Register, base: Amd64Register, offset: i8 },
+    /// Move word with sign-extension, used for loading signed sub-word integers
+    MovsxReg64FromPtr16Reg64Off8 { dst: Amd64Register, base: Amd64Register, offset: i8 },
+    MovzxReg32FromPtr8Reg64Off32 { dst: Amd64Register, base: Amd64Register, offset: i32 },
+    MovzxReg32FromPtr16Reg64Off32 { dst: Amd64Register, base: Amd64Register, offset: i32 },
+    MovsxReg64FromPtr8Reg64Off32 { dst: Amd64Register, base: Amd64Register, offset: i32 },
+    MovsxReg64FromPtr16Reg64Off32 { dst: Amd64Register, base: Amd64Register, offset: i32 },
+
     MovImm32ToPtrReg64 { base: Amd64Register, src: i32 },
     MovImm32ToPtrReg64Off8 { base: Amd64Register, offset: i8, src: i32 },
 
@@ -74,11 +129,24 @@ pub enum Amd64Instruction {
     MovReg32ToPtrReg64Off8 { base: Amd64Register, offset: i8, src: Amd64Register },
     MovReg64ToPtrReg64 { base: Amd64Register, src: Amd64Register },
     MovReg64ToPtrReg64Off8 { base: Amd64Register, offset: i8, src: Amd64Register },
+    MovReg8ToPtrReg64Off8 { base: Amd64Register, offset: i8, src: Amd64Register },
+    MovReg16ToPtrReg64Off8 { base: Amd64Register, offset: i8, src: Amd64Register },
+    MovReg8ToPtrReg64Off32 { base: Amd64Register, offset: i32, src: Amd64Register },
+    MovReg16ToPtrReg64Off32 { base: Amd64Register, offset: i32, src: Amd64Register },
 
     MovReg32Imm32 { dst: Amd64Register, src: i32 },
+    MovReg64Imm64 { dst: Amd64Register, src: i64 },
     MovReg32Reg32 { dst: Amd64Register, src: Amd64Register },
     MovReg64Reg64 { dst: Amd64Register, src: Amd64Register },
 
+    /// Move quadword from a general-purpose register to an SSE register
+    MovqXmmReg64 { dst: Amd64FloatRegister, src: Amd64Register },
+    /// Move quadword from an SSE register to a general-purpose register
+    MovqReg64Xmm { dst: Amd64Register, src: Amd64FloatRegister },
+
+    /// Multiply scalar double-precision floating-point value
+    MulsdXmmXmm { dst: Amd64FloatRegister, src: Amd64FloatRegister },
+
     PopReg64 { reg: Amd64Register },
     PushReg64 { reg: Amd64Register },
 
@@ -87,6 +155,12 @@ pub enum Amd64Instruction {
     SubReg32Imm8 { dst: Amd64Register, src: i8 },
     SubReg64Imm8 { dst: Amd64Register, src: i8 },
     SubReg32Reg32 { dst: Amd64Register, src: Amd64Register },
+    /// Subtract scalar double-precision floating-point value
+    SubsdXmmXmm { dst: Amd64FloatRegister, src: Amd64FloatRegister },
+
+    /// Unordered compare scalar double-precision floating-point values and
+    /// set EFLAGS (ZF, PF and CF, like an unsigned comparison)
+    UcomisdXmmXmm { lhs: Amd64FloatRegister, rhs: Amd64FloatRegister },
 }
 
 impl Amd64Instruction {
@@ -96,6 +170,15 @@ impl Amd64Instruction {
             Self::Jmp { .. } => true,
             Self::JeShort { .. } => true,
             Self::JneShort { .. } => true,
+            Self::JgShort { .. } => true,
+            Self::JgeShort { .. } => true,
+            Self::JlShort { .. } => true,
+            Self::JleShort { .. } => true,
+            Self::JaShort { .. } => true,
+            Self::JaeShort { .. } => true,
+            Self::JbShort { .. } => true,
+            Self::JbeShort { .. } => true,
+            Self::JpShort { .. } => true,
             _ => false,
         }
     }
@@ -113,6 +196,11 @@ impl Amd64Instruction {
                 output.push(mod_rm_byte_reg_reg(*dst, *src))
             }
 
+            Self::AddsdXmmXmm { dst, src } => {
+                output.extend_from_slice(&[0xf2, 0x0f, 0x58]);
+                output.push(mod_rm_byte_xmm(dst.mod_rm_bits(), src.mod_rm_bits()));
+            }
+
             Self::CallNearRelative { symbol_name } => {
                 _ = symbol_name;
 
@@ -120,6 +208,8 @@ impl Amd64Instruction {
                 output.extend_from_slice(&0u32.to_le_bytes());
             }
 
+            Self::Cdq => output.push(0x99),
+
             Self::CmpReg32Imm8 { lhs, rhs } => {
                 output.push(0x83);
                 output.push(mod_rm_byte_extra_op(7, *lhs));
@@ -137,6 +227,46 @@ impl Amd64Instruction {
                 output.push(mod_rm_byte_reg_reg(*lhs, *rhs));
             }
 
+            Self::Cvtsi2sdXmmReg64 { dst, src } => {
+                output.push(0xf2);
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0x2a]);
+                output.push(mod_rm_byte_xmm(dst.mod_rm_bits(), src.mod_rm_bits()));
+            }
+
+            Self::Cvttsd2siReg64Xmm { dst, src } => {
+                output.push(0xf2);
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0x2c]);
+                output.push(mod_rm_byte_xmm(dst.mod_rm_bits(), src.mod_rm_bits()));
+            }
+
+            Self::DivsdXmmXmm { dst, src } => {
+                output.extend_from_slice(&[0xf2, 0x0f, 0x5e]);
+                output.push(mod_rm_byte_xmm(dst.mod_rm_bits(), src.mod_rm_bits()));
+            }
+
+            Self::DivReg32 { src } => {
+                output.push(0xf7);
+                output.push(mod_rm_byte_extra_op(6, *src));
+            }
+
+            Self::IdivReg32 { src } => {
+                output.push(0xf7);
+                output.push(mod_rm_byte_extra_op(7, *src));
+            }
+
+            Self::ImulReg32Reg32 { dst, src } => {
+                output.extend_from_slice(&[0x0f, 0xaf]);
+                output.push(mod_rm_byte_reg_reg(*src, *dst));
+            }
+
+            Self::ImulReg32Reg32Imm32 { dst, src, imm } => {
+                output.push(0x69);
+                output.push(mod_rm_byte_reg_reg(*src, *dst));
+                output.extend_from_slice(&imm.to_le_bytes());
+            }
+
             Self::Inc32 { reg } => {
                 output.push(0xff);
                 output.push(mod_rm_byte_reg(*reg));
@@ -198,6 +328,141 @@ impl Amd64Instruction {
                 output.push(offset as u8);
             }
 
+            Self::JgShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JgShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x7f);
+                output.push(offset as u8);
+            }
+
+            Self::JgeShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JgeShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x7d);
+                output.push(offset as u8);
+            }
+
+            Self::JlShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JlShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x7c);
+                output.push(offset as u8);
+            }
+
+            Self::JleShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JleShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x7e);
+                output.push(offset as u8);
+            }
+
+            Self::JaShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JaShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x77);
+                output.push(offset as u8);
+            }
+
+            Self::JaeShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JaeShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x73);
+                output.push(offset as u8);
+            }
+
+            Self::JbShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JbShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x72);
+                output.push(offset as u8);
+            }
+
+            Self::JbeShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JbeShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x76);
+                output.push(offset as u8);
+            }
+
+            Self::JpShort { location } => {
+                let offset = {
+                    let destination = *label_offsets.get(location).unwrap() as isize;
+                    let offset = offset as isize;
+                    (destination - offset - 2) as i64
+                };
+
+                let Ok(offset) = i8::try_from(offset) else {
+                    panic!("JpShort past niet, we willen 0x{offset:x}");
+                };
+
+                output.push(0x7a);
+                output.push(offset as u8);
+            }
+
             Self::LeaReg32FromReg32 { dst, base } => {
                 output.push(0x8d);
                 output.push(mod_rm_no_displacement(*dst, *base));
@@ -246,6 +511,58 @@ impl Amd64Instruction {
                 output.push(*offset as u8);
             }
 
+            Self::MovzxReg32FromPtr8Reg64Off8 { dst, base, offset } => {
+                output.extend_from_slice(&[0x0f, 0xb6]);
+                output.push(mod_rm_8_bit_displacement(*dst, *base));
+                output.push(*offset as u8);
+            }
+
+            Self::MovzxReg32FromPtr16Reg64Off8 { dst, base, offset } => {
+                output.extend_from_slice(&[0x0f, 0xb7]);
+                output.push(mod_rm_8_bit_displacement(*dst, *base));
+                output.push(*offset as u8);
+            }
+
+            Self::MovsxReg64FromPtr8Reg64Off8 { dst, base, offset } => {
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0xbe]);
+                output.push(mod_rm_8_bit_displacement(*dst, *base));
+                output.push(*offset as u8);
+            }
+
+            Self::MovsxReg64FromPtr16Reg64Off8 { dst, base, offset } => {
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0xbf]);
+                output.push(mod_rm_8_bit_displacement(*dst, *base));
+                output.push(*offset as u8);
+            }
+
+            Self::MovzxReg32FromPtr8Reg64Off32 { dst, base, offset } => {
+                output.extend_from_slice(&[0x0f, 0xb6]);
+                output.push(mod_rm_32_bit_displacement(*dst, *base));
+                output.extend_from_slice(&offset.to_le_bytes());
+            }
+
+            Self::MovzxReg32FromPtr16Reg64Off32 { dst, base, offset } => {
+                output.extend_from_slice(&[0x0f, 0xb7]);
+                output.push(mod_rm_32_bit_displacement(*dst, *base));
+                output.extend_from_slice(&offset.to_le_bytes());
+            }
+
+            Self::MovsxReg64FromPtr8Reg64Off32 { dst, base, offset } => {
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0xbe]);
+                output.push(mod_rm_32_bit_displacement(*dst, *base));
+                output.extend_from_slice(&offset.to_le_bytes());
+            }
+
+            Self::MovsxReg64FromPtr16Reg64Off32 { dst, base, offset } => {
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0xbf]);
+                output.push(mod_rm_32_bit_displacement(*dst, *base));
+                output.extend_from_slice(&offset.to_le_bytes());
+            }
+
             Self::MovImm32ToPtrReg64 { base, src } => {
                 output.push(0xc7);
                 output.push(base.mod_rm_bits());
@@ -283,11 +600,53 @@ impl Amd64Instruction {
                 output.push(*offset as u8);
             }
 
+            Self::MovReg8ToPtrReg64Off8 { base, offset, src } => {
+                // Without a REX prefix, these encodings would mean ah, ch, dh and bh.
+                if matches!(src, Amd64Register::Rsp | Amd64Register::Rbp | Amd64Register::Rsi | Amd64Register::Rdi) {
+                    output.push(register_extension(false, false, false, false));
+                }
+
+                output.push(0x88);
+                output.push(mod_rm_8_bit_displacement(*src, *base));
+                output.push(*offset as u8);
+            }
+
+            Self::MovReg16ToPtrReg64Off8 { base, offset, src } => {
+                output.push(0x66);
+                output.push(0x89);
+                output.push(mod_rm_8_bit_displacement(*src, *base));
+                output.push(*offset as u8);
+            }
+
+            Self::MovReg8ToPtrReg64Off32 { base, offset, src } => {
+                // Without a REX prefix, these encodings would mean ah, ch, dh and bh.
+                if matches!(src, Amd64Register::Rsp | Amd64Register::Rbp | Amd64Register::Rsi | Amd64Register::Rdi) {
+                    output.push(register_extension(false, false, false, false));
+                }
+
+                output.push(0x88);
+                output.push(mod_rm_32_bit_displacement(*src, *base));
+                output.extend_from_slice(&offset.to_le_bytes());
+            }
+
+            Self::MovReg16ToPtrReg64Off32 { base, offset, src } => {
+                output.push(0x66);
+                output.push(0x89);
+                output.push(mod_rm_32_bit_displacement(*src, *base));
+                output.extend_from_slice(&offset.to_le_bytes());
+            }
+
             Self::MovReg32Imm32 { dst, src } => {
                 output.push(0xb8 + dst.mod_rm_bits());
                 output.extend_from_slice(&src.to_le_bytes());
             }
 
+            Self::MovReg64Imm64 { dst, src } => {
+                output.push(register_extension(true, false, false, false));
+                output.push(0xb8 + dst.mod_rm_bits());
+                output.extend_from_slice(&src.to_le_bytes());
+            }
+
             Self::MovReg32Reg32 { dst, src } => {
                 output.push(0x89);
                 output.push(mod_rm_byte_reg_reg(*dst, *src));
@@ -299,6 +658,25 @@ impl Amd64Instruction {
                 output.push(mod_rm_byte_reg_reg(*dst, *src));
             }
 
+            Self::MovqXmmReg64 { dst, src } => {
+                output.push(0x66);
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0x6e]);
+                output.push(mod_rm_byte_xmm(dst.mod_rm_bits(), src.mod_rm_bits()));
+            }
+
+            Self::MovqReg64Xmm { dst, src } => {
+                output.push(0x66);
+                output.push(register_extension(true, false, false, false));
+                output.extend_from_slice(&[0x0f, 0x7e]);
+                output.push(mod_rm_byte_xmm(src.mod_rm_bits(), dst.mod_rm_bits()));
+            }
+
+            Self::MulsdXmmXmm { dst, src } => {
+                output.extend_from_slice(&[0xf2, 0x0f, 0x59]);
+                output.push(mod_rm_byte_xmm(dst.mod_rm_bits(), src.mod_rm_bits()));
+            }
+
             Self::PopReg64 { reg } => {
                 output.push(0x58 + reg.mod_rm_bits());
             }
@@ -326,6 +704,16 @@ impl Amd64Instruction {
                 output.push(0x29);
                 output.push(mod_rm_byte_reg_reg(*dst, *src))
             }
+
+            Self::SubsdXmmXmm { dst, src } => {
+                output.extend_from_slice(&[0xf2, 0x0f, 0x5c]);
+                output.push(mod_rm_byte_xmm(dst.mod_rm_bits(), src.mod_rm_bits()));
+            }
+
+            Self::UcomisdXmmXmm { lhs, rhs } => {
+                output.extend_from_slice(&[0x66, 0x0f, 0x2e]);
+                output.push(mod_rm_byte_xmm(lhs.mod_rm_bits(), rhs.mod_rm_bits()));
+            }
         }
     }
 }
@@ -341,10 +729,16 @@ impl Display for Amd64Instruction {
                 f.write_fmt(format_args!("add {}, {}", dst.name32(), src.name32()))
             }
 
+            Self::AddsdXmmXmm { dst, src } => {
+                f.write_fmt(format_args!("addsd {dst}, {src}"))
+            }
+
             Self::CallNearRelative { symbol_name } => {
                 f.write_fmt(format_args!("call {symbol_name}"))
             }
 
+            Self::Cdq => f.write_str("cdq"),
+
             Self::CmpReg32Imm8 { lhs, rhs } => {
                 f.write_fmt(format_args!("cmp {}, 0x{:x}", lhs.name32(), rhs))
             }
@@ -360,6 +754,34 @@ impl Display for Amd64Instruction {
                 f.write_str(rhs.name32())
             }
 
+            Self::Cvtsi2sdXmmReg64 { dst, src } => {
+                f.write_fmt(format_args!("cvtsi2sd {dst}, {}", src.name64()))
+            }
+
+            Self::Cvttsd2siReg64Xmm { dst, src } => {
+                f.write_fmt(format_args!("cvttsd2si {}, {src}", dst.name64()))
+            }
+
+            Self::DivsdXmmXmm { dst, src } => {
+                f.write_fmt(format_args!("divsd {dst}, {src}"))
+            }
+
+            Self::DivReg32 { src } => {
+                f.write_fmt(format_args!("div {}", src.name32()))
+            }
+
+            Self::IdivReg32 { src } => {
+                f.write_fmt(format_args!("idiv {}", src.name32()))
+            }
+
+            Self::ImulReg32Reg32 { dst, src } => {
+                f.write_fmt(format_args!("imul {}, {}", dst.name32(), src.name32()))
+            }
+
+            Self::ImulReg32Reg32Imm32 { dst, src, imm } => {
+                f.write_fmt(format_args!("imul {}, {}, 0x{imm:x}", dst.name32(), src.name32()))
+            }
+
             Self::Inc32 { reg } => {
                 f.write_str("inc ")?;
                 f.write_str(reg.name32())
@@ -377,6 +799,42 @@ impl Display for Amd64Instruction {
                 f.write_fmt(format_args!("jne {location}"))
             }
 
+            Self::JgShort { location } => {
+                f.write_fmt(format_args!("jg {location}"))
+            }
+
+            Self::JgeShort { location } => {
+                f.write_fmt(format_args!("jge {location}"))
+            }
+
+            Self::JlShort { location } => {
+                f.write_fmt(format_args!("jl {location}"))
+            }
+
+            Self::JleShort { location } => {
+                f.write_fmt(format_args!("jle {location}"))
+            }
+
+            Self::JaShort { location } => {
+                f.write_fmt(format_args!("ja {location}"))
+            }
+
+            Self::JaeShort { location } => {
+                f.write_fmt(format_args!("jae {location}"))
+            }
+
+            Self::JbShort { location } => {
+                f.write_fmt(format_args!("jb {location}"))
+            }
+
+            Self::JbeShort { location } => {
+                f.write_fmt(format_args!("jbe {location}"))
+            }
+
+            Self::JpShort { location } => {
+                f.write_fmt(format_args!("jp {location}"))
+            }
+
             Self::LeaReg32FromReg32 { dst, base } => {
                 f.write_fmt(format_args!("lea {}, [{}]", dst.name32(), base.name32()))
             }
@@ -409,6 +867,38 @@ impl Display for Amd64Instruction {
                 f.write_fmt(format_args!("mov {}, [{} + 0x{offset:x}]", dst.name64(), base.name64()))
             }
 
+            Self::MovzxReg32FromPtr8Reg64Off8 { dst, base, offset } => {
+                f.write_fmt(format_args!("movzx {}, byte [{} + 0x{offset:x}]", dst.name32(), base.name64()))
+            }
+
+            Self::MovzxReg32FromPtr16Reg64Off8 { dst, base, offset } => {
+                f.write_fmt(format_args!("movzx {}, word [{} + 0x{offset:x}]", dst.name32(), base.name64()))
+            }
+
+            Self::MovsxReg64FromPtr8Reg64Off8 { dst, base, offset } => {
+                f.write_fmt(format_args!("movsx {}, byte [{} + 0x{offset:x}]", dst.name64(), base.name64()))
+            }
+
+            Self::MovsxReg64FromPtr16Reg64Off8 { dst, base, offset } => {
+                f.write_fmt(format_args!("movsx {}, word [{} + 0x{offset:x}]", dst.name64(), base.name64()))
+            }
+
+            Self::MovzxReg32FromPtr8Reg64Off32 { dst, base, offset } => {
+                f.write_fmt(format_args!("movzx {}, byte [{} + 0x{offset:x}]", dst.name32(), base.name64()))
+            }
+
+            Self::MovzxReg32FromPtr16Reg64Off32 { dst, base, offset } => {
+                f.write_fmt(format_args!("movzx {}, word [{} + 0x{offset:x}]", dst.name32(), base.name64()))
+            }
+
+            Self::MovsxReg64FromPtr8Reg64Off32 { dst, base, offset } => {
+                f.write_fmt(format_args!("movsx {}, byte [{} + 0x{offset:x}]", dst.name64(), base.name64()))
+            }
+
+            Self::MovsxReg64FromPtr16Reg64Off32 { dst, base, offset } => {
+                f.write_fmt(format_args!("movsx {}, word [{} + 0x{offset:x}]", dst.name64(), base.name64()))
+            }
+
             Self::MovImm32ToPtrReg64 { base, src } => {
                 f.write_fmt(format_args!("mov [{}], 0x{src:x}", base.name64()))
             }
@@ -433,10 +923,30 @@ impl Display for Amd64Instruction {
                 f.write_fmt(format_args!("mov [{} + 0x{offset:x}], {}", base.name64(), src.name64()))
             }
 
+            Self::MovReg8ToPtrReg64Off8 { base, offset, src } => {
+                f.write_fmt(format_args!("mov [{} + 0x{offset:x}], {}", base.name64(), src.name8()))
+            }
+
+            Self::MovReg16ToPtrReg64Off8 { base, offset, src } => {
+                f.write_fmt(format_args!("mov [{} + 0x{offset:x}], {}", base.name64(), src.name16()))
+            }
+
+            Self::MovReg8ToPtrReg64Off32 { base, offset, src } => {
+                f.write_fmt(format_args!("mov [{} + 0x{offset:x}], {}", base.name64(), src.name8()))
+            }
+
+            Self::MovReg16ToPtrReg64Off32 { base, offset, src } => {
+                f.write_fmt(format_args!("mov [{} + 0x{offset:x}], {}", base.name64(), src.name16()))
+            }
+
             Self::MovReg32Imm32 { dst, src } => {
                 f.write_fmt(format_args!("mov {}, 0x{src:x}", dst.name32()))
             }
 
+            Self::MovReg64Imm64 { dst, src } => {
+                f.write_fmt(format_args!("mov {}, 0x{src:x}", dst.name64()))
+            }
+
             Self::MovReg32Reg32 { dst, src } => {
                 f.write_fmt(format_args!("mov {}, {}", dst.name32(), src.name32()))
             }
@@ -445,6 +955,18 @@ impl Display for Amd64Instruction {
                 f.write_fmt(format_args!("mov {}, {}", dst.name64(), src.name64()))
             }
 
+            Self::MovqXmmReg64 { dst, src } => {
+                f.write_fmt(format_args!("movq {dst}, {}", src.name64()))
+            }
+
+            Self::MovqReg64Xmm { dst, src } => {
+                f.write_fmt(format_args!("movq {}, {src}", dst.name64()))
+            }
+
+            Self::MulsdXmmXmm { dst, src } => {
+                f.write_fmt(format_args!("mulsd {dst}, {src}"))
+            }
+
             Self::PopReg64 { reg } => {
                 f.write_fmt(format_args!("pop {}", reg.name64()))
             }
@@ -466,6 +988,14 @@ impl Display for Amd64Instruction {
             Self::SubReg32Reg32 { dst, src } => {
                 f.write_fmt(format_args!("sub {}, {}", dst.name32(), src.name32()))
             }
+
+            Self::SubsdXmmXmm { dst, src } => {
+                f.write_fmt(format_args!("subsd {dst}, {src}"))
+            }
+
+            Self::UcomisdXmmXmm { lhs, rhs } => {
+                f.write_fmt(format_args!("ucomisd {lhs}, {rhs}"))
+            }
         }
     }
 }
@@ -497,6 +1027,13 @@ fn mod_rm_byte_reg_reg(dst: Amd64Register, src: Amd64Register) -> u8 {
     byte
 }
 
+/// Creates a register-direct ModR/M byte from the raw register numbers, used
+/// by the SSE instructions that mix general-purpose and `xmm` registers.
+#[must_use]
+fn mod_rm_byte_xmm(reg: u8, rm: u8) -> u8 {
+    0b11_000_000 | (reg << 3) | rm
+}
+
 /// Extra op meaning the number after the slash (e.g. /7 is 7)
 #[must_use]
 fn mod_rm_byte_extra_op(rm: u8, reg: Amd64Register) -> u8 {
@@ -517,6 +1054,16 @@ fn mod_rm_8_bit_displacement(dst: Amd64Register, src: Amd64Register) -> u8 {
     byte
 }
 
+#[must_use]
+fn mod_rm_32_bit_displacement(dst: Amd64Register, src: Amd64Register) -> u8 {
+    let mut byte = 0b10_000_000;
+
+    byte |= dst.mod_rm_bits() << 3;
+    byte |= src.mod_rm_bits();
+
+    byte
+}
+
 #[must_use]
 fn mod_rm_8_bit_displacement_single(reg: Amd64Register) -> u8 {
     let mut byte = 0b01_000_000;
@@ -581,6 +1128,30 @@ mod tests {
         Amd64Instruction::SubReg32Reg32 { dst: Amd64Register::Rax, src: Amd64Register::Rsi },
         [ 0x29, 0xf0 ].to_vec(),
     )]
+    #[case(
+        Amd64Instruction::ImulReg32Reg32 { dst: Amd64Register::Rax, src: Amd64Register::Rdx },
+        [ 0x0f, 0xaf, 0xc2 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::ImulReg32Reg32Imm32 { dst: Amd64Register::Rax, src: Amd64Register::Rcx, imm: 300 },
+        [ 0x69, 0xc1, 0x2c, 0x01, 0x00, 0x00 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::Cdq,
+        [ 0x99 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::IdivReg32 { src: Amd64Register::Rcx },
+        [ 0xf7, 0xf9 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::DivReg32 { src: Amd64Register::Rbx },
+        [ 0xf7, 0xf3 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovReg64Imm64 { dst: Amd64Register::Rcx, src: 0x400921fb54442d18 },
+        [ 0x48, 0xb9, 0x18, 0x2d, 0x44, 0x54, 0xfb, 0x21, 0x09, 0x40 ].to_vec(),
+    )]
     fn check_encoding(#[case] input: Amd64Instruction, #[case] expected: Vec<u8>) {
         let mut actual = Vec::new();
         input.encode(&mut actual, 0, &HashMap::new());
@@ -625,10 +1196,94 @@ mod tests {
         Amd64Instruction::MovImm32ToPtrReg64Off8 { base: Amd64Register::Rdi, offset: 4, src: 62 },
         [ 0xc7, 0x47, 0x04, 62, 0x00, 0x00, 0x00 ].to_vec(),
     )]
+    #[case(
+        Amd64Instruction::MovzxReg32FromPtr8Reg64Off8 { dst: Amd64Register::Rax, base: Amd64Register::Rcx, offset: 4 },
+        [ 0x0f, 0xb6, 0x41, 0x04 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovsxReg64FromPtr16Reg64Off8 { dst: Amd64Register::Rax, base: Amd64Register::Rcx, offset: 4 },
+        [ 0x48, 0x0f, 0xbf, 0x41, 0x04 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovzxReg32FromPtr16Reg64Off8 { dst: Amd64Register::Rax, base: Amd64Register::Rcx, offset: 4 },
+        [ 0x0f, 0xb7, 0x41, 0x04 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovsxReg64FromPtr8Reg64Off8 { dst: Amd64Register::Rax, base: Amd64Register::Rcx, offset: -4 },
+        [ 0x48, 0x0f, 0xbe, 0x41, 0xfc ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovzxReg32FromPtr8Reg64Off32 { dst: Amd64Register::Rax, base: Amd64Register::Rcx, offset: 0x100 },
+        [ 0x0f, 0xb6, 0x81, 0x00, 0x01, 0x00, 0x00 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovzxReg32FromPtr16Reg64Off32 { dst: Amd64Register::Rax, base: Amd64Register::Rdx, offset: 0x200 },
+        [ 0x0f, 0xb7, 0x82, 0x00, 0x02, 0x00, 0x00 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovsxReg64FromPtr8Reg64Off32 { dst: Amd64Register::Rax, base: Amd64Register::Rdx, offset: 0x200 },
+        [ 0x48, 0x0f, 0xbe, 0x82, 0x00, 0x02, 0x00, 0x00 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovsxReg64FromPtr16Reg64Off32 { dst: Amd64Register::Rax, base: Amd64Register::Rcx, offset: 0x100 },
+        [ 0x48, 0x0f, 0xbf, 0x81, 0x00, 0x01, 0x00, 0x00 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovReg8ToPtrReg64Off8 { base: Amd64Register::Rdi, offset: 8, src: Amd64Register::Rsi },
+        [ 0x40, 0x88, 0x77, 0x08 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovReg16ToPtrReg64Off8 { base: Amd64Register::Rcx, offset: 2, src: Amd64Register::Rax },
+        [ 0x66, 0x89, 0x41, 0x02 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovReg8ToPtrReg64Off32 { base: Amd64Register::Rdi, offset: 0x100, src: Amd64Register::Rsi },
+        [ 0x40, 0x88, 0xb7, 0x00, 0x01, 0x00, 0x00 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovReg16ToPtrReg64Off32 { base: Amd64Register::Rcx, offset: 0x200, src: Amd64Register::Rax },
+        [ 0x66, 0x89, 0x81, 0x00, 0x02, 0x00, 0x00 ].to_vec(),
+    )]
     fn check_encoding_mov_deref(#[case] input: Amd64Instruction, #[case] expected: Vec<u8>) {
         let mut actual = Vec::new();
         input.encode(&mut actual, 0, &HashMap::new());
 
         assert_eq!(actual, expected, "actual wasn't the expected! Instruction was: {input}");
     }
+
+    #[rstest]
+    #[case(
+        Amd64Instruction::MovqXmmReg64 { dst: Amd64FloatRegister::Xmm0, src: Amd64Register::Rax },
+        [ 0x66, 0x48, 0x0f, 0x6e, 0xc0 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::MovqReg64Xmm { dst: Amd64Register::Rdx, src: Amd64FloatRegister::Xmm1 },
+        [ 0x66, 0x48, 0x0f, 0x7e, 0xca ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::AddsdXmmXmm { dst: Amd64FloatRegister::Xmm0, src: Amd64FloatRegister::Xmm1 },
+        [ 0xf2, 0x0f, 0x58, 0xc1 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::DivsdXmmXmm { dst: Amd64FloatRegister::Xmm1, src: Amd64FloatRegister::Xmm0 },
+        [ 0xf2, 0x0f, 0x5e, 0xc8 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::UcomisdXmmXmm { lhs: Amd64FloatRegister::Xmm0, rhs: Amd64FloatRegister::Xmm1 },
+        [ 0x66, 0x0f, 0x2e, 0xc1 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::Cvtsi2sdXmmReg64 { dst: Amd64FloatRegister::Xmm0, src: Amd64Register::Rcx },
+        [ 0xf2, 0x48, 0x0f, 0x2a, 0xc1 ].to_vec(),
+    )]
+    #[case(
+        Amd64Instruction::Cvttsd2siReg64Xmm { dst: Amd64Register::Rax, src: Amd64FloatRegister::Xmm0 },
+        [ 0xf2, 0x48, 0x0f, 0x2c, 0xc0 ].to_vec(),
+    )]
+    fn check_encoding_sse(#[case] input: Amd64Instruction, #[case] expected: Vec<u8>) {
+        let mut actual = Vec::new();
+        input.encode(&mut actual, 0, &HashMap::new());
+
+        assert_eq!(actual, expected, "actual wasn't the expected! Instruction was: {input}");
+    }
 }
@@ -12,6 +12,7 @@ use self::{
     instruction::Amd64Instruction,
     function_characteristics::Amd64FunctionCharacteristics,
     register::{
+        Amd64FloatRegister,
         Amd64Register,
         Amd64RegisterNameMode,
     },
@@ -188,6 +188,37 @@ impl Display for Amd64Register {
     }
 }
 
+/// The SSE registers, currently only used as scratch registers for the
+/// floating-point operations, since the values themselves live in the
+/// general-purpose registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Amd64FloatRegister {
+    Xmm0,
+    Xmm1,
+}
+
+impl Amd64FloatRegister {
+    #[must_use]
+    pub const fn mod_rm_bits(&self) -> u8 {
+        *self as u8
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Xmm0 => "xmm0",
+            Self::Xmm1 => "xmm1",
+        }
+    }
+}
+
+impl Display for Amd64FloatRegister {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Amd64RegisterNameMode {
     Quad,
@@ -28,11 +28,16 @@ impl LifeAnalysis {
 
     fn add_instruction(&mut self, index: usize, instruction: &Instruction) {
         match instruction {
-            Instruction::Compare { lhs, rhs } => {
+            Instruction::Compare { lhs, rhs, .. } => {
                 self.add_lifetime(lhs, index);
                 self.try_add_lifetime(rhs, index);
             }
 
+            Instruction::CompareFloatingPoint { lhs, rhs } => {
+                self.add_lifetime(lhs, index);
+                self.add_lifetime(rhs, index);
+            }
+
             Instruction::Increment { register } => {
                 self.add_lifetime(register, index);
             }
@@ -82,6 +87,19 @@ impl LifeAnalysis {
                 self.try_add_lifetime(rhs, index);
             }
 
+            Instruction::FloatingPointMath { operation, destination, lhs, rhs } => {
+                _ = operation;
+                self.add_lifetime(destination, index);
+                self.add_lifetime(lhs, index);
+                self.add_lifetime(rhs, index);
+            }
+
+            Instruction::ConvertIntegerToFloatingPoint { destination, source } |
+            Instruction::ConvertFloatingPointToInteger { destination, source } => {
+                self.add_lifetime(destination, index);
+                self.add_lifetime(source, index);
+            }
+
             Instruction::StackAlloc { dst, size } => {
                 _ = size;
                 self.add_lifetime(dst, index);
@@ -4,11 +4,11 @@
 //! The compiler class takes a [`ParseTree`] as input, turns it into IR, runs
 //! the optimizer, and generates machine code.
 
-use std::rc::Rc;
+use std::{collections::HashMap, rc::Rc};
 
 use babbelaar::*;
 
-use crate::{optimize_program, ArgumentList, FunctionBuilder, Immediate, MathOperation, Operand, PrimitiveType, Program, ProgramBuilder, Register, TypeId};
+use crate::{optimize_program, ArgumentList, CompileError, FloatingPointOperation, FunctionBuilder, Immediate, Label, MathOperation, Operand, PrimitiveType, Program, ProgramBuilder, Register, TypeId};
 
 #[derive(Debug)]
 pub struct Compiler {
@@ -22,45 +22,279 @@ impl Compiler {
         }
     }
 
-    pub fn compile_trees(&mut self, trees: &[ParseTree]) {
+    /// Compiles the given trees, which must be free of semantic errors. The
+    /// constructs the compiler doesn't support yet are returned as errors.
+    pub fn compile_trees(&mut self, trees: &[ParseTree]) -> Result<(), Vec<CompileError>> {
+        self.evaluate_constants(trees);
+        self.declare_functions(trees);
         self.layout_structures(trees);
+        self.declare_operator_methods(trees);
+        self.declare_generic_methods(trees);
+        self.declare_tuple_returns(trees);
         self.compile_methods(trees);
         self.compile_functions(trees);
+        self.compile_generic_instantiations();
+
+        let errors = self.program_builder.take_errors();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn evaluate_constants(&mut self, trees: &[ParseTree]) {
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in order_constants_by_dependency(tree.constants(), Statement::as_constant) {
+                let StatementKind::Constant(constant) = &statement.kind else {
+                    panic!();
+                };
+
+                let value = evaluate_constant(constant, None, |owner, name| self.program_builder.resolve_constant(owner, name));
+                self.program_builder.add_constant(constant.name.value(), value);
+            }
+        }
+
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in tree.structures() {
+                let StatementKind::Structure(structure) = &statement.kind else {
+                    continue;
+                };
+
+                let mut siblings = HashMap::new();
+                for constant in order_constants_by_dependency(&structure.constants, Some) {
+                    let structure = Some((structure.name.value(), &siblings));
+                    let value = evaluate_constant(constant, structure, |owner, name| self.program_builder.resolve_constant(owner, name));
+                    siblings.insert(constant.name.value().clone(), value);
+                }
+
+                for (name, value) in siblings {
+                    self.program_builder.add_structure_constant(structure.name.value(), &name, value);
+                }
+            }
+        }
+    }
+
+    fn declare_functions(&mut self, trees: &[ParseTree]) {
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in tree.functions() {
+                let StatementKind::Function(func) = &statement.kind else {
+                    panic!();
+                };
+
+                if func.body.is_none() {
+                    continue;
+                }
+
+                self.program_builder.declare_function(&func.name);
+
+                if !func.generic_types.is_empty() {
+                    let name = create_mangled_name(&self.program_builder.qualify(&func.name));
+                    self.program_builder.add_generic_function(name, func, CallingConvention::Regular);
+                }
+            }
+        }
     }
 
     fn layout_structures(&mut self, trees: &[ParseTree]) {
-        for statement in trees.iter().flat_map(|t| t.structures()) {
-            let StatementKind::Structure(structure) = &statement.kind else {
-                panic!();
-            };
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in tree.structures() {
+                match &statement.kind {
+                    StatementKind::Structure(structure) => self.program_builder.add_structure(structure),
+                    StatementKind::Enumeration(enumeration) => self.program_builder.add_enumeration(enumeration),
+                    _ => panic!(),
+                }
+            }
+        }
+    }
+
+    /// Operators used on structures are lowered to calls to the methods of
+    /// the builtin interfaces, e.g. `Optelbaar` for `+`, so these have to be
+    /// known before compiling any function.
+    fn declare_operator_methods(&mut self, trees: &[ParseTree]) {
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in tree.extensions() {
+                let StatementKind::Extension(extension) = &statement.kind else {
+                    continue;
+                };
+
+                let TypeSpecifier::Custom { name, .. } = extension.type_specifier.value() else {
+                    continue;
+                };
 
-            self.program_builder.add_structure(structure);
+                let Some(interface) = extension.interface_specifier.as_ref()
+                    .and_then(|x| Builtin::interface_by_name(&x.name)) else {
+                    continue;
+                };
+
+                if !extension.generic_types.is_empty() {
+                    continue;
+                }
+
+                let type_id = self.program_builder.type_id_for_structure(name);
+                let structure_name = self.program_builder.structure_name(type_id);
+                let name = create_mangled_method_name(structure_name, &BabString::new_static(interface.method_name));
+                self.program_builder.declare_operator_method(name);
+            }
+        }
+    }
+
+    /// Generic methods are only compiled when they are called, which can be
+    /// from any function, so they have to be known before compiling those.
+    fn declare_generic_methods(&mut self, trees: &[ParseTree]) {
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in tree.structures() {
+                let StatementKind::Structure(structure) = &statement.kind else {
+                    continue;
+                };
+
+                let type_id = self.program_builder.type_id_for_structure(structure.name.value());
+                let structure_name = self.program_builder.qualify(structure.name.value());
+
+                for method in &structure.methods {
+                    if !method.function.generic_types.is_empty() {
+                        let name = create_mangled_method_name(&structure_name, method.function.name.value());
+                        let call_convention = CallingConvention::for_method(method, type_id);
+                        self.program_builder.add_generic_function(name, &method.function, call_convention);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The callers of functions returning a tuple have to allocate it, so
+    /// these are known before compiling any function. For instances of
+    /// generic functions, this happens when they are instantiated.
+    fn declare_tuple_returns(&mut self, trees: &[ParseTree]) {
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in tree.functions() {
+                let StatementKind::Function(func) = &statement.kind else {
+                    panic!();
+                };
+
+                if func.body.is_some() && func.generic_types.is_empty() {
+                    let name = create_mangled_name(&self.program_builder.qualify(&func.name));
+                    self.declare_tuple_return(func, name);
+                }
+            }
+
+            for statement in tree.structures() {
+                let StatementKind::Structure(structure) = &statement.kind else {
+                    continue;
+                };
+
+                let structure_name = self.program_builder.qualify(structure.name.value());
+
+                for method in &structure.methods {
+                    if method.function.generic_types.is_empty() {
+                        let name = create_mangled_method_name(&structure_name, method.function.name.value());
+                        self.declare_tuple_return(&method.function, name);
+                    }
+                }
+            }
+        }
+    }
+
+    fn declare_tuple_return(&mut self, func: &FunctionStatement, name: BabString) {
+        let Some(return_type) = &func.return_type else {
+            return;
+        };
+
+        if let TypeSpecifier::Tuple(..) = return_type.specifier.value() {
+            let return_type = self.program_builder.type_id_for(return_type, &HashMap::new());
+            self.program_builder.declare_tuple_return(name, return_type);
         }
     }
 
     fn compile_functions(&mut self, trees: &[ParseTree]) {
-        for statement in trees.iter().flat_map(|t| t.functions()) {
-            let StatementKind::Function(func) = &statement.kind else {
-                panic!();
-            };
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
+
+            for statement in tree.functions() {
+                let StatementKind::Function(func) = &statement.kind else {
+                    panic!();
+                };
 
-            self.compile_function(func, func.name.value().clone(), CallingConvention::Regular);
+                if !func.generic_types.is_empty() {
+                    continue;
+                }
+
+                let name = create_mangled_name(&self.program_builder.qualify(&func.name));
+                self.compile_function(func, name, CallingConvention::Regular, &HashMap::new());
+            }
+        }
+    }
+
+    /// Compiles the instances of the generic functions, which are requested
+    /// while compiling the calls to them. Compiling an instance can request
+    /// other instances, until all calls are resolved.
+    fn compile_generic_instantiations(&mut self) {
+        while let Some(instantiation) = self.program_builder.next_generic_instantiation() {
+            let function = instantiation.function;
+            self.program_builder.set_module(function.module);
+
+            self.compile_function(&function.function, instantiation.name, function.call_convention, &instantiation.generic_types);
         }
     }
 
     fn compile_methods(&mut self, trees: &[ParseTree]) {
-        for statement in trees.iter().flat_map(|t| t.structures()) {
-            let StatementKind::Structure(structure) = &statement.kind else {
-                panic!();
-            };
+        for tree in trees {
+            self.program_builder.set_module(tree.module_path());
 
-            let type_id = self.program_builder.type_id_for_structure(structure.name.value());
+            for statement in tree.structures() {
+                let StatementKind::Structure(structure) = &statement.kind else {
+                    continue;
+                };
+
+                let type_id = self.program_builder.type_id_for_structure(structure.name.value());
+                let structure_name = self.program_builder.qualify(structure.name.value());
+
+                for method in &structure.methods {
+                    if !method.function.generic_types.is_empty() {
+                        continue;
+                    }
 
-            for method in &structure.methods {
-                let name = create_mangled_method_name(structure.name.value(), method.function.name.value());
-                self.compile_function(&method.function, name, CallingConvention::Method {
-                    this: type_id,
-                });
+                    let name = create_mangled_method_name(&structure_name, method.function.name.value());
+                    self.compile_function(&method.function, name, CallingConvention::for_method(method, type_id), &HashMap::new());
+                }
+            }
+
+            for statement in tree.extensions() {
+                let StatementKind::Extension(extension) = &statement.kind else {
+                    continue;
+                };
+
+                // Only the methods of non-generic extensions of structures
+                // are supported for now.
+                let TypeSpecifier::Custom { name, .. } = extension.type_specifier.value() else {
+                    continue;
+                };
+
+                if !extension.generic_types.is_empty() {
+                    continue;
+                }
+
+                let type_id = self.program_builder.type_id_for_structure(name);
+                let structure_name = self.program_builder.structure_name(type_id).clone();
+
+                for method in &extension.methods {
+                    let name = create_mangled_method_name(&structure_name, method.function.name.value());
+                    self.compile_function(&method.function, name, CallingConvention::for_method(method, type_id), &HashMap::new());
+                }
             }
         }
     }
@@ -72,7 +306,9 @@ impl Compiler {
         program
     }
 
-    fn compile_function(&mut self, func: &FunctionStatement, name: BabString, call_convention: CallingConvention) {
+    /// Compiles a function, where the `generic_types` contain the actual types
+    /// of the generic types, if this is an instance of a generic function.
+    fn compile_function(&mut self, func: &FunctionStatement, name: BabString, call_convention: CallingConvention, generic_types: &HashMap<BabString, TypeId>) {
         let Some(body) = &func.body else {
             return;
         };
@@ -80,18 +316,26 @@ impl Compiler {
         let mut arguments = ArgumentList::new();
 
         match call_convention {
-            CallingConvention::Regular => (),
+            CallingConvention::Regular | CallingConvention::Static { .. } => (),
             CallingConvention::Method { this } => {
                 arguments.add_this(this);
             }
         }
 
         for parameter in &func.parameters {
-            let type_id = self.program_builder.type_id_for_structure(&parameter.ty.specifier.unqualified_name());
+            let type_id = self.program_builder.type_id_for(&parameter.ty, generic_types);
             arguments.add(parameter.name.value(), type_id);
         }
 
+        if let Some(return_type) = self.program_builder.tuple_return_type(&name) {
+            arguments.add_return_pointer(return_type);
+        }
+
         self.program_builder.build_function(name, arguments, |builder| {
+            if let CallingConvention::Static { owner } = call_convention {
+                builder.set_owner(owner);
+            }
+
             for statement in body {
                 statement.compile(builder);
             }
@@ -101,11 +345,131 @@ impl Compiler {
     }
 }
 
+/// Creates the symbol name of a function. The periods in the names of
+/// functions inside a module (e.g. `pad.naar.module.naam`) aren't valid in
+/// symbols, so they are replaced.
+#[must_use]
+fn create_mangled_name(name: &str) -> BabString {
+    BabString::new(name.replace('.', "__"))
+}
+
 #[must_use]
 fn create_mangled_method_name(structure: &BabString, method: &BabString) -> BabString {
-    BabString::new(
-        format!("{structure}__{method}")
-    )
+    create_mangled_name(&format!("{structure}__{method}"))
+}
+
+/// Computes the value of a `vast` at compile time. The `structure` contains
+/// the previously declared constants of the same structure, if applicable.
+#[must_use]
+fn evaluate_constant(
+    constant: &ConstantStatement,
+    structure: Option<(&BabString, &HashMap<BabString, ConstantValue>)>,
+    resolve: impl Fn(Option<&BabString>, &BabString) -> Option<ConstantValue>,
+) -> ConstantValue {
+    let mut evaluator = ConstantEvaluator::new(|owner: Option<&BabString>, name: &BabString| {
+        let sibling = structure
+            .filter(|(structure, _)| owner.is_none_or(|owner| owner == *structure))
+            .and_then(|(_, constants)| constants.get(name));
+
+        match sibling {
+            Some(sibling) => Some(sibling.clone()),
+            None => resolve(owner, name),
+        }
+    }).with_declared_type(constant.ty.value());
+
+    match evaluator.evaluate(&constant.value) {
+        Ok(value) => value,
+        Err(error) => panic!("ICE: vaste waarde `{}` kon niet uitgerekend worden: {error}", constant.name.value()),
+    }
+}
+
+/// Computes the value of an integer expression at compile time, if possible.
+#[must_use]
+fn evaluate_integer(expression: &Ranged<Expression>, builder: &FunctionBuilder) -> Option<i64> {
+    let mut evaluator = ConstantEvaluator::new(|owner: Option<&BabString>, name: &BabString| match owner {
+        Some(owner) => builder.structure_constant(owner, name),
+        None => builder.constant(name),
+    });
+
+    match evaluator.evaluate(expression) {
+        Ok(ConstantValue::Integer { value, .. }) => Some(value),
+        _ => None,
+    }
+}
+
+/// The step of a `reeks` decides the direction of the comparisons of the
+/// loop. When it isn't known at compile time, its sign is checked at runtime.
+#[derive(Debug, Clone, Copy)]
+enum RangeStep {
+    Constant(i64),
+    Register(Register),
+}
+
+impl RangeStep {
+    #[must_use]
+    fn compile(step: Option<&Ranged<Expression>>, builder: &mut FunctionBuilder) -> Self {
+        let Some(step) = step else {
+            return Self::Constant(1);
+        };
+
+        match evaluate_integer(step, builder) {
+            Some(step) => Self::Constant(step),
+            None => Self::Register(step.compile(builder).to_readable(builder)),
+        }
+    }
+}
+
+/// The comparison between the current value and the end of a `reeks` that
+/// holds as long as the loop should continue.
+#[must_use]
+fn range_continue_comparison(step_is_negative: bool, is_inclusive: bool) -> Comparison {
+    match (step_is_negative, is_inclusive) {
+        (false, false) => Comparison::LessThan,
+        (false, true) => Comparison::LessThanOrEqual,
+        (true, false) => Comparison::GreaterThan,
+        (true, true) => Comparison::GreaterThanOrEqual,
+    }
+}
+
+/// The registers of a `volg` over a `reeks`.
+struct RangeLoop {
+    current: Register,
+    end: Register,
+    step: RangeStep,
+    type_id: TypeId,
+    is_inclusive: bool,
+}
+
+impl RangeLoop {
+    /// Jumps to `body` when the loop continues with the current value, and to
+    /// `after` otherwise.
+    fn jump_if_continues(&self, builder: &mut FunctionBuilder, body: Label, after: Label) {
+        match self.step {
+            RangeStep::Constant(step) => {
+                compare_integers(builder, self.current, self.end, self.type_id);
+                jump_if(builder, range_continue_comparison(step.is_negative(), self.is_inclusive), body);
+            }
+
+            RangeStep::Register(step) => {
+                let negative = builder.create_label("volg-negatieve-stap");
+
+                compare_integers(builder, step, Immediate::Integer64(0), self.type_id);
+                // A step of zero would never reach the end.
+                builder.jump_if_equal(after);
+                builder.jump_if_less(negative);
+
+                compare_integers(builder, self.current, self.end, self.type_id);
+                jump_if(builder, range_continue_comparison(false, self.is_inclusive), body);
+                builder.jump(after);
+
+                builder.link_label_here(negative);
+                compare_integers(builder, self.current, self.end, self.type_id);
+                jump_if(builder, range_continue_comparison(true, self.is_inclusive), body);
+            }
+        }
+
+        builder.jump(after);
+    }
 }
 
 trait CompileStatement {
@@ -119,16 +483,32 @@ impl CompileStatement for Statement {
                 statement.compile(builder);
             }
 
+            StatementKind::Break(..) => {
+                builder.jump_to_loop_break();
+            }
+
+            StatementKind::Constant(statement) => {
+                statement.compile(builder);
+            }
+
+            StatementKind::Continue(..) => {
+                builder.jump_to_loop_continue();
+            }
+
+            StatementKind::Enumeration(statement) => {
+                _ = statement;
+            }
+
             StatementKind::Expression(expression) => {
                 _ = expression.compile(builder);
             }
 
-            StatementKind::Extension(statement) => {
-                statement.compile(builder);
+            StatementKind::Extension(..) => {
+                builder.report_unsupported("Een uitbreiding binnen een werkwijze", self.range);
             }
 
-            StatementKind::Function(statement) => {
-                statement.compile(builder);
+            StatementKind::Function(..) => {
+                builder.report_unsupported("Een werkwijze binnen een werkwijze", self.range);
             }
 
             StatementKind::For(statement) => {
@@ -139,8 +519,8 @@ impl CompileStatement for Statement {
                 statement.compile(builder);
             }
 
-            StatementKind::Interface(statement) => {
-                statement.compile(builder);
+            StatementKind::Interface(..) => {
+                builder.report_unsupported("Een koppelvlak binnen een werkwijze", self.range);
             }
 
             StatementKind::Return(statement) => {
@@ -154,30 +534,71 @@ impl CompileStatement for Statement {
             StatementKind::Variable(statement) => {
                 statement.compile(builder);
             }
+
+            StatementKind::While(statement) => {
+                statement.compile(builder);
+            }
         }
     }
 }
 
+impl CompileStatement for ConstantStatement {
+    fn compile(&self, builder: &mut FunctionBuilder) {
+        let value = evaluate_constant(self, None, |owner, name| match owner {
+            Some(owner) => builder.structure_constant(owner, name),
+            None => builder.constant(name),
+        });
+
+        builder.associate_constant_to_local(self.name.value(), value);
+    }
+}
+
 impl CompileStatement for AssignStatement {
     fn compile(&self, builder: &mut FunctionBuilder) {
-        let destination = self.destination.compile(builder).to_readable(builder);
+        if let Some(operator) = self.operator {
+            compile_compound_assignment(builder, self, operator);
+            return;
+        }
+
+        let destination = self.destination.compile(builder);
+
+        // Fields and elements of arrays are stored in memory, not in the register.
+        if let ExpressionResultKind::PointerRegister { base_ptr, offset, typ } = destination.kind {
+            let source = self.source.compile(builder).to_readable(builder);
+            builder.store_ptr(base_ptr, Operand::Immediate(Immediate::Integer64(offset as _)), source, typ);
+            return;
+        }
+
+        let destination = destination.to_readable(builder);
         println!("Dest {} is at {destination}", self.source.value());
         let source = self.source.compile(builder).to_readable(builder);
         builder.move_register(destination, source);
     }
 }
 
-impl CompileStatement for ExtensionStatement {
-    fn compile(&self, builder: &mut FunctionBuilder) {
-        _ = builder;
-        todo!();
-    }
-}
+/// Compiles e.g. `a += b` to a single read-modify-write, such that the
+/// destination is only computed once.
+fn compile_compound_assignment(builder: &mut FunctionBuilder, statement: &AssignStatement, operator: MathOperator) {
+    let destination = statement.destination.compile(builder);
+    let type_id = destination.type_id;
+
+    match destination.kind {
+        ExpressionResultKind::PointerRegister { base_ptr, offset, typ } => {
+            let current = builder.load_ptr(base_ptr, Immediate::Integer64(offset as _), typ);
+            let source = statement.source.compile(builder).to_readable(builder);
+            let result = compile_math_operator(builder, operator, statement.equals_sign, type_id, current, source);
+            builder.store_ptr(base_ptr, Operand::Immediate(Immediate::Integer64(offset as _)), result, typ);
+        }
 
-impl CompileStatement for FunctionStatement {
-    fn compile(&self, builder: &mut FunctionBuilder) {
-        _ = builder;
-        todo!();
+        ExpressionResultKind::Register(current) => {
+            let source = statement.source.compile(builder).to_readable(builder);
+            let result = compile_math_operator(builder, operator, statement.equals_sign, type_id, current, source);
+            builder.move_register(current, result);
+        }
+
+        ExpressionResultKind::Comparison(..) => {
+            unreachable!("een vergelijking kan geen bestemming van een toewijzing zijn")
+        }
     }
 }
 
@@ -187,8 +608,48 @@ impl CompileStatement for ForStatement {
 
         match self.iterable.value() {
             ForIterableKind::Expression(expression) => {
-                _ = expression;
-                todo!("ondersteun Doorloper-gebaseerde expressies met `volg`");
+                let (array, ty) = expression.compile(builder).to_readable_and_type(builder);
+
+                let layout = builder.layout_of(ty);
+                let Some(element) = layout.element() else {
+                    builder.report_unsupported("Het doorlopen van iets anders dan een opeenvolging met `volg`", expression.range());
+                    builder.link_label_here(after);
+                    return;
+                };
+
+                let (offset, stride, type_id, typ) = (element.offset(), element.stride(), element.type_id(), element.primitive_type());
+                let length = layout.field(&BabString::new_static("lengte"));
+                let (length_offset, length_typ) = (length.offset(), length.primitive_type());
+
+                let length = builder.load_ptr(array, Immediate::Integer64(length_offset as _), length_typ);
+                let index = builder.load_immediate(Immediate::Integer64(0));
+
+                // Instead of computing the address of every element from the
+                // index, a pointer is moved along the elements.
+                let element_ptr = builder.math(MathOperation::Add, array, Immediate::Integer64(offset as _));
+
+                builder.compare(index, length);
+                builder.jump_if_greater_or_equal(after);
+
+                let body = builder.create_label_and_link_here("volg-lichaam");
+                let next = builder.create_label("volg-volgende");
+
+                let current_value = builder.load_ptr(element_ptr, Immediate::Integer64(0), typ);
+                builder.associate_register_to_local(current_value, self.iterator_name.value(), type_id);
+
+                builder.push_loop(next, after);
+                for statement in &self.body {
+                    statement.compile(builder);
+                }
+                builder.pop_loop();
+
+                builder.link_label_here(next);
+                builder.increment(index);
+                let next_ptr = builder.math(MathOperation::Add, element_ptr, Immediate::Integer64(stride as _));
+                builder.move_register(element_ptr, next_ptr);
+
+                builder.compare(index, length);
+                builder.jump_if_less(body);
             }
 
             ForIterableKind::Range(range) => {
@@ -197,21 +658,40 @@ impl CompileStatement for ForStatement {
                 builder.associate_register_to_local(current_value, self.iterator_name.value(), ty);
 
                 let end = range.end.compile(builder).to_readable(builder);
+                let range_loop = RangeLoop {
+                    current: current_value,
+                    end,
+                    step: RangeStep::compile(range.step.as_deref(), builder),
+                    type_id: ty,
+                    is_inclusive: range.is_inclusive,
+                };
 
-                builder.compare(current_value, end);
-                builder.jump_if_greater_or_equal(after);
+                let body = builder.create_label("volg-lichaam");
+                let next = builder.create_label("volg-volgende");
 
-                let body = builder.create_label_and_link_here("volg-lichaam");
+                range_loop.jump_if_continues(builder, body, after);
+                builder.link_label_here(body);
 
+                builder.push_loop(next, after);
                 for statement in &self.body {
                     statement.compile(builder);
                 }
+                builder.pop_loop();
+
+                builder.link_label_here(next);
+                match range_loop.step {
+                    RangeStep::Constant(1) => builder.increment(current_value),
+                    RangeStep::Constant(step) => {
+                        let next_value = builder.math(MathOperation::Add, current_value, Immediate::Integer64(step));
+                        builder.move_register(current_value, next_value);
+                    }
+                    RangeStep::Register(step) => {
+                        let next_value = builder.math(MathOperation::Add, current_value, step);
+                        builder.move_register(current_value, next_value);
+                    }
+                }
 
-                builder.increment(current_value);
-
-                builder.compare(current_value, end);
-                builder.jump_if_less(body);
-                // otherwise, fall through to the after the for-statement
+                range_loop.jump_if_continues(builder, body, after);
             }
         }
 
@@ -219,40 +699,48 @@ impl CompileStatement for ForStatement {
     }
 }
 
-impl CompileStatement for IfStatement {
+impl CompileStatement for ElseStatement {
     fn compile(&self, builder: &mut FunctionBuilder) {
-        let after_block = builder.create_label(format!("na-als"));
-        let then_block = builder.create_label(format!("als {}", self.condition.value()));
-
-        let comparison = self.condition.compile(builder).to_comparison(builder);
-
-        match comparison {
-            Comparison::Equality => {
-                builder.jump_if_equal(then_block);
-                builder.jump(after_block);
+        match &self.kind {
+            ElseStatementKind::ElseIf(statement) => {
+                statement.compile(builder);
             }
 
-            Comparison::Inequality => {
-                builder.jump_if_not_equal(then_block);
-                builder.jump(after_block);
+            ElseStatementKind::Else(body) => {
+                for statement in body {
+                    statement.compile(builder);
+                }
             }
+        }
+    }
+}
 
-            _ => todo!(),
+impl CompileStatement for IfStatement {
+    fn compile(&self, builder: &mut FunctionBuilder) {
+        let after_block = builder.create_label(format!("na-als"));
+        let then_block = builder.create_label(format!("als {}", self.condition.value()));
+        let else_block = match &self.else_statement {
+            Some(..) => builder.create_label("anders"),
+            None => after_block,
         };
 
+        let comparison = self.condition.compile(builder).to_comparison(builder);
+        jump_if(builder, comparison, then_block);
+        builder.jump(else_block);
+
         builder.link_label_here(then_block);
         for statement in &self.body {
             statement.compile(builder);
         }
 
-        builder.link_label_here(after_block);
-    }
-}
+        if let Some(else_statement) = &self.else_statement {
+            builder.jump(after_block);
 
-impl CompileStatement for InterfaceStatement {
-    fn compile(&self, builder: &mut FunctionBuilder) {
-        _ = builder;
-        todo!();
+            builder.link_label_here(else_block);
+            else_statement.compile(builder);
+        }
+
+        builder.link_label_here(after_block);
     }
 }
 
@@ -261,6 +749,13 @@ impl CompileStatement for ReturnStatement {
         match &self.expression {
             Some(expression) => {
                 let register = expression.compile(builder).to_readable(builder);
+
+                if let Some((ty, return_pointer)) = builder.return_pointer() {
+                    copy_tuple(builder, ty, register, return_pointer);
+                    builder.ret_with(return_pointer);
+                    return;
+                }
+
                 builder.ret_with(register);
             }
 
@@ -273,8 +768,165 @@ impl CompileStatement for ReturnStatement {
 
 impl CompileStatement for VariableStatement {
     fn compile(&self, builder: &mut FunctionBuilder) {
-        let (register, ty) = self.expression.compile(builder).to_readable_and_type(builder);
-        builder.associate_register_to_local(register, self.name.value(), ty);
+        let annotation = self.ty.as_ref().map(|ty| builder.type_id_for(ty));
+
+        let (register, mut ty) = match annotation {
+            Some(expected) => compile_with_expected_type(builder, &self.expression, expected),
+            None => self.expression.compile(builder),
+        }.to_readable_and_type(builder);
+
+        // The type of e.g. `[]` is only known from the annotation.
+        if let Some(annotation) = annotation {
+            ty = annotation;
+        }
+
+        match &self.name {
+            VariableName::Single(name) => {
+                builder.associate_register_to_local(register, name.value(), ty);
+            }
+
+            VariableName::Tuple(names) => {
+                let elements: Vec<(usize, PrimitiveType, TypeId)> = builder.layout_of(ty)
+                    .fields()
+                    .iter()
+                    .map(|field| (field.offset(), field.primitive_type(), field.type_id()))
+                    .collect();
+
+                for (name, (offset, typ, type_id)) in names.iter().zip(elements) {
+                    if name.value() == &Constants::DISCARDING_IDENT {
+                        continue;
+                    }
+
+                    let element = builder.load_ptr(register, Immediate::Integer64(offset as _), typ);
+                    builder.associate_register_to_local(element, name.value(), type_id);
+                }
+            }
+        }
+    }
+}
+
+/// Compiles array and tuple literals with the layout of the `expected` type,
+/// such that the elements of `stel xs: g8[] = [1, 2];` are stored as bytes.
+/// Other expressions already have the expected type.
+fn compile_with_expected_type(builder: &mut FunctionBuilder, expression: &Ranged<Expression>, expected: TypeId) -> ExpressionResult {
+    match expression.value() {
+        Expression::Primary(PrimaryExpression::ArrayLiteral(elements)) => {
+            let Some(element_type) = builder.layout_of(expected).element().map(|element| element.type_id()) else {
+                return expression.compile(builder);
+            };
+
+            let values = elements.iter()
+                .map(|element| compile_with_expected_type(builder, element, element_type).to_readable(builder))
+                .collect();
+
+            store_array_literal(builder, expected, values)
+        }
+
+        Expression::Primary(PrimaryExpression::Tuple(elements)) => {
+            let types: Vec<TypeId> = builder.layout_of(expected)
+                .fields()
+                .iter()
+                .map(|field| field.type_id())
+                .collect();
+
+            let values = elements.iter()
+                .zip(types)
+                .map(|(element, ty)| compile_with_expected_type(builder, element, ty).to_readable(builder))
+                .collect();
+
+            store_tuple_literal(builder, expected, values)
+        }
+
+        _ => expression.compile(builder),
+    }
+}
+
+fn store_array_literal(builder: &mut FunctionBuilder, ty: TypeId, values: Vec<Register>) -> ExpressionResult {
+    let register = builder.allocate_array(ty, values.len());
+
+    let element = builder.layout_of(ty).element().expect("ICE: opeenvolging zonder elementindeling");
+    let (offset, stride, typ) = (element.offset(), element.stride(), element.primitive_type());
+
+    for (index, value) in values.into_iter().enumerate() {
+        let offset = offset + stride * index;
+        builder.store_ptr(register, Operand::Immediate(Immediate::Integer64(offset as _)), value, typ);
+    }
+
+    ExpressionResult::typed(register, ty)
+}
+
+fn store_tuple_literal(builder: &mut FunctionBuilder, ty: TypeId, values: Vec<Register>) -> ExpressionResult {
+    let (layout, register) = builder.allocate(ty);
+
+    let fields: Vec<(usize, PrimitiveType)> = layout.fields()
+        .iter()
+        .map(|field| (field.offset(), field.primitive_type()))
+        .collect();
+
+    for ((offset, typ), value) in fields.into_iter().zip(values) {
+        builder.store_ptr(register, Operand::Immediate(Immediate::Integer64(offset as _)), value, typ);
+    }
+
+    ExpressionResult::typed(register, ty)
+}
+
+/// Copies the elements of the tuple at `source` to `destination`, which is
+/// used to return a tuple to the memory allocated by the caller.
+fn copy_tuple(builder: &mut FunctionBuilder, ty: TypeId, source: Register, destination: Register) {
+    let elements: Vec<(usize, PrimitiveType)> = builder.layout_of(ty)
+        .fields()
+        .iter()
+        .map(|field| (field.offset(), field.primitive_type()))
+        .collect();
+
+    for (offset, typ) in elements {
+        let element = builder.load_ptr(source, Immediate::Integer64(offset as _), typ);
+        builder.store_ptr(destination, Operand::Immediate(Immediate::Integer64(offset as _)), element, typ);
+    }
+}
+
+impl CompileStatement for WhileStatement {
+    fn compile(&self, builder: &mut FunctionBuilder) {
+        let condition_block = builder.create_label_and_link_here(format!("zolang {}", self.condition.value()));
+        let body_block = builder.create_label("zolang-lichaam");
+        let after_block = builder.create_label("na-zolang");
+
+        let comparison = self.condition.compile(builder).to_comparison(builder);
+        jump_if(builder, comparison, body_block);
+        builder.jump(after_block);
+
+        builder.link_label_here(body_block);
+        builder.push_loop(condition_block, after_block);
+        for statement in &self.body {
+            statement.compile(builder);
+        }
+        builder.pop_loop();
+
+        builder.jump(condition_block);
+
+        builder.link_label_here(after_block);
+    }
+}
+
+/// Compares two integers of the given type, natural numbers (`n8` up to `n64`)
+/// are compared without a sign.
+fn compare_integers(builder: &mut FunctionBuilder, lhs: Register, rhs: impl Into<Operand>, type_id: TypeId) {
+    if type_id.is_unsigned_integer() {
+        builder.compare_unsigned(lhs, rhs);
+    } else {
+        builder.compare(lhs, rhs);
+    }
+}
+
+/// Jumps to `location` when the flags, set by a previous `compare`, match the `comparison`.
+fn jump_if(builder: &mut FunctionBuilder, comparison: Comparison, location: Label) {
+    match comparison {
+        Comparison::Equality => builder.jump_if_equal(location),
+        Comparison::Inequality => builder.jump_if_not_equal(location),
+        Comparison::GreaterThan => builder.jump_if_greater(location),
+        Comparison::GreaterThanOrEqual => builder.jump_if_greater_or_equal(location),
+        Comparison::LessThan => builder.jump_if_less(location),
+        Comparison::LessThanOrEqual => builder.jump_if_less_or_equal(location),
     }
 }
 
@@ -304,44 +956,162 @@ impl CompileExpression for Expression {
     }
 }
 
+/// Some expressions can't be compiled yet, which are reported here, since the
+/// range of the expression is needed for that.
+impl CompileExpression for Ranged<Expression> {
+    fn compile(&self, builder: &mut FunctionBuilder) -> ExpressionResult {
+        let Expression::Primary(expression) = self.value() else {
+            return self.value().compile(builder);
+        };
+
+        let feature = match expression {
+            PrimaryExpression::Closure(..) => "Een anonieme werkwijze",
+            PrimaryExpression::OptionalSome(..) | PrimaryExpression::OptionalNone => "Een `Misschien`",
+            PrimaryExpression::ResultOk(..) | PrimaryExpression::ResultError(..) => "Een `Resultaat`",
+            PrimaryExpression::Range(..) => "Een `reeks` als waarde",
+            PrimaryExpression::TemplateString { .. } => "Een sjabloonslinger",
+
+            PrimaryExpression::Reference(name) if !builder.has_local(name.value()) && builder.constant(name.value()).is_none() => {
+                "Een werkwijze als waarde"
+            }
+
+            _ => return expression.compile(builder),
+        };
+
+        builder.report_unsupported(feature, self.range());
+        builder.load_immediate(Immediate::Integer64(0)).into()
+    }
+}
+
 impl CompileExpression for BiExpression {
     fn compile(&self, builder: &mut FunctionBuilder) -> ExpressionResult {
-        let lhs = self.lhs.compile(builder).to_readable(builder);
+        let (lhs, lhs_type) = self.lhs.compile(builder).to_readable_and_type(builder);
         let rhs = self.rhs.compile(builder).to_readable(builder);
 
-        match self.operator.value() {
-            BiOperator::Math(math) => {
-
-                let math_operation = match math {
-                    MathOperator::Add => MathOperation::Add,
-                    MathOperator::Subtract => MathOperation::Subtract,
+        if lhs_type == TypeId::KOMMAGETAL {
+            return compile_floating_point_bi_expression(builder, self.operator.value(), self.operator.range(), lhs, rhs);
+        }
 
-                    _ => todo!("Ondersteun {:?}", self.operator.value()),
-                };
+        if let Some(result) = compile_overloaded_operator(builder, *self.operator.value(), lhs_type, lhs, rhs) {
+            return result;
+        }
 
-                builder.math(math_operation, lhs, rhs).into()
+        match self.operator.value() {
+            BiOperator::Math(math) => {
+                compile_math_operator(builder, *math, self.operator.range(), lhs_type, lhs, rhs).into()
             }
 
             BiOperator::Comparison(comparison) => {
-                builder.compare(lhs, rhs);
+                compare_integers(builder, lhs, rhs, lhs_type);
                 comparison.into()
             }
         }
     }
 }
 
+/// Compiles an operator used on a structure implementing the builtin
+/// interface of the operator, by calling its method, e.g. `Geld__optellen`
+/// for `+`.
+fn compile_overloaded_operator(builder: &mut FunctionBuilder, operator: BiOperator, type_id: TypeId, lhs: Register, rhs: Register) -> Option<ExpressionResult> {
+    let interface = BuiltinInterface::for_operator(operator)?;
+
+    let name = create_mangled_method_name(builder.layout_of(type_id).name(), &BabString::new_static(interface.method_name));
+    if !builder.is_operator_method(&name) {
+        return None;
+    }
+
+    let result = compile_call(builder, name, vec![lhs, rhs]);
+
+    let BiOperator::Comparison(comparison) = operator else {
+        return Some(ExpressionResult::typed(result.to_readable(builder), type_id));
+    };
+
+    let result = result.to_readable(builder);
+    match comparison {
+        // `isGelijkAan` returns a `bool`, which is compared with `waar`.
+        Comparison::Equality | Comparison::Inequality => builder.compare(result, Immediate::Integer8(1)),
+
+        // `vergelijk` returns a number less than, equal to or greater than zero.
+        _ => builder.compare(result, Immediate::Integer32(0)),
+    }
+
+    Some(comparison.into())
+}
+
+fn compile_math_operator(builder: &mut FunctionBuilder, operator: MathOperator, range: FileRange, type_id: TypeId, lhs: Register, rhs: Register) -> Register {
+    if type_id == TypeId::KOMMAGETAL {
+        return compile_floating_point_bi_expression(builder, &BiOperator::Math(operator), range, lhs, rhs).to_readable(builder);
+    }
+
+    let math_operation = match operator {
+        MathOperator::Add => MathOperation::Add,
+        MathOperator::Subtract => MathOperation::Subtract,
+        MathOperator::Multiply => MathOperation::Multiply,
+        MathOperator::Divide if type_id.is_unsigned_integer() => MathOperation::DivideUnsigned,
+        MathOperator::Divide => MathOperation::Divide,
+        MathOperator::Modulo if type_id.is_unsigned_integer() => MathOperation::ModuloUnsigned,
+        MathOperator::Modulo => MathOperation::Modulo,
+
+        _ => {
+            builder.report_unsupported(format!("De operator `{}` voor gehele getallen", operator.as_str()), range);
+            return lhs;
+        }
+    };
+
+    builder.math(math_operation, lhs, rhs)
+}
+
+fn compile_floating_point_bi_expression(builder: &mut FunctionBuilder, operator: &BiOperator, range: FileRange, lhs: Register, rhs: Register) -> ExpressionResult {
+    match operator {
+        BiOperator::Math(math) => {
+            let operation = match math {
+                MathOperator::Add => FloatingPointOperation::Add,
+                MathOperator::Subtract => FloatingPointOperation::Subtract,
+                MathOperator::Multiply => FloatingPointOperation::Multiply,
+                MathOperator::Divide => FloatingPointOperation::Divide,
+
+                _ => {
+                    builder.report_unsupported(format!("De operator `{}` voor kommagetallen", math.as_str()), range);
+                    return ExpressionResult::typed(lhs, TypeId::KOMMAGETAL);
+                }
+            };
+
+            let register = builder.floating_point_math(operation, lhs, rhs);
+            ExpressionResult::typed(register, TypeId::KOMMAGETAL)
+        }
+
+        BiOperator::Comparison(comparison) => {
+            builder.compare_floating_point(lhs, rhs);
+            comparison.into()
+        }
+    }
+}
+
 impl CompileExpression for PostfixExpression {
     fn compile(&self, builder: &mut FunctionBuilder) -> ExpressionResult {
+        if let Some(result) = compile_structure_constant(self, builder) {
+            return result;
+        }
+
+        if let Some(result) = compile_enumeration_variant(self, builder) {
+            return result;
+        }
+
         match self.kind.value() {
             PostfixExpressionKind::Call(call) => {
-                let arguments = call.arguments.compile(builder);
+                let (arguments, argument_types) = compile_arguments(&call.arguments, builder);
 
                 match self.lhs.value() {
-                    Expression::Primary(PrimaryExpression::Reference(reference)) => {
-                        builder.call(reference.value().clone(), arguments).into()
+                    Expression::Primary(PrimaryExpression::Reference(reference)) if !builder.has_local(reference.value()) => {
+                        let name = create_mangled_name(&builder.resolve_function_name(reference.value()));
+                        let name = builder.instantiate_generic_function(&name, &argument_types).unwrap_or(name);
+                        compile_call(builder, name, arguments)
                     }
 
-                    _ => todo!("Ondersteun aanroepexpressies met linkerzijde: {:#?}", self.lhs)
+                    _ => {
+                        builder.report_unsupported("Het aanroepen van een werkwijze-waarde", self.lhs.range());
+                        builder.load_immediate(Immediate::Integer64(0)).into()
+                    }
                 }
             }
 
@@ -359,28 +1129,189 @@ impl CompileExpression for PostfixExpression {
             }
 
             PostfixExpressionKind::MethodCall(method) => {
+                if let Some(result) = compile_static_method_call(self, method, builder) {
+                    return result;
+                }
+
                 let lhs = self.lhs.compile(builder);
                 let struct_ty = lhs.type_id;
 
-                let mut arguments = method.call.arguments.compile(builder);
+                if let Some(result) = compile_conversion_method(builder, struct_ty, method.method_name.value(), &lhs) {
+                    return result;
+                }
+
+                if let Some(result) = compile_array_method(builder, struct_ty, &method.method_name, &lhs) {
+                    return result;
+                }
+
+                let (mut arguments, argument_types) = compile_arguments(&method.call.arguments, builder);
                 arguments.insert(0, lhs.to_readable(builder));
 
                 let name = create_mangled_method_name(
                     builder.layout_of(struct_ty).name(),
                     &method.method_name
                 );
+                let name = builder.instantiate_generic_function(&name, &argument_types).unwrap_or(name);
 
-                builder.call(name, arguments).into()
+                compile_call(builder, name, arguments)
             }
 
             PostfixExpressionKind::Subscript(subscript) => {
-                _ = subscript;
-                todo!()
+                let (base_ptr, ty) = self.lhs.compile(builder).to_readable_and_type(builder);
+                let element = builder.layout_of(ty).element().expect("ICE: index van iets anders dan een opeenvolging");
+                let (offset, stride, type_id, typ) = (element.offset(), element.stride(), element.type_id(), element.primitive_type());
+
+                let index = match subscript.value() {
+                    Expression::Primary(PrimaryExpression::IntegerLiteral(index)) => *index as usize,
+                    _ => {
+                        builder.report_unsupported("Een index die niet vast is", subscript.range());
+                        0
+                    }
+                };
+
+                let offset = offset + stride * index;
+                ExpressionResult::pointer(base_ptr, offset as isize, type_id, typ)
+            }
+
+            PostfixExpressionKind::Propagate => {
+                self.lhs.compile(builder);
+                builder.report_unsupported("Het doorgeven van een fout met `?`", self.kind.range());
+                builder.load_immediate(Immediate::Integer64(0)).into()
             }
         }
     }
 }
 
+/// Calls the function with the symbol `name`. When it returns a tuple, the
+/// tuple is allocated here, and the pointer to it is passed as the last
+/// argument.
+fn compile_call(builder: &mut FunctionBuilder, name: BabString, mut arguments: Vec<Register>) -> ExpressionResult {
+    let Some(ty) = builder.tuple_return_type(&name) else {
+        return builder.call(name, arguments).into();
+    };
+
+    let (_, tuple) = builder.allocate(ty);
+    arguments.push(tuple);
+    _ = builder.call(name, arguments);
+
+    ExpressionResult::typed(tuple, ty)
+}
+
+/// Compiles the arguments of a call, and returns their types as well, which
+/// are needed to pick the instance of a generic function.
+fn compile_arguments(arguments: &[Ranged<Expression>], builder: &mut FunctionBuilder) -> (Vec<Register>, Vec<TypeId>) {
+    arguments.iter()
+        .map(|argument| argument.compile(builder).to_readable_and_type(builder))
+        .unzip()
+}
+
+/// Compiles the builtin conversions between `g32` and `kommagetal`, which
+/// don't need a function call.
+fn compile_conversion_method(builder: &mut FunctionBuilder, ty: TypeId, method_name: &BabString, lhs: &ExpressionResult) -> Option<ExpressionResult> {
+    match (ty, method_name.as_str()) {
+        (TypeId::G32, "naarKommagetal") => {
+            let source = lhs.clone().to_readable(builder);
+            let register = builder.convert_integer_to_floating_point(source);
+            Some(ExpressionResult::typed(register, TypeId::KOMMAGETAL))
+        }
+
+        (TypeId::KOMMAGETAL, "naarGetal") => {
+            let source = lhs.clone().to_readable(builder);
+            let register = builder.convert_floating_point_to_integer(source);
+            Some(ExpressionResult::typed(register, TypeId::G32))
+        }
+
+        _ => None,
+    }
+}
+
+/// Compiles calls to `gedeeld` methods like `Persoon.maak("Jan")`, which
+/// don't get a `dit` as their first argument.
+fn compile_static_method_call(expression: &PostfixExpression, method: &MethodCallExpression, builder: &mut FunctionBuilder) -> Option<ExpressionResult> {
+    let structure = expression.lhs.value().as_identifier()?;
+    let ty = builder.structure_type(structure)?;
+
+    let (arguments, argument_types) = compile_arguments(&method.call.arguments, builder);
+
+    let name = create_mangled_method_name(builder.layout_of(ty).name(), &method.method_name);
+    let name = builder.instantiate_generic_function(&name, &argument_types).unwrap_or(name);
+
+    Some(compile_call(builder, name, arguments))
+}
+
+/// Compiles the builtin methods of arrays, such as `lengte()`, which is read
+/// from the header of the array.
+fn compile_array_method(builder: &mut FunctionBuilder, ty: TypeId, method_name: &Ranged<BabString>, lhs: &ExpressionResult) -> Option<ExpressionResult> {
+    let layout = builder.layout_of(ty);
+    layout.element()?;
+
+    let length = layout.field(&BabString::new_static("lengte"));
+    let (offset, typ) = (length.offset(), length.primitive_type());
+
+    match method_name.as_str() {
+        "lengte" => {
+            let array = lhs.clone().to_readable(builder);
+            let register = builder.load_ptr(array, Immediate::Integer64(offset as _), typ);
+            Some(ExpressionResult::typed(register, TypeId::G32))
+        }
+
+        name => {
+            builder.report_unsupported(format!("De opeenvolgingswerkwijze `{name}`"), method_name.range());
+            Some(builder.load_immediate(Immediate::Integer64(0)).into())
+        }
+    }
+}
+
+/// Compiles expressions like `Buffer.GROOTTE`, by loading the value that was
+/// computed at compile time.
+fn compile_structure_constant(expression: &PostfixExpression, builder: &mut FunctionBuilder) -> Option<ExpressionResult> {
+    let PostfixExpressionKind::Member(name) = expression.kind.value() else {
+        return None;
+    };
+
+    let structure_name = expression.lhs.value().as_identifier()?;
+    let value = builder.structure_constant(structure_name, name)?;
+    Some(builder.load_constant(&value).into())
+}
+
+/// Compiles expressions like `Vorm.Punt` and `Vorm.Cirkel(5)` by storing the
+/// tag of the variant, followed by its fields.
+fn compile_enumeration_variant(expression: &PostfixExpression, builder: &mut FunctionBuilder) -> Option<ExpressionResult> {
+    let (name, arguments) = match expression.kind.value() {
+        PostfixExpressionKind::Member(name) => (name, [].as_slice()),
+        PostfixExpressionKind::MethodCall(method) => (&method.method_name, method.call.arguments.as_slice()),
+        _ => return None,
+    };
+
+    let enumeration_name = expression.lhs.value().as_identifier()?;
+    let layout = builder.enumeration_layout(enumeration_name)?;
+
+    let ty = *layout.type_id();
+    let tag = layout.field(&BabString::new_static("tag"));
+    let tag_offset = tag.offset();
+    let tag_type = tag.primitive_type();
+
+    let Some((variant, fields)) = layout.variant(name.value()) else {
+        panic!("ICE: opsomming `{enumeration_name}` heeft geen variant `{}`", name.value());
+    };
+
+    let fields: Vec<(usize, PrimitiveType)> = fields.iter()
+        .map(|field| (field.offset(), field.primitive_type()))
+        .collect();
+
+    let (_, register) = builder.allocate_structure(enumeration_name);
+
+    let tag = builder.load_immediate(Immediate::Integer32(variant as _));
+    builder.store_ptr(register, Operand::Immediate(Immediate::Integer64(tag_offset as _)), tag, tag_type);
+
+    for ((offset, typ), argument) in fields.into_iter().zip(arguments) {
+        let value = argument.compile(builder).to_readable(builder);
+        builder.store_ptr(register, Operand::Immediate(Immediate::Integer64(offset as _)), value, typ);
+    }
+
+    Some(ExpressionResult::typed(register, ty))
+}
+
 impl CompileExpression for PrimaryExpression {
     fn compile(&self, builder: &mut FunctionBuilder) -> ExpressionResult {
         match self {
@@ -392,15 +1323,60 @@ impl CompileExpression for PrimaryExpression {
                 builder.load_immediate(Immediate::Integer32(*c as i32)).into()
             }
 
+            Self::Closure(..) => {
+                unreachable!("ICE: anonieme werkwijzen worden gemeld bij het compileren van de `Ranged<Expression>`")
+            }
+
+            Self::OptionalSome(..) | Self::OptionalNone => {
+                unreachable!("ICE: `Misschien` wordt gemeld bij het compileren van de `Ranged<Expression>`")
+            }
+
+            Self::ResultOk(..) | Self::ResultError(..) => {
+                unreachable!("ICE: `Resultaat` wordt gemeld bij het compileren van de `Ranged<Expression>`")
+            }
+
             Self::IntegerLiteral(i) => {
                 builder.load_immediate(Immediate::Integer64(*i)).into()
             }
 
+            Self::FloatLiteral(f) => {
+                let register = builder.load_immediate(Immediate::Float64(*f));
+                ExpressionResult::typed(register, TypeId::KOMMAGETAL)
+            }
+
+            Self::Match(expression) => {
+                expression.compile(builder)
+            }
+
             Self::Parenthesized(expression) => {
                 expression.compile(builder)
             }
 
+            Self::ArrayLiteral(elements) => {
+                let (values, types): (Vec<Register>, Vec<TypeId>) = elements.iter()
+                    .map(|element| element.compile(builder).to_readable_and_type(builder))
+                    .unzip();
+
+                // Empty arrays get their type from the annotation of the variable.
+                let element_type = types.first().copied().unwrap_or(TypeId::G32);
+                let ty = builder.add_array(element_type);
+                store_array_literal(builder, ty, values)
+            }
+
+            Self::Tuple(elements) => {
+                let (values, types): (Vec<Register>, Vec<TypeId>) = elements.iter()
+                    .map(|element| element.compile(builder).to_readable_and_type(builder))
+                    .unzip();
+
+                let ty = builder.add_tuple(&types);
+                store_tuple_literal(builder, ty, values)
+            }
+
             Self::Reference(ranged) => {
+                if let Some(value) = builder.constant(ranged.value()) {
+                    return builder.load_constant(&value).into();
+                }
+
                 builder.load_local(ranged.value()).into()
             }
 
@@ -409,9 +1385,24 @@ impl CompileExpression for PrimaryExpression {
             }
 
             Self::SizedArrayInitializer { typ, size } => {
-                _ = typ;
-                _ = size;
-                todo!()
+                let element_type = builder.type_id_for(typ.value());
+                let ty = builder.add_array(element_type);
+
+                // The elements start out with their default value, which is
+                // zero for every type that has one.
+                let length = match evaluate_integer(size, builder).and_then(|length| usize::try_from(length).ok()) {
+                    Some(length) => length,
+                    None => {
+                        builder.report_unsupported("Een opeenvolging met een grootte die niet vast is", size.range());
+                        0
+                    }
+                };
+
+                let values = (0..length)
+                    .map(|_| builder.load_immediate(Immediate::Integer64(0)))
+                    .collect();
+
+                store_array_literal(builder, ty, values)
             }
 
             Self::StringLiteral(literal) => {
@@ -422,11 +1413,131 @@ impl CompileExpression for PrimaryExpression {
                 expression.compile(builder)
             }
 
-            Self::TemplateString { parts } => {
-                _ = parts;
-                todo!()
+            Self::TemplateString { .. } => {
+                unreachable!("ICE: sjabloonslingers worden gemeld bij het compileren van de `Ranged<Expression>`")
+            }
+
+            Self::Range(..) => {
+                unreachable!("ICE: `reeks` als waarde wordt gemeld bij het compileren van de `Ranged<Expression>`")
+            }
+        }
+    }
+}
+
+impl CompileExpression for MatchExpression {
+    fn compile(&self, builder: &mut FunctionBuilder) -> ExpressionResult {
+        let (value, value_type) = self.scrutinee.compile(builder).to_readable_and_type(builder);
+
+        let result = builder.load_immediate(Immediate::Integer64(0));
+        let mut result_type = TypeId::G32;
+
+        let after = builder.create_label("na-kies");
+
+        for (idx, arm) in self.arms.iter().enumerate() {
+            let next_arm = builder.create_label(format!("kies-tak-{}", idx + 1));
+
+            compile_pattern(builder, &arm.pattern, value, value_type, next_arm);
+
+            let (arm_value, arm_type) = arm.expression.compile(builder).to_readable_and_type(builder);
+            builder.move_register(result, arm_value);
+            result_type = arm_type;
+            builder.jump(after);
+
+            builder.link_label_here(next_arm);
+        }
+
+        builder.link_label_here(after);
+
+        ExpressionResult::typed(result, result_type)
+    }
+}
+
+/// Jumps to `mismatch` when the `value` doesn't match the `pattern`, otherwise
+/// the bindings of the pattern are available as locals.
+fn compile_pattern(builder: &mut FunctionBuilder, pattern: &Ranged<Pattern>, value: Register, value_type: TypeId, mismatch: Label) {
+    match pattern.value() {
+        Pattern::Wildcard => (),
+
+        Pattern::Binding(name) => {
+            builder.associate_register_to_local(value, name.value(), value_type);
+        }
+
+        Pattern::Boolean(b) => {
+            builder.compare(value, Operand::Immediate(Immediate::Integer32(*b as i32)));
+            builder.jump_if_not_equal(mismatch);
+        }
+
+        Pattern::CharacterLiteral(c) => {
+            builder.compare(value, Operand::Immediate(Immediate::Integer32(*c as i32)));
+            builder.jump_if_not_equal(mismatch);
+        }
+
+        Pattern::IntegerLiteral(i) => {
+            builder.compare(value, Operand::Immediate(Immediate::Integer64(*i)));
+            builder.jump_if_not_equal(mismatch);
+        }
+
+        Pattern::StringLiteral(..) => {
+            builder.report_unsupported("Een slingerpatroon binnen `kies`", pattern.range());
+        }
+
+        Pattern::Range { start, end } => {
+            compare_integers(builder, value, Immediate::Integer64(*start.value()), value_type);
+            builder.jump_if_less(mismatch);
+
+            compare_integers(builder, value, Immediate::Integer64(*end.value()), value_type);
+            builder.jump_if_greater_or_equal(mismatch);
+        }
+
+        Pattern::Structure { fields, .. } => {
+            let layout = builder.layout_of(value_type);
+            let fields: Vec<(usize, PrimitiveType, TypeId, &Ranged<Pattern>)> = fields.iter()
+                .map(|field| {
+                    let layout = layout.field(field.name.value());
+                    (layout.offset(), layout.primitive_type(), layout.type_id(), &field.pattern)
+                })
+                .collect();
+
+            for (offset, typ, type_id, pattern) in fields {
+                let field = builder.load_ptr(value, Immediate::Integer64(offset as _), typ);
+                compile_pattern(builder, pattern, field, type_id, mismatch);
+            }
+        }
+
+        Pattern::EnumerationVariant { enumeration, variant, fields } => {
+            let Some(layout) = builder.enumeration_layout(enumeration.value()) else {
+                panic!("ICE: opsomming `{}` is onbekend", enumeration.value());
+            };
+
+            let tag = layout.field(&BabString::new_static("tag"));
+            let tag_offset = tag.offset();
+            let tag_type = tag.primitive_type();
+
+            let Some((variant, variant_fields)) = layout.variant(variant.value()) else {
+                panic!("ICE: opsomming `{}` heeft geen variant `{}`", enumeration.value(), variant.value());
+            };
+
+            let variant_fields: Vec<(usize, PrimitiveType, TypeId)> = variant_fields.iter()
+                .map(|field| (field.offset(), field.primitive_type(), field.type_id()))
+                .collect();
+
+            let tag = builder.load_ptr(value, Immediate::Integer64(tag_offset as _), tag_type);
+            builder.compare(tag, Operand::Immediate(Immediate::Integer32(variant as _)));
+            builder.jump_if_not_equal(mismatch);
+
+            for ((offset, typ, type_id), pattern) in variant_fields.into_iter().zip(fields) {
+                let field = builder.load_ptr(value, Immediate::Integer64(offset as _), typ);
+                compile_pattern(builder, pattern, field, type_id, mismatch);
             }
         }
+
+        Pattern::OptionalSome(..) | Pattern::OptionalNone => {
+            builder.report_unsupported("Een `Misschien`-patroon binnen `kies`", pattern.range());
+        }
+
+        Pattern::ResultOk(..) | Pattern::ResultError(..) => {
+            builder.report_unsupported("Een `Resultaat`-patroon binnen `kies`", pattern.range());
+        }
     }
 }
 
@@ -436,7 +1547,7 @@ impl CompileExpression for StructureInstantiationExpression {
 
         let ty = layout.type_id().clone();
 
-        let default_values: Vec<(usize, PrimitiveType, Rc<Expression>)> = layout
+        let default_values: Vec<(usize, PrimitiveType, Rc<Ranged<Expression>>)> = layout
             .fields()
             .iter()
             .filter_map(|field| {
@@ -471,8 +1582,33 @@ impl CompileExpression for StructureInstantiationExpression {
 
 impl CompileExpression for UnaryExpression {
     fn compile(&self, builder: &mut FunctionBuilder) -> ExpressionResult {
-        _ = builder;
-        todo!()
+        match self.kind.value() {
+            UnaryExpressionKind::AddressOf => {
+                builder.report_unsupported("Het adres van een waarde met `&`", self.kind.range());
+                builder.load_immediate(Immediate::Integer64(0)).into()
+            }
+
+            UnaryExpressionKind::Negate => {
+                let (value, type_id) = self.rhs.compile(builder).to_readable_and_type(builder);
+
+                let register = if type_id == TypeId::KOMMAGETAL {
+                    let zero = builder.load_immediate(Immediate::Float64(0.0));
+                    builder.floating_point_math(FloatingPointOperation::Subtract, zero, value)
+                } else {
+                    let zero = builder.load_immediate(Immediate::Integer64(0));
+                    builder.math(MathOperation::Subtract, zero, value)
+                };
+
+                ExpressionResult::typed(register, type_id)
+            }
+
+            UnaryExpressionKind::Not => {
+                // A `bool` is `onwaar` when it is zero.
+                let value = self.rhs.compile(builder).to_readable(builder);
+                builder.compare(value, Immediate::Integer8(0));
+                Comparison::Equality.into()
+            }
+        }
     }
 }
 
@@ -586,9 +1722,18 @@ impl ExpressionResultKind {
     #[must_use]
     fn to_readable(self, builder: &mut FunctionBuilder<'_>) -> Register {
         match self {
-            Self::Comparison(..) => {
-                _ = builder; // Use the builder to load the comparison flags (NZCV / FLAGS) to a register, somehow
-                todo!("Hoe moet ik een Vergelijkresultaat omzetten naar een Register?")
+            Self::Comparison(comparison) => {
+                // Load `waar` beforehand, and overwrite it with `onwaar` when
+                // the comparison doesn't hold.
+                let result = builder.load_immediate(Immediate::Integer8(1));
+                let after = builder.create_label("na-vergelijking");
+                jump_if(builder, comparison, after);
+
+                let false_value = builder.load_immediate(Immediate::Integer8(0));
+                builder.move_register(result, false_value);
+
+                builder.link_label_here(after);
+                result
             }
 
             Self::Register(reg) => reg,
@@ -600,26 +1745,27 @@ impl ExpressionResultKind {
     }
 }
 
-trait CompileSome {
-    type Output;
-
-    fn compile(&self, builder: &mut FunctionBuilder) -> Self::Output;
-}
-
-impl CompileSome for Vec<Ranged<Expression>> {
-    type Output = Vec<Register>;
-
-    fn compile(&self, builder: &mut FunctionBuilder) -> Self::Output {
-        self.iter()
-            .map(|x| x.compile(builder).to_readable(builder))
-            .collect()
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum CallingConvention {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallingConvention {
     Regular,
     Method {
         this: TypeId,
     },
+
+    /// A `gedeeld` method, which is called like a regular function, but can
+    /// still use the constants of its structure.
+    Static {
+        owner: TypeId,
+    },
+}
+
+impl CallingConvention {
+    #[must_use]
+    fn for_method(method: &Method, structure: TypeId) -> Self {
+        if method.is_static {
+            Self::Static { owner: structure }
+        } else {
+            Self::Method { this: structure }
+        }
+    }
 }
@@ -0,0 +1,35 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use babbelaar::FileRange;
+use thiserror::Error;
+
+/// A construct that the interpreter supports, but that can't be compiled to
+/// machine code (yet). These are reported instead of panicking or generating
+/// wrong code.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{feature} wordt nog niet ondersteund door de compiler")]
+pub struct CompileError {
+    feature: String,
+    range: FileRange,
+}
+
+impl CompileError {
+    #[must_use]
+    pub fn unsupported(feature: impl Into<String>, range: FileRange) -> Self {
+        Self {
+            feature: feature.into(),
+            range,
+        }
+    }
+
+    #[must_use]
+    pub fn feature(&self) -> &str {
+        &self.feature
+    }
+
+    #[must_use]
+    pub fn range(&self) -> FileRange {
+        self.range
+    }
+}
@@ -7,7 +7,7 @@ use std::collections::HashMap;
 
 use babbelaar::BabString;
 
-use crate::{Immediate, Instruction, JumpCondition, Label, MathOperation, Operand, Program, Register};
+use crate::{FloatingPointOperation, Immediate, Instruction, JumpCondition, Label, Operand, Program, Register};
 
 pub struct Interpreter {
     program: Program,
@@ -110,7 +110,7 @@ impl Interpreter {
                 OperationResult::Continue
             }
 
-            Instruction::Compare { lhs, rhs } => {
+            Instruction::Compare { lhs, rhs, unsigned } => {
                 let lhs = self.register(&lhs);
                 let rhs = self.operand_to_immediate(&rhs);
 
@@ -118,6 +118,19 @@ impl Interpreter {
                 let lhs = lhs.as_i64();
                 let rhs = rhs.as_i64();
 
+                if unsigned {
+                    // The jumps only look at `negative`, which means "less than" here.
+                    let is_less = (lhs as u64) < (rhs as u64);
+                    self.comparison_flags = ComparisonFlags {
+                        negative: is_less,
+                        zero: lhs == rhs,
+                        carry: is_less,
+                        overflow: false,
+                    };
+
+                    return OperationResult::Continue;
+                }
+
                 let overflow = lhs.checked_sub(rhs).is_none();
                 let value = lhs.wrapping_sub(rhs);
 
@@ -131,6 +144,20 @@ impl Interpreter {
                 OperationResult::Continue
             }
 
+            Instruction::CompareFloatingPoint { lhs, rhs } => {
+                let lhs = self.register(&lhs).as_f64();
+                let rhs = self.register(&rhs).as_f64();
+
+                self.comparison_flags = ComparisonFlags {
+                    negative: lhs < rhs,
+                    zero: lhs == rhs,
+                    carry: false,
+                    overflow: false,
+                };
+
+                OperationResult::Continue
+            }
+
             Instruction::Increment { register } => {
                 let value = Immediate::Integer64(self.register(&register).as_i64() + 1);
                 self.frame().set_register(register, value);
@@ -211,15 +238,41 @@ impl Interpreter {
                 let lhs = self.operand_to_immediate(&lhs);
                 let rhs = self.operand_to_immediate(&rhs);
 
-                let value = match operation {
-                    MathOperation::Add => Immediate::Integer64(lhs.as_i64() + rhs.as_i64()),
-                    MathOperation::Subtract => Immediate::Integer64(lhs.as_i64() - rhs.as_i64()),
+                let Some(value) = operation.evaluate(lhs, rhs) else {
+                    panic!("Deling door nul bij {operation} {lhs}, {rhs}");
                 };
 
                 self.frame().set_register(destination, value);
                 OperationResult::Continue
             }
 
+            Instruction::FloatingPointMath { operation, destination, lhs, rhs } => {
+                let lhs = self.register(&lhs).as_f64();
+                let rhs = self.register(&rhs).as_f64();
+
+                let value = match operation {
+                    FloatingPointOperation::Add => lhs + rhs,
+                    FloatingPointOperation::Subtract => lhs - rhs,
+                    FloatingPointOperation::Multiply => lhs * rhs,
+                    FloatingPointOperation::Divide => lhs / rhs,
+                };
+
+                self.frame().set_register(destination, Immediate::Float64(value));
+                OperationResult::Continue
+            }
+
+            Instruction::ConvertIntegerToFloatingPoint { destination, source } => {
+                let value = self.register(&source).as_i64() as f64;
+                self.frame().set_register(destination, Immediate::Float64(value));
+                OperationResult::Continue
+            }
+
+            Instruction::ConvertFloatingPointToInteger { destination, source } => {
+                let value = self.register(&source).as_f64() as i64;
+                self.frame().set_register(destination, Immediate::Integer64(value));
+                OperationResult::Continue
+            }
+
             Instruction::StackAlloc { dst, size } => {
                 let offset = self.stack.len();
                 self.stack.extend(std::iter::repeat_n(0, size));
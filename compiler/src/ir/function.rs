@@ -13,6 +13,10 @@ use super::{Instruction, Label, Register};
 pub enum ArgumentName {
     This,
     Name(BabString),
+
+    /// The pointer to the tuple a function returns, which is allocated by
+    /// the caller.
+    ReturnPointer,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,6 +41,10 @@ impl ArgumentList {
         self.items.push((ArgumentName::This, ty));
     }
 
+    pub fn add_return_pointer(&mut self, ty: TypeId) {
+        self.items.push((ArgumentName::ReturnPointer, ty));
+    }
+
     #[must_use]
     pub fn iter(&self) -> impl Iterator<Item = &(ArgumentName, TypeId)> {
         self.items.iter()
@@ -52,6 +60,13 @@ pub struct Function {
 }
 
 impl Function {
+    /// The labels of the IR are numbered from one, so the code generators
+    /// can create labels of their own after the highest one.
+    #[must_use]
+    pub(crate) fn highest_label_id(&self) -> usize {
+        self.label_names.keys().map(|label| label.id).max().unwrap_or(0)
+    }
+
     #[must_use]
     pub fn name(&self) -> &BabString {
         &self.name
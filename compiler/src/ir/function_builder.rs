@@ -3,11 +3,11 @@
 
 use std::collections::HashMap;
 
-use babbelaar::BabString;
+use babbelaar::{BabString, ConstantValue, FileRange, Type};
 
 use crate::{StructureLayout, TypeId};
 
-use super::{Function, Immediate, Instruction, JumpCondition, Label, MathOperation, Operand, PrimitiveType, ProgramBuilder, Register, RegisterAllocator};
+use super::{FloatingPointOperation, Function, Immediate, Instruction, JumpCondition, Label, MathOperation, Operand, PrimitiveType, ProgramBuilder, Register, RegisterAllocator};
 
 #[derive(Debug)]
 pub struct FunctionBuilder<'program> {
@@ -15,12 +15,19 @@ pub struct FunctionBuilder<'program> {
     pub(super) name: BabString,
     pub(super) register_allocator: RegisterAllocator,
     pub(super) this: Option<(TypeId, Register)>,
+
+    /// The structure this function is a method of, which can use the
+    /// constants of that structure without `Structuur.` in front of them.
+    pub(super) owner: Option<TypeId>,
+    pub(super) return_pointer: Option<(TypeId, Register)>,
     pub(super) argument_registers: Vec<Register>,
     pub(super) instructions: Vec<Instruction>,
     pub(super) locals: HashMap<BabString, FunctionLocal>,
+    pub(super) constants: HashMap<BabString, ConstantValue>,
     pub(super) label_counter: usize,
     pub(crate) label_names: HashMap<Label, BabString>,
     pub(super) label_positions: HashMap<Label, usize>,
+    pub(super) loops: Vec<LoopLabels>,
 }
 
 impl<'program> FunctionBuilder<'program> {
@@ -39,10 +46,43 @@ impl<'program> FunctionBuilder<'program> {
     pub fn compare(&mut self, lhs: Register, rhs: impl Into<Operand>) {
         self.instructions.push(Instruction::Compare {
             lhs,
-            rhs: rhs.into()
+            rhs: rhs.into(),
+            unsigned: false,
         });
     }
 
+    pub fn compare_unsigned(&mut self, lhs: Register, rhs: impl Into<Operand>) {
+        self.instructions.push(Instruction::Compare {
+            lhs,
+            rhs: rhs.into(),
+            unsigned: true,
+        });
+    }
+
+    pub fn compare_floating_point(&mut self, lhs: Register, rhs: Register) {
+        self.instructions.push(Instruction::CompareFloatingPoint { lhs, rhs });
+    }
+
+    pub fn convert_integer_to_floating_point(&mut self, source: Register) -> Register {
+        let destination = self.register_allocator.next();
+        self.instructions.push(Instruction::ConvertIntegerToFloatingPoint { destination, source });
+        destination
+    }
+
+    pub fn convert_floating_point_to_integer(&mut self, source: Register) -> Register {
+        let destination = self.register_allocator.next();
+        self.instructions.push(Instruction::ConvertFloatingPointToInteger { destination, source });
+        destination
+    }
+
+    pub fn floating_point_math(&mut self, operation: FloatingPointOperation, lhs: Register, rhs: Register) -> Register {
+        let destination = self.register_allocator.next();
+
+        self.instructions.push(Instruction::FloatingPointMath { operation, destination, lhs, rhs });
+
+        destination
+    }
+
     pub fn increment(&mut self, register: Register) {
         self.instructions.push(Instruction::Increment { register });
     }
@@ -104,12 +144,72 @@ impl<'program> FunctionBuilder<'program> {
         });
     }
 
+    /// Declares a `vast` inside the body of the function, which isn't stored
+    /// in a register, but is loaded at every usage instead.
+    pub fn associate_constant_to_local(&mut self, local_name: impl Into<BabString>, value: ConstantValue) {
+        self.constants.insert(local_name.into(), value);
+    }
+
+    /// Finds the value of the constant with the given `name`, which can be
+    /// declared inside the function, in the structure of `dit`, or at the
+    /// top level, in that order.
+    #[must_use]
+    pub fn constant(&self, name: &BabString) -> Option<ConstantValue> {
+        if self.locals.contains_key(name) {
+            return None;
+        }
+
+        if let Some(value) = self.constants.get(name) {
+            return Some(value.clone());
+        }
+
+        if let Some(owner) = self.owner {
+            let structure = self.layout_of(owner).name();
+            if let Some(value) = self.program_builder.structure_constant(structure, name) {
+                return Some(value.clone());
+            }
+        }
+
+        self.program_builder.constant(name).cloned()
+    }
+
+    /// Finds the value of `Structuur.NAAM`, if `structure` doesn't refer to
+    /// a local instead.
+    #[must_use]
+    pub fn structure_constant(&self, structure: &BabString, name: &BabString) -> Option<ConstantValue> {
+        if self.locals.contains_key(structure) {
+            return None;
+        }
+
+        self.program_builder.structure_constant(structure, name).cloned()
+    }
+
+    #[must_use]
+    pub fn load_constant(&mut self, value: &ConstantValue) -> (TypeId, Register) {
+        let type_id = self.program_builder.type_id_for_structure(&value.ty().name());
+
+        let register = match value {
+            ConstantValue::Bool(b) => self.load_immediate(Immediate::Integer32(*b as i32)),
+            ConstantValue::Character(c) => self.load_immediate(Immediate::Integer32(*c as i32)),
+            ConstantValue::Float(f) => self.load_immediate(Immediate::Float64(*f)),
+            ConstantValue::Integer { value, .. } => self.load_immediate(Immediate::Integer64(*value)),
+            ConstantValue::String(string) => self.load_string(string),
+        };
+
+        (type_id, register)
+    }
+
     #[must_use]
     pub fn load_string(&mut self, string: &str) -> Register {
         let immediate = self.program_builder.program.add_string(string);
         self.load_immediate(immediate)
     }
 
+    #[must_use]
+    pub fn has_local(&self, name: &BabString) -> bool {
+        self.locals.contains_key(name)
+    }
+
     #[must_use]
     pub fn load_local(&mut self, name: &BabString) -> (TypeId, Register) {
         let src = *self.locals.get(name).expect("Local name is not valid");
@@ -184,17 +284,8 @@ impl<'program> FunctionBuilder<'program> {
     /// the start of that block.
     #[must_use]
     pub fn allocate_structure(&mut self, name: &BabString) -> (&StructureLayout, Register) {
-        let layout = self.program_builder.type_manager.layout_of(name);
-        let size = layout.size();
-
-        let dst = self.register_allocator.next();
-
-        self.instructions.push(Instruction::StackAlloc {
-            dst,
-            size,
-        });
-
-        (layout, dst)
+        let ty = *self.program_builder.type_manager.layout_of(name).type_id();
+        self.allocate(ty)
     }
 
     /// Stores a value at the `base_ptr` offset by `offset`.
@@ -221,19 +312,164 @@ impl<'program> FunctionBuilder<'program> {
         destination
     }
 
+    #[must_use]
+    pub fn resolve_function_name(&self, name: &BabString) -> BabString {
+        self.program_builder.resolve_function_name(name)
+    }
+
+    /// See [`ProgramBuilder::instantiate_generic_function`].
+    pub fn instantiate_generic_function(&mut self, name: &BabString, argument_types: &[TypeId]) -> Option<BabString> {
+        self.program_builder.instantiate_generic_function(name, argument_types)
+    }
+
     #[must_use]
     pub fn layout_of(&self, ty: TypeId) -> &StructureLayout {
         self.program_builder.type_manager.layout(ty)
     }
 
+    /// Returns the layout of the enumeration with the given name, if `name`
+    /// doesn't refer to a local instead.
+    #[must_use]
+    pub fn enumeration_layout(&self, name: &BabString) -> Option<&StructureLayout> {
+        if self.locals.contains_key(name) {
+            return None;
+        }
+
+        self.program_builder.type_manager.try_layout_of(name)
+            .filter(|layout| layout.is_enumeration())
+    }
+
+    /// Sets the structure of a `gedeeld` method, which doesn't have a `dit`
+    /// to derive it from.
+    pub fn set_owner(&mut self, owner: TypeId) {
+        self.owner = Some(owner);
+    }
+
+    /// Returns the type of the structure `name` refers to, e.g. for calling
+    /// `Persoon.maak()`, unless `name` refers to a local instead.
+    #[must_use]
+    pub fn structure_type(&self, name: &BabString) -> Option<TypeId> {
+        if self.locals.contains_key(name) {
+            return None;
+        }
+
+        self.program_builder.type_manager.try_layout_of(name)
+            .filter(|layout| !layout.is_enumeration())
+            .map(|layout| *layout.type_id())
+    }
+
     #[must_use]
     pub fn load_this(&self) -> Option<(TypeId, Register)> {
         self.this.clone()
     }
 
+    /// The pointer to the tuple this function returns, if it returns one.
+    #[must_use]
+    pub fn return_pointer(&self) -> Option<(TypeId, Register)> {
+        self.return_pointer
+    }
+
+    /// See [`ProgramBuilder::add_tuple`].
+    #[must_use]
+    pub fn add_tuple(&mut self, elements: &[TypeId]) -> TypeId {
+        self.program_builder.add_tuple(elements)
+    }
+
+    /// See [`ProgramBuilder::add_array`].
+    #[must_use]
+    pub fn add_array(&mut self, element: TypeId) -> TypeId {
+        self.program_builder.add_array(element)
+    }
+
+    /// See [`ProgramBuilder::type_id_for`], for types written inside the body
+    /// of the function.
+    #[must_use]
+    pub fn type_id_for(&mut self, ty: &Type) -> TypeId {
+        self.program_builder.type_id_for(ty, &HashMap::new())
+    }
+
+    /// See [`ProgramBuilder::tuple_return_type`].
+    #[must_use]
+    pub fn tuple_return_type(&self, name: &BabString) -> Option<TypeId> {
+        self.program_builder.tuple_return_type(name)
+    }
+
+    /// See [`ProgramBuilder::report_unsupported`].
+    pub fn report_unsupported(&mut self, feature: impl Into<String>, range: FileRange) {
+        self.program_builder.report_unsupported(feature, range);
+    }
+
+    /// See [`ProgramBuilder::is_operator_method`].
+    #[must_use]
+    pub fn is_operator_method(&self, name: &BabString) -> bool {
+        self.program_builder.is_operator_method(name)
+    }
+
+    /// Allocates the value of type `ty` on the stack, e.g. a tuple, which
+    /// doesn't have a name to use with [`Self::allocate_structure`].
+    #[must_use]
+    pub fn allocate(&mut self, ty: TypeId) -> (&StructureLayout, Register) {
+        let layout = self.program_builder.type_manager.layout(ty);
+        let size = layout.size();
+
+        let dst = self.register_allocator.next();
+
+        self.instructions.push(Instruction::StackAlloc {
+            dst,
+            size,
+        });
+
+        (layout, dst)
+    }
+
+    /// Allocates an array of type `ty` with room for `length` elements on the
+    /// stack, and stores the `lengte` in its header.
+    #[must_use]
+    pub fn allocate_array(&mut self, ty: TypeId, length: usize) -> Register {
+        let layout = self.program_builder.type_manager.layout(ty);
+        let element = layout.element().expect("ICE: opeenvolging zonder elementindeling");
+        let size = layout.size() + element.stride() * length;
+        let length_type = layout.field(&BabString::new_static("lengte")).primitive_type();
+
+        let dst = self.register_allocator.next();
+
+        self.instructions.push(Instruction::StackAlloc {
+            dst,
+            size,
+        });
+
+        let length = self.load_immediate(Immediate::Integer32(length as _));
+        self.store_ptr(dst, Operand::Immediate(Immediate::Integer64(0)), length, length_type);
+
+        dst
+    }
+
     pub fn move_register(&mut self, destination: Register, source: Register) {
         self.instructions.push(Instruction::Move { source, destination });
     }
+
+    /// Enters a loop, where `continue_label` is the start of the next iteration
+    /// and `break_label` is the location after the loop.
+    pub fn push_loop(&mut self, continue_label: Label, break_label: Label) {
+        self.loops.push(LoopLabels { continue_label, break_label });
+    }
+
+    pub fn pop_loop(&mut self) {
+        let popped = self.loops.pop();
+        debug_assert!(popped.is_some(), "Cannot pop a loop when not inside one!");
+    }
+
+    /// Jumps to the location after the innermost loop.
+    pub fn jump_to_loop_break(&mut self) {
+        let location = self.loops.last().expect("`stop` must be inside a loop").break_label;
+        self.jump(location);
+    }
+
+    /// Jumps to the start of the next iteration of the innermost loop.
+    pub fn jump_to_loop_continue(&mut self) {
+        let location = self.loops.last().expect("`ga door` must be inside a loop").continue_label;
+        self.jump(location);
+    }
 }
 
 #[cfg(test)]
@@ -253,6 +489,12 @@ mod tests {
 
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct LoopLabels {
+    continue_label: Label,
+    break_label: Label,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FunctionLocal {
     pub(super) register: Register,
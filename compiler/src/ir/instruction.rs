@@ -12,10 +12,9 @@ pub struct Label {
     pub(super) id: usize,
 }
 
-#[cfg(test)]
 impl Label {
     #[must_use]
-    pub fn new(id: usize) -> Self {
+    pub(crate) fn new(id: usize) -> Self {
         Self { id }
     }
 }
@@ -43,6 +42,17 @@ pub enum Instruction {
     Compare {
         lhs: Register,
         rhs: Operand,
+
+        /// Whether the operands are natural numbers (`n8` up to `n64`), which
+        /// changes the meaning of the ordering [`JumpCondition`]s.
+        unsigned: bool,
+    },
+
+    /// Compares two floating-point values, the result can be used by a
+    /// [`Instruction::JumpConditional`] just like [`Instruction::Compare`].
+    CompareFloatingPoint {
+        lhs: Register,
+        rhs: Register,
     },
 
     //
@@ -104,6 +114,26 @@ pub enum Instruction {
         rhs: Operand,
     },
 
+    FloatingPointMath {
+        operation: FloatingPointOperation,
+        destination: Register,
+        lhs: Register,
+        rhs: Register,
+    },
+
+    /// Converts the signed integer in `source` to a floating-point value.
+    ConvertIntegerToFloatingPoint {
+        destination: Register,
+        source: Register,
+    },
+
+    /// Converts the floating-point value in `source` to a signed integer,
+    /// rounding towards zero.
+    ConvertFloatingPointToInteger {
+        destination: Register,
+        source: Register,
+    },
+
     //
     // Stack Allocation
     //
@@ -131,13 +161,17 @@ pub enum Instruction {
 impl Display for Instruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Instruction::Compare { lhs, rhs } => {
-                f.write_str("Vergelijk ")?;
+            Instruction::Compare { lhs, rhs, unsigned } => {
+                f.write_str(if *unsigned { "VergelijkNatuurlijk " } else { "Vergelijk " })?;
                 lhs.fmt(f)?;
                 f.write_str(", ")?;
                 rhs.fmt(f)
             }
 
+            Instruction::CompareFloatingPoint { lhs, rhs } => {
+                f.write_fmt(format_args!("VergelijkKomma {lhs}, {rhs}"))
+            }
+
             Instruction::LoadImmediate { immediate, destination_reg } => {
                 f.write_str("Laad ")?;
                 destination_reg.fmt(f)?;
@@ -207,6 +241,18 @@ impl Display for Instruction {
                 Ok(())
             }
 
+            Instruction::FloatingPointMath { operation, destination, lhs, rhs } => {
+                f.write_fmt(format_args!("{operation} {destination}, {lhs}, {rhs}"))
+            }
+
+            Instruction::ConvertIntegerToFloatingPoint { destination, source } => {
+                f.write_fmt(format_args!("NaarKommagetal {destination}, {source}"))
+            }
+
+            Instruction::ConvertFloatingPointToInteger { destination, source } => {
+                f.write_fmt(format_args!("NaarGetal {destination}, {source}"))
+            }
+
             Instruction::StackAlloc { dst, size } => {
                 f.write_fmt(format_args!("StapelAllocatie {dst}, #{size}"))
             }
@@ -267,6 +313,19 @@ impl Display for JumpCondition {
 pub enum MathOperation {
     Add,
     Subtract,
+    Multiply,
+
+    /// Division of signed integers, rounding towards zero.
+    Divide,
+
+    /// Division of natural numbers (`n8` up to `n64`).
+    DivideUnsigned,
+
+    /// The remainder of [`Self::Divide`], which has the sign of the lhs.
+    Modulo,
+
+    /// The remainder of [`Self::DivideUnsigned`].
+    ModuloUnsigned,
 }
 
 impl MathOperation {
@@ -275,8 +334,32 @@ impl MathOperation {
         match self {
             Self::Add => "TelOp",
             Self::Subtract => "TrekAf",
+            Self::Multiply => "Vermenigvuldig",
+            Self::Divide => "Deel",
+            Self::DivideUnsigned => "DeelNatuurlijk",
+            Self::Modulo => "Rest",
+            Self::ModuloUnsigned => "RestNatuurlijk",
         }
     }
+
+    /// Computes the operation on two known values, or [`None`] when it can't
+    /// be done, such as dividing by zero.
+    #[must_use]
+    pub fn evaluate(&self, lhs: Immediate, rhs: Immediate) -> Option<Immediate> {
+        let (lhs, rhs) = (lhs.as_i64(), rhs.as_i64());
+
+        let value = match self {
+            Self::Add => lhs.wrapping_add(rhs),
+            Self::Subtract => lhs.wrapping_sub(rhs),
+            Self::Multiply => lhs.wrapping_mul(rhs),
+            Self::Divide => lhs.checked_div(rhs)?,
+            Self::DivideUnsigned => (lhs as u64).checked_div(rhs as u64)? as i64,
+            Self::Modulo => lhs.checked_rem(rhs)?,
+            Self::ModuloUnsigned => (lhs as u64).checked_rem(rhs as u64)? as i64,
+        };
+
+        Some(Immediate::Integer64(value))
+    }
 }
 
 impl Display for MathOperation {
@@ -285,6 +368,32 @@ impl Display for MathOperation {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatingPointOperation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl FloatingPointOperation {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Add => "KommaTelOp",
+            Self::Subtract => "KommaTrekAf",
+            Self::Multiply => "KommaVermenigvuldig",
+            Self::Divide => "KommaDeel",
+        }
+    }
+}
+
+impl Display for FloatingPointOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PrimitiveType {
     signed: bool,
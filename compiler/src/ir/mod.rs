@@ -16,6 +16,7 @@ pub use self::{
     },
     function_builder::FunctionBuilder,
     instruction::{
+        FloatingPointOperation,
         Instruction,
         JumpCondition,
         Label,
@@ -29,5 +30,9 @@ pub use self::{
         RegisterAllocator,
     },
     program::Program,
-    program_builder::ProgramBuilder,
+    program_builder::{
+        GenericFunction,
+        GenericInstantiation,
+        ProgramBuilder,
+    },
 };
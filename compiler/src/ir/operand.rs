@@ -3,17 +3,22 @@
 
 use std::fmt::{Display, Write};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Immediate {
     Integer8(i8),
     Integer16(i16),
     Integer32(i32),
     Integer64(i64),
+    Float64(f64),
 }
 
 impl Immediate {
     #[must_use]
     pub fn shrink_if_possible(self) -> Self {
+        if let Self::Float64(..) = self {
+            return self;
+        }
+
         let value = self.as_i64();
 
         if let Ok(i8) = i8::try_from(value) {
@@ -38,6 +43,7 @@ impl Immediate {
             Self::Integer16(i) => *i as i8,
             Self::Integer32(i) => *i as i8,
             Self::Integer64(i) => *i as i8,
+            Self::Float64(f) => f.to_bits() as i8,
         }
     }
 
@@ -48,6 +54,7 @@ impl Immediate {
             Self::Integer16(i) => *i,
             Self::Integer32(i) => *i as i16,
             Self::Integer64(i) => *i as i16,
+            Self::Float64(f) => f.to_bits() as i16,
         }
     }
 
@@ -58,6 +65,7 @@ impl Immediate {
             Self::Integer16(i) => *i as i32,
             Self::Integer32(i) => *i,
             Self::Integer64(i) => *i as i32,
+            Self::Float64(f) => f.to_bits() as i32,
         }
     }
 
@@ -68,6 +76,17 @@ impl Immediate {
             Self::Integer16(i) => *i as i64,
             Self::Integer32(i) => *i as i64,
             Self::Integer64(i) => *i,
+            Self::Float64(f) => f.to_bits() as i64,
+        }
+    }
+
+    /// Interprets the immediate as a floating-point value. Integers are
+    /// reinterpreted bitwise, as they are when loaded into a register.
+    #[must_use]
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Self::Float64(f) => *f,
+            _ => f64::from_bits(self.as_i64() as u64),
         }
     }
 }
@@ -79,6 +98,7 @@ impl Display for Immediate {
             Self::Integer16(val) => val.fmt(f),
             Self::Integer32(val) => val.fmt(f),
             Self::Integer64(val) => val.fmt(f),
+            Self::Float64(val) => std::fmt::Debug::fmt(val, f),
         }
     }
 }
@@ -127,7 +147,7 @@ impl RegisterAllocator {
 }
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Operand {
     Immediate(Immediate),
     Register(Register),
@@ -1,11 +1,11 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::collections::HashMap;
+use std::{collections::{HashMap, HashSet}, rc::Rc};
 
-use babbelaar::{BabString, Structure};
+use babbelaar::{qualify_name, BabString, ConstantValue, Enumeration, FileRange, FunctionStatement, Structure, Type, TypeQualifier, TypeSpecifier};
 
-use crate::{ir::{function_builder::FunctionLocal, ArgumentName}, ArgumentList, TypeId, TypeManager};
+use crate::{compiler::CallingConvention, CompileError, ir::{function_builder::FunctionLocal, ArgumentName}, ArgumentList, TypeId, TypeManager};
 
 use super::{FunctionBuilder, Program, RegisterAllocator};
 
@@ -13,6 +13,39 @@ use super::{FunctionBuilder, Program, RegisterAllocator};
 pub struct ProgramBuilder {
     pub(super) program: Program,
     pub(super) type_manager: TypeManager,
+
+    /// The module of the code currently being compiled.
+    module: Option<BabString>,
+
+    /// The qualified names of all functions in the program.
+    functions: HashSet<BabString>,
+
+    /// The values of the `vast` declarations, keyed by their qualified name,
+    /// or `Structuur.NAAM` for the constants of structures.
+    constants: HashMap<BabString, ConstantValue>,
+
+    /// The generic functions and methods, keyed by their symbol name, which
+    /// are only compiled once they are called with specific types.
+    generic_functions: HashMap<BabString, GenericFunction>,
+
+    /// The instances of generic functions that still have to be compiled.
+    pending_instantiations: Vec<GenericInstantiation>,
+
+    /// The symbol names of all instances of generic functions requested so
+    /// far, such that every instance is only compiled once.
+    instantiations: HashSet<BabString>,
+
+    /// The functions returning a tuple, keyed by their symbol name. The
+    /// caller allocates the tuple and passes a pointer to it as the last
+    /// argument, where the function stores the elements.
+    tuple_returns: HashMap<BabString, TypeId>,
+
+    /// The symbol names of the methods implementing the builtin interfaces of
+    /// operators, such as `Geld__optellen` for `uitbreiding Optelbaar op Geld`.
+    operator_methods: HashSet<BabString>,
+
+    /// The constructs that couldn't be compiled, see [`CompileError`].
+    errors: Vec<CompileError>,
 }
 
 impl ProgramBuilder {
@@ -21,6 +54,15 @@ impl ProgramBuilder {
         Self {
             program: Program::new(),
             type_manager: TypeManager::new(),
+            module: None,
+            functions: HashSet::new(),
+            constants: HashMap::new(),
+            generic_functions: HashMap::new(),
+            pending_instantiations: Vec::new(),
+            instantiations: HashSet::new(),
+            tuple_returns: HashMap::new(),
+            operator_methods: HashSet::new(),
+            errors: Vec::new(),
         }
     }
 
@@ -33,11 +75,15 @@ impl ProgramBuilder {
             register_allocator: RegisterAllocator::new(),
             argument_registers: Vec::new(),
             this: None,
+            owner: None,
+            return_pointer: None,
             instructions: Vec::new(),
             locals: HashMap::new(),
+            constants: HashMap::new(),
             label_counter: 0,
             label_names: HashMap::new(),
             label_positions: HashMap::new(),
+            loops: Vec::new(),
         };
 
         for (name, type_id) in arguments.iter().cloned() {
@@ -55,6 +101,12 @@ impl ProgramBuilder {
                 ArgumentName::This => {
                     debug_assert_eq!(builder.this, None);
                     builder.this = Some((type_id, register));
+                    builder.owner = Some(type_id);
+                }
+
+                ArgumentName::ReturnPointer => {
+                    debug_assert_eq!(builder.return_pointer, None);
+                    builder.return_pointer = Some((type_id, register));
                 }
             }
         }
@@ -70,12 +122,245 @@ impl ProgramBuilder {
         self.program
     }
 
+    /// Reports a construct that the compiler doesn't support, which fails the
+    /// compilation after all functions are compiled. Types in signatures are
+    /// visited more than once, so the same error is only reported once.
+    pub fn report_unsupported(&mut self, feature: impl Into<String>, range: FileRange) {
+        let error = CompileError::unsupported(feature, range);
+        if !self.errors.contains(&error) {
+            self.errors.push(error);
+        }
+    }
+
+    #[must_use]
+    pub fn take_errors(&mut self) -> Vec<CompileError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    pub fn set_module(&mut self, module: Option<BabString>) {
+        self.type_manager.set_module(module.clone());
+        self.module = module;
+    }
+
+    /// Makes the function available to other functions, before it is built,
+    /// such that [`Self::resolve_function_name`] can find it.
+    pub fn declare_function(&mut self, name: &BabString) {
+        self.functions.insert(self.qualify(name));
+    }
+
+    /// Resolves the `name` of a called function to its qualified name, where
+    /// the functions of the current module take precedence.
+    #[must_use]
+    pub fn resolve_function_name(&self, name: &BabString) -> BabString {
+        let qualified = self.qualify(name);
+        if self.functions.contains(&qualified) {
+            qualified
+        } else {
+            name.clone()
+        }
+    }
+
+    #[must_use]
+    pub fn qualify(&self, name: &BabString) -> BabString {
+        qualify_name(self.module.as_ref(), name)
+    }
+
+    pub fn add_constant(&mut self, name: &BabString, value: ConstantValue) {
+        self.constants.insert(self.qualify(name), value);
+    }
+
+    pub fn add_structure_constant(&mut self, structure: &BabString, name: &BabString, value: ConstantValue) {
+        let structure = self.qualify(structure);
+        self.constants.insert(BabString::new(format!("{structure}.{name}")), value);
+    }
+
+    /// Finds the value of the constant with the given `name`, where the
+    /// constants of the current module take precedence.
+    #[must_use]
+    pub fn constant(&self, name: &BabString) -> Option<&ConstantValue> {
+        self.constants.get(&self.qualify(name))
+            .or_else(|| self.constants.get(name))
+    }
+
+    #[must_use]
+    pub fn structure_constant(&self, structure: &BabString, name: &BabString) -> Option<&ConstantValue> {
+        self.constants.get(&BabString::new(format!("{}.{name}", self.qualify(structure))))
+            .or_else(|| self.constants.get(&BabString::new(format!("{structure}.{name}"))))
+    }
+
+    /// Resolves `NAAM` or `Structuur.NAAM` when the `owner` is given.
+    #[must_use]
+    pub fn resolve_constant(&self, owner: Option<&BabString>, name: &BabString) -> Option<ConstantValue> {
+        match owner {
+            Some(owner) => self.structure_constant(owner, name),
+            None => self.constant(name),
+        }.cloned()
+    }
+
+    pub fn add_generic_function(&mut self, name: BabString, function: &FunctionStatement, call_convention: CallingConvention) {
+        self.generic_functions.insert(name, GenericFunction {
+            function: Rc::new(function.clone()),
+            module: self.module.clone(),
+            call_convention,
+        });
+    }
+
+    /// Requests an instance of the generic function with the symbol `name`,
+    /// for the types of the arguments it is called with, and returns the
+    /// symbol name of that instance. Returns [`None`] when the function
+    /// isn't generic.
+    pub fn instantiate_generic_function(&mut self, name: &BabString, argument_types: &[TypeId]) -> Option<BabString> {
+        let function = self.generic_functions.get(name)?.clone();
+
+        let mut generic_types = HashMap::new();
+        for (parameter, ty) in function.function.parameters.iter().zip(argument_types) {
+            let parameter_type = parameter.ty.specifier.unqualified_name();
+            if function.function.generic_types.iter().any(|x| *x.name.value() == parameter_type) {
+                generic_types.entry(parameter_type).or_insert(*ty);
+            }
+        }
+
+        let mut instance_name = name.to_string();
+        for generic in &function.function.generic_types {
+            let Some(ty) = generic_types.get(generic.name.value()) else {
+                panic!("ICE: type van generieke parameter `{}` van `{name}` is onbekend", generic.name.value());
+            };
+
+            instance_name += "__";
+            instance_name += &self.type_manager.layout(*ty).name().replace('.', "__");
+        }
+
+        let instance_name = BabString::new(instance_name);
+
+        if let Some(return_type) = &function.function.return_type {
+            if let TypeSpecifier::Tuple(..) = return_type.specifier.value() {
+                let return_type = self.type_id_for(return_type, &generic_types);
+                self.declare_tuple_return(instance_name.clone(), return_type);
+            }
+        }
+
+        if self.instantiations.insert(instance_name.clone()) {
+            self.pending_instantiations.push(GenericInstantiation {
+                name: instance_name.clone(),
+                function,
+                generic_types,
+            });
+        }
+
+        Some(instance_name)
+    }
+
+    #[must_use]
+    pub fn next_generic_instantiation(&mut self) -> Option<GenericInstantiation> {
+        self.pending_instantiations.pop()
+    }
+
     pub fn add_structure(&mut self, structure: &Structure)  {
         self.type_manager.add_structure(structure);
     }
 
+    pub fn add_enumeration(&mut self, enumeration: &Enumeration) {
+        self.type_manager.add_enumeration(enumeration);
+    }
+
     #[must_use]
     pub fn type_id_for_structure(&self, name: &BabString) -> TypeId {
         self.type_manager.layout_of(name).type_id().clone()
     }
+
+    /// Returns the qualified name of the structure, which methods are mangled
+    /// with.
+    #[must_use]
+    pub fn structure_name(&self, ty: TypeId) -> &BabString {
+        self.type_manager.layout(ty).name()
+    }
+
+    /// Resolves the `ty`, where the `generic_types` contain the actual types
+    /// of the generic types, if any. The layouts of tuples and arrays are
+    /// created when they are first used.
+    pub fn type_id_for(&mut self, ty: &Type, generic_types: &HashMap<BabString, TypeId>) -> TypeId {
+        if let Some((qualifier, rest)) = ty.qualifiers.split_last() {
+            assert!(matches!(qualifier.value(), TypeQualifier::Array), "ondersteun wijzers");
+
+            let element = Type {
+                specifier: ty.specifier.clone(),
+                qualifiers: rest.to_vec(),
+            };
+            let element = self.type_id_for(&element, generic_types);
+            return self.type_manager.add_array(element);
+        }
+
+        if let TypeSpecifier::Function { .. } = ty.specifier.value() {
+            self.report_unsupported("Het type van een werkwijze-waarde", ty.specifier.range());
+            return TypeId::G64;
+        }
+
+        if let TypeSpecifier::Tuple(elements) = ty.specifier.value() {
+            let elements: Vec<TypeId> = elements.iter()
+                .map(|element| self.type_id_for(element, generic_types))
+                .collect();
+            return self.type_manager.add_tuple(&elements);
+        }
+
+        let type_name = ty.specifier.unqualified_name();
+        if type_name == "Misschien" || type_name == "Resultaat" {
+            self.report_unsupported(format!("Het type `{type_name}`"), ty.specifier.range());
+            return TypeId::G64;
+        }
+
+        match generic_types.get(&type_name) {
+            Some(type_id) => *type_id,
+            None => self.type_id_for_structure(&type_name),
+        }
+    }
+
+    #[must_use]
+    pub fn add_tuple(&mut self, elements: &[TypeId]) -> TypeId {
+        self.type_manager.add_tuple(elements)
+    }
+
+    #[must_use]
+    pub fn add_array(&mut self, element: TypeId) -> TypeId {
+        self.type_manager.add_array(element)
+    }
+
+    pub fn declare_tuple_return(&mut self, name: BabString, ty: TypeId) {
+        self.tuple_returns.insert(name, ty);
+    }
+
+    /// Returns the type of the tuple the function with the symbol `name`
+    /// returns, if it returns a tuple.
+    #[must_use]
+    pub fn tuple_return_type(&self, name: &BabString) -> Option<TypeId> {
+        self.tuple_returns.get(name).copied()
+    }
+
+    pub fn declare_operator_method(&mut self, name: BabString) {
+        self.operator_methods.insert(name);
+    }
+
+    /// Returns whether the method with the symbol `name` implements an
+    /// operator, which is then called instead of using the operator directly.
+    #[must_use]
+    pub fn is_operator_method(&self, name: &BabString) -> bool {
+        self.operator_methods.contains(name)
+    }
+}
+
+/// A generic `werkwijze`, which is compiled once for every combination of
+/// types it is called with.
+#[derive(Debug, Clone)]
+pub struct GenericFunction {
+    pub function: Rc<FunctionStatement>,
+    pub module: Option<BabString>,
+    pub call_convention: CallingConvention,
+}
+
+/// An instance of a [`GenericFunction`] that has to be compiled, where each
+/// generic type is replaced by an actual type.
+#[derive(Debug, Clone)]
+pub struct GenericInstantiation {
+    pub name: BabString,
+    pub function: GenericFunction,
+    pub generic_types: HashMap<BabString, TypeId>,
 }
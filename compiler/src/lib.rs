@@ -3,6 +3,7 @@
 
 mod backend;
 mod compiler;
+mod error;
 mod interpreter;
 mod ir;
 mod memory;
@@ -23,11 +24,15 @@ pub use self::{
         RegisterAllocator,
     },
     compiler::Compiler,
+    error::CompileError,
     interpreter::Interpreter,
     ir::{
         ArgumentList,
+        FloatingPointOperation,
         Function,
         FunctionBuilder,
+        GenericFunction,
+        GenericInstantiation,
         Immediate,
         Instruction,
         JumpCondition,
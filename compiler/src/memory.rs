@@ -1,9 +1,9 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::{collections::HashMap, rc::Rc};
+use std::{collections::HashMap, ops::Range, rc::Rc};
 
-use babbelaar::{BabString, Builtin, BuiltinType, Expression, Structure, Type};
+use babbelaar::{qualify_name, BabString, Builtin, BuiltinType, Enumeration, Expression, Ranged, Structure, Type};
 
 use crate::PrimitiveType;
 
@@ -18,14 +18,14 @@ pub struct FieldLayout {
     /// from the `size` when it is e.g. aligned.
     stride: usize,
 
-    default_value_expression: Option<Rc<Expression>>,
+    default_value_expression: Option<Rc<Ranged<Expression>>>,
 
     type_id: TypeId,
 }
 
 impl FieldLayout {
     #[must_use]
-    pub fn default_value_expression(&self) -> Option<&Rc<Expression>> {
+    pub fn default_value_expression(&self) -> Option<&Rc<Ranged<Expression>>> {
         self.default_value_expression.as_ref()
     }
 
@@ -50,7 +50,7 @@ impl FieldLayout {
     }
 
     pub fn primitive_type(&self) -> PrimitiveType {
-        PrimitiveType::new(self.size, true)
+        PrimitiveType::new(self.size, !self.type_id.is_unsigned_integer())
     }
 }
 
@@ -62,6 +62,13 @@ pub struct StructureLayout {
     size: usize,
     fields: Vec<FieldLayout>,
     field_names: HashMap<BabString, usize>,
+
+    /// The variants of an enumeration, empty for ordinary structures.
+    variants: Vec<VariantLayout>,
+
+    /// The layout of the elements of an array, which follow the header with
+    /// the `lengte`. `None` for all other types.
+    element: Option<FieldLayout>,
 }
 
 impl StructureLayout {
@@ -89,6 +96,28 @@ impl StructureLayout {
         &self.fields[index]
     }
 
+    /// Returns the index of the variant, together with the layout of its
+    /// fields, if this layout describes an enumeration.
+    #[must_use]
+    pub fn variant(&self, name: &BabString) -> Option<(usize, &[FieldLayout])> {
+        let index = self.variants.iter().position(|variant| variant.name == *name)?;
+        let fields = &self.fields[self.variants[index].fields.clone()];
+        Some((index, fields))
+    }
+
+    /// Returns the layout of the elements, if this layout describes an array.
+    /// The offset of the first element is given, and every next element is
+    /// a stride further.
+    #[must_use]
+    pub fn element(&self) -> Option<&FieldLayout> {
+        self.element.as_ref()
+    }
+
+    #[must_use]
+    pub fn is_enumeration(&self) -> bool {
+        !self.variants.is_empty()
+    }
+
     #[must_use]
     pub fn type_id(&self) -> &TypeId {
         &self.type_id
@@ -100,10 +129,22 @@ impl StructureLayout {
     }
 }
 
+#[derive(Debug, Clone)]
+struct VariantLayout {
+    name: BabString,
+
+    /// The indices of the fields of this variant in [`StructureLayout::fields`].
+    fields: Range<usize>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TypeManager {
     types: Vec<StructureLayout>,
     type_names: HashMap<BabString, usize>,
+
+    /// The module of the code currently being compiled, which takes precedence
+    /// when looking up a type by name.
+    module: Option<BabString>,
 }
 
 impl TypeManager {
@@ -115,15 +156,21 @@ impl TypeManager {
         this
     }
 
+    pub fn set_module(&mut self, module: Option<BabString>) {
+        self.module = module;
+    }
+
     pub fn add_structure(&mut self, structure: &Structure) {
         let mut layout = StructureLayout {
-            name: structure.name.value().clone(),
+            name: qualify_name(self.module.as_ref(), structure.name.value()),
             type_id: TypeId {
                 index: self.types.len(),
             },
             size: 0,
             fields: Vec::new(),
             field_names: HashMap::new(),
+            variants: Vec::new(),
+            element: None,
         };
 
         let mut offset = 0;
@@ -135,7 +182,7 @@ impl TypeManager {
                 offset,
                 size,
                 stride: size,
-                default_value_expression: ast_field.default_value.as_ref().map(|x| Rc::new(x.value().clone())),
+                default_value_expression: ast_field.default_value.as_ref().map(|x| Rc::new(x.clone())),
                 type_id,
             };
 
@@ -149,6 +196,174 @@ impl TypeManager {
         self.add_type(layout);
     }
 
+    /// Lays out an enumeration as a tagged union: the `tag` field holds the
+    /// index of the variant, and the fields of each variant are stored after
+    /// it as `Variant.field`, overlapping the fields of the other variants.
+    pub fn add_enumeration(&mut self, enumeration: &Enumeration) {
+        let mut layout = StructureLayout {
+            name: qualify_name(self.module.as_ref(), enumeration.name.value()),
+            type_id: TypeId {
+                index: self.types.len(),
+            },
+            size: 0,
+            fields: Vec::new(),
+            field_names: HashMap::new(),
+            variants: Vec::new(),
+            element: None,
+        };
+
+        let tag_size = self.layout(TypeId::G32).size;
+        let tag = FieldLayout {
+            offset: 0,
+            size: tag_size,
+            stride: tag_size.next_multiple_of(self.platform_alignment_size()),
+            default_value_expression: None,
+            type_id: TypeId::G32,
+        };
+
+        let payload_start = tag.stride;
+        layout.add_field(BabString::new_static("tag"), tag);
+
+        let mut size = payload_start;
+        for variant in &enumeration.variants {
+            let mut offset = payload_start;
+            let first_field = layout.fields.len();
+
+            for ast_field in &variant.fields {
+                let size = self.size_of(&ast_field.ty);
+                let type_id = self.layout_of(&ast_field.ty.specifier.unqualified_name()).type_id;
+
+                let field = FieldLayout {
+                    offset,
+                    size,
+                    stride: size,
+                    default_value_expression: None,
+                    type_id,
+                };
+
+                offset += field.stride.next_multiple_of(self.platform_alignment_size());
+
+                let name = BabString::new(format!("{}.{}", variant.name.value(), ast_field.name.value()));
+                layout.add_field(name, field);
+            }
+
+            layout.variants.push(VariantLayout {
+                name: variant.name.value().clone(),
+                fields: first_field..layout.fields.len(),
+            });
+
+            size = size.max(offset);
+        }
+
+        layout.size = size;
+
+        self.add_type(layout);
+    }
+
+    /// Lays out a tuple like a structure, where the elements are the fields
+    /// `0`, `1`, etc. Tuples are anonymous, so the layout is shared by all
+    /// tuples with the same element types.
+    pub fn add_tuple(&mut self, elements: &[TypeId]) -> TypeId {
+        let names: Vec<&str> = elements.iter().map(|ty| self.layout(*ty).name.as_str()).collect();
+        let name = BabString::new(format!("({})", names.join(", ")));
+
+        if let Some(index) = self.type_names.get(&name) {
+            return self.types[*index].type_id;
+        }
+
+        let mut layout = StructureLayout {
+            name,
+            type_id: TypeId {
+                index: self.types.len(),
+            },
+            size: 0,
+            fields: Vec::new(),
+            field_names: HashMap::new(),
+            variants: Vec::new(),
+            element: None,
+        };
+
+        let mut offset = 0;
+        for (index, type_id) in elements.iter().enumerate() {
+            let size = self.stored_size_of(*type_id);
+
+            let field = FieldLayout {
+                offset,
+                size,
+                stride: size,
+                default_value_expression: None,
+                type_id: *type_id,
+            };
+
+            offset += field.stride.next_multiple_of(self.platform_alignment_size());
+
+            layout.add_field(BabString::new(index.to_string()), field);
+        }
+
+        layout.size = offset;
+
+        let type_id = layout.type_id;
+        self.add_type(layout);
+        type_id
+    }
+
+    /// Lays out an array as a header with the `lengte` field, followed by the
+    /// elements. The size of the layout is only that of the header, since the
+    /// number of elements is only known when the array is allocated.
+    pub fn add_array(&mut self, element: TypeId) -> TypeId {
+        let name = BabString::new(format!("{}[]", self.layout(element).name));
+
+        if let Some(index) = self.type_names.get(&name) {
+            return self.types[*index].type_id;
+        }
+
+        let header_size = self.pointer_size();
+        let element_size = self.stored_size_of(element);
+
+        let mut layout = StructureLayout {
+            name,
+            type_id: TypeId {
+                index: self.types.len(),
+            },
+            size: header_size,
+            fields: Vec::new(),
+            field_names: HashMap::new(),
+            variants: Vec::new(),
+            element: Some(FieldLayout {
+                offset: header_size,
+                size: element_size,
+                stride: element_size.next_multiple_of(self.platform_alignment_size()),
+                default_value_expression: None,
+                type_id: element,
+            }),
+        };
+
+        layout.add_field(BabString::new_static("lengte"), FieldLayout {
+            offset: 0,
+            size: 4,
+            stride: 4,
+            default_value_expression: None,
+            type_id: TypeId::G32,
+        });
+
+        let type_id = layout.type_id;
+        self.add_type(layout);
+        type_id
+    }
+
+    /// The size of a value of type `ty` when stored inside a tuple or array.
+    /// Values that don't fit in a register are passed around as a pointer,
+    /// so that is what is stored.
+    #[must_use]
+    fn stored_size_of(&self, ty: TypeId) -> usize {
+        let layout = self.layout(ty);
+        if layout.fields.is_empty() {
+            layout.size
+        } else {
+            self.pointer_size()
+        }
+    }
+
     #[must_use]
     pub fn size_of(&self, ty: &Type) -> usize {
         assert!(ty.qualifiers.is_empty());
@@ -160,8 +375,25 @@ impl TypeManager {
         &self.types[ty.index]
     }
 
+    #[must_use]
+    fn index_of(&self, name: &BabString) -> Option<usize> {
+        if self.module.is_some() {
+            if let Some(index) = self.type_names.get(&qualify_name(self.module.as_ref(), name)) {
+                return Some(*index);
+            }
+        }
+
+        self.type_names.get(name).copied()
+    }
+
+    #[must_use]
+    pub fn try_layout_of(&self, name: &BabString) -> Option<&StructureLayout> {
+        let index = self.index_of(name)?;
+        Some(&self.types[index])
+    }
+
     pub fn layout_of(&self, name: &BabString) -> &StructureLayout {
-        let Some(index) = self.type_names.get(name).copied() else {
+        let Some(index) = self.index_of(name) else {
             panic!("ICE: ongeldige typenaam gegeven: `{name}`!")
         };
 
@@ -186,6 +418,8 @@ impl TypeManager {
             type_id: TypeId::SLINGER,
             fields: Vec::new(),
             field_names: HashMap::new(),
+            variants: Vec::new(),
+            element: None,
             size: 0,
         };
 
@@ -217,9 +451,16 @@ impl TypeManager {
                 BuiltinType::G8 => 1,
                 BuiltinType::G16 => 2,
                 BuiltinType::G32 => 4,
+                BuiltinType::G64 => 8,
+                BuiltinType::Kommagetal => 8,
+                BuiltinType::N8 => 1,
+                BuiltinType::N16 => 2,
+                BuiltinType::N32 => 4,
+                BuiltinType::N64 => 8,
                 BuiltinType::Teken => 4,
 
                 BuiltinType::Null => continue,
+                BuiltinType::Reeks => continue,
                 BuiltinType::Slinger => continue,
             };
 
@@ -228,6 +469,12 @@ impl TypeManager {
                 BuiltinType::G8 => TypeId::G8,
                 BuiltinType::G16 => TypeId::G16,
                 BuiltinType::G32 => TypeId::G32,
+                BuiltinType::G64 => TypeId::G64,
+                BuiltinType::Kommagetal => TypeId::KOMMAGETAL,
+                BuiltinType::N8 => TypeId::N8,
+                BuiltinType::N16 => TypeId::N16,
+                BuiltinType::N32 => TypeId::N32,
+                BuiltinType::N64 => TypeId::N64,
                 BuiltinType::Teken => TypeId::TEKEN,
                 _ => unreachable!(),
             };
@@ -238,6 +485,8 @@ impl TypeManager {
                 size,
                 fields: Vec::new(),
                 field_names: HashMap::new(),
+                variants: Vec::new(),
+                element: None,
             };
 
             self.add_type(layout);
@@ -260,6 +509,17 @@ impl TypeId {
     pub const G8: Self = Self { index: 1 };
     pub const G16: Self = Self { index: 2 };
     pub const G32: Self = Self { index: 3 };
-    pub const TEKEN: Self = Self { index: 4 };
-    pub const SLINGER: Self = Self { index: 5 };
+    pub const G64: Self = Self { index: 4 };
+    pub const KOMMAGETAL: Self = Self { index: 5 };
+    pub const N8: Self = Self { index: 6 };
+    pub const N16: Self = Self { index: 7 };
+    pub const N32: Self = Self { index: 8 };
+    pub const N64: Self = Self { index: 9 };
+    pub const TEKEN: Self = Self { index: 10 };
+    pub const SLINGER: Self = Self { index: 11 };
+
+    #[must_use]
+    pub fn is_unsigned_integer(&self) -> bool {
+        [Self::N8, Self::N16, Self::N32, Self::N64].contains(self)
+    }
 }
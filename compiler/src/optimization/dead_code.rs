@@ -150,6 +150,11 @@ impl DeadStoreEliminator {
                     }
                 }
 
+                Instruction::CompareFloatingPoint { lhs, rhs } => {
+                    self.notice_read(lhs);
+                    self.notice_read(rhs);
+                }
+
                 Instruction::Call { ret_val_reg, arguments, .. } => {
                     for arg in arguments {
                         self.notice_read(arg);
@@ -162,17 +167,13 @@ impl DeadStoreEliminator {
                     self.notice_write(register, index);
                 }
 
-                Instruction::Jump { location } => {
-                    _ = location;
+                // The value of a register can be read after a jump or by
+                // another path reaching a label, so the writes before it can't
+                // be considered useless anymore.
+                Instruction::Jump { .. } | Instruction::JumpConditional { .. } | Instruction::Label(..) => {
+                    self.writing_instructions_per_register.clear();
                 }
 
-                Instruction::JumpConditional { condition, location } => {
-                    _ = condition;
-                    _ = location;
-                }
-
-                Instruction::Label(..) => (),
-
                 Instruction::LoadImmediate { destination_reg, immediate } => {
                     _ = immediate;
                     self.notice_write(destination_reg, index);
@@ -203,6 +204,20 @@ impl DeadStoreEliminator {
                     self.notice_write(destination, index);
                 }
 
+                Instruction::FloatingPointMath { operation, destination, lhs, rhs } => {
+                    _ = operation;
+
+                    self.notice_read(lhs);
+                    self.notice_read(rhs);
+                    self.notice_write(destination, index);
+                }
+
+                Instruction::ConvertIntegerToFloatingPoint { destination, source } |
+                Instruction::ConvertFloatingPointToInteger { destination, source } => {
+                    self.notice_read(source);
+                    self.notice_write(destination, index);
+                }
+
                 Instruction::Return { value_reg } => {
                     if let Some(return_value) = value_reg {
                         self.notice_read(return_value);
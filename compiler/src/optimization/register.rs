@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use crate::{Function, FunctionOptimizer, Immediate, Instruction, MathOperation, Operand, Register};
+use crate::{Function, FunctionOptimizer, Immediate, Instruction, Operand, Register};
 
 #[derive(Debug, Default)]
 pub struct RegisterInliner {
@@ -20,16 +20,19 @@ impl FunctionOptimizer for RegisterInliner {
                     self.values.remove(ret_val_reg);
                 }
 
-                Instruction::Compare { lhs, rhs } => {
+                Instruction::Compare { lhs, rhs, unsigned } => {
                     let lhs = lhs.clone();
+                    let unsigned = *unsigned;
 
                     let Operand::Register(register) = rhs else { continue };
                     let Some(rhs) = self.values.get(register) else { continue };
                     let rhs = Operand::Immediate(*rhs);
 
-                    *instruction = Instruction::Compare { lhs, rhs };
+                    *instruction = Instruction::Compare { lhs, rhs, unsigned };
                 }
 
+                Instruction::CompareFloatingPoint { .. } => (),
+
                 Instruction::Increment { register } => {
                     if let Some(value) = self.values.get(register) {
                         self.values.insert(register.clone(), Immediate::Integer64(value.as_i64() + 1));
@@ -51,7 +54,10 @@ impl FunctionOptimizer for RegisterInliner {
                     self.values.insert(destination_reg.clone(), immediate.clone());
                 }
 
-                Instruction::Label(..) => (),
+                Instruction::Label(..) => {
+                    // Another path can jump to this label with other values.
+                    self.values.clear();
+                }
 
                 Instruction::Move { source, destination } => {
                     if let Some(known_value) = self.values.get(source).cloned() {
@@ -88,14 +94,10 @@ impl FunctionOptimizer for RegisterInliner {
                     // TODO: is it necessary to honor the bit size of the
                     //       integer (wrapping at that boundary), or would
                     //       the CPU also overflow?
-                    let value = match operation {
-                        MathOperation::Add => {
-                            Immediate::Integer64(lhs.as_i64().wrapping_add(rhs.as_i64()))
-                        }
-
-                        MathOperation::Subtract => {
-                            Immediate::Integer64(lhs.as_i64().wrapping_sub(rhs.as_i64()))
-                        }
+                    let Some(value) = operation.evaluate(lhs, rhs) else {
+                        // Dividing by zero is left to happen at runtime.
+                        self.values.remove(destination);
+                        continue;
                     };
 
                     self.values.insert(destination.clone(), value);
@@ -107,6 +109,15 @@ impl FunctionOptimizer for RegisterInliner {
                     };
                 }
 
+                Instruction::FloatingPointMath { destination, .. } => {
+                    self.values.remove(destination);
+                }
+
+                Instruction::ConvertIntegerToFloatingPoint { destination, .. } |
+                Instruction::ConvertFloatingPointToInteger { destination, .. } => {
+                    self.values.remove(destination);
+                }
+
                 Instruction::Return { .. } => {
 
                 }
@@ -5,7 +5,7 @@ use std::{error::Error, fmt::Display, mem::replace, path::{Path, PathBuf}};
 
 use babbelaar::ParseTree;
 
-use crate::{backend::Amd64CodeGenerator, os::{macos::MacOsLdLinker, windows::WindowsLinkLinker}, AArch64CodeGenerator, Architecture, CompiledObject, Compiler, Function, OperatingSystem, Platform};
+use crate::{backend::Amd64CodeGenerator, os::{macos::MacOsLdLinker, windows::WindowsLinkLinker}, AArch64CodeGenerator, Architecture, CompiledObject, CompileError, Compiler, Function, OperatingSystem, Platform};
 
 #[derive(Debug)]
 pub struct Pipeline {
@@ -22,9 +22,9 @@ impl Pipeline {
         }
     }
 
-    pub fn compile_trees(&mut self, trees: &[ParseTree]) {
+    pub fn compile_trees(&mut self, trees: &[ParseTree]) -> Result<(), Vec<CompileError>> {
         let mut compiler = Compiler::new();
-        compiler.compile_trees(trees);
+        compiler.compile_trees(trees)?;
 
         let program = compiler.finish();
         println!("Program: {program}");
@@ -32,6 +32,8 @@ impl Pipeline {
         for function in program.functions() {
             self.code_gen(function);
         }
+
+        Ok(())
     }
 
     fn code_gen(&mut self, function: &Function) {
@@ -164,7 +164,7 @@ fn compile_and_execute(function: &'static str, code: &str) -> isize {
     let tree = parse_string_to_tree(code).unwrap();
 
     let mut compiler = Compiler::new();
-    compiler.compile_trees(&[tree]);
+    compiler.compile_trees(&[tree]).unwrap();
 
     let program = compiler.finish();
     println!("{program}");
@@ -3,6 +3,7 @@
 
 use babbelaar::{parse_string_to_tree, BabString, ParseDiagnostic};
 use babbelaar_compiler::{Compiler, Immediate, Interpreter};
+use rstest::rstest;
 
 fn compile_and_interpret(code: &str, function: &'static str) -> Option<Immediate> {
     let tree = match parse_string_to_tree(code) {
@@ -20,7 +21,7 @@ fn compile_and_interpret(code: &str, function: &'static str) -> Option<Immediate
     };
 
     let mut compiler = Compiler::new();
-    compiler.compile_trees(&[tree]);
+    compiler.compile_trees(&[tree]).unwrap();
 
     let program = compiler.finish();
 
@@ -30,6 +31,17 @@ fn compile_and_interpret(code: &str, function: &'static str) -> Option<Immediate
     interpreter.execute_function(&BabString::new_static(function), Vec::new())
 }
 
+/// Returns the constructs the compiler reported as unsupported.
+fn compile_and_return_unsupported(code: &str) -> Vec<String> {
+    let tree = parse_string_to_tree(code).unwrap();
+
+    let mut compiler = Compiler::new();
+    match compiler.compile_trees(&[tree]) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors.iter().map(|error| error.feature().to_string()).collect(),
+    }
+}
+
 #[test]
 fn function_that_returns_two() {
     let value = compile_and_interpret("
@@ -66,6 +78,29 @@ fn function_with_jump_and_returning_zero() {
     assert_eq!(value.map(|x| x.as_i64()), Some(0));
 }
 
+#[test]
+fn comparisons_and_negations_can_be_used_as_values() {
+    let value = compile_and_interpret("
+    werkwijze vergelijkingAlsWaarde() -> g32 {
+        stel groter = 3 > 2;
+        stel ongelijk = 3 != 3;
+        stel totaal = -5;
+
+        als groter {
+            totaal = totaal + 10;
+        }
+
+        als !ongelijk {
+            totaal = totaal + 100;
+        }
+
+        bekeer totaal;
+    }
+    ", "vergelijkingAlsWaarde");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(105));
+}
+
 #[test]
 fn function_with_for_statement() {
     let value = compile_and_interpret("
@@ -82,6 +117,40 @@ fn function_with_for_statement() {
     assert_eq!(value.map(|x| x.as_i64()), Some(100));
 }
 
+#[test]
+fn function_with_for_statement_with_step() {
+    let value = compile_and_interpret("
+    werkwijze volg_i_in_reeks_10_tot_en_met_0() -> g32 {
+        stel totaal = 0;
+        volg i in reeks(10, 0, stap: -2, totEnMet: waar) {
+            totaal = totaal + i;
+        }
+        bekeer totaal;
+    }
+    ", "volg_i_in_reeks_10_tot_en_met_0");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(30));
+}
+
+#[test]
+fn function_with_for_statement_with_variable_step() {
+    let value = compile_and_interpret("
+    werkwijze som(begin: g32, eind: g32, stap: g32) -> g32 {
+        stel totaal = 0;
+        volg i in reeks(begin, eind, stap: stap) {
+            totaal += i;
+        }
+        bekeer totaal;
+    }
+
+    werkwijze volg_met_variabele_stap() -> g32 {
+        bekeer som(0, 10, 3) - som(10, 0, -3) + som(0, 10, 0);
+    }
+    ", "volg_met_variabele_stap");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(-4));
+}
+
 #[test]
 fn structure_and_using_function() {
     let value = compile_and_interpret("
@@ -179,3 +248,530 @@ fn assignment_statement() {
 
     assert_eq!(value.map(|x| x.as_i64()), Some(72));
 }
+
+#[test]
+fn sized_arrays_start_out_with_default_values() {
+    let value = compile_and_interpret("
+    vast GROOTTE: g32 = 4;
+
+    werkwijze opeenvolgingMetGrootte() -> g32 {
+        stel getallen = nieuw g32[GROOTTE];
+        getallen[2] = 7;
+        bekeer getallen[0] + getallen[2] + getallen.lengte();
+    }
+    ", "opeenvolgingMetGrootte");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(11));
+}
+
+#[test]
+fn compound_assignment_statement() {
+    let value = compile_and_interpret("
+    structuur Teller {
+        veld waarde: g32,
+    }
+
+    werkwijze samengesteldeToewijzing() -> g32 {
+        stel teller = nieuw Teller {
+            waarde: 40,
+        };
+
+        stel getal = 10;
+        getal += 5;
+        getal -= 3;
+
+        teller.waarde += getal;
+        teller.waarde -= 2;
+
+        bekeer teller.waarde;
+    }
+
+    ", "samengesteldeToewijzing");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(50));
+}
+
+#[rstest]
+#[case(7, 3, 7 * 3 + 7 / 3 + 7 % 3)]
+#[case(-7, 3, -7 * 3 + -7 / 3 + -7 % 3)]
+#[case(7, -3, 7 * -3 + 7 / -3 + 7 % -3)]
+fn integer_multiply_divide_and_modulo(#[case] lhs: i64, #[case] rhs: i64, #[case] expected: i64) {
+    let value = compile_and_interpret(&format!("
+    werkwijze reken(a: g32, b: g32) -> g32 {{
+        stel product = 1;
+        product *= a;
+        product *= b;
+
+        stel quotient = 0;
+        quotient += a;
+        quotient /= b;
+
+        stel rest = 0;
+        rest += a;
+        rest %= b;
+
+        bekeer product + quotient + rest;
+    }}
+
+    werkwijze main() -> g32 {{
+        bekeer reken({lhs}, {rhs});
+    }}
+    "), "main");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(expected));
+}
+
+#[test]
+fn constants_are_evaluated_at_compile_time() {
+    let value = compile_and_interpret("
+    vast BASIS: g32 = 10;
+
+    structuur Buffer {
+        vast GROOTTE: g32 = BASIS * 4,
+
+        veld inhoud: g32,
+    }
+
+    werkwijze vasteWaarden() -> g32 {
+        vast EXTRA: g32 = Buffer.GROOTTE + 2;
+        bekeer EXTRA + BASIS;
+    }
+
+    ", "vasteWaarden");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(52));
+}
+
+#[test]
+fn constants_can_refer_to_later_constants() {
+    let value = compile_and_interpret("
+    vast TOTAAL: g32 = BLOK * 3;
+    vast BLOK: g32 = 4;
+
+    structuur Rooster {
+        vast CELLEN: g32 = BREEDTE * BREEDTE,
+        vast BREEDTE: g32 = BLOK + 1,
+
+        veld inhoud: g32,
+    }
+
+    werkwijze vasteWaarden() -> g32 {
+        bekeer TOTAAL + Rooster.CELLEN;
+    }
+
+    ", "vasteWaarden");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(37));
+}
+
+#[test]
+fn generic_functions_are_instantiated_per_type() {
+    let value = compile_and_interpret("
+    structuur Doos {
+        veld inhoud: g32,
+
+        werkwijze ofwel<T>(standaard: T) -> T {
+            bekeer standaard;
+        }
+    }
+
+    werkwijze keuze<T>(voorwaarde: bool, a: T, b: T) -> T {
+        als voorwaarde {
+            bekeer a;
+        }
+
+        bekeer b;
+    }
+
+    werkwijze generiek() -> g32 {
+        stel doos = nieuw Doos {
+            inhoud: 1,
+        };
+
+        bekeer keuze(onwaar, 3, doos.ofwel(4)) + keuze(waar, 10, 20);
+    }
+
+    ", "generiek");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(14));
+}
+
+#[test]
+fn tuples_are_returned_and_destructured() {
+    let value = compile_and_interpret("
+    werkwijze somEnVerschil(a: g32, b: g32) -> (g32, g32) {
+        bekeer (a + b, a - b);
+    }
+
+    werkwijze paar<T>(a: T, b: T) -> (T, T) {
+        bekeer (a, b);
+    }
+
+    werkwijze tupels() -> g32 {
+        stel (som, verschil) = somEnVerschil(10, 3);
+        stel (x, y) = paar(som, 1);
+        stel (_, z) = (x, verschil + y);
+        bekeer som - verschil + z;
+    }
+
+    ", "tupels");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(14));
+}
+
+#[test]
+fn array_literals_are_laid_out_with_their_length() {
+    let value = compile_and_interpret("
+    werkwijze opeenvolgingen() -> g32 {
+        stel getallen = [10, 20, 30];
+        stel rooster = [[1, 2], [3]];
+        stel leeg: g32[] = [];
+        getallen[1] = 5;
+        bekeer getallen[0] + getallen[1] + rooster[1][0] + getallen.lengte() - leeg.lengte();
+    }
+
+    ", "opeenvolgingen");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(21));
+}
+
+#[test]
+fn for_statement_over_array() {
+    let value = compile_and_interpret("
+    werkwijze somVanOpeenvolging() -> g32 {
+        stel totaal = 0;
+        volg getal in [1, 2, 3, 4] {
+            als getal == 3 {
+                ga door;
+            }
+            totaal = totaal + getal;
+        }
+
+        volg leeg in nieuw g32[0] {
+            bekeer 0;
+        }
+
+        bekeer totaal;
+    }
+    ", "somVanOpeenvolging");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(7));
+}
+
+#[test]
+fn annotated_literals_are_laid_out_with_the_annotated_type() {
+    let value = compile_and_interpret("
+    werkwijze kleintjes() -> g8 {
+        stel getallen: g8[] = [1, 2, 3];
+        stel paar: (g8, g32) = (4, 5);
+        stel (a, _) = paar;
+        bekeer getallen[0] + getallen[2] + a;
+    }
+
+    ", "kleintjes");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(8));
+}
+
+#[test]
+fn static_methods_are_called_without_this() {
+    let value = compile_and_interpret("
+    structuur Teller {
+        vast BEGIN: g32 = 5,
+        veld waarde: g32,
+
+        gedeeld werkwijze begin() -> g32 {
+            bekeer BEGIN;
+        }
+
+        gedeeld werkwijze som(a: g32, b: g32) -> g32 {
+            bekeer a + b;
+        }
+    }
+
+    werkwijze tellers() -> g32 {
+        bekeer Teller.som(Teller.begin(), 2);
+    }
+
+    ", "tellers");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(7));
+}
+
+#[test]
+fn operators_on_structures_call_builtin_interface_methods() {
+    let value = compile_and_interpret("
+    structuur Geld {
+        veld centen: g32,
+    }
+
+    uitbreiding Optelbaar op Geld {
+        werkwijze optellen(ander: Geld) -> Geld {
+            bekeer nieuw Geld {
+                centen: dit.centen + ander.centen,
+            };
+        }
+    }
+
+    uitbreiding Vergelijkbaar op Geld {
+        werkwijze vergelijk(ander: Geld) -> g32 {
+            bekeer dit.centen - ander.centen;
+        }
+    }
+
+    uitbreiding Gelijkwaardig op Geld {
+        werkwijze isGelijkAan(ander: Geld) -> bool {
+            als dit.centen == ander.centen {
+                bekeer waar;
+            }
+
+            bekeer onwaar;
+        }
+    }
+
+    werkwijze geld() -> g32 {
+        stel a = nieuw Geld {
+            centen: 150,
+        };
+        stel b = nieuw Geld {
+            centen: 250,
+        };
+        stel verwacht = nieuw Geld {
+            centen: 400,
+        };
+        stel som = a + b;
+
+        als b < a {
+            bekeer 1;
+        }
+
+        als som == verwacht {
+            bekeer som.centen;
+        }
+
+        bekeer 2;
+    }
+
+    ", "geld");
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(400));
+}
+
+#[test]
+fn functions_in_module_are_mangled_and_prefer_own_module() {
+    let root = parse_string_to_tree("
+    werkwijze twee() -> g32 {
+        bekeer 3;
+    }
+    ").unwrap();
+
+    let module = parse_string_to_tree("
+    module wiskunde;
+
+    werkwijze twee() -> g32 {
+        bekeer 2;
+    }
+
+    werkwijze vier() -> g32 {
+        bekeer twee() + twee();
+    }
+    ").unwrap();
+
+    let mut compiler = Compiler::new();
+    compiler.compile_trees(&[root, module]).unwrap();
+
+    let program = compiler.finish();
+
+    let mut interpreter = Interpreter::new(program);
+    let value = interpreter.execute_function(&BabString::new_static("wiskunde__vier"), Vec::new());
+
+    assert_eq!(value.map(|x| x.as_i64()), Some(4));
+}
+
+#[rstest]
+#[case(
+    "
+    werkwijze een(tekst: Slinger) -> g32 {
+        bekeer kies tekst {
+            \"een\" => 1,
+            _ => 0,
+        };
+    }
+    ",
+    &["Een slingerpatroon binnen `kies`"],
+)]
+#[case(
+    "
+    werkwijze rest(a: kommagetal, b: kommagetal) -> kommagetal {
+        bekeer a % b;
+    }
+    ",
+    &["De operator `%` voor kommagetallen"],
+)]
+#[case(
+    "
+    werkwijze laagsteBit(a: g32) -> g32 {
+        bekeer a & 1;
+    }
+    ",
+    &["De operator `&` voor gehele getallen"],
+)]
+#[case(
+    "
+    werkwijze zoek(getallen: g32[], i: g32) -> bool {
+        stel getal = getallen[i];
+        bekeer getallen.bevat(getal);
+    }
+    ",
+    &[
+        "Een index die niet vast is",
+        "De opeenvolgingswerkwijze `bevat`",
+    ],
+)]
+#[case(
+    "
+    werkwijze isGroter(a: g32, b: g32) -> bool {
+        bekeer waar;
+    }
+
+    werkwijze vergelijk(a: g32, b: g32, vergelijker: werkwijze(g32, g32) -> bool) -> bool {
+        bekeer vergelijker(a, b);
+    }
+
+    werkwijze probeer(x: g32) -> bool {
+        stel f = werkwijze(y: g32) -> g32 {
+            bekeer y * 2;
+        };
+        bekeer vergelijk(x, 2, isGroter);
+    }
+    ",
+    &[
+        "Het type van een werkwijze-waarde",
+        "Het aanroepen van een werkwijze-waarde",
+        "Een anonieme werkwijze",
+        "Een werkwijze als waarde",
+    ],
+)]
+#[case(
+    "
+    werkwijze buiten() -> g32 {
+        werkwijze binnen() -> g32 {
+            bekeer 1;
+        }
+
+        bekeer 2;
+    }
+    ",
+    &[
+        "Een werkwijze binnen een werkwijze",
+    ],
+)]
+#[case(
+    "
+    werkwijze zoek(x: g32) -> Misschien<g32> {
+        als x > 0 {
+            bekeer Iets(x);
+        }
+        bekeer Niets;
+    }
+
+    werkwijze isGevonden(waarde: Misschien<g32>) -> bool {
+        bekeer kies waarde {
+            Niets => onwaar,
+            _ => waar,
+        };
+    }
+    ",
+    &[
+        "Een `Misschien`",
+        "Een `Misschien`",
+        "Het type `Misschien`",
+        "Een `Misschien`-patroon binnen `kies`",
+    ],
+)]
+#[case(
+    "
+    werkwijze maak(grootte: g32) {
+        stel getallen = nieuw g32[grootte];
+    }
+    ",
+    &[
+        "Een opeenvolging met een grootte die niet vast is",
+    ],
+)]
+#[case(
+    "
+    werkwijze deel(a: g32, b: g32) -> Resultaat<g32, Slinger> {
+        als b == 0 {
+            bekeer Fout(\"delen door nul\");
+        }
+        bekeer Goed(a / b);
+    }
+
+    werkwijze verdubbel(a: g32) -> Resultaat<g32, Slinger> {
+        stel b = deel(a, 1)?;
+        bekeer Goed(b * 2);
+    }
+
+    werkwijze isGoed(resultaat: Resultaat<g32, Slinger>) -> bool {
+        bekeer kies resultaat {
+            Goed(_) => waar,
+            _ => onwaar,
+        };
+    }
+    ",
+    &[
+        "Een `Resultaat`",
+        "Een `Resultaat`",
+        "Het doorgeven van een fout met `?`",
+        "Een `Resultaat`",
+        "Het type `Resultaat`",
+        "Een `Resultaat`-patroon binnen `kies`",
+    ],
+)]
+#[case(
+    "
+    werkwijze beschrijf(getal: g32) -> Slinger {
+        bekeer €\"getal: {getal}\";
+    }
+    ",
+    &[
+        "Een sjabloonslinger",
+    ],
+)]
+#[case(
+    "
+    werkwijze maakReeks() {
+        stel getallen = reeks(0, 10);
+    }
+    ",
+    &[
+        "Een `reeks` als waarde",
+    ],
+)]
+#[case(
+    "
+    structuur Geld {
+        veld centen: g32,
+    }
+
+    werkwijze buiten() {
+        koppelvlak Telbaar {
+            werkwijze tel() -> g32;
+        }
+
+        uitbreiding Telbaar op Geld {
+            werkwijze tel() -> g32 {
+                bekeer dit.centen;
+            }
+        }
+    }
+    ",
+    &[
+        "Een koppelvlak binnen een werkwijze",
+        "Een uitbreiding binnen een werkwijze",
+    ],
+)]
+fn unsupported_constructs_are_reported(#[case] code: &str, #[case] expected: &[&str]) {
+    assert_eq!(compile_and_return_unsupported(code), expected);
+}
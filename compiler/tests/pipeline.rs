@@ -195,7 +195,7 @@ fn create_single_object_executable(code: &str, directory: &Path) -> std::path::P
     let tree = parse_string_to_tree(code).unwrap();
 
     let mut pipeline = Pipeline::new(Platform::host_platform());
-    pipeline.compile_trees(&[tree]);
+    pipeline.compile_trees(&[tree]).unwrap();
     pipeline.create_object(directory, "BabBestand").unwrap();
 
     let executable = pipeline.link_to_executable(directory, "BabUitvoerbare").unwrap();
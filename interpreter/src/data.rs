@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use babbelaar::{AttributeList, BabString, ExtensionId, FunctionStatement, InterfaceId, InterfaceStatement, MethodId, Structure};
+use babbelaar::{AttributeList, BabString, ExtensionId, FunctionStatement, InterfaceId, InterfaceStatement, MethodId, Structure, Value};
 
 #[derive(Debug)]
 pub struct InterpreterExtension {
@@ -15,6 +15,10 @@ pub struct InterpreterExtension {
 pub struct InterpreterFunction {
     pub attributes: AttributeList,
     pub function: FunctionStatement,
+
+    /// The module this function was declared in, which is used to resolve the
+    /// names used inside of it.
+    pub module: Option<BabString>,
 }
 
 #[derive(Debug)]
@@ -32,6 +36,8 @@ impl InterpreterInterface {
 
 #[derive(Debug)]
 pub struct InterpreterStructure {
+    /// The evaluated `vast` values of the structure, e.g. `Buffer.GROOTTE`.
+    pub constants: HashMap<BabString, Value>,
     pub method_ids: HashMap<BabString, MethodId>,
     pub extension_ids: Vec<ExtensionId>,
     pub extension_method_ids: HashMap<BabString, MethodId>,
@@ -0,0 +1,278 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::fmt::Write;
+
+use babbelaar::*;
+
+/// Generates a static reference from the `///` comments of the declarations
+/// of the given files, together with the ingebouwde werkwijzen.
+#[derive(Debug, Default)]
+pub struct Documenter {
+    structures: Vec<DocumentationEntry>,
+    interfaces: Vec<DocumentationEntry>,
+    functions: Vec<DocumentationEntry>,
+}
+
+impl Documenter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_tree(&mut self, tree: &ParseTree) {
+        for statement in tree.structures() {
+            if let StatementKind::Structure(structure) = &statement.kind {
+                self.add_structure(structure);
+            }
+        }
+
+        for statement in tree.interfaces() {
+            if let StatementKind::Interface(interface) = &statement.kind {
+                self.add_interface(interface);
+            }
+        }
+
+        for statement in tree.functions() {
+            // Test functions aren't part of the reference.
+            if statement.attributes.iter().any(|attribute| attribute.name.value() == Attribute::NAME_TEST) {
+                continue;
+            }
+
+            if let StatementKind::Function(function) = &statement.kind {
+                if function.visibility() == Visibility::Publiek {
+                    self.functions.push(DocumentationEntry::new(function_signature(function, false), &function.documentation));
+                }
+            }
+        }
+    }
+
+    fn add_structure(&mut self, structure: &Structure) {
+        let mut entry = DocumentationEntry::new(
+            format!("structuur {}{}", structure.name.value(), generic_types(&structure.generic_types)),
+            &structure.documentation,
+        );
+
+        for field in structure.fields.iter().filter(|field| field.visibility() == Visibility::Publiek) {
+            entry.members.push(DocumentationEntry::new(
+                format!("veld {}: {}", field.name.value(), field.ty.value()),
+                &field.documentation,
+            ));
+        }
+
+        for method in structure.methods.iter().filter(|method| method.visibility() == Visibility::Publiek) {
+            entry.members.push(DocumentationEntry::new(
+                function_signature(&method.function, method.is_static),
+                &method.function.documentation,
+            ));
+        }
+
+        self.structures.push(entry);
+    }
+
+    fn add_interface(&mut self, interface: &InterfaceStatement) {
+        let mut entry = DocumentationEntry::new(
+            format!("koppelvlak {}{}", interface.name.value(), generic_types(&interface.generic_types)),
+            &interface.documentation,
+        );
+
+        for method in &interface.methods {
+            entry.members.push(DocumentationEntry::new(
+                function_signature(&method.function, method.is_static),
+                &method.function.documentation,
+            ));
+        }
+
+        self.interfaces.push(entry);
+    }
+
+    fn builtin_functions() -> Vec<DocumentationEntry> {
+        Builtin::FUNCTIONS.iter()
+            .map(|function| {
+                let parameters = function.parameters.iter()
+                    .map(|parameter| format!("{}: {}", parameter.name, parameter.typ))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let return_type = function.return_type.resolve();
+                let return_type = if return_type.is_null() {
+                    String::new()
+                } else {
+                    format!(" -> {return_type}")
+                };
+
+                DocumentationEntry {
+                    signature: format!("werkwijze {}({parameters}){return_type}", function.name),
+                    documentation: Some(function.documentation.to_string()),
+                    members: Vec::new(),
+                }
+            })
+            .collect()
+    }
+
+    fn sections(&self) -> [(&'static str, Vec<DocumentationEntry>); 4] {
+        [
+            ("Structuren", self.structures.clone()),
+            ("Koppelvlakken", self.interfaces.clone()),
+            ("Werkwijzen", self.functions.clone()),
+            ("Ingebouwde werkwijzen", Self::builtin_functions()),
+        ]
+    }
+
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Naslagwerk\n");
+
+        for (title, entries) in self.sections() {
+            if entries.is_empty() {
+                continue;
+            }
+
+            _ = write!(out, "\n## {title}\n");
+
+            for entry in entries {
+                _ = write!(out, "\n### `{}`\n", entry.signature);
+
+                if let Some(documentation) = &entry.documentation {
+                    _ = write!(out, "\n{}\n", demote_markdown_headings(documentation));
+                }
+
+                if !entry.members.is_empty() {
+                    out += "\n";
+                }
+
+                for member in &entry.members {
+                    _ = write!(out, "- `{}`", member.signature);
+
+                    if let Some(documentation) = &member.documentation {
+                        // Make sure the following lines belong to the same list item.
+                        _ = write!(out, ": {}", demote_markdown_headings(documentation).replace('\n', "\n  "));
+                    }
+
+                    out += "\n";
+                }
+            }
+        }
+
+        out
+    }
+
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut out = String::from("<!DOCTYPE html>\n<html lang=\"nl\">\n<head>\n<meta charset=\"utf-8\">\n<title>Naslagwerk</title>\n</head>\n<body>\n<h1>Naslagwerk</h1>\n");
+
+        for (title, entries) in self.sections() {
+            if entries.is_empty() {
+                continue;
+            }
+
+            _ = writeln!(out, "<h2>{}</h2>", escape_html(title));
+
+            for entry in entries {
+                _ = writeln!(out, "<h3><code>{}</code></h3>", escape_html(&entry.signature));
+
+                if let Some(documentation) = &entry.documentation {
+                    _ = writeln!(out, "<p>{}</p>", escape_html(documentation).replace('\n', "<br>\n"));
+                }
+
+                if entry.members.is_empty() {
+                    continue;
+                }
+
+                out += "<ul>\n";
+                for member in &entry.members {
+                    _ = write!(out, "<li><code>{}</code>", escape_html(&member.signature));
+
+                    if let Some(documentation) = &member.documentation {
+                        _ = write!(out, ": {}", escape_html(documentation).replace('\n', "<br>\n"));
+                    }
+
+                    out += "</li>\n";
+                }
+                out += "</ul>\n";
+            }
+        }
+
+        out += "</body>\n</html>\n";
+        out
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DocumentationEntry {
+    signature: String,
+    documentation: Option<String>,
+    members: Vec<DocumentationEntry>,
+}
+
+impl DocumentationEntry {
+    #[must_use]
+    fn new(signature: String, documentation: &Option<BabString>) -> Self {
+        Self {
+            signature,
+            documentation: documentation.as_ref().map(|x| x.to_string()),
+            members: Vec::new(),
+        }
+    }
+}
+
+fn function_signature(function: &FunctionStatement, is_static: bool) -> String {
+    let parameters = function.parameters.iter()
+        .map(|parameter| format!("{}: {}", parameter.name.value(), parameter.ty.value()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let return_type = match &function.return_type {
+        Some(ty) => format!(" -> {}", ty.value()),
+        None => String::new(),
+    };
+
+    format!(
+        "{}werkwijze {}{}({parameters}){return_type}",
+        if is_static { "gedeeld " } else { "" },
+        function.name.value(),
+        generic_types(&function.generic_types),
+    )
+}
+
+fn generic_types(generic_types: &[GenericTypeDeclaration]) -> String {
+    if generic_types.is_empty() {
+        return String::new();
+    }
+
+    let names = generic_types.iter()
+        .map(|ty| ty.name.value().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("<{names}>")
+}
+
+/// Demotes the headings in the documentation, such as `## Voorbeeld`, so that
+/// they fall under the `###` heading of the declaration. Lines inside a code
+/// block are left unchanged.
+fn demote_markdown_headings(documentation: &str) -> String {
+    let mut is_in_code_block = false;
+
+    documentation.lines()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                is_in_code_block = !is_in_code_block;
+            }
+
+            if !is_in_code_block && line.starts_with('#') {
+                format!("###{line}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -3,7 +3,7 @@
 
 use std::fmt::Display;
 
-use babbelaar::BabString;
+use babbelaar::{BabString, FileRange, IntegerType};
 
 #[derive(Debug, Clone)]
 pub struct RuntimeError {
@@ -18,6 +18,55 @@ impl RuntimeError {
         }
     }
 
+    #[must_use]
+    pub fn division_by_zero() -> Self {
+        Self {
+            message: BabString::new_static("Deling door nul"),
+        }
+    }
+
+    #[must_use]
+    pub fn integer_overflow(lhs: i128, operator: &str, rhs: i128, ty: IntegerType) -> Self {
+        Self {
+            message: format!("Getaloverloop: de uitkomst van `{lhs} {operator} {rhs}` past niet in een `{ty}`").into(),
+        }
+    }
+
+    #[must_use]
+    pub fn integer_out_of_range(value: i128, ty: IntegerType) -> Self {
+        Self {
+            message: format!("Getal {value} past niet in een `{ty}`, dat loopt van {} tot en met {}", ty.min(), ty.max()).into(),
+        }
+    }
+
+    #[must_use]
+    pub fn expectation_failed(message: BabString) -> Self {
+        Self {
+            message,
+        }
+    }
+
+    #[must_use]
+    pub fn no_default_value() -> Self {
+        Self {
+            message: BabString::new_static("Het type van de elementen heeft geen standaardwaarde om de opeenvolging mee te vullen"),
+        }
+    }
+
+    #[must_use]
+    pub fn range_step_is_zero() -> Self {
+        Self {
+            message: BabString::new_static("De stap van een `reeks` mag niet nul zijn"),
+        }
+    }
+
+    #[must_use]
+    pub fn unsupported(feature: BabString) -> Self {
+        Self {
+            message: format!("{feature} wordt nog niet ondersteund").into(),
+        }
+    }
+
     #[must_use]
     pub fn message(&self) -> BabString {
         self.message.clone()
@@ -29,3 +78,30 @@ impl Display for RuntimeError {
         f.write_str(&self.message)
     }
 }
+
+/// A `verwacht` or `verwachtGelijk` that wasn't met during a `@test`.
+#[derive(Debug, Clone)]
+pub struct FailedExpectation {
+    message: BabString,
+    location: FileRange,
+}
+
+impl FailedExpectation {
+    #[must_use]
+    pub fn new(message: BabString, location: FileRange) -> Self {
+        Self {
+            message,
+            location,
+        }
+    }
+
+    #[must_use]
+    pub fn message(&self) -> BabString {
+        self.message.clone()
+    }
+
+    #[must_use]
+    pub fn location(&self) -> FileRange {
+        self.location
+    }
+}
@@ -3,7 +3,7 @@
 
 use std::{borrow::Cow, env::var, process::exit};
 
-use babbelaar::{Attribute, BabString, Constants, PrimaryExpression, Value};
+use babbelaar::{Attribute, BabString, Constants, IntegerType, PrimaryExpression, Value};
 use libloading::Library;
 use log::error;
 
@@ -29,19 +29,15 @@ impl FFIManager {
 
     pub fn execute(&self, attrib: &Attribute, arguments: Vec<Value>) -> Value {
         let arguments = arguments.iter()
-            .map(|x| match x {
-                Value::Array { .. } => todo!(),
-                Value::ArrayElementReference { .. } => todo!(),
+            .map(|x| match x.actual_value().as_ref() {
                 Value::Bool(b) => *b as Primitive,
-                Value::Function { .. } => todo!(),
-                Value::Null => 0,
-                Value::Integer(int) => *int as Primitive,
+                Value::Integer { value, .. } => *value as Primitive,
                 Value::Character(char) => *char as Primitive,
                 Value::String(s) => s.as_ptr() as Primitive,
-                Value::MethodReference { .. } => todo!(),
-                Value::MethodIdReference { .. } => todo!(),
-                Value::Object { .. } => todo!(),
                 Value::Pointer { address, .. } => *address as Primitive,
+
+                // The semantic analyzer only allows the parameter types above.
+                value => unreachable!("ICE: waarde {value:?} kan niet doorgegeven worden aan een uitheemse werkwijze"),
             })
             .collect();
 
@@ -53,7 +49,7 @@ impl FFIManager {
             if arg.name.value() == "naam" {
                 let PrimaryExpression::StringLiteral(literal) = arg.value.value() else {
                     error!("Invalid @uitheems `naam`!");
-                    return Value::Null;
+                    return Value::unit();
                 };
 
                 return self.execute_named_libc(literal, arguments)
@@ -61,7 +57,7 @@ impl FFIManager {
         }
 
         error!("@uitheems has no `naam` argument");
-        Value::Null
+        Value::unit()
     }
 
     fn execute_named_libc(&self, name: &BabString, arguments: Vec<i64>) -> Value {
@@ -94,11 +90,14 @@ impl FFIManager {
 
             _ => {
                 error!("Too many arguments to destructure");
-                return Value::Null
+                return Value::unit()
             }
         };
 
-        Value::Integer(return_value)
+        Value::Integer {
+            value: return_value,
+            ty: IntegerType::G64,
+        }
     }
 }
 
@@ -2,7 +2,7 @@
 // All Rights Reserved.
 
 use std::{
-    cell::RefCell, collections::HashMap, process::exit, rc::Rc, sync::Arc
+    cell::RefCell, collections::HashMap, panic::{catch_unwind, resume_unwind, AssertUnwindSafe}, process::exit, rc::Rc, sync::Arc
 };
 
 use babbelaar::*;
@@ -14,12 +14,28 @@ pub struct Interpreter<D>
         where D: Debugger {
     functions: HashMap<FunctionId, Arc<InterpreterFunction>>,
     structures: HashMap<StructureId, InterpreterStructure>,
+    enumerations: HashMap<EnumerationId, Enumeration>,
     interfaces: HashMap<InterfaceId, InterpreterInterface>,
     extensions: Vec<InterpreterExtension>,
     debugger: D,
     scope: Scope,
     ffi: FFIManager,
     methods: HashMap<MethodId, Arc<InterpreterFunction>>,
+
+    /// The error propagated by `?`, until the enclosing werkwijze returns it.
+    propagated_error: Option<Value>,
+
+    /// The location of the call to the builtin function that is currently
+    /// running, such that `verwacht` can report where it went wrong.
+    builtin_caller_location: FileRange,
+
+    /// The failed expectations of the `@test` that is currently running, or
+    /// [`None`] if no test is running.
+    failed_expectations: Option<Vec<FailedExpectation>>,
+
+    /// The location of the statement that is currently being executed, used
+    /// to tell where a runtime error inside a `@test` occurred.
+    statement_location: FileRange,
 }
 
 impl<D> Interpreter<D>
@@ -28,46 +44,105 @@ impl<D> Interpreter<D>
         Self {
             functions: HashMap::new(),
             structures: create_top_level_structures(),
+            enumerations: HashMap::new(),
             interfaces: HashMap::new(),
             extensions: Vec::new(),
             scope: Scope::new_top_level(),
             debugger,
             ffi: FFIManager::new(),
             methods: HashMap::new(),
+            propagated_error: None,
+            builtin_caller_location: FileRange::INTERNAL,
+            failed_expectations: None,
+            statement_location: FileRange::INTERNAL,
         }
     }
 
     pub fn execute_trees(&mut self, trees: &[ParseTree]) {
+        self.declare_trees(trees);
+
         for tree in trees {
-            for statement in tree.structures() {
+            self.scope.module = tree.module_path();
+            for statement in tree.statements() {
+                match self.execute_statement(statement) {
+                    StatementResult::Continue | StatementResult::Break | StatementResult::NextIteration => continue,
+                    StatementResult::Return(..) => break,
+                }
+            }
+        }
+    }
+
+    /// Runs the `@test` function declared by [`Self::declare_trees`], and
+    /// returns the expectations that weren't met.
+    pub fn execute_test(&mut self, tree: &ParseTree, function: &FunctionStatement) -> Vec<FailedExpectation> {
+        self.scope.module = tree.module_path();
+        let id = FunctionId::new(&self.scope.qualify(&function.name));
+
+        self.failed_expectations = Some(Vec::new());
+        let scope_depth = self.scope.depth();
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            self.execute_function_by_id(id, Vec::new(), None, function.name.range())
+        }));
+
+        if let Err(payload) = result {
+            let error = match payload.downcast::<RuntimeError>() {
+                Ok(error) => error,
+                Err(payload) => {
+                    self.failed_expectations = None;
+                    resume_unwind(payload);
+                }
+            };
+
+            while self.scope.depth() > scope_depth {
+                self.scope = std::mem::take(&mut self.scope).pop();
+            }
+            self.propagated_error = None;
+
+            let location = self.statement_location;
+            if let Some(failed_expectations) = &mut self.failed_expectations {
+                failed_expectations.push(FailedExpectation::new(error.message(), location));
+            }
+        }
+
+        self.failed_expectations.take().unwrap_or_default()
+    }
+
+    /// Declares the constants, structures, interfaces, extensions and
+    /// functions, without executing the top-level statements.
+    pub fn declare_trees(&mut self, trees: &[ParseTree]) {
+        for tree in trees {
+            self.scope.module = tree.module_path();
+            for statement in order_constants_by_dependency(tree.constants(), Statement::as_constant) {
                 _ = self.execute_statement(statement);
             }
         }
 
         for tree in trees {
-            for statement in tree.interfaces() {
+            self.scope.module = tree.module_path();
+            for statement in tree.structures() {
                 _ = self.execute_statement(statement);
             }
         }
 
         for tree in trees {
-            for statement in tree.extensions() {
+            self.scope.module = tree.module_path();
+            for statement in tree.interfaces() {
                 _ = self.execute_statement(statement);
             }
         }
 
         for tree in trees {
-            for statement in tree.functions() {
+            self.scope.module = tree.module_path();
+            for statement in tree.extensions() {
                 _ = self.execute_statement(statement);
             }
         }
 
         for tree in trees {
-            for statement in tree.statements() {
-                match self.execute_statement(statement) {
-                    StatementResult::Continue => continue,
-                    StatementResult::Return(..) => break,
-                }
+            self.scope.module = tree.module_path();
+            for statement in tree.functions() {
+                _ = self.execute_statement(statement);
             }
         }
     }
@@ -78,14 +153,58 @@ impl<D> Interpreter<D>
     }
 
     fn execute_statement(&mut self, statement: &Statement) -> StatementResult {
+        if self.propagated_error.is_some() {
+            return StatementResult::Return(None);
+        }
+
         self.debugger.on_statement(statement);
+        self.statement_location = statement.range;
         match &statement.kind {
             StatementKind::Assignment(assignment) => {
+                if let Some(operator) = assignment.operator {
+                    self.execute_compound_assign(&assignment.destination, operator, &assignment.source);
+                    return StatementResult::Continue;
+                }
+
                 let new_value = self.execute_expression(&assignment.source);
+                if self.propagated_error.is_some() {
+                    return StatementResult::Return(None);
+                }
+
                 self.execute_assign(&assignment.destination, new_value);
                 StatementResult::Continue
             }
 
+            StatementKind::Break(..) => StatementResult::Break,
+
+            StatementKind::Constant(constant) => {
+                let value = self.evaluate_constant(constant, None);
+
+                let name = if self.scope.parent.is_none() {
+                    self.scope.qualify(constant.name.value())
+                } else {
+                    BabString::clone(&constant.name)
+                };
+
+                self.scope.define(name, value);
+                StatementResult::Continue
+            }
+
+            StatementKind::Continue(..) => StatementResult::NextIteration,
+
+            StatementKind::Enumeration(enumeration) => {
+                let name = self.scope.qualify(enumeration.name.value());
+                let id = EnumerationId::new(&name);
+
+                let prev = self.scope.enumerations.insert(name, id);
+                debug_assert!(prev.is_none(), "Illegal double value: {prev:#?}");
+
+                let prev = self.enumerations.insert(id, enumeration.clone());
+                debug_assert!(prev.is_none(), "Illegal double value: {prev:#?}");
+
+                StatementResult::Continue
+            }
+
             StatementKind::Expression(expression) => {
                 self.execute_expression(expression);
                 StatementResult::Continue
@@ -100,7 +219,11 @@ impl<D> Interpreter<D>
                 let structure = self.structures.get_mut(&structure_id).unwrap();
 
                 let mut extension = InterpreterExtension {
-                    interface: ext.interface_specifier.as_ref().map(|x| self.scope.find_interface_id(&x.name).unwrap()),
+                    interface: ext.interface_specifier.as_ref().map(|x| {
+                        self.scope.find_interface_id(&x.name)
+                            .or_else(|| Builtin::interface_by_name(&x.name).map(InterfaceId::for_builtin))
+                            .unwrap()
+                    }),
                     methods: HashMap::new(),
                 };
 
@@ -123,6 +246,7 @@ impl<D> Interpreter<D>
                     self.methods.insert(id, Arc::new(InterpreterFunction {
                         attributes: AttributeList::new(),
                         function: method.function.clone(),
+                        module: self.scope.module.clone(),
                     }));
                 }
 
@@ -136,12 +260,14 @@ impl<D> Interpreter<D>
             }
 
             StatementKind::Function(func) => {
-                let id = FunctionId::from(func);
+                let name = self.scope.qualify(&func.name);
+                let id = FunctionId::new(&name);
                 self.functions.insert(id, Arc::new(InterpreterFunction {
                     attributes: statement.attributes.clone(),
                     function: func.clone(),
+                    module: self.scope.module.clone(),
                 }));
-                self.scope.variables.insert(BabString::clone(&func.name), Value::Function { name: func.name.to_string(), id });
+                self.scope.define(name, Value::Function { name: func.name.to_string(), id });
                 StatementResult::Continue
             }
 
@@ -150,7 +276,8 @@ impl<D> Interpreter<D>
             }
 
             StatementKind::Interface(ast_interface) => {
-                let id = InterfaceId::from(ast_interface);
+                let name = self.scope.qualify(ast_interface.name.value());
+                let id = InterfaceId::new(&name);
 
                 let mut interface = InterpreterInterface {
                     interface: ast_interface.clone(),
@@ -162,6 +289,7 @@ impl<D> Interpreter<D>
                         InterpreterFunction {
                             attributes: AttributeList::new(),
                             function: method.function.clone(),
+                            module: self.scope.module.clone(),
                         }
                     );
 
@@ -170,7 +298,7 @@ impl<D> Interpreter<D>
                     interface.method_ids.insert(BabString::clone(&method.function.name), id);
                 }
 
-                let prev = self.scope.interfaces.insert(BabString::clone(interface.name()), id);
+                let prev = self.scope.interfaces.insert(name, id);
                 debug_assert!(prev.is_none(), "Illegal double value: {prev:#?}");
 
                 let prev = self.interfaces.insert(id, interface);
@@ -187,10 +315,18 @@ impl<D> Interpreter<D>
             }
 
             StatementKind::Structure(ast_structure) => {
-                let id = StructureId::from(ast_structure);
+                let name = self.scope.qualify(ast_structure.name.value());
+                let id = StructureId::new(&name);
+
+                let mut constants = HashMap::new();
+                for constant in order_constants_by_dependency(&ast_structure.constants, Some) {
+                    let value = self.evaluate_constant(constant, Some((ast_structure.name.value(), &constants)));
+                    constants.insert(BabString::clone(&constant.name), value);
+                }
 
                 let mut structure = InterpreterStructure {
                     structure: ast_structure.clone(),
+                    constants,
                     method_ids: HashMap::new(),
                     extension_ids: Vec::new(),
                     extension_method_ids: HashMap::new(),
@@ -201,6 +337,7 @@ impl<D> Interpreter<D>
                         InterpreterFunction {
                             attributes: AttributeList::new(),
                             function: method.function.clone(),
+                            module: self.scope.module.clone(),
                         }
                     );
 
@@ -209,7 +346,7 @@ impl<D> Interpreter<D>
                     structure.method_ids.insert(BabString::clone(&method.function.name), id);
                 }
 
-                let prev = self.scope.structures.insert(BabString::clone(structure.name()), id);
+                let prev = self.scope.structures.insert(name, id);
                 debug_assert!(prev.is_none(), "Illegal double value: {prev:#?}");
 
                 let prev = self.structures.insert(id, structure);
@@ -219,10 +356,71 @@ impl<D> Interpreter<D>
             }
 
             StatementKind::Variable(variable) => {
-                let value = self.execute_expression(&variable.expression);
-                self.scope.variables.insert(BabString::clone(&variable.name), value);
+                // `stel x = xs[0];` gets the value of the element, not a reference to it.
+                let mut value = self.execute_expression(&variable.expression).actual_value().into_owned();
+
+                // An empty array gets the element type of the annotated type.
+                if let (Some(typ), Value::Array { ty, values }) = (&variable.ty, &mut value) {
+                    if values.borrow().is_empty() {
+                        if let ValueType::Array(element) = self.resolve_qualified_type(typ) {
+                            *ty = *element;
+                        }
+                    }
+                }
+
+                // An integer gets the width of the annotated type, e.g. `stel x: g8 = 127;`.
+                if let Some(typ) = &variable.ty {
+                    value = self.conform_to_type(value, typ);
+                }
+
+                match &variable.name {
+                    VariableName::Single(name) => {
+                        self.scope.define(BabString::clone(name), value);
+                    }
+
+                    VariableName::Tuple(names) => {
+                        let Value::Tuple(values) = value.actual_value().into_owned() else {
+                            panic!("Ongeldige waarde voor uitpakken van tupel: {value:#?}");
+                        };
+
+                        for (name, value) in names.iter().zip(values) {
+                            self.scope.define(BabString::clone(name), value);
+                        }
+                    }
+                }
+
                 StatementResult::Continue
             }
+
+            StatementKind::While(statement) => {
+                self.execute_while_statement(statement)
+            }
+        }
+    }
+
+    /// Evaluates the value of a `vast` declaration. The `structure` contains
+    /// the previously declared constants of the same structure, if any.
+    fn evaluate_constant(&self, constant: &ConstantStatement, structure: Option<(&BabString, &HashMap<BabString, Value>)>) -> Value {
+        let mut evaluator = ConstantEvaluator::new(|owner: Option<&BabString>, name: &BabString| {
+            let sibling = structure
+                .filter(|(structure, _)| owner.is_none_or(|owner| owner == *structure))
+                .and_then(|(_, constants)| constants.get(name));
+
+            let value = match (sibling, owner) {
+                (Some(value), _) => value.clone(),
+                (None, Some(owner)) => {
+                    let id = self.scope.find_structure_id(owner)?;
+                    self.structures.get(&id)?.constants.get(name)?.clone()
+                }
+                (None, None) => self.scope.find(name)?,
+            };
+
+            ConstantValue::try_from(value).ok()
+        }).with_declared_type(constant.ty.value());
+
+        match evaluator.evaluate(&constant.value) {
+            Ok(value) => value.into(),
+            Err(error) => panic!("Vaste waarde `{}` kon niet uitgerekend worden: {error}", constant.name.value()),
         }
     }
 
@@ -233,8 +431,7 @@ impl<D> Interpreter<D>
                     return;
                 }
 
-                if let Some(variable) = self.scope.find_mut(reference) {
-                    *variable = new_value;
+                if self.scope.overwrite(reference, new_value) {
                     return;
                 }
             }
@@ -272,7 +469,72 @@ impl<D> Interpreter<D>
         panic!("Invalid reference: {expression:#?}")
     }
 
+    /// Executes e.g. `dit.inhoud[i] += 1`, evaluating the destination only
+    /// once.
+    fn execute_compound_assign(&mut self, destination: &Expression, operator: MathOperator, source: &Ranged<Expression>) {
+        match destination {
+            Expression::Primary(PrimaryExpression::Reference(reference)) => {
+                let current = self.scope.find(reference)
+                    .unwrap_or_else(|| panic!("ICE: onbekende variabele `{}`", reference.value()));
+                let rhs = self.execute_expression(source);
+                if self.propagated_error.is_some() {
+                    return;
+                }
+
+                let new_value = self.execute_math_operator(operator, current, rhs);
+                if !self.scope.overwrite(reference, new_value) {
+                    panic!("Invalid reference: {reference:#?}");
+                }
+            }
+
+            Expression::Postfix(postfix) => match postfix.kind.value() {
+                PostfixExpressionKind::Member(member) => {
+                    let object = self.execute_expression(&postfix.lhs);
+                    let current = self.execute_member_reference(object.clone(), member);
+                    let rhs = self.execute_expression(source);
+                    if self.propagated_error.is_some() {
+                        return;
+                    }
+
+                    let new_value = self.execute_math_operator(operator, current, rhs);
+
+                    let Value::Object { fields, .. } = object else {
+                        panic!("Cannot assign to non-Object Value");
+                    };
+
+                    fields.borrow_mut().insert(member.to_string(), new_value);
+                }
+
+                PostfixExpressionKind::Subscript(..) => {
+                    let value = self.execute_postfix_expression(postfix);
+                    let Value::ArrayElementReference { array, index } = value else {
+                        panic!("ICE: unexpected outcome of subscript postfix-expression: {value:#?}");
+                    };
+
+                    let current = array.borrow()[index].clone();
+                    let rhs = self.execute_expression(source);
+                    if self.propagated_error.is_some() {
+                        return;
+                    }
+
+                    let new_value = self.execute_math_operator(operator, current, rhs);
+                    array.borrow_mut()[index] = new_value;
+                }
+
+                _ => panic!("Invalid reference: {destination:#?}"),
+            },
+
+            _ => panic!("Invalid reference: {destination:#?}"),
+        }
+    }
+
     pub fn execute_expression(&mut self, expression: &Ranged<Expression>) -> Value {
+        // While an error is propagated by `?`, the results of expressions
+        // aren't used anymore.
+        if self.propagated_error.is_some() {
+            return Value::unit();
+        }
+
         self.debugger.on_expression(expression);
 
         match expression.value() {
@@ -291,10 +553,18 @@ impl<D> Interpreter<D>
 
             PrimaryExpression::Reference(reference) => {
                 self.scope.find(reference)
+                    .unwrap_or_else(|| panic!("ICE: onbekende variabele `{}`", reference.value()))
             }
 
             PrimaryExpression::IntegerLiteral(integer) => {
-                Value::Integer(*integer)
+                Value::Integer {
+                    value: *integer,
+                    ty: IntegerType::G32,
+                }
+            }
+
+            PrimaryExpression::FloatLiteral(float) => {
+                Value::Float(*float)
             }
 
             PrimaryExpression::CharacterLiteral(char) => {
@@ -306,18 +576,16 @@ impl<D> Interpreter<D>
             }
 
             PrimaryExpression::StructureInstantiation(instantiation) => {
-                let (id, structure) = self.structures.iter()
-                        .find(|(_, registered)| registered.name() == instantiation.name.value())
-                        .unwrap_or_else(|| panic!("failed to find structure `{}`, structures: {:#?}", instantiation.name.value(), self.structures))
-                        .clone();
+                let id = self.scope.find_structure_id(instantiation.name.value())
+                        .unwrap_or_else(|| panic!("failed to find structure `{}`, structures: {:#?}", instantiation.name.value(), self.structures));
 
-                let id = *id;
+                let structure = &self.structures[&id];
 
                 self.scope = std::mem::take(&mut self.scope).push();
 
                 for (generic_decl, generic_def) in structure.structure.generic_types.iter().zip(instantiation.type_parameters.iter()) {
                     let generic_type = self.resolve_type(&generic_def).0;
-                    self.scope.generic_types.insert(generic_decl.value().clone(), generic_type);
+                    self.scope.generic_types.insert(generic_decl.name.value().clone(), generic_type);
                 }
 
                 let fields = structure.structure.fields.iter()
@@ -330,13 +598,13 @@ impl<D> Interpreter<D>
                             field.default_value.as_ref().unwrap()
                         };
 
-                        (field.name.to_string(), expression.clone())
+                        (field.name.to_string(), expression.clone(), field.ty.value().clone())
                     })
                     .collect::<Vec<_>>()
                     .into_iter()
-                    .map(|(name, expression)| {
+                    .map(|(name, expression, ty)| {
                         let value = self.execute_expression(&expression);
-                        (name, value)
+                        (name, self.conform_to_type(value, &ty))
                     })
                     .collect();
 
@@ -360,7 +628,8 @@ impl<D> Interpreter<D>
                         }
 
                         TemplateStringExpressionPart::Expression(expression) => {
-                            string += &self.execute_expression(expression).to_string();
+                            let value = self.execute_expression(expression);
+                            string += &self.format_value(&value);
                         }
                     }
                 }
@@ -374,19 +643,181 @@ impl<D> Interpreter<D>
 
             PrimaryExpression::Parenthesized(expression) => self.execute_expression(expression),
 
+            PrimaryExpression::ArrayLiteral(elements) => {
+                let values: Vec<Value> = elements.iter()
+                    .map(|element| self.execute_expression(element).actual_value().into_owned())
+                    .collect();
+
+                // The element type follows from the first element, an empty
+                // literal has no elements to take it from.
+                let ty = values.first()
+                    .map_or(ValueType::Builtin(BuiltinType::Null), Value::typ);
+
+                Value::Array {
+                    ty,
+                    values: Rc::new(RefCell::new(values)),
+                }
+            }
+
+            PrimaryExpression::Tuple(elements) => {
+                let values = elements.iter()
+                    .map(|element| self.execute_expression(element).actual_value().into_owned())
+                    .collect();
+
+                Value::Tuple(values)
+            }
+
+            PrimaryExpression::Match(expression) => self.execute_match_expression(expression),
+
+            PrimaryExpression::OptionalSome(expression) => {
+                let value = self.execute_expression(expression).actual_value().into_owned();
+                Value::Optional(Some(Box::new(value)))
+            }
+
+            PrimaryExpression::OptionalNone => Value::Optional(None),
+
+            PrimaryExpression::ResultOk(expression) => {
+                let value = self.execute_expression(expression).actual_value().into_owned();
+                Value::Result(Ok(Box::new(value)))
+            }
+
+            PrimaryExpression::ResultError(expression) => {
+                let value = self.execute_expression(expression).actual_value().into_owned();
+                Value::Result(Err(Box::new(value)))
+            }
+
+            PrimaryExpression::Closure(closure) => Value::Closure(Rc::new(Closure {
+                function: Arc::clone(&closure.function),
+                captures: self.scope.capture(),
+                this: self.scope.this.clone(),
+                module: self.scope.module.clone(),
+            })),
+
             PrimaryExpression::SizedArrayInitializer { typ, size } => {
                 let size = self.execute_expression(&size);
-                let Value::Integer(size) = size else {
+                let Value::Integer { value: size, .. } = size else {
                     panic!("Ongeldige opeensommingsgrootte: {size:#?}");
                 };
 
                 let (ty, default_value) = self.resolve_type(typ);
 
+                let values = match default_value {
+                    Some(default_value) => vec![default_value; size as usize],
+                    None if size == 0 => Vec::new(),
+                    None => self.report_runtime_error(RuntimeError::no_default_value()),
+                };
+
                 Value::Array {
                     ty,
-                    values: Rc::new(RefCell::new(vec![default_value; size as usize])),
+                    values: Rc::new(RefCell::new(values)),
+                }
+            }
+
+            PrimaryExpression::Range(range) => self.execute_range(range),
+        }
+    }
+
+    fn execute_range(&mut self, range: &RangeExpression) -> Value {
+        let start = self.execute_expression(&range.start);
+        let end = self.execute_expression(&range.end);
+        let step = match &range.step {
+            Some(step) => self.execute_expression(step),
+            None => Value::Integer { value: 1, ty: IntegerType::G32 },
+        };
+
+        if self.propagated_error.is_some() {
+            return Value::unit();
+        }
+
+        let Value::Integer { value: start, .. } = start.actual_value().into_owned() else {
+            panic!("Ongeldige startwaarde voor `reeks`: {start:?}");
+        };
+
+        let Value::Integer { value: end, .. } = end.actual_value().into_owned() else {
+            panic!("Ongeldige eindwaarde voor `reeks`: {end:?}");
+        };
+
+        let Value::Integer { value: step, .. } = step.actual_value().into_owned() else {
+            panic!("Ongeldige stap voor `reeks`: {step:?}");
+        };
+
+        if step == 0 {
+            self.report_runtime_error(RuntimeError::range_step_is_zero());
+        }
+
+        Value::Range(RangeValue {
+            start,
+            end,
+            step,
+            is_inclusive: range.is_inclusive,
+        })
+    }
+
+    fn execute_match_expression(&mut self, expression: &MatchExpression) -> Value {
+        let value = self.execute_expression(&expression.scrutinee).actual_value().into_owned();
+        if self.propagated_error.is_some() {
+            return Value::unit();
+        }
+
+        for arm in &expression.arms {
+            self.scope = std::mem::take(&mut self.scope).push();
+
+            if self.match_pattern(&arm.pattern, &value) {
+                let result = self.execute_expression(&arm.expression);
+                self.scope = std::mem::take(&mut self.scope).pop();
+                return result;
+            }
+
+            self.scope = std::mem::take(&mut self.scope).pop();
+        }
+
+        panic!("Geen enkele tak van `kies` past bij de waarde {value}");
+    }
+
+    /// Checks whether the value matches the pattern, binding the captured
+    /// values into the current scope.
+    fn match_pattern(&mut self, pattern: &Pattern, value: &Value) -> bool {
+        match (pattern, value) {
+            (Pattern::Wildcard, _) => true,
+
+            (Pattern::Binding(name), value) => {
+                self.scope.define(BabString::clone(name.value()), value.clone());
+                true
+            }
+
+            (Pattern::Boolean(expected), Value::Bool(actual)) => expected == actual,
+            (Pattern::CharacterLiteral(expected), Value::Character(actual)) => expected == actual,
+            (Pattern::IntegerLiteral(expected), Value::Integer { value, ty }) => *expected as i128 == ty.widen(*value),
+            (Pattern::StringLiteral(expected), Value::String(actual)) => expected.as_str() == actual,
+
+            (Pattern::Range { start, end }, Value::Integer { value, ty }) => (*start.value() as i128..*end.value() as i128).contains(&ty.widen(*value)),
+
+            (Pattern::Structure { fields: patterns, .. }, Value::Object { fields, .. }) => {
+                let fields = fields.borrow().clone();
+                patterns.iter().all(|pattern| {
+                    let Some(field) = fields.get(pattern.name.value().as_str()) else {
+                        panic!("Kon veld `{}` niet vinden binnen {fields:#?}", pattern.name.value());
+                    };
+
+                    self.match_pattern(&pattern.pattern, field)
+                })
+            }
+
+            (Pattern::EnumerationVariant { enumeration, variant, fields: patterns }, Value::Enumeration { enumeration: id, name, fields, .. }) => {
+                if self.scope.find_enumeration_id(enumeration.value()) != Some(*id) || name != variant.value() {
+                    return false;
                 }
+
+                patterns.iter().zip(fields.iter()).all(|(pattern, field)| self.match_pattern(pattern, field))
             }
+
+            (Pattern::OptionalSome(pattern), Value::Optional(Some(value))) => self.match_pattern(pattern.value(), value),
+            (Pattern::OptionalNone, Value::Optional(None)) => true,
+
+            (Pattern::ResultOk(pattern), Value::Result(Ok(value))) => self.match_pattern(pattern.value(), value),
+            (Pattern::ResultError(pattern), Value::Result(Err(error))) => self.match_pattern(pattern.value(), error),
+
+            _ => false,
         }
     }
 
@@ -398,12 +829,34 @@ impl<D> Interpreter<D>
 
             UnaryExpressionKind::Negate => {
                 let rhs = self.execute_expression(&expression.rhs);
+                if self.propagated_error.is_some() {
+                    return Value::unit();
+                }
 
                 match rhs {
-                    Value::Integer(integer) => Value::Integer(-integer),
+                    Value::Integer { value, ty } => {
+                        let value = ty.widen(value);
+                        match ty.checked(-value) {
+                            Some(negated) => Value::Integer { value: negated, ty },
+                            None => self.report_runtime_error(RuntimeError::integer_out_of_range(-value, ty)),
+                        }
+                    }
+                    Value::Float(float) => Value::Float(-float),
                     _ => panic!("Kan waarde {rhs:?} niet omkeren"),
                 }
             }
+
+            UnaryExpressionKind::Not => {
+                let rhs = self.execute_expression(&expression.rhs);
+                if self.propagated_error.is_some() {
+                    return Value::unit();
+                }
+
+                match rhs.actual_value().as_ref() {
+                    Value::Bool(boolean) => Value::Bool(!boolean),
+                    _ => panic!("Kan waarde {rhs:?} niet logisch omkeren"),
+                }
+            }
         }
     }
 
@@ -416,6 +869,9 @@ impl<D> Interpreter<D>
 
     fn execute_iterating_for_statement(&mut self, statement: &ForStatement, expression: &Ranged<Expression>) -> StatementResult {
         let expression = self.execute_expression(expression);
+        if self.propagated_error.is_some() {
+            return StatementResult::Return(None);
+        }
 
         let result = match expression {
             Value::Array { values, .. } => {
@@ -426,6 +882,10 @@ impl<D> Interpreter<D>
                 self.execute_iterating_for_statement_doorloper(statement, *structure, expression)
             }
 
+            Value::Range(range) => {
+                self.execute_iterating_for_statement_range(statement, range)
+            }
+
             _ => {
                 panic!("Invalid iterable");
             }
@@ -440,24 +900,26 @@ impl<D> Interpreter<D>
 
         let length = self.execute_function(iterable.length, Vec::new(), Some(this.clone()));
 
-        let Value::Integer(length) = length else {
+        let Value::Integer { value: length, ty } = length else {
             panic!("Ongeldige bekeerwaarde voor `Doorloper`-werkwijze `lengte()`: {length:?}");
         };
 
         for i in 0..length.max(0) {
-            let arguments = vec![Value::Integer(i)];
+            let arguments = vec![Value::Integer { value: i, ty }];
             let value = self.execute_function(Arc::clone(&iterable.get), arguments, Some(this.clone()));
 
             self.scope = std::mem::take(&mut self.scope).push();
-            self.scope.variables.insert(BabString::clone(&statement.iterator_name), value.clone());
+            self.scope.define(BabString::clone(&statement.iterator_name), value.clone());
 
-            for statement in &statement.body {
-                if let StatementResult::Return(value) = self.execute_statement(statement) {
-                    return StatementResult::Return(value);
-                }
-            }
+            let result = self.execute_statements(&statement.body);
 
             self.scope = std::mem::take(&mut self.scope).pop();
+
+            match result {
+                StatementResult::Continue | StatementResult::NextIteration => (),
+                StatementResult::Break => break,
+                StatementResult::Return(value) => return StatementResult::Return(value),
+            }
         }
 
         StatementResult::Continue
@@ -504,10 +966,13 @@ impl<D> Interpreter<D>
         let values = values.borrow().clone();
 
         for x in values {
-            self.scope.variables.insert(BabString::clone(&statement.iterator_name), x.actual_value().into_owned());
+            self.scope.define(BabString::clone(&statement.iterator_name), x.actual_value().into_owned());
 
-            for statement in &statement.body {
-                if let StatementResult::Return(value) = self.execute_statement(statement) {
+            match self.execute_block(&statement.body) {
+                StatementResult::Continue | StatementResult::NextIteration => (),
+                StatementResult::Break => break,
+                StatementResult::Return(value) => {
+                    self.scope = std::mem::take(&mut self.scope).pop();
                     return StatementResult::Return(value);
                 }
             }
@@ -519,21 +984,24 @@ impl<D> Interpreter<D>
     }
 
     fn execute_ranged_for_statement(&mut self, statement: &ForStatement, range: &RangeExpression) -> StatementResult {
-        let Value::Integer(start) = self.execute_expression(&range.start) else {
-            panic!("Invalid start");
+        let Value::Range(range) = self.execute_range(range) else {
+            return StatementResult::Return(None);
         };
 
-        let Value::Integer(end) = self.execute_expression(&range.end) else {
-            panic!("Invalid end");
-        };
+        self.execute_iterating_for_statement_range(statement, range)
+    }
 
+    fn execute_iterating_for_statement_range(&mut self, statement: &ForStatement, range: RangeValue) -> StatementResult {
         self.scope = std::mem::take(&mut self.scope).push();
 
-        for x in start..end {
-            self.scope.variables.insert(BabString::clone(&statement.iterator_name), Value::Integer(x));
+        for x in range.values() {
+            self.scope.define(BabString::clone(&statement.iterator_name), Value::Integer { value: x, ty: IntegerType::G32 });
 
-            for statement in &statement.body {
-                if let StatementResult::Return(value) = self.execute_statement(statement) {
+            match self.execute_block(&statement.body) {
+                StatementResult::Continue | StatementResult::NextIteration => (),
+                StatementResult::Break => break,
+                StatementResult::Return(value) => {
+                    self.scope = std::mem::take(&mut self.scope).pop();
                     return StatementResult::Return(value);
                 }
             }
@@ -545,39 +1013,79 @@ impl<D> Interpreter<D>
     }
 
     fn execute_if_statement(&mut self, statement: &IfStatement) -> StatementResult {
-        self.scope = std::mem::take(&mut self.scope).push();
+        if self.execute_expression(&statement.condition).is_true() {
+            return self.execute_block(&statement.body);
+        }
 
-        if !self.execute_expression(&statement.condition).is_true() {
-            return StatementResult::Continue;
+        match &statement.else_statement {
+            Some(else_statement) => self.execute_else_statement(else_statement),
+            None => StatementResult::Continue,
         }
+    }
 
-        for statement in &statement.body {
-            if let StatementResult::Return(value) = self.execute_statement(statement) {
-                return StatementResult::Return(value);
-            }
+    fn execute_else_statement(&mut self, statement: &ElseStatement) -> StatementResult {
+        match &statement.kind {
+            ElseStatementKind::ElseIf(if_statement) => self.execute_if_statement(if_statement),
+            ElseStatementKind::Else(body) => self.execute_block(body),
         }
+    }
 
+    fn execute_block(&mut self, body: &[Statement]) -> StatementResult {
+        self.scope = std::mem::take(&mut self.scope).push();
+        let result = self.execute_statements(body);
         self.scope = std::mem::take(&mut self.scope).pop();
+        result
+    }
+
+    /// Executes the statements until one of them interrupts the normal flow,
+    /// such as `bekeer`, `stop` or `ga door`.
+    fn execute_statements(&mut self, body: &[Statement]) -> StatementResult {
+        for statement in body {
+            match self.execute_statement(statement) {
+                StatementResult::Continue => (),
+                result => return result,
+            }
+        }
+
+        StatementResult::Continue
+    }
+
+    fn execute_while_statement(&mut self, statement: &WhileStatement) -> StatementResult {
+        while self.execute_expression(&statement.condition).is_true() {
+            match self.execute_block(&statement.body) {
+                StatementResult::Continue | StatementResult::NextIteration => (),
+                StatementResult::Break => break,
+                StatementResult::Return(value) => return StatementResult::Return(value),
+            }
+        }
 
         StatementResult::Continue
     }
 
     fn execute_bi_expression(&mut self, expression: &BiExpression) -> Value {
-        let lhs = self.execute_expression(&expression.lhs);
+        let mut lhs = self.execute_expression(&expression.lhs);
         let rhs = self.execute_expression(&expression.rhs);
+        if self.propagated_error.is_some() {
+            return Value::unit();
+        }
+
+        // Like in the semantic analysis, an integer literal on the left takes
+        // the type of the right-hand side, so `1 + x` with `x: g8` is a `g8`.
+        if expression.lhs.as_integer_literal().is_some() {
+            if let Value::Integer { ty, .. } = rhs.actual_value().as_ref() {
+                lhs = self.conform_to_integer_type(lhs, *ty);
+            }
+        }
+
+        if let Some(value) = self.execute_overloaded_operator(*expression.operator, &lhs, &rhs) {
+            return value;
+        }
 
         match *expression.operator {
-            BiOperator::Math(MathOperator::Add) => self.execute_expression_add(lhs, rhs),
-            BiOperator::Math(MathOperator::Subtract) => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a - b),
-            BiOperator::Math(MathOperator::Multiply) => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a * b),
-            BiOperator::Math(MathOperator::Modulo) => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a % b),
-            BiOperator::Math(MathOperator::Divide) => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a / b),
-            BiOperator::Math(MathOperator::BitwiseAnd) => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a & b),
-            BiOperator::Math(MathOperator::BitwiseOr) => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a | b),
-            BiOperator::Math(MathOperator::BitwiseXor) => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a ^ b),
-
-            BiOperator::Math(MathOperator::LogicalAnd) => Value::Bool(lhs == Value::Bool(true) && rhs == Value::Bool(true)),
-            BiOperator::Math(MathOperator::LogicalOr) => Value::Bool(lhs == Value::Bool(true) || rhs == Value::Bool(true)),
+            BiOperator::Math(operator) => self.execute_math_operator(operator, lhs, rhs),
+
+            BiOperator::Comparison(Comparison::Equality) => Value::Bool(babbelaar::Interpreter::are_equal(self, &lhs, &rhs)),
+            BiOperator::Comparison(Comparison::Inequality) => Value::Bool(!babbelaar::Interpreter::are_equal(self, &lhs, &rhs)),
 
             BiOperator::Comparison(comparison) => {
                 Value::Bool(lhs.compare(&rhs, comparison))
@@ -585,17 +1093,168 @@ impl<D> Interpreter<D>
         }
     }
 
+    /// Finds the method of the builtin interface, if the structure of `value`
+    /// has an extension implementing it.
+    fn find_builtin_interface_method(&self, value: &Value, builtin: &BuiltinInterface) -> Option<Arc<InterpreterFunction>> {
+        let value = value.actual_value();
+        let Value::Object { structure, .. } = value.as_ref() else {
+            return None;
+        };
+
+        let interface = InterfaceId::for_builtin(builtin);
+
+        let method = self.structures.get(structure)?.extension_ids.iter()
+            .map(|id| &self.extensions[id.id])
+            .find(|ext| ext.interface == Some(interface))?
+            .methods.get(&BabString::new_static(builtin.method_name))?;
+
+        self.methods.get(method).cloned()
+    }
+
+    /// Converts a value to text, like in a template string. A structure with a
+    /// `Weergeefbaar` extension decides this itself, other structures are
+    /// shown as `Persoon { naam: "Jan", leeftijd: 35 }`.
+    fn format_value(&mut self, value: &Value) -> String {
+        let value = value.actual_value().into_owned();
+
+        match &value {
+            Value::Object { structure, fields, .. } => {
+                let weergeefbaar = Builtin::interface_by_name("Weergeefbaar").unwrap();
+                if let Some(method) = self.find_builtin_interface_method(&value, weergeefbaar) {
+                    return self.execute_function(method, Vec::new(), Some(value.clone())).to_string();
+                }
+
+                let structure = &self.structures[structure].structure;
+                let mut string = structure.name.to_string();
+                let names: Vec<String> = structure.fields.iter().map(|field| field.name.to_string()).collect();
+
+                if names.is_empty() {
+                    return string + " {}";
+                }
+
+                let fields = fields.borrow().clone();
+                for (idx, name) in names.iter().enumerate() {
+                    string += if idx == 0 { " { " } else { ", " };
+                    string += name;
+                    string += ": ";
+                    string += &self.format_field_value(&fields[name]);
+                }
+
+                string + " }"
+            }
+
+            Value::Array { values, .. } => {
+                let values = values.borrow().clone();
+                self.format_values("[", &values, "]")
+            }
+
+            Value::Tuple(values) => self.format_values("(", values, ")"),
+
+            _ => value.to_string(),
+        }
+    }
+
+    fn format_values(&mut self, open: &str, values: &[Value], close: &str) -> String {
+        let mut string = open.to_string();
+
+        for (idx, value) in values.iter().enumerate() {
+            if idx != 0 {
+                string += ", ";
+            }
+
+            string += &self.format_value(value);
+        }
+
+        string + close
+    }
+
+    /// Formats the value of a field, putting text between quotes.
+    fn format_field_value(&mut self, value: &Value) -> String {
+        match value.actual_value().as_ref() {
+            Value::String(string) => format!("{string:?}"),
+            Value::Character(character) => format!("{character:?}"),
+            value => self.format_value(value),
+        }
+    }
+
+    /// Executes an operator on a structure that implements the corresponding
+    /// builtin interface, such as `Optelbaar` for `+`.
+    fn execute_overloaded_operator(&mut self, operator: BiOperator, lhs: &Value, rhs: &Value) -> Option<Value> {
+        let builtin = BuiltinInterface::for_operator(operator)?;
+        let method = self.find_builtin_interface_method(lhs, builtin)?;
+
+        let result = self.execute_function(method, vec![rhs.clone()], Some(lhs.clone()));
+
+        Some(match operator {
+            BiOperator::Math(..) | BiOperator::Comparison(Comparison::Equality) => result,
+            BiOperator::Comparison(Comparison::Inequality) => Value::Bool(result != Value::Bool(true)),
+
+            // `vergelijk` returns a number less than, equal to or greater than zero.
+            BiOperator::Comparison(comparison) => {
+                Value::Bool(result.compare(&Value::Integer { value: 0, ty: IntegerType::G32 }, comparison))
+            }
+        })
+    }
+
+    fn execute_math_operator(&mut self, operator: MathOperator, lhs: Value, rhs: Value) -> Value {
+        match operator {
+            MathOperator::Add => self.execute_expression_add(lhs, rhs),
+            MathOperator::Subtract => self.execute_bi_expression_arithmetic(lhs, rhs, MathOperator::Subtract, i128::checked_sub, |a, b| a - b),
+            MathOperator::Multiply => self.execute_bi_expression_arithmetic(lhs, rhs, MathOperator::Multiply, i128::checked_mul, |a, b| a * b),
+            MathOperator::Modulo => self.execute_bi_expression_arithmetic(lhs, rhs, MathOperator::Modulo, i128::checked_rem, |a, b| a % b),
+            MathOperator::Divide => self.execute_bi_expression_arithmetic(lhs, rhs, MathOperator::Divide, i128::checked_div, |a, b| a / b),
+            MathOperator::BitwiseAnd => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a & b),
+            MathOperator::BitwiseOr => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a | b),
+            MathOperator::BitwiseXor => self.execute_bi_expression_numeric(lhs, rhs, |a, b| a ^ b),
+
+            MathOperator::LogicalAnd => Value::Bool(lhs == Value::Bool(true) && rhs == Value::Bool(true)),
+            MathOperator::LogicalOr => Value::Bool(lhs == Value::Bool(true) || rhs == Value::Bool(true)),
+        }
+    }
+
     fn execute_expression_add(&mut self, lhs: Value, rhs: Value) -> Value {
         match (&lhs, &rhs) {
-            (Value::Integer(lhs), Value::Integer(rhs)) => Value::Integer(lhs + rhs),
             (Value::String(lhs), Value::String(rhs)) => Value::String(format!("{lhs}{rhs}")),
-            _ => panic!("Invalid operands for add: {lhs:?} and {rhs:?}"),
+            _ => self.execute_bi_expression_arithmetic(lhs, rhs, MathOperator::Add, i128::checked_add, |a, b| a + b),
+        }
+    }
+
+    /// Executes an arithmetic operation. The result of two integers has the
+    /// type of the lhs, and it is an error if the result doesn't fit in it
+    /// (instead of silently overflowing).
+    fn execute_bi_expression_arithmetic(
+        &mut self,
+        lhs: Value,
+        rhs: Value,
+        operator: MathOperator,
+        f: impl FnOnce(i128, i128) -> Option<i128>,
+        g: impl FnOnce(f64, f64) -> f64,
+    ) -> Value {
+        match (&lhs, &rhs) {
+            (Value::Integer { value: lhs, ty }, Value::Integer { value: rhs, ty: rhs_ty }) => {
+                let lhs = ty.widen(*lhs);
+                let rhs = rhs_ty.widen(*rhs);
+
+                if rhs == 0 && matches!(operator, MathOperator::Divide | MathOperator::Modulo) {
+                    self.report_runtime_error(RuntimeError::division_by_zero());
+                }
+
+                match f(lhs, rhs).and_then(|result| ty.checked(result)) {
+                    Some(value) => Value::Integer { value, ty: *ty },
+                    None => self.report_runtime_error(RuntimeError::integer_overflow(lhs, operator.as_str(), rhs, *ty)),
+                }
+            }
+            (Value::Float(lhs), Value::Float(rhs)) => Value::Float(g(*lhs, *rhs)),
+            _ => panic!("ICE: Invalid operands for arithmetic: {lhs:?} and {rhs:?}"),
         }
     }
 
     fn execute_bi_expression_numeric(&self, lhs: Value, rhs: Value, f: impl FnOnce(i64, i64) -> i64) -> Value {
         match (&lhs, &rhs) {
-            (Value::Integer(lhs), Value::Integer(rhs)) => Value::Integer(f(*lhs, *rhs)),
+            (Value::Integer { value: lhs, ty }, Value::Integer { value: rhs, .. }) => Value::Integer {
+                value: ty.wrapping(f(*lhs, *rhs) as i128),
+                ty: *ty,
+            },
             _ => panic!("ICE: Invalid operands for numeric: {lhs:?} and {rhs:?}"),
         }
     }
@@ -606,6 +1265,10 @@ impl<D> Interpreter<D>
             arguments.push(self.execute_expression(argument));
         }
 
+        if self.propagated_error.is_some() {
+            return Value::unit();
+        }
+
         match lhs {
             Value::MethodReference { lhs, method } => {
                 (method.function())(self, arguments, Some(*lhs))
@@ -621,6 +1284,10 @@ impl<D> Interpreter<D>
                 self.execute_function_by_id(id, arguments, None, func.token_left_paren).unwrap()
             }
 
+            Value::Closure(closure) => {
+                self.execute_closure(closure, arguments, func.token_left_paren)
+            }
+
             _ => panic!("Unexpected lhs: {lhs:#?}"),
         }
     }
@@ -629,6 +1296,17 @@ impl<D> Interpreter<D>
         if id.namespace == usize::MAX {
             let function = Builtin::FUNCTIONS[id.id];
 
+            // Structures are passed as text, just like in a template string.
+            let arguments: Vec<Value> = arguments.into_iter()
+                .enumerate()
+                .map(|(index, argument)| match (function.parameters.get(index), &argument) {
+                    (Some(parameter), Value::Object { .. }) if parameter.typ == BuiltinParameterType::Builtin(BuiltinType::Slinger) => {
+                        Value::String(self.format_value(&argument))
+                    }
+                    _ => argument,
+                })
+                .collect();
+
             self.debugger.enter_function(DebuggerFunction {
                 ty: DebuggerFunctionType::Normal,
                 name: function.name,
@@ -636,7 +1314,9 @@ impl<D> Interpreter<D>
                 callee_location: None,
             }, &arguments);
 
+            let previous_caller_location = std::mem::replace(&mut self.builtin_caller_location, caller_location);
             let value = (function.function)(self, arguments, this);
+            self.builtin_caller_location = previous_caller_location;
 
             self.debugger.leave_function(DebuggerFunction {
                 ty: DebuggerFunctionType::Normal,
@@ -677,44 +1357,238 @@ impl<D> Interpreter<D>
                 let value = self.ffi.execute(attrib, arguments);
 
                 let Some(ty) = &func.function.return_type else {
-                    return Value::Null;
+                    return Value::unit();
                 };
 
                 return self.ensure_ffi_value_conforms_to_spec(value, ty);
             }
         }
 
-        self.scope = std::mem::take(&mut self.scope).push_function(this);
+        self.scope = std::mem::take(&mut self.scope).push_function(this, func.module.clone());
+        self.execute_function_body(&func.function, arguments)
+    }
+
+    fn execute_closure(&mut self, closure: Rc<Closure>, arguments: Vec<Value>, caller_location: FileRange) -> Value {
+        let func = &closure.function;
+
+        self.debugger.enter_function(DebuggerFunction {
+            ty: DebuggerFunctionType::Normal,
+            name: func.name.value(),
+            caller_location,
+            callee_location: Some(func.name.range()),
+        }, &arguments);
+
+        self.scope = std::mem::take(&mut self.scope).push_function(closure.this.clone(), closure.module.clone());
+        self.scope.is_closure = true;
+        self.scope.define_captures(&closure.captures);
+
+        let value = self.execute_function_body(func, arguments);
 
-        for idx in 0..func.function.parameters.len() {
-            let name = BabString::clone(&func.function.parameters[idx].name);
-            debug_assert!(arguments.get(idx).is_some(), "Werkwijze `{}` aangeroepen met {} waarde(s), terwijl hij {} verwacht, dus parameter `{name}` (#{idx}) heeft geen waarde", func.function.name.value(), arguments.len(), func.function.parameters.len());
+        self.debugger.leave_function(DebuggerFunction {
+            ty: DebuggerFunctionType::Normal,
+            name: func.name.value(),
+            caller_location,
+            callee_location: Some(func.name.range()),
+        });
 
-            self.scope.variables.insert(name, arguments[idx].clone());
+        value
+    }
+
+    /// Binds the `arguments` to the parameters of `func` in the scope that was
+    /// just pushed for it, and runs the body, after which that scope is popped.
+    fn execute_function_body(&mut self, func: &FunctionStatement, arguments: Vec<Value>) -> Value {
+        self.bind_generic_types(func, &arguments);
+
+        for idx in 0..func.parameters.len() {
+            let name = BabString::clone(&func.parameters[idx].name);
+            debug_assert!(arguments.get(idx).is_some(), "Werkwijze `{}` aangeroepen met {} waarde(s), terwijl hij {} verwacht, dus parameter `{name}` (#{idx}) heeft geen waarde", func.name.value(), arguments.len(), func.parameters.len());
+
+            let value = self.conform_to_type(arguments[idx].clone(), func.parameters[idx].ty.value());
+            self.scope.define(name, value);
         }
 
-        for statement in func.function.body.as_ref().unwrap() {
+        for statement in func.body.as_ref().unwrap() {
             match self.execute_statement(statement) {
-                StatementResult::Continue => (),
+                StatementResult::Continue | StatementResult::Break | StatementResult::NextIteration => (),
                 StatementResult::Return(value) => {
                     self.scope = std::mem::take(&mut self.scope).pop();
-                    return value.unwrap_or(Value::Null);
+
+                    if let Some(error) = self.propagated_error.take() {
+                        return error;
+                    }
+
+                    let value = value.unwrap_or(Value::unit());
+                    return match &func.return_type {
+                        Some(ty) => self.conform_to_type(value, ty.value()),
+                        None => value,
+                    };
                 }
             }
         }
 
         self.scope = std::mem::take(&mut self.scope).pop();
-        Value::Null
+        self.propagated_error.take().unwrap_or(Value::unit())
+    }
+
+    /// Infers the generic types of a werkwijze from the types of the
+    /// arguments, such that e.g. `nieuw T[n]` inside it knows what `T` is.
+    fn bind_generic_types(&mut self, func: &FunctionStatement, arguments: &[Value]) {
+        if func.generic_types.is_empty() {
+            return;
+        }
+
+        for (parameter, argument) in func.parameters.iter().zip(arguments) {
+            let TypeSpecifier::Custom { name, .. } = parameter.ty.specifier.value() else {
+                continue;
+            };
+
+            if !func.generic_types.iter().any(|x| x.name.value() == name.value()) {
+                continue;
+            }
+
+            let ty = parameter.ty.qualifiers.iter().try_fold(argument.typ(), |ty, qualifier| {
+                match (qualifier.value(), ty) {
+                    (TypeQualifier::Array, ValueType::Array(element)) => Some(*element),
+                    (TypeQualifier::Pointer, ValueType::Pointer(pointee)) => Some(*pointee),
+                    _ => None,
+                }
+            });
+
+            if let Some(ty) = ty {
+                self.scope.generic_types.entry(name.value().clone()).or_insert(ty);
+            }
+        }
     }
 
     fn execute_postfix_expression(&mut self, expression: &PostfixExpression) -> Value {
+        if let Some(value) = self.execute_enumeration_variant(expression) {
+            return value;
+        }
+
+        if let Some(value) = self.execute_structure_constant(expression) {
+            return value;
+        }
+
+        if let Some(value) = self.execute_static_method(expression) {
+            return value;
+        }
+
         let lhs = self.execute_expression(&expression.lhs);
+        if self.propagated_error.is_some() {
+            return Value::unit();
+        }
+
         match expression.kind.value() {
             PostfixExpressionKind::Call(call) => self.execute_function_call(lhs, call),
             PostfixExpressionKind::Member(member) => self.execute_member_reference(lhs, member),
             PostfixExpressionKind::MethodCall(method) => self.execute_method_invocation(lhs, method),
             PostfixExpressionKind::Subscript(subscript) => self.execute_subscript(lhs, subscript),
+            PostfixExpressionKind::Propagate => self.execute_propagate(lhs),
+        }
+    }
+
+    /// Unpacks a `Goed` value, or keeps the error such that the enclosing
+    /// werkwijze returns it.
+    fn execute_propagate(&mut self, lhs: Value) -> Value {
+        match lhs.actual_value().into_owned() {
+            Value::Result(Ok(value)) => *value,
+            Value::Result(Err(error)) => {
+                self.propagated_error = Some(Value::Result(Err(error)));
+                Value::unit()
+            }
+            value => panic!("ICE: `?` gebruikt op een waarde die geen `Resultaat` is: {value:?}"),
+        }
+    }
+
+    /// Looks up a constant of a structure, such as `Buffer.GROOTTE`.
+    fn execute_structure_constant(&mut self, expression: &PostfixExpression) -> Option<Value> {
+        let PostfixExpressionKind::Member(name) = expression.kind.value() else {
+            return None;
+        };
+
+        let structure_name = expression.lhs.value().as_identifier()?;
+        if self.scope.contains(structure_name) {
+            return None;
+        }
+
+        let id = self.scope.find_structure_id(structure_name)?;
+        self.structures.get(&id)?.constants.get(name.value()).cloned()
+    }
+
+    /// Calls a static method, such as `Persoon.maak("Jan")`.
+    fn execute_static_method(&mut self, expression: &PostfixExpression) -> Option<Value> {
+        let PostfixExpressionKind::MethodCall(method) = expression.kind.value() else {
+            return None;
+        };
+
+        let structure_name = expression.lhs.value().as_identifier()?;
+        if self.scope.contains(structure_name) {
+            return None;
         }
+
+        let id = self.scope.find_structure_id(structure_name)?;
+        let structure = self.structures.get(&id)?;
+        let method_id = structure.get_method_by_name(method.method_name.value())?;
+        let constants = structure.constants.clone();
+
+        let mut arguments = Vec::with_capacity(method.call.arguments.len());
+        for argument in &method.call.arguments {
+            arguments.push(self.execute_expression(argument));
+        }
+
+        if self.propagated_error.is_some() {
+            return Some(Value::unit());
+        }
+
+        // Like in other methods, the constants of the structure can be used
+        // without the `Structuur.` prefix.
+        self.scope = std::mem::take(&mut self.scope).push();
+        for (name, value) in constants {
+            self.scope.define(name, value);
+        }
+
+        let function = self.methods.get(&method_id).unwrap().clone();
+        let value = self.execute_function(function, arguments, None);
+
+        self.scope = std::mem::take(&mut self.scope).pop();
+        Some(value)
+    }
+
+    /// Creates an enumeration variant, such as `Vorm.Punt` or `Vorm.Cirkel(5)`.
+    fn execute_enumeration_variant(&mut self, expression: &PostfixExpression) -> Option<Value> {
+        let (name, call) = match expression.kind.value() {
+            PostfixExpressionKind::Member(name) => (name, None),
+            PostfixExpressionKind::MethodCall(method) => (&method.method_name, Some(&method.call)),
+            _ => return None,
+        };
+
+        let enumeration_name = expression.lhs.value().as_identifier()?;
+        if self.scope.contains(enumeration_name) {
+            return None;
+        }
+
+        let id = self.scope.find_enumeration_id(enumeration_name)?;
+        let enumeration = self.enumerations.get(&id).expect("illegal EnumerationId");
+
+        let Some(variant) = enumeration.variants.iter().position(|variant| variant.name.value() == name.value()) else {
+            panic!("Opsomming `{enumeration_name}` heeft geen variant genaamd `{}`", name.value());
+        };
+
+        let name = BabString::clone(name.value());
+
+        let fields = match call {
+            Some(call) => call.arguments.iter()
+                .map(|argument| self.execute_expression(argument).actual_value().into_owned())
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Some(Value::Enumeration {
+            enumeration: id,
+            variant,
+            name,
+            fields,
+        })
     }
 
     fn execute_member_reference(&mut self, lhs: Value, member: &Ranged<BabString>) -> Value {
@@ -733,9 +1607,20 @@ impl<D> Interpreter<D>
     }
 
     fn execute_method_invocation(&mut self, lhs: Value, expression: &MethodCallExpression) -> Value {
-        if let Value::Object { generic_types, .. } = &lhs {
+        // E.g. `rooster[1].lengte()`, where the lhs is a reference to the element.
+        let lhs = lhs.actual_value().into_owned();
+
+        if let Value::Object { structure, generic_types, .. } = &lhs {
             self.scope = std::mem::take(&mut self.scope).push();
             self.scope.generic_types = generic_types.clone();
+
+            // Inside its methods, the constants of the structure can also be
+            // used without the `Structuur.` prefix.
+            if let Some(structure) = self.structures.get(structure) {
+                for (name, value) in structure.constants.clone() {
+                    self.scope.define(name, value);
+                }
+            }
         }
 
         let Some(method) = self.get_method(&lhs, &expression.method_name) else {
@@ -799,19 +1684,32 @@ impl<D> Interpreter<D>
 
             ValueType::Structure(structure_id, ..) => {
                 let structure = self.structures.get(&structure_id).expect("illegal StructureId");
-                let method = structure.get_method_by_name(method_name)?;
+                let Some(method) = structure.get_method_by_name(method_name) else {
+                    // A field containing a `werkwijze` can be called like a method.
+                    let Value::Object { fields, .. } = value else {
+                        return None;
+                    };
+
+                    return fields.borrow().get(method_name.as_str()).cloned();
+                };
 
                 return Some(Value::MethodIdReference {
                     lhs: Box::new(value.clone()),
                     method,
                 });
             }
+
+            ValueType::Enumeration(..) => (),
+            ValueType::Function => (),
+            ValueType::Optional => (),
+            ValueType::Result => (),
+            ValueType::Tuple(..) => (),
         }
 
         None
     }
 
-    fn resolve_type(&self, typ: &Type) -> (ValueType, Value) {
+    fn resolve_type(&self, typ: &Type) -> (ValueType, Option<Value>) {
         assert!(typ.qualifiers.len() == 0);
         match typ.specifier.value() {
             TypeSpecifier::BuiltIn(ty) => {
@@ -826,40 +1724,85 @@ impl<D> Interpreter<D>
                     return (generic, default_value);
                 }
 
+                // Structures don't have a default value, but they can be
+                // passed as a generic type, such as `Mand<Appel>`.
+                if let Some(structure) = self.scope.find_structure_id(name) {
+                    return (ValueType::Structure(structure, HashMap::new()), None);
+                }
+
                 log::info!("Scope: {:#?}", self.scope);
                 todo!("Resolve typ: {typ:#?}")
             }
+
+            TypeSpecifier::Function { .. } => (ValueType::Function, None),
+
+            TypeSpecifier::Tuple(elements) => {
+                let (types, values): (Vec<_>, Vec<_>) = elements.iter()
+                    .map(|element| self.resolve_type(element))
+                    .unzip();
+
+                let values = values.into_iter().collect::<Option<Vec<_>>>();
+                (ValueType::Tuple(types), values.map(Value::Tuple))
+            }
         }
     }
 
-    fn get_default_value(&self, typ: &ValueType) -> Value {
-        match typ {
-            ValueType::Builtin(BuiltinType::Bool) => Value::Bool(false),
-            ValueType::Builtin(BuiltinType::G32) => Value::Integer(0),
-            ValueType::Builtin(BuiltinType::Null) => Value::Null,
-            ValueType::Builtin(BuiltinType::Slinger) => Value::String(String::new()),
-            ValueType::Builtin(BuiltinType::Teken) => Value::Character('\0'),
+    /// Like [`Self::resolve_type`], but including the array and pointer qualifiers.
+    fn resolve_qualified_type(&self, typ: &Type) -> ValueType {
+        let base = Type {
+            specifier: typ.specifier.clone(),
+            qualifiers: Vec::new(),
+        };
+
+        let mut ty = self.resolve_type(&base).0;
+        for qualifier in &typ.qualifiers {
+            ty = match qualifier.value() {
+                TypeQualifier::Array => ValueType::Array(Box::new(ty)),
+                TypeQualifier::Pointer => ValueType::Pointer(Box::new(ty)),
+            };
+        }
+
+        ty
+    }
+
+    /// The value used to fill e.g. `nieuw T[n]`, or `None` if the type doesn't
+    /// have one, such as structures.
+    fn get_default_value(&self, typ: &ValueType) -> Option<Value> {
+        if let ValueType::Builtin(ty) = typ {
+            if let Some(ty) = ty.integer_type() {
+                return Some(Value::Integer { value: 0, ty });
+            }
+        }
 
-            _ => todo!("Get default value of type {typ:#?}"),
+        match typ {
+            ValueType::Builtin(BuiltinType::Bool) => Some(Value::Bool(false)),
+            ValueType::Builtin(BuiltinType::Kommagetal) => Some(Value::Float(0.0)),
+            ValueType::Builtin(BuiltinType::Null) => Some(Value::unit()),
+            ValueType::Builtin(BuiltinType::Slinger) => Some(Value::String(String::new())),
+            ValueType::Builtin(BuiltinType::Teken) => Some(Value::Character('\0')),
+            ValueType::Tuple(elements) => elements.iter()
+                .map(|element| self.get_default_value(element))
+                .collect::<Option<Vec<_>>>()
+                .map(Value::Tuple),
+
+            _ => None,
         }
     }
 
     fn execute_subscript(&mut self, lhs: Value, subscript: &Ranged<Expression>) -> Value {
         let subscript = self.execute_expression(&subscript);
 
-        let Value::Integer(index) = subscript else {
+        let Value::Integer { value: index, .. } = subscript else {
             panic!("ICE: subscript index is not a number");
         };
 
+        // For nested arrays such as `rooster[0][1]`, the lhs is a reference.
+        let lhs = lhs.actual_value().into_owned();
+
         if let Value::String(s) = lhs {
             return match s.chars().nth(index as _) {
                 Some(c) => Value::Character(c),
-                None => {
-                    let error = RuntimeError::array_out_of_bounds(s.len(), index);
-                    self.debugger.on_runtime_error(&error);
-                    error!("Fout: {error}");
-                    exit(1);
-                }
+                None => self.report_runtime_error(RuntimeError::array_out_of_bounds(s.len(), index)),
             };
         }
 
@@ -869,10 +1812,7 @@ impl<D> Interpreter<D>
 
         let array_size = array.borrow().len();
         if index < 0 || index as usize >= array_size {
-            let error = RuntimeError::array_out_of_bounds(array_size, index);
-            self.debugger.on_runtime_error(&error);
-            error!("Fout: {error}");
-            exit(1);
+            self.report_runtime_error(RuntimeError::array_out_of_bounds(array_size, index));
         }
 
         Value::ArrayElementReference {
@@ -884,13 +1824,23 @@ impl<D> Interpreter<D>
     fn ensure_ffi_value_conforms_to_spec(&self, value: Value, ty: &Type) -> Value {
         let mut value = value;
 
+        // Foreign functions always return a full register, so the bits that
+        // don't belong to the type must be discarded.
+        if let (Value::Integer { value: integer, .. }, TypeSpecifier::BuiltIn(builtin)) = (&value, ty.specifier.value()) {
+            if let Some(integer_type) = builtin.value().integer_type().filter(|_| ty.qualifiers.is_empty()) {
+                return Value::Integer {
+                    value: integer_type.wrapping(*integer as i128),
+                    ty: integer_type,
+                };
+            }
+        }
+
         for qual in &ty.qualifiers {
             match qual.value() {
                 TypeQualifier::Array => todo!("Opeensommingen kunnen nog niet opgebouwd worden vanuit uitheemse werkwijzen"),
                 TypeQualifier::Pointer => {
                     let address = match value {
-                        Value::Integer(integer) => integer as usize,
-                        Value::Null => 0 as usize,
+                        Value::Integer { value: integer, .. } => integer as usize,
                         _ => todo!("Kan {value:?} geen wijzer maken")
                     };
 
@@ -901,6 +1851,77 @@ impl<D> Interpreter<D>
 
         value
     }
+
+    /// Converts an integer to the declared type of a parameter, field or
+    /// return value. It is an error if the value doesn't fit in it.
+    fn conform_to_type(&mut self, value: Value, ty: &Type) -> Value {
+        if let Some((qualifier, rest)) = ty.qualifiers.split_last() {
+            let (TypeQualifier::Array, Value::Array { ty: element_value_type, values }) = (qualifier.value(), value.clone()) else {
+                return value;
+            };
+
+            let element_type = Type {
+                specifier: ty.specifier.clone(),
+                qualifiers: rest.to_vec(),
+            };
+
+            // The elements are converted in place, such that other references
+            // to the same array see them as well.
+            for element in values.borrow_mut().iter_mut() {
+                *element = self.conform_to_type(std::mem::replace(element, Value::unit()), &element_type);
+            }
+
+            let ty = match values.borrow().first() {
+                Some(first @ (Value::Integer { .. } | Value::Array { .. })) => first.typ(),
+                _ => element_value_type,
+            };
+
+            return Value::Array { ty, values };
+        }
+
+        if let (TypeSpecifier::Tuple(types), Value::Tuple(elements)) = (ty.specifier.value(), &value) {
+            let elements = elements.clone();
+            return Value::Tuple(
+                elements.into_iter()
+                    .zip(types)
+                    .map(|(element, ty)| self.conform_to_type(element, ty.value()))
+                    .collect()
+            );
+        }
+
+        let TypeSpecifier::BuiltIn(expected) = ty.specifier.value() else {
+            return value;
+        };
+
+        match expected.value().integer_type().filter(|_| ty.qualifiers.is_empty()) {
+            Some(expected) => self.conform_to_integer_type(value, expected),
+            None => value,
+        }
+    }
+
+    fn conform_to_integer_type(&mut self, value: Value, expected: IntegerType) -> Value {
+        let Value::Integer { value: integer, ty: actual } = value else {
+            return value;
+        };
+
+        let integer = actual.widen(integer);
+        match expected.checked(integer) {
+            Some(value) => Value::Integer { value, ty: expected },
+            None => self.report_runtime_error(RuntimeError::integer_out_of_range(integer, expected)),
+        }
+    }
+
+    fn report_runtime_error(&mut self, error: RuntimeError) -> ! {
+        self.debugger.on_runtime_error(&error);
+
+        // Inside a `@test`, the error only fails that test.
+        if self.failed_expectations.is_some() {
+            resume_unwind(Box::new(error));
+        }
+
+        error!("Fout: {error}");
+        exit(1);
+    }
 }
 
 fn create_top_level_structures() -> HashMap<StructureId, InterpreterStructure> {
@@ -909,6 +1930,7 @@ fn create_top_level_structures() -> HashMap<StructureId, InterpreterStructure> {
     for ty in Builtin::TYPES {
         let structure = InterpreterStructure {
             structure: Structure::from_builtin_type(*ty),
+            constants: HashMap::new(),
             method_ids: HashMap::new(),
             extension_ids: Vec::new(),
             extension_method_ids: HashMap::new(),
@@ -922,16 +1944,51 @@ fn create_top_level_structures() -> HashMap<StructureId, InterpreterStructure> {
 
 impl<D> babbelaar::Interpreter for Interpreter<D>
         where D: Debugger {
+    fn are_equal(&mut self, lhs: &Value, rhs: &Value) -> bool {
+        let gelijkwaardig = BuiltinInterface::for_operator(BiOperator::Comparison(Comparison::Equality)).unwrap();
 
+        if let Some(method) = self.find_builtin_interface_method(lhs, gelijkwaardig) {
+            let this = lhs.actual_value().into_owned();
+            return self.execute_function(method, vec![rhs.actual_value().into_owned()], Some(this)).is_true();
+        }
+
+        lhs.is_structurally_equal_to(rhs, &mut |lhs, rhs| self.are_equal(lhs, rhs))
+    }
+
+    fn report_failed_expectation(&mut self, message: BabString) {
+        let location = self.builtin_caller_location;
+
+        if let Some(failed_expectations) = &mut self.failed_expectations {
+            failed_expectations.push(FailedExpectation::new(message, location));
+            return;
+        }
+
+        self.report_runtime_error(RuntimeError::expectation_failed(message));
+    }
+
+    fn report_unsupported(&mut self, feature: BabString) -> Value {
+        self.report_runtime_error(RuntimeError::unsupported(feature))
+    }
 }
 
 struct InterpreterAdapter;
 
-impl babbelaar::Interpreter for InterpreterAdapter {}
+impl babbelaar::Interpreter for InterpreterAdapter {
+    fn report_failed_expectation(&mut self, message: BabString) {
+        _ = message;
+    }
+
+    fn report_unsupported(&mut self, feature: BabString) -> Value {
+        _ = feature;
+        Value::unit()
+    }
+}
 
 #[must_use]
 enum StatementResult {
     Continue,
+    Break,
+    NextIteration,
     Return(Option<Value>),
 }
 
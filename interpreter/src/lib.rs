@@ -26,7 +26,7 @@ pub use self::{
         DebuggerFunction,
         DebuggerFunctionType,
     },
-    error::RuntimeError,
+    error::{FailedExpectation, RuntimeError},
     ffi::FFIManager,
     interpreter::Interpreter,
     scope::Scope,
@@ -8,13 +8,15 @@
 mod data;
 mod debugger;
 mod debug_adapter;
+mod documenter;
 mod error;
 mod ffi;
 mod interpreter;
 mod logger;
 mod scope;
+mod tester;
 
-use std::{fmt::Display, fs::read_dir, path::{Path, PathBuf}, process::exit};
+use std::{fmt::Display, path::{Path, PathBuf}, process::exit};
 
 pub use babbelaar::*;
 // use babbelaar_compiler::LlvmContext;
@@ -35,10 +37,12 @@ pub use self::{
         DebuggerFunctionType,
     },
     debug_adapter::DebugAdapter,
-    error::RuntimeError,
+    documenter::Documenter,
+    error::{FailedExpectation, RuntimeError},
     ffi::FFIManager,
     interpreter::Interpreter,
     scope::Scope,
+    tester::TestRunner,
 };
 
 #[derive(clap::Parser, Debug)]
@@ -66,6 +70,24 @@ enum Commands {
     Debug {
         bestand: PathBuf,
     },
+
+    /// Genereer een naslagwerk uit de `///`-opmerkingen in de code. Eindigt
+    /// het uitvoerbestand op `.html`, dan wordt HTML gegenereerd, anders
+    /// Markdown.
+    Documenteer {
+        bestand: PathBuf,
+
+        #[arg(short, long)]
+        uitvoer: Option<PathBuf>,
+    },
+    /// Voer de `@test`-werkwijzen uit en laat zien welke er geslaagd zijn.
+    Test {
+        bestand: PathBuf,
+
+        /// Voer alleen de test met deze naam uit.
+        #[arg(short, long)]
+        naam: Option<String>,
+    },
     Uitvoeren {
         bestand: PathBuf,
     },
@@ -84,6 +106,12 @@ fn main() {
         Commands::Debug { bestand } => {
             interpret(&bestand, DebugAdapter::new(bestand.to_string_lossy().to_string()));
         }
+        Commands::Documenteer { bestand, uitvoer } => {
+            document(&bestand, uitvoer.as_deref());
+        }
+        Commands::Test { bestand, naam } => {
+            test(&bestand, naam.as_deref());
+        }
         Commands::Uitvoeren { bestand } => {
             interpret(&bestand, ());
         }
@@ -101,11 +129,10 @@ fn main() {
 // }
 
 pub fn interpret<D: Debugger>(path: &Path, debugger: D) {
-    let files: Vec<(SourceCode, ParseTree)> = read_dir(&path.parent().unwrap())
+    let files: Vec<(SourceCode, ParseTree)> = SourceCode::find_in_directory(path.parent().unwrap())
         .unwrap()
-        .flatten()
-        .filter(|x| x.file_name().to_string_lossy().ends_with(".bab"))
-        .map(|x| parse(&x.path()))
+        .iter()
+        .map(|path| parse(path))
         .collect();
 
     analyze(&files);
@@ -119,6 +146,48 @@ pub fn interpret<D: Debugger>(path: &Path, debugger: D) {
     interpreter.execute_trees(&trees);
 }
 
+fn test(path: &Path, name: Option<&str>) {
+    let directory = if path.is_dir() { path } else { path.parent().unwrap() };
+
+    let files: Vec<(SourceCode, ParseTree)> = SourceCode::find_in_directory(directory)
+        .unwrap()
+        .iter()
+        .map(|path| parse(path))
+        .collect();
+
+    analyze(&files);
+
+    if !TestRunner::new(&files).run(name) {
+        exit(1);
+    }
+}
+
+fn document(path: &Path, output: Option<&Path>) {
+    let directory = if path.is_dir() { path } else { path.parent().unwrap() };
+
+    let mut documenter = Documenter::new();
+    for file in SourceCode::find_in_directory(directory).unwrap() {
+        let (_, tree) = parse(&file);
+        documenter.add_tree(&tree);
+    }
+
+    let Some(output) = output else {
+        print!("{}", documenter.to_markdown());
+        return;
+    };
+
+    let contents = if output.extension().is_some_and(|extension| extension == "html" || extension == "htm") {
+        documenter.to_html()
+    } else {
+        documenter.to_markdown()
+    };
+
+    if let Err(e) = std::fs::write(output, contents) {
+        eprintln!("{}: kon {} niet schrijven: {e}", "fout".red().bold(), output.display());
+        exit(1);
+    }
+}
+
 fn analyze(files: &[(SourceCode, ParseTree)]) {
     let file_ids = files.iter()
         .map(|(source_code, _)| (source_code.file_id(), source_code.clone()))
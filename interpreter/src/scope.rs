@@ -1,20 +1,32 @@
 // Copyright (C) 2023 - 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use babbelaar::{BabString, InterfaceId, StructureId, ValueType};
+use babbelaar::{qualify_name, BabString, EnumerationId, InterfaceId, StructureId, ValueType};
 
 use crate::{Builtin, FunctionId, Value};
 
 #[derive(Default, Debug)]
 pub struct Scope {
     pub parent: Option<Box<Scope>>,
-    pub variables: HashMap<BabString, Value>,
+
+    /// The variables declared in this scope. These are shared with the
+    /// anonymous werkwijzen that capture them, so that changes made by either
+    /// of them are seen by the other.
+    variables: HashMap<BabString, Rc<RefCell<Value>>>,
     pub structures: HashMap<BabString, StructureId>,
+    pub enumerations: HashMap<BabString, EnumerationId>,
     pub interfaces: HashMap<BabString, InterfaceId>,
     pub generic_types: HashMap<BabString, ValueType>,
     pub this: Option<Value>,
+
+    /// The module of the code that is running in this scope.
+    pub module: Option<BabString>,
+
+    /// Whether this is the scope of an anonymous werkwijze, which only sees
+    /// its captures and the top-level scope, not the scopes of its caller.
+    pub is_closure: bool,
 }
 
 impl Scope {
@@ -23,9 +35,12 @@ impl Scope {
             parent: None,
             variables: HashMap::new(),
             structures: HashMap::new(),
+            enumerations: HashMap::new(),
             interfaces: HashMap::new(),
             generic_types: HashMap::new(),
             this,
+            module: None,
+            is_closure: false,
         }
     }
 
@@ -37,7 +52,7 @@ impl Scope {
                 namespace: usize::MAX,
                 id: func_idx,
             };
-            this.variables.insert(BabString::new_static(func.name), Value::Function { name: func.name.to_string(), id });
+            this.define(BabString::new_static(func.name), Value::Function { name: func.name.to_string(), id });
         }
 
         for ty in Builtin::TYPES {
@@ -49,24 +64,31 @@ impl Scope {
 
     pub fn push(self) -> Self {
         let this = self.this.clone();
+        let module = self.module.clone();
         Self {
             parent: Some(Box::new(self)),
             variables: HashMap::new(),
             structures: HashMap::new(),
+            enumerations: HashMap::new(),
             interfaces: HashMap::new(),
             generic_types: HashMap::new(),
             this,
+            module,
+            is_closure: false,
         }
     }
 
-    pub fn push_function(self, this: Option<Value>) -> Self {
+    pub fn push_function(self, this: Option<Value>, module: Option<BabString>) -> Self {
         Self {
             parent: Some(Box::new(self)),
             variables: HashMap::new(),
             structures: HashMap::new(),
+            enumerations: HashMap::new(),
             interfaces: HashMap::new(),
             generic_types: HashMap::new(),
             this,
+            module,
+            is_closure: false,
         }
     }
 
@@ -74,28 +96,95 @@ impl Scope {
         *self.parent.expect("Top-level scope popped!")
     }
 
-    pub fn find(&self, reference: &BabString) -> Value {
-        if let Some(value) = self.variables.get(reference) {
-            return value.clone();
+    /// The number of scopes this scope is nested in.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.parent.as_ref().map_or(0, |parent| parent.depth() + 1)
+    }
+
+    /// The name under which a top-level declaration called `name` of the
+    /// current module is registered.
+    #[must_use]
+    pub fn qualify(&self, name: &BabString) -> BabString {
+        qualify_name(self.module.as_ref(), name)
+    }
+
+    /// Walks up the scopes to find `name` using `get`. At the top level, the
+    /// declarations of the current module take precedence over those in the
+    /// root namespace.
+    fn lookup<'this, T>(&'this self, name: &BabString, get: impl Fn(&'this Self, &BabString) -> Option<T>) -> Option<T> {
+        let mut scope = self;
+
+        while let Some(parent) = &scope.parent {
+            if let Some(value) = get(scope, name) {
+                return Some(value);
+            }
+
+            scope = if scope.is_closure { parent.root() } else { parent };
         }
 
-        if let Some(parent) = self.parent.as_ref() {
-            return parent.find(reference);
+        if self.module.is_some() {
+            if let Some(value) = get(scope, &self.qualify(name)) {
+                return Some(value);
+            }
         }
 
-        Value::Null
+        get(scope, name)
     }
 
-    pub fn find_mut(&mut self, reference: &BabString) -> Option<&mut Value> {
-        if let Some(value) = self.variables.get_mut(reference) {
-            return Some(value);
+    fn root(&self) -> &Self {
+        match &self.parent {
+            Some(parent) => parent.root(),
+            None => self,
         }
+    }
 
-        if let Some(parent) = self.parent.as_mut() {
-            return parent.find_mut(reference);
+    fn find_variable(&self, reference: &BabString) -> Option<Rc<RefCell<Value>>> {
+        self.lookup(reference, |scope, name| scope.variables.get(name).cloned())
+    }
+
+    #[must_use]
+    pub fn find(&self, reference: &BabString) -> Option<Value> {
+        self.find_variable(reference)
+            .map(|variable| variable.borrow().clone())
+    }
+
+    #[must_use]
+    pub fn contains(&self, reference: &BabString) -> bool {
+        self.find_variable(reference).is_some()
+    }
+
+    /// Declares a new variable in this scope, shadowing any variable with the
+    /// same name in the parent scopes.
+    pub fn define(&mut self, name: BabString, value: Value) {
+        self.variables.insert(name, Rc::new(RefCell::new(value)));
+    }
+
+    /// Declares the variables shared with the anonymous werkwijze that
+    /// captured them.
+    pub fn define_captures(&mut self, captures: &HashMap<BabString, Rc<RefCell<Value>>>) {
+        for (name, variable) in captures {
+            self.variables.insert(name.clone(), Rc::clone(variable));
         }
+    }
 
-        None
+    /// The variables visible from this scope, for an anonymous `werkwijze`
+    /// created in it. The variables of the top-level scope aren't captured,
+    /// since those are reachable from every scope.
+    #[must_use]
+    pub fn capture(&self) -> HashMap<BabString, Rc<RefCell<Value>>> {
+        let mut captures = HashMap::new();
+        let mut scope = self;
+
+        while let Some(parent) = &scope.parent {
+            for (name, variable) in &scope.variables {
+                captures.entry(name.clone()).or_insert_with(|| Rc::clone(variable));
+            }
+
+            scope = if scope.is_closure { parent.root() } else { parent };
+        }
+
+        captures
     }
 
     pub fn find_generic_type(&self, name: &BabString) -> Option<ValueType> {
@@ -111,39 +200,24 @@ impl Scope {
     }
 
     pub fn find_structure_id(&self, name: &BabString) -> Option<StructureId> {
-        if let Some(id) = self.structures.get(name) {
-            return Some(*id);
-        }
-
-        if let Some(parent) = self.parent.as_ref() {
-            return parent.find_structure_id(name);
-        }
+        self.lookup(name, |scope, name| scope.structures.get(name).copied())
+    }
 
-        None
+    pub fn find_enumeration_id(&self, name: &BabString) -> Option<EnumerationId> {
+        self.lookup(name, |scope, name| scope.enumerations.get(name).copied())
     }
 
     pub fn find_interface_id(&self, name: &BabString) -> Option<InterfaceId> {
-        if let Some(id) = self.interfaces.get(name) {
-            return Some(*id);
-        }
-
-        if let Some(parent) = self.parent.as_ref() {
-            return parent.find_interface_id(name);
-        }
-
-        None
+        self.lookup(name, |scope, name| scope.interfaces.get(name).copied())
     }
 
     pub fn overwrite(&mut self, reference: &BabString, new: Value) -> bool {
-        if let Some(value) = self.variables.get_mut(reference) {
-            *value = new;
-            return true;
-        }
-
-        if let Some(parent) = self.parent.as_mut() {
-            return parent.overwrite(reference, new);
+        match self.find_variable(reference) {
+            Some(variable) => {
+                *variable.borrow_mut() = new;
+                true
+            }
+            None => false,
         }
-
-        false
     }
 }
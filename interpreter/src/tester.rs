@@ -0,0 +1,93 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::path::Path;
+
+use babbelaar::*;
+use colored::Colorize;
+
+use crate::{FailedExpectation, Interpreter};
+
+/// Finds and executes the `@test` functions of the given files.
+pub struct TestRunner<'files> {
+    files: &'files [(SourceCode, ParseTree)],
+}
+
+impl<'files> TestRunner<'files> {
+    #[must_use]
+    pub fn new(files: &'files [(SourceCode, ParseTree)]) -> Self {
+        Self { files }
+    }
+
+    /// Runs the tests, or only the test called `name` if given, and prints the
+    /// outcome of each of them. Returns whether all tests succeeded.
+    pub fn run(&self, name: Option<&str>) -> bool {
+        let tests: Vec<(&ParseTree, &FunctionStatement)> = self.find_tests()
+            .filter(|(_, function)| name.is_none_or(|name| function.name.value() == name))
+            .collect();
+
+        match tests.len() {
+            0 => println!("Geen tests gevonden"),
+            1 => println!("1 test gevonden"),
+            count => println!("{count} tests gevonden"),
+        }
+        println!();
+
+        let trees: Vec<ParseTree> = self.files.iter()
+            .map(|(_, tree)| tree.clone())
+            .collect();
+
+        let mut failed = 0;
+        for (tree, function) in &tests {
+            print!("test {} ... ", function.name.value());
+
+            // Each test gets a new interpreter, such that tests can't
+            // influence each other.
+            let mut interpreter = Interpreter::new(());
+            interpreter.declare_trees(&trees);
+            let failed_expectations = interpreter.execute_test(tree, function);
+
+            if failed_expectations.is_empty() {
+                println!("{}", "geslaagd".green());
+                continue;
+            }
+
+            failed += 1;
+            println!("{}", "GEFAALD".red().bold());
+
+            for failed_expectation in &failed_expectations {
+                self.print_failed_expectation(failed_expectation);
+            }
+        }
+
+        let succeeded = tests.len() - failed;
+        println!();
+        println!(
+            "Resultaat: {}. {succeeded} geslaagd, {failed} gefaald",
+            if failed == 0 { "geslaagd".green() } else { "GEFAALD".red().bold() },
+        );
+
+        failed == 0
+    }
+
+    fn find_tests(&self) -> impl Iterator<Item = (&'files ParseTree, &'files FunctionStatement)> {
+        self.files.iter()
+            .flat_map(|(_, tree)| tree.functions().iter().map(move |statement| (tree, statement)))
+            .filter(|(_, statement)| statement.attributes.iter().any(|attribute| attribute.name.value() == Attribute::NAME_TEST))
+            .filter_map(|(tree, statement)| match &statement.kind {
+                StatementKind::Function(function) => Some((tree, function)),
+                _ => None,
+            })
+    }
+
+    fn print_failed_expectation(&self, failed_expectation: &FailedExpectation) {
+        let location = failed_expectation.location();
+        let path = self.files.iter()
+            .find(|(source_code, _)| source_code.file_id() == location.file_id())
+            .map(|(source_code, _)| source_code.path())
+            .unwrap_or(Path::new("(onbekend)"));
+
+        println!("    {}", failed_expectation.message().bold());
+        println!("    In {}:{}:{}", path.display(), location.start().line() + 1, location.start().column() + 1);
+    }
+}
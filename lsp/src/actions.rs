@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use babbelaar::{BabbelaarCodeAction, BabbelaarCodeActionType, BabbelaarFixKind, BiExpression, Expression, FileEdit, FileId, FileLocation, FileRange, FunctionCallExpression, InterfaceStatement, ParseDiagnostic, ParseTree, PostfixExpression, PostfixExpressionKind, PrimaryExpression, SemanticAnalyzer, Statement, StatementKind, StrExt, Structure, StructureInstantiationExpression, TemplateStringExpressionPart, UnaryExpression};
+use babbelaar::{BabbelaarCodeAction, BabbelaarCodeActionType, BabbelaarFixKind, BiExpression, ElseStatementKind, Expression, FileEdit, FileId, FileLocation, FileRange, FunctionCallExpression, IfStatement, InterfaceStatement, ParseDiagnostic, ParseTree, PostfixExpression, PostfixExpressionKind, PrimaryExpression, SemanticAnalyzer, Statement, StatementKind, StrExt, Structure, StructureInstantiationExpression, TemplateStringExpressionPart, UnaryExpression};
 use tower_lsp::lsp_types::VersionedTextDocumentIdentifier;
 
 use crate::BabbelaarLspError;
@@ -166,6 +166,8 @@ impl CodeActionsAnalyzable for PostfixExpression {
             PostfixExpressionKind::Subscript(ranged) => {
                 ranged.analyze(ctx);
             }
+
+            PostfixExpressionKind::Propagate => (),
         }
     }
 }
@@ -175,12 +177,44 @@ impl CodeActionsAnalyzable for PrimaryExpression {
         match self {
             Self::Boolean(..) => (),
             Self::CharacterLiteral(..) => (),
+
+            Self::Closure(closure) => {
+                for stmt in closure.function.body.as_deref().unwrap_or_default() {
+                    stmt.analyze(ctx);
+                }
+            }
+
             Self::IntegerLiteral(..) => (),
+            Self::FloatLiteral(..) => (),
+
+            Self::Match(expression) => {
+                expression.scrutinee.analyze(ctx);
+
+                for arm in &expression.arms {
+                    arm.expression.analyze(ctx);
+                }
+            }
+
+            Self::OptionalSome(expr) => {
+                expr.analyze(ctx);
+            }
+
+            Self::OptionalNone => (),
+
+            Self::ResultOk(expr) | Self::ResultError(expr) => {
+                expr.analyze(ctx);
+            }
 
             Self::Parenthesized(expr) => {
                 expr.analyze(ctx);
             }
 
+            Self::ArrayLiteral(elements) | Self::Tuple(elements) => {
+                for element in elements {
+                    element.analyze(ctx);
+                }
+            }
+
             Self::Reference(..) => (),
             Self::ReferenceThis => (),
             Self::StringLiteral(..) => (),
@@ -189,6 +223,15 @@ impl CodeActionsAnalyzable for PrimaryExpression {
                 size.analyze(ctx);
             }
 
+            Self::Range(range) => {
+                range.start.analyze(ctx);
+                range.end.analyze(ctx);
+
+                if let Some(step) = &range.step {
+                    step.analyze(ctx);
+                }
+            }
+
             Self::StructureInstantiation(structure) => {
                 structure.analyze(ctx);
             }
@@ -217,6 +260,16 @@ impl CodeActionsAnalyzable for Statement {
                 stmt.source.analyze(ctx);
             }
 
+            StatementKind::Break(..) => (),
+
+            StatementKind::Constant(stmt) => {
+                stmt.value.analyze(ctx);
+            }
+
+            StatementKind::Continue(..) => (),
+
+            StatementKind::Enumeration(..) => (),
+
             StatementKind::Extension(ext) => {
                 for method in &ext.methods {
                     for stmt in method.function.body.as_ref().map(Vec::as_slice).unwrap_or_default() {
@@ -238,10 +291,7 @@ impl CodeActionsAnalyzable for Statement {
             }
 
             StatementKind::If(stmt) => {
-                stmt.condition.analyze(ctx);
-                for stmt in &stmt.body {
-                    stmt.analyze(ctx);
-                }
+                stmt.analyze(ctx);
             }
 
             StatementKind::Interface(stmt) => {
@@ -309,6 +359,13 @@ impl CodeActionsAnalyzable for Statement {
             StatementKind::Variable(stmt) => {
                 stmt.expression.analyze(ctx);
             }
+
+            StatementKind::While(stmt) => {
+                stmt.condition.analyze(ctx);
+                for stmt in &stmt.body {
+                    stmt.analyze(ctx);
+                }
+            }
         }
     }
 }
@@ -327,6 +384,25 @@ impl CodeActionsAnalyzable for UnaryExpression {
     }
 }
 
+impl CodeActionsAnalyzable for IfStatement {
+    fn analyze(&self, ctx: &mut CodeActionsAnalysisContext<'_>) {
+        self.condition.analyze(ctx);
+        for stmt in &self.body {
+            stmt.analyze(ctx);
+        }
+
+        let Some(else_statement) = &self.else_statement else { return };
+        match &else_statement.kind {
+            ElseStatementKind::ElseIf(stmt) => stmt.analyze(ctx),
+            ElseStatementKind::Else(body) => {
+                for stmt in body {
+                    stmt.analyze(ctx);
+                }
+            }
+        }
+    }
+}
+
 impl CodeActionsAnalyzable for InterfaceStatement {
     fn analyze(&self, ctx: &mut CodeActionsAnalysisContext<'_>) {
         _ = ctx;
@@ -463,6 +539,28 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
                 );
             }
 
+            Self::ExpectedCommaAfterEnumerationVariant { location, .. } => {
+                ctx.items.push(
+                    BabbelaarCodeAction::new(
+                        BabbelaarCodeActionType::Insert{ text: "," },
+                        vec![
+                            FileEdit::new(location.as_zero_range(), ",")
+                        ]
+                    ),
+                );
+            }
+
+            Self::ExpectedCommaAfterMatchArm { location, .. } => {
+                ctx.items.push(
+                    BabbelaarCodeAction::new(
+                        BabbelaarCodeActionType::Insert{ text: "," },
+                        vec![
+                            FileEdit::new(location.as_zero_range(), ",")
+                        ]
+                    ),
+                );
+            }
+
             Self::ExpectedCommaOrGreaterThanInGenericTypePack { token, .. } => {
                 let range = token.begin.as_zero_range();
                 ctx.items.push(
@@ -494,8 +592,30 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
                 );
             }
 
+            Self::ExpectedFatArrowAfterPattern { range, .. } => {
+                ctx.items.push(
+                    BabbelaarCodeAction::new(
+                        BabbelaarCodeActionType::Insert{ text: "=>" },
+                        vec![
+                            FileEdit::new(*range, " =>")
+                        ]
+                    ),
+                );
+            }
+
             Self::ExpectedGenericTypeName { .. } => (),
 
+            Self::ExpectedKeywordDoor { token } => {
+                ctx.items.push(
+                    BabbelaarCodeAction::new(
+                        BabbelaarCodeActionType::Insert{ text: "door" },
+                        vec![
+                            FileEdit::new(token.begin.as_zero_range(), "door ")
+                        ]
+                    ),
+                );
+            }
+
             Self::ExpectedKeywordOp { range, .. } => {
                 ctx.items.push(
                     BabbelaarCodeAction::new(
@@ -509,6 +629,8 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
 
             Self::ExpectedNameAfterNieuw { .. } => (),
 
+            Self::ExpectedNameOfConstant { .. } => (),
+
             Self::ExpectedNameOfField { .. } => (),
 
             Self::ExpectedNameOfStructuur { .. } => (),
@@ -519,7 +641,7 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
 
             Self::ExpectedNameOfVariable { .. } => (),
 
-            Self::ExpectedEqualsInsideVariable { token } => {
+            Self::ExpectedEqualsInsideVariable { token } | Self::ExpectedEqualsInsideConstant { token } => {
                 ctx.items.push(
                     BabbelaarCodeAction::new(
                         BabbelaarCodeActionType::Insert{ text: "=" },
@@ -588,6 +710,7 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
             }
 
             Self::ExpectedRightSquareBracketForArrayInitializer { token }
+                | Self::ExpectedRightSquareBracketForArrayLiteral { token }
                 | Self::ExpectedRightSquareBracketForArrayQualifier { token }
                 | Self::ExpectedRightSquareBracketForSubscript { token } => {
                 ctx.items.push(
@@ -611,7 +734,9 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
                 );
             }
 
-            Self::ExpectedStructureMethodPrefixWerkwijze { token } => {
+            Self::ExpectedStructureMethodPrefixWerkwijze { token }
+                | Self::ExpectedWerkwijzeAfterGedeeld { token }
+                | Self::ExpectedWerkwijzeAfterVisibility { token, .. } => {
                 ctx.items.push(
                     BabbelaarCodeAction::new(
                         BabbelaarCodeActionType::AddKeyword{ keyword: "werkwijze" },
@@ -622,8 +747,12 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
                 );
             }
 
+            Self::ExpectedMemberAfterVisibility { .. } => (),
+
             Self::FunctionStatementExpectedName { .. } => (),
 
+            Self::InvalidPattern { .. } => (),
+
             Self::ExpectedIdentifier { .. } => (),
 
             Self::ForStatementExpectedIteratorName { .. } => (),
@@ -651,6 +780,10 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
                 );
             }
 
+            Self::DuplicateModuleDeclaration { .. } => (),
+
+            Self::ModuleHeaderNotAtTop { .. } => (),
+
             Self::ParameterExpectedName { .. } => (),
 
             Self::ParameterExpectedComma { token } => {
@@ -667,6 +800,9 @@ impl CodeActionsAnalyzable for ParseDiagnostic {
             Self::PostfixMemberOrReferenceExpectedIdentifier { .. } => (),
 
             Self::RangeExpectedKeyword { .. } => (),
+            Self::RangeExpectedNamedArgument { .. } => (),
+            Self::RangeInclusiveExpectedBoolean { .. } => (),
+            Self::RangeUnknownNamedArgument { .. } => (),
 
             Self::ResidualTokensInTemplateString { range, .. } => {
                 ctx.items.push(
@@ -3,7 +3,6 @@
 
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use std::fs::read_dir;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -107,13 +106,40 @@ impl Backend {
                     command: "babbelaar.uitvoeren".into(),
                     arguments: Some(vec![
                         "uitvoeren".into(),
-                        url.into(),
+                        url.clone().into(),
                     ]),
                 }),
                 data: None,
             });
         }
 
+        let test_lenses = self.with_syntax(&params.text_document, |tree, source_code| {
+            let converter = self.converter(source_code);
+
+            let lenses = tree.functions().iter()
+                .filter(|statement| statement.attributes.iter().any(|attribute| attribute.name.value() == Attribute::NAME_TEST))
+                .filter_map(|statement| match &statement.kind {
+                    StatementKind::Function(function) => Some(CodeLens {
+                        range: converter.convert_file_range(function.name.range()),
+                        command: Some(Command {
+                            title: "► Test uitvoeren".into(),
+                            command: "babbelaar.testUitvoeren".into(),
+                            arguments: Some(vec![
+                                url.clone().into(),
+                                function.name.value().to_string().into(),
+                            ]),
+                        }),
+                        data: None,
+                    }),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            Ok(lenses)
+        }).await?;
+
+        lenses.extend(test_lenses);
+
         Ok(Some(lenses))
     }
 
@@ -484,7 +510,22 @@ impl Backend {
                 end.character = last.end.column() as _;
             }
 
-            for statement in parser.parse_tree().all() {
+            let tree = parser.parse_tree();
+
+            if let Some(module) = tree.module() {
+                result += &module.format_to_string();
+                result += "\n";
+            }
+
+            for import in tree.imports() {
+                result += &import.format_to_string();
+            }
+
+            if !tree.imports().is_empty() {
+                result += "\n";
+            }
+
+            for statement in tree.all() {
                 result += &statement.format_to_string();
                 result += "\n";
             }
@@ -507,12 +548,10 @@ impl Backend {
 
         if let Some(folder) = workspace_folder {
             let path = folder.to_path().unwrap();
-            let read_dir = read_dir(&path).map_err(|error| BabbelaarLspError::InvalidWorkspacePath { error, path })?;
+            let files = SourceCode::find_in_directory(&path).map_err(|error| BabbelaarLspError::InvalidWorkspacePath { error, path })?;
 
-            for file in read_dir.flatten() {
-                if file.file_name().to_string_lossy().ends_with(".bab") {
-                    self.context.load_and_register_file(file.path()).await?;
-                }
+            for file in files {
+                self.context.load_and_register_file(file).await?;
             }
         }
 
@@ -705,18 +744,28 @@ impl Backend {
             let (reference, source_code) = self.with_semantics(&params.text_document_position_params.text_document, |analyzer, source_code| {
                 let pos = params.text_document_position_params.position;
                 let location = self.converter(source_code).convert_location(pos);
-                Ok((analyzer.find_reference_at(location), source_code.clone()))
+                let reference = analyzer.find_reference_at(location).map(|(range, reference)| {
+                    let documentation = analyzer.find_documentation_of(&reference);
+                    (range, reference, documentation)
+                });
+                Ok((reference, source_code.clone()))
             }).await?;
 
-            if let Some((range, reference)) = reference {
+            if let Some((range, reference, documentation)) = reference {
                 debug_assert_eq!(range.file_id(), source_code.file_id(), "is invalid");
                 let text = reference.hover();
                 let file_name = self.file_humanized_name(reference.declaration_range.file_id()).await.unwrap_or_else(|| "(onbekend)".to_string());
 
+                let mut value = format!("```babbelaar\n// In bestand {file_name}\n{text}\n```");
+                if let Some(documentation) = documentation {
+                    value += "\n\n";
+                    value += &documentation;
+                }
+
                 hover = Some(Hover {
                     contents: HoverContents::Markup(MarkupContent {
                         kind: MarkupKind::Markdown,
-                        value: format!("```babbelaar\n// In bestand {file_name}\n{text}\n```"),
+                        value,
                     }),
                     range: Some(self.converter(&source_code).convert_file_range(range)),
                 });
@@ -180,10 +180,12 @@ impl<'b> CompletionEngine<'b> {
             let mut scope_stack = Vec::new();
             for token in previous {
                 match token.kind {
+                    TokenKind::Keyword(kw @ Keyword::Opsomming) => scope_stack.push(kw),
                     TokenKind::Keyword(kw @ Keyword::Structuur) => scope_stack.push(kw),
                     TokenKind::Keyword(kw @ Keyword::Uitbreiding) => scope_stack.push(kw),
                     TokenKind::Keyword(kw @ Keyword::Volg) => scope_stack.push(kw),
                     TokenKind::Keyword(kw @ Keyword::Werkwijze) => scope_stack.push(kw),
+                    TokenKind::Keyword(kw @ Keyword::Zolang) => scope_stack.push(kw),
                     TokenKind::Punctuator(Punctuator::RightCurlyBracket) => {
                         _ = scope_stack.pop();
                     }
@@ -230,8 +232,14 @@ impl<'b> CompletionEngine<'b> {
     async fn complete_global_function(&mut self, ident: &str) -> Result<()> {
         let document = &self.params.text_document_position.text_document;
 
-        self.server.with_semantics(document, |analyzer, _| {
+        self.server.with_semantics(document, |analyzer, source_code| {
             if let Some(func) = analyzer.find_function_by_name(|f| f.starts_with(&ident)) {
+                if let SemanticType::Function(function) | SemanticType::FunctionReference(FunctionReference::Custom(function)) = &func.typ {
+                    if !analyzer.context.is_accessible_from(source_code.file_id(), function.visibility(), function.name.range()) {
+                        return Ok(());
+                    }
+                }
+
                 self.completions.push(CompletionItem {
                     label: func.function_name().to_string(),
                     kind: Some(CompletionItemKind::FUNCTION),
@@ -255,13 +263,13 @@ impl<'b> CompletionEngine<'b> {
 
         self.server.with_semantics(document, |analyzer, _| {
             analyzer.scopes_surrounding(range.start(), |scope| {
-                for (name, _) in &scope.structures {
+                for (name, structure) in &scope.structures {
                     if let Some(idx) = name.find(&structure_to_complete) {
                         self.completions.push(CompletionItem {
                             label: name.to_string(),
                             filter_text: Some(name[idx..].to_string()),
                             kind: Some(CompletionItemKind::FIELD),
-                            documentation: None,
+                            documentation: markdown_documentation(&structure.documentation),
                             ..Default::default()
                         })
                     }
@@ -290,7 +298,7 @@ impl<'b> CompletionEngine<'b> {
                                 detail: Some(field.ty.to_string()),
                                 insert_text_format: Some(InsertTextFormat::SNIPPET),
                                 kind: Some(CompletionItemKind::FIELD),
-                                documentation: None,
+                                documentation: markdown_documentation(&field.documentation),
                                 ..Default::default()
                             })
                         }
@@ -334,15 +342,37 @@ impl<'b> CompletionEngine<'b> {
             ..Default::default()
         });
 
+        self.completions.push(CompletionItem {
+            label: "@test".to_string(),
+            kind: Some(CompletionItemKind::FUNCTION),
+            insert_text: Some("test\nwerkwijze ${1:testnaam}() {\n    $0\n}".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            documentation: None,
+            ..Default::default()
+        });
+
         Ok(())
     }
 
     async fn complete_method(&mut self, last_identifier: FileRange) -> Result<()> {
         let document = &self.params.text_document_position.text_document;
 
-        let completions = self.server.with_semantics(document, |analyzer, _| {
-            let completions = if let Some(typ) = analyzer.context.definition_tracker.as_ref().and_then(|tracker| Some(tracker.get(&last_identifier)?.typ.clone())) {
-                match typ {
+        let completions = self.server.with_semantics(document, |analyzer, source_code| {
+            let is_accessible = |visibility, declaration| {
+                analyzer.context.is_accessible_from(source_code.file_id(), visibility, declaration)
+            };
+
+            let reference = analyzer.context.definition_tracker.as_ref().and_then(|tracker| tracker.get(&last_identifier).cloned());
+            let completions = if let Some(reference) = reference {
+                match reference.typ {
+                    SemanticType::Enumeration(enumeration) if reference.local_kind == SemanticLocalKind::EnumerationReference => {
+                        self.complete_enumeration_variants(&enumeration)
+                    }
+
+                    SemanticType::Custom { base, .. } if reference.local_kind == SemanticLocalKind::StructureReference => {
+                        self.complete_structure_static_members(&base, is_accessible)
+                    }
+
                     SemanticType::Array(array) => {
                         self.complete_array_methods(*array)
                     }
@@ -352,7 +382,7 @@ impl<'b> CompletionEngine<'b> {
                     }
 
                     SemanticType::Custom { base, ..} => {
-                        self.complete_structure_method_or_field(base.clone(), "")
+                        self.complete_structure_method_or_field(base.clone(), "", is_accessible)
                     }
 
                     _ => Vec::new(),
@@ -410,14 +440,74 @@ impl<'b> CompletionEngine<'b> {
         completions
     }
 
-    fn complete_structure_method_or_field(&self, structure: Arc<SemanticStructure>, prefix: &str) -> Vec<CompletionItem> {
+    fn complete_enumeration_variants(&self, enumeration: &SemanticEnumeration) -> Vec<CompletionItem> {
+        let mut completions = Vec::new();
+
+        for variant in &enumeration.variants {
+            let name = variant.name.value();
+            completions.push(CompletionItem {
+                label: variant.to_string(),
+                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                insert_text: Some(if variant.fields.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{name}($1)$0")
+                }),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                preselect: Some(true),
+                ..Default::default()
+            });
+        }
+
+        completions
+    }
+
+    /// Completes `Persoon.`, with the constants and `gedeeld` methods.
+    fn complete_structure_static_members(&self, structure: &SemanticStructure, is_accessible: impl Fn(Visibility, FileRange) -> bool) -> Vec<CompletionItem> {
+        let mut completions = Vec::new();
+
+        let methods = structure.methods.iter()
+            .filter(|method| method.is_static)
+            .filter(|method| is_accessible(method.function.visibility(), method.function.name.range()));
+
+        for method in methods {
+            let name = &method.function.name;
+            completions.push(CompletionItem {
+                label: format!("{}()", name.value()),
+                kind: Some(CompletionItemKind::METHOD),
+                documentation: markdown_documentation(&method.function.documentation),
+                insert_text: Some(format!("{}($1)$0", name.value())),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                preselect: Some(true),
+                ..Default::default()
+            });
+        }
+
+        for constant in &structure.constants {
+            completions.push(CompletionItem {
+                label: constant.name.to_string(),
+                detail: Some(constant.ty.to_string()),
+                kind: Some(CompletionItemKind::CONSTANT),
+                ..Default::default()
+            });
+        }
+
+        completions
+    }
+
+    fn complete_structure_method_or_field(&self, structure: Arc<SemanticStructure>, prefix: &str, is_accessible: impl Fn(Visibility, FileRange) -> bool) -> Vec<CompletionItem> {
         let mut completions = Vec::new();
 
-        for method in &structure.methods {
+        let methods = structure.methods.iter()
+            .filter(|method| !method.is_static)
+            .filter(|method| is_accessible(method.function.visibility(), method.function.name.range()));
+
+        for method in methods {
             let name = &method.function.name;
             completions.push(CompletionItem {
                 label: format!("{prefix}{}()", name.value()),
                 kind: Some(CompletionItemKind::METHOD),
+                documentation: markdown_documentation(&method.function.documentation),
                 insert_text: Some(format!("{prefix}{}($1);$0", name.value())),
                 insert_text_format: Some(InsertTextFormat::SNIPPET),
                 preselect: Some(true),
@@ -425,7 +515,7 @@ impl<'b> CompletionEngine<'b> {
             });
         }
 
-        for field in &structure.fields {
+        for field in structure.fields.iter().filter(|field| is_accessible(field.visibility(), field.name.range())) {
             completions.push(CompletionItem {
                 label: format!("{prefix}{}", field.name.to_string()),
                 label_details: Some(CompletionItemLabelDetails {
@@ -435,7 +525,7 @@ impl<'b> CompletionEngine<'b> {
                 preselect: Some(true),
                 detail: Some(field.ty.to_string()),
                 kind: Some(CompletionItemKind::FIELD),
-                documentation: None,
+                documentation: markdown_documentation(&field.documentation),
                 ..Default::default()
             });
         }
@@ -476,6 +566,20 @@ impl<'b> CompletionEngine<'b> {
             }
         }
 
+        for interface in Builtin::INTERFACES {
+            if interface.name.to_lowercase().starts_with(ident) {
+                self.completions.push(CompletionItem {
+                    label: interface.name.to_string(),
+                    kind: Some(CompletionItemKind::INTERFACE),
+                    documentation: Some(Documentation::MarkupContent(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: interface.documentation.to_string(),
+                    })),
+                    ..Default::default()
+                });
+            }
+        }
+
     }
 
     async fn suggest_this(&mut self, range: FileRange, ident: &str) -> Result<()> {
@@ -491,7 +595,8 @@ impl<'b> CompletionEngine<'b> {
             semantics.scopes_surrounding(range.start(), |scope| {
                 if let Some(this) = &scope.this {
                     if let SemanticType::Custom { base, .. } = this {
-                        completions.extend(self.complete_structure_method_or_field(base.clone(), "dit."));
+                        // Everything is accessible from within the structure itself.
+                        completions.extend(self.complete_structure_method_or_field(base.clone(), "dit.", |_, _| true));
                     }
                 }
             });
@@ -542,6 +647,13 @@ impl<'b> CompletionEngine<'b> {
 }
 
 #[must_use]
+fn markdown_documentation(documentation: &Option<BabString>) -> Option<Documentation> {
+    documentation.as_ref().map(|documentation| Documentation::MarkupContent(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: documentation.to_string(),
+    }))
+}
+
 async fn check_if_snippets_are_supported_by_the_client(server: &Backend) -> bool {
     check_if_snippets_are_supported_by_the_client_inner(server).await.unwrap_or(false)
 }
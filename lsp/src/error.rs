@@ -18,7 +18,7 @@ pub enum BabbelaarLspError {
     IoError(IoError),
 
     #[error("parseerfout: {0}")]
-    ParseError(ParseDiagnostic),
+    ParseError(Box<ParseDiagnostic>),
 
     #[error("geopend document heeft geen bestandspad as URL")]
     UrlNotFilePath,
@@ -44,7 +44,7 @@ impl From<IoError> for BabbelaarLspError {
 
 impl From<ParseDiagnostic> for BabbelaarLspError {
     fn from(value: ParseDiagnostic) -> Self {
-        Self::ParseError(value)
+        Self::ParseError(Box::new(value))
     }
 }
 
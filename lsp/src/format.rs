@@ -6,7 +6,7 @@
 
 use std::fmt::Write;
 
-use babbelaar::{AssignStatement, BiExpression, BuiltinType, Expression, Field, ForIterableKind, ForStatement, FunctionCallExpression, FunctionStatement, IfStatement, Keyword, MethodCallExpression, OptionExt, Parameter, PostfixExpression, PostfixExpressionKind, PrimaryExpression, ReturnStatement, Statement, StatementKind, Structure, StructureInstantiationExpression, TemplateStringExpressionPart, Type, TypeSpecifier, UnaryExpression, UnaryExpressionKind, VariableStatement};
+use babbelaar::{AssignStatement, BabString, BiExpression, BuiltinType, ClosureExpression, ConstantStatement, ElseStatement, ElseStatementKind, Enumeration, EnumerationVariant, Expression, Field, ForIterableKind, ForStatement, FunctionCallExpression, FunctionStatement, GenericTypeDeclaration, IfStatement, ImportStatement, Keyword, MatchExpression, MethodCallExpression, ModuleStatement, OptionExt, Parameter, PostfixExpression, PostfixExpressionKind, PrimaryExpression, RangeExpression, ReturnStatement, Statement, StatementKind, Structure, StructureInstantiationExpression, TemplateStringExpressionPart, Type, TypeSpecifier, UnaryExpression, UnaryExpressionKind, VariableName, VariableStatement, WhileStatement};
 
 pub struct Formatter {
     buffer: String,
@@ -40,6 +40,20 @@ impl Formatter {
         }
     }
 
+    /// Writes the `///` comments in front of a declaration.
+    fn write_documentation(&mut self, documentation: &Option<BabString>) {
+        let Some(documentation) = documentation else { return };
+
+        for line in documentation.split('\n') {
+            self.write_str("///");
+            if !line.is_empty() {
+                self.write_char(' ');
+                self.write_str(line);
+            }
+            self.new_line();
+        }
+    }
+
     fn new_line(&mut self) {
         self.buffer += "\n";
         self.must_indent = true;
@@ -69,6 +83,16 @@ impl Formatter {
         self.new_line();
     }
 
+    /// Like [`Self::with_curly_block`], but keeps the cursor after the `}`,
+    /// e.g. for `} anders {`.
+    fn with_curly_block_continued(&mut self, f: impl FnOnce(&mut Self)) {
+        self.write_str(" {");
+        self.new_line();
+
+        self.with_indent(f);
+        self.write_char('}');
+    }
+
     fn with_body_statements(&mut self, body: &[Statement]) {
         self.with_curly_block(|f| {
             for statement in body {
@@ -134,6 +158,13 @@ impl Format for StatementKind {
 
         match self {
             Self::Assignment(statement) => statement.format(f),
+            Self::Break(..) => f.write_str("stop;\n"),
+            Self::Constant(statement) => {
+                statement.format(f);
+                f.write_str(";\n");
+            }
+            Self::Continue(..) => f.write_str("ga door;\n"),
+            Self::Enumeration(statement) => statement.format(f),
             Self::Expression(expr) => {
                 expr.format(f);
                 f.write_char(';');
@@ -154,6 +185,7 @@ impl Format for StatementKind {
             Self::Return(statement) => statement.format(f),
             Self::Structure(statement) => statement.format(f),
             Self::Variable(statement) => statement.format(f),
+            Self::While(statement) => statement.format(f),
         }
 
         if let Some(last) = f.indents.last_mut() {
@@ -203,6 +235,7 @@ impl Format for PostfixExpressionKind {
                 subscript.format(f);
                 f.write_char(']');
             }
+            Self::Propagate => f.write_char('?'),
         }
     }
 }
@@ -234,16 +267,56 @@ impl Format for MethodCallExpression {
 impl Format for AssignStatement {
     fn format(&self, f: &mut Formatter) {
         self.destination.format(f);
-        f.write_str(" = ");
+        f.write_char(' ');
+        if let Some(operator) = self.operator {
+            f.write_str(operator.as_str());
+        }
+        f.write_str("= ");
         self.source.format(f);
         f.write_str(";\n");
     }
 }
 
+impl Format for ModuleStatement {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("module ");
+        f.write_str(&self.path.name());
+        f.write_str(";\n");
+    }
+}
+
+impl Format for ImportStatement {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("gebruik ");
+        f.write_str(&self.path.name());
+        f.write_str(";\n");
+    }
+}
+
 impl Format for FunctionStatement {
     fn format(&self, f: &mut Formatter) {
+        f.write_documentation(&self.documentation);
+
+        if let Some(visibility) = &self.visibility {
+            f.write_str(visibility.keyword());
+            f.write_char(' ');
+        }
+
         f.write_str("werkwijze ");
         f.write_str(self.name.value());
+
+        if !self.generic_types.is_empty() {
+            f.write_char('<');
+            for (idx, ty) in self.generic_types.iter().enumerate() {
+                if idx != 0 {
+                    f.write_str(", ");
+                }
+
+                ty.format(f);
+            }
+            f.write_char('>');
+        }
+
         f.write_char('(');
         for (idx, param) in self.parameters.iter().enumerate() {
             if idx != 0 {
@@ -266,29 +339,67 @@ impl Format for ForStatement {
 
         match self.iterable.value() {
             ForIterableKind::Expression(expression) => expression.value().format(f),
-            ForIterableKind::Range(range) => {
-                f.write_str("reeks(");
-                range.start.value().format(f);
-                f.write_str(", ");
-                range.end.value().format(f);
-                f.write_char(')');
-            }
+            ForIterableKind::Range(range) => range.format(f),
         }
 
         f.with_body_statements(&self.body);
     }
 }
 
+impl Format for RangeExpression {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("reeks(");
+        self.start.value().format(f);
+        f.write_str(", ");
+        self.end.value().format(f);
+
+        if let Some(step) = &self.step {
+            f.write_str(", stap: ");
+            step.value().format(f);
+        }
+
+        if self.is_inclusive {
+            f.write_str(", totEnMet: ");
+            f.write_str(Keyword::Waar.as_ref());
+        }
+
+        f.write_char(')');
+    }
+}
+
 impl Format for IfStatement {
     fn format(&self, f: &mut Formatter) {
         f.write_str("als ");
         self.condition.format(f);
 
-        f.with_curly_block(|f| {
-            for statement in &self.body {
+        match &self.else_statement {
+            Some(else_statement) => {
+                f.with_curly_block_continued(|f| {
+                    for statement in &self.body {
+                        statement.format(f);
+                    }
+                });
+
+                else_statement.format(f);
+            }
+
+            None => f.with_body_statements(&self.body),
+        }
+    }
+}
+
+impl Format for ElseStatement {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str(" anders");
+
+        match &self.kind {
+            ElseStatementKind::ElseIf(statement) => {
+                f.write_char(' ');
                 statement.format(f);
             }
-        });
+
+            ElseStatementKind::Else(body) => f.with_body_statements(body),
+        }
     }
 }
 
@@ -307,9 +418,16 @@ impl Format for ReturnStatement {
 
 impl Format for Structure {
     fn format(&self, f: &mut Formatter) {
+        f.write_documentation(&self.documentation);
         f.write_str("structuur ");
         f.write_str(self.name.value());
         f.with_curly_block(|f| {
+            for constant in &self.constants {
+                constant.format(f);
+                f.write_char(',');
+                f.new_line();
+            }
+
             for field in &self.fields {
                 field.format(f);
                 f.write_char(',');
@@ -320,8 +438,49 @@ impl Format for Structure {
     }
 }
 
+impl Format for Enumeration {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("opsomming ");
+        f.write_str(self.name.value());
+        f.with_curly_block(|f| {
+            for variant in &self.variants {
+                variant.format(f);
+                f.write_char(',');
+                f.new_line();
+            }
+        });
+    }
+}
+
+impl Format for EnumerationVariant {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str(self.name.value());
+
+        if self.fields.is_empty() {
+            return;
+        }
+
+        f.write_char('(');
+        for (idx, field) in self.fields.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ");
+            }
+
+            field.format(f);
+        }
+        f.write_char(')');
+    }
+}
+
 impl Format for Field {
     fn format(&self, f: &mut Formatter) {
+        f.write_documentation(&self.documentation);
+
+        if let Some(visibility) = &self.visibility {
+            f.write_str(visibility.keyword());
+            f.write_char(' ');
+        }
+
         f.write_str("veld ");
         f.write_str(self.name.value());
         f.write_str(": ");
@@ -329,16 +488,58 @@ impl Format for Field {
     }
 }
 
+impl Format for ConstantStatement {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("vast ");
+        f.write_str(self.name.value());
+        f.write_str(": ");
+        self.ty.format(f);
+        f.write_str(" = ");
+        self.value.format(f);
+    }
+}
+
 impl Format for VariableStatement {
     fn format(&self, f: &mut Formatter) {
         f.write_str("stel ");
-        f.write_str(&self.name);
+        self.name.format(f);
+        if let Some(ty) = &self.ty {
+            f.write_str(": ");
+            ty.format(f);
+        }
         f.write_str(" = ");
         self.expression.format(f);
         f.write_str(";\n");
     }
 }
 
+impl Format for VariableName {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            Self::Single(name) => f.write_str(name),
+            Self::Tuple(names) => {
+                f.write_char('(');
+                for (idx, name) in names.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ");
+                    }
+                    f.write_str(name);
+                }
+                f.write_char(')');
+            }
+        }
+    }
+}
+
+impl Format for WhileStatement {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("zolang ");
+        self.condition.format(f);
+
+        f.with_body_statements(&self.body);
+    }
+}
+
 impl Format for Parameter {
     fn format(&self, f: &mut Formatter) {
         f.write_str(&self.name);
@@ -347,6 +548,29 @@ impl Format for Parameter {
     }
 }
 
+impl Format for GenericTypeDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str(self.name.value());
+
+        for (idx, bound) in self.bounds.iter().enumerate() {
+            f.write_str(if idx == 0 { ": " } else { " + " });
+            f.write_str(bound.name.value());
+
+            if !bound.type_parameters.is_empty() {
+                f.write_char('<');
+                for (idx, ty) in bound.type_parameters.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ");
+                    }
+
+                    f.write_str(&ty.to_string());
+                }
+                f.write_char('>');
+            }
+        }
+    }
+}
+
 impl Format for Type {
     fn format(&self, f: &mut Formatter) {
         self.specifier.format(f);
@@ -358,6 +582,8 @@ impl Format for TypeSpecifier {
         match self {
             Self::BuiltIn(builtin) => builtin.format(f),
             Self::Custom { .. } => todo!(),
+            Self::Function { .. } => f.write_str(&self.to_string()),
+            Self::Tuple(..) => f.write_str(&self.to_string()),
         }
     }
 }
@@ -371,8 +597,11 @@ impl Format for PrimaryExpression {
                 // TODO use source code for original formatting, e.g. with number separators etc.
                 _ = f.write_fmt(format_args!("{integer}"));
             }
+            Self::FloatLiteral(float) => {
+                f.write_str(&format!("{float:?}").replace('.', ","));
+            }
             Self::ReferenceThis => f.write_str(Keyword::Dit.as_ref()),
-            Self::Reference(s) => f.write_str(s.value()),
+            Self::Reference(s) => f.write_str(as_written_in_source(s.value())),
             Self::CharacterLiteral(c) => {
                 f.write_char('"');
                 f.write_char(*c);
@@ -395,7 +624,49 @@ impl Format for PrimaryExpression {
                 expr.format(f);
                 f.write_char(')');
             }
+            Self::Tuple(elements) => {
+                f.write_char('(');
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ");
+                    }
+                    element.format(f);
+                }
+                f.write_char(')');
+            }
+            Self::ArrayLiteral(elements) => {
+                f.write_char('[');
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ");
+                    }
+                    element.format(f);
+                }
+                f.write_char(']');
+            }
             Self::StructureInstantiation(structure) => structure.format(f),
+            Self::Match(expression) => expression.format(f),
+            Self::Closure(closure) => closure.format(f),
+            Self::OptionalSome(expr) => {
+                f.write_str(Keyword::Iets.as_ref());
+                f.write_char('(');
+                expr.format(f);
+                f.write_char(')');
+            }
+            Self::OptionalNone => f.write_str(Keyword::Niets.as_ref()),
+            Self::ResultOk(expr) => {
+                f.write_str(Keyword::Goed.as_ref());
+                f.write_char('(');
+                expr.format(f);
+                f.write_char(')');
+            }
+            Self::ResultError(expr) => {
+                f.write_str(Keyword::Fout.as_ref());
+                f.write_char('(');
+                expr.format(f);
+                f.write_char(')');
+            }
+            Self::Range(range) => range.format(f),
             Self::SizedArrayInitializer{ typ, size } => {
                 f.write_str("nieuw ");
                 typ.format(f);
@@ -407,6 +678,48 @@ impl Format for PrimaryExpression {
     }
 }
 
+impl Format for ClosureExpression {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("werkwijze(");
+        for (idx, param) in self.function.parameters.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ");
+            }
+
+            param.format(f);
+        }
+        f.write_char(')');
+
+        if let Some(return_type) = &self.function.return_type {
+            f.write_str(" -> ");
+            return_type.format(f);
+        }
+
+        f.with_curly_block_continued(|f| {
+            for statement in self.function.body.as_inner_slice() {
+                statement.format(f);
+            }
+        });
+    }
+}
+
+impl Format for MatchExpression {
+    fn format(&self, f: &mut Formatter) {
+        f.write_str("kies ");
+        self.scrutinee.format(f);
+        f.write_str(" ");
+        f.with_curly_block(|f| {
+            for arm in &self.arms {
+                f.write_str(&arm.pattern.value().to_string());
+                f.write_str(" => ");
+                arm.expression.format(f);
+                f.write_char(',');
+                f.new_line();
+            }
+        })
+    }
+}
+
 impl Format for StructureInstantiationExpression {
     fn format(&self, f: &mut Formatter) {
         f.write_str("nieuw ");
@@ -443,8 +756,19 @@ impl Format for UnaryExpression {
         match self.kind.value() {
             UnaryExpressionKind::AddressOf => f.write_char('&'),
             UnaryExpressionKind::Negate => f.write_char('-'),
+            UnaryExpressionKind::Not => f.write_char('!'),
         }
 
         self.rhs.format(f);
     }
 }
+
+/// Names from other modules are qualified by the parser, e.g. `pad.naar.module.naam`,
+/// but are written as `module.naam` in the source code.
+#[must_use]
+fn as_written_in_source(name: &str) -> &str {
+    match name.rmatch_indices('.').nth(1) {
+        Some((index, _)) => &name[index + 1..],
+        None => name,
+    }
+}
@@ -64,7 +64,10 @@ impl InlayHintsEngine {
             SemanticLocalKind::FunctionReference => return,
             SemanticLocalKind::FieldReference => return,
             SemanticLocalKind::StructureReference => return,
+            SemanticLocalKind::EnumerationReference => return,
+            SemanticLocalKind::EnumerationVariant => return,
             SemanticLocalKind::Parameter => return,
+            SemanticLocalKind::Constant => return,
 
             SemanticLocalKind::Iterator => (),
             SemanticLocalKind::Variable => (),
@@ -86,6 +89,10 @@ impl InlayHintsEngine {
     fn visit_statement(&mut self, statement: &Statement) {
         match &statement.kind {
             StatementKind::Assignment(assignment) => self.visit_assignment(assignment),
+            StatementKind::Break(..) => (),
+            StatementKind::Constant(constant) => self.visit_expression(&constant.value),
+            StatementKind::Continue(..) => (),
+            StatementKind::Enumeration(..) => (),
             StatementKind::Expression(expression) => self.visit_expression(expression),
             StatementKind::Extension(extension) => self.visit_extension(extension),
             StatementKind::Function(function) => self.visit_function(function),
@@ -95,6 +102,7 @@ impl InlayHintsEngine {
             StatementKind::Return(return_statement) => self.visit_return_statement(return_statement),
             StatementKind::Structure(structure) => self.visit_structure(structure),
             StatementKind::Variable(variable) => self.visit_variable(variable),
+            StatementKind::While(while_statement) => self.visit_while_statement(while_statement),
         }
     }
 
@@ -112,6 +120,15 @@ impl InlayHintsEngine {
         }
     }
 
+    fn visit_range(&mut self, range: &RangeExpression) {
+        self.visit_expression(&range.start);
+        self.visit_expression(&range.end);
+
+        if let Some(step) = &range.step {
+            self.visit_expression(step);
+        }
+    }
+
     fn visit_primary_expression(&mut self, expression: &PrimaryExpression) {
         match expression {
             PrimaryExpression::StructureInstantiation(instantiation) => {
@@ -126,6 +143,36 @@ impl InlayHintsEngine {
                 self.visit_expression(&expression);
             }
 
+            PrimaryExpression::Range(range) => {
+                self.visit_range(range);
+            }
+
+            PrimaryExpression::ArrayLiteral(elements) | PrimaryExpression::Tuple(elements) => {
+                for element in elements {
+                    self.visit_expression(element);
+                }
+            }
+
+            PrimaryExpression::OptionalSome(expression) => {
+                self.visit_expression(expression);
+            }
+
+            PrimaryExpression::ResultOk(expression) | PrimaryExpression::ResultError(expression) => {
+                self.visit_expression(expression);
+            }
+
+            PrimaryExpression::Closure(closure) => {
+                self.visit_function(&closure.function);
+            }
+
+            PrimaryExpression::Match(expression) => {
+                self.visit_expression(&expression.scrutinee);
+
+                for arm in &expression.arms {
+                    self.visit_expression(&arm.expression);
+                }
+            }
+
             PrimaryExpression::TemplateString { parts } => {
                 for part in parts {
                     if let TemplateStringExpressionPart::Expression(expression) = part {
@@ -176,6 +223,8 @@ impl InlayHintsEngine {
             PostfixExpressionKind::Subscript(subscript) => {
                 self.visit_expression(&subscript);
             }
+
+            PostfixExpressionKind::Propagate => (),
         }
     }
 
@@ -197,8 +246,7 @@ impl InlayHintsEngine {
         match for_statement.iterable.value() {
             ForIterableKind::Expression(expression) => self.visit_expression(&expression),
             ForIterableKind::Range(range) => {
-                self.visit_expression(&range.start);
-                self.visit_expression(&range.end);
+                self.visit_range(range);
             }
         }
 
@@ -213,6 +261,21 @@ impl InlayHintsEngine {
         for statement in &if_statement.body {
             self.visit_statement(statement);
         }
+
+        if let Some(else_statement) = &if_statement.else_statement {
+            self.visit_else_statement(else_statement);
+        }
+    }
+
+    fn visit_else_statement(&mut self, else_statement: &ElseStatement) {
+        match &else_statement.kind {
+            ElseStatementKind::ElseIf(if_statement) => self.visit_if_statement(if_statement),
+            ElseStatementKind::Else(body) => {
+                for statement in body {
+                    self.visit_statement(statement);
+                }
+            }
+        }
     }
 
     fn visit_interface_statement(&mut self, interface: &InterfaceStatement) {
@@ -228,6 +291,10 @@ impl InlayHintsEngine {
     }
 
     fn visit_structure(&mut self, structure: &Structure) {
+        for constant in &structure.constants {
+            self.visit_expression(&constant.value);
+        }
+
         for field in &structure.fields {
             if let Some(expression) = &field.default_value {
                 self.visit_expression(&expression);
@@ -243,6 +310,14 @@ impl InlayHintsEngine {
         self.visit_expression(&variable.expression);
     }
 
+    fn visit_while_statement(&mut self, while_statement: &WhileStatement) {
+        self.visit_expression(&while_statement.condition);
+
+        for statement in &while_statement.body {
+            self.visit_statement(statement);
+        }
+    }
+
     fn visit_structure_instantiation(&mut self, instantiation: &StructureInstantiationExpression) {
         for field in &instantiation.fields {
             self.visit_expression(&field.value);
@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use babbelaar::{AssignStatement, Attribute, BabString, Expression, ExtensionStatement, Field, FileRange, ForIterableKind, ForStatement, FunctionStatement, IfStatement, InterfaceSpecifier, InterfaceStatement, Keyword, Method, OptionExt, Parameter, ParseTree, PostfixExpression, PostfixExpressionKind, PrimaryExpression, Ranged, ReturnStatement, SemanticAnalysisPhase, SemanticAnalyzer, SemanticLocalKind, SourceCode, Statement, StatementKind, Structure, StructureInstantiationExpression, TemplateStringExpressionPart, TemplateStringToken, Token, TokenKind, Type, TypeSpecifier, VariableStatement};
+use babbelaar::{AssignStatement, Attribute, BabString, ConstantStatement, ElseStatement, ElseStatementKind, Enumeration, Expression, ExtensionStatement, Field, FileRange, ForIterableKind, ForStatement, FunctionStatement, GenericTypeDeclaration, IfStatement, InterfaceSpecifier, InterfaceStatement, Keyword, Method, OptionExt, Parameter, ParseTree, Pattern, PostfixExpression, PostfixExpressionKind, PrimaryExpression, RangeExpression, Ranged, ReturnStatement, SemanticAnalysisPhase, SemanticAnalyzer, SemanticLocalKind, SourceCode, Statement, StatementKind, Structure, StructureInstantiationExpression, TemplateStringExpressionPart, TemplateStringToken, Token, TokenKind, Type, TypeSpecifier, VariableStatement, WhileStatement};
 use log::error;
 use strum::EnumIter;
 use tower_lsp::lsp_types::{DocumentSymbolResponse, SemanticToken, SemanticTokenModifier, SemanticTokenType, SymbolInformation, SymbolKind, Uri};
@@ -42,6 +42,10 @@ impl Symbolizer {
 
         match &statement.kind {
             StatementKind::Assignment(statement) => self.add_statement_assign(statement),
+            StatementKind::Break(..) => (),
+            StatementKind::Constant(statement) => self.add_statement_constant(statement),
+            StatementKind::Continue(..) => (),
+            StatementKind::Enumeration(statement) => self.add_statement_enumeration(statement),
             StatementKind::Expression(expression) => self.add_expression(expression),
             StatementKind::Extension(extension) => self.add_extension(extension),
             StatementKind::For(statement) => self.add_statement_for(statement),
@@ -51,6 +55,7 @@ impl Symbolizer {
             StatementKind::Return(statement) => self.add_statement_return(statement),
             StatementKind::Structure(statement) => self.add_statement_structure(statement),
             StatementKind::Variable(statement) => self.add_statement_variable(statement),
+            StatementKind::While(statement) => self.add_statement_while(statement),
         }
     }
 
@@ -61,10 +66,16 @@ impl Symbolizer {
             range: token.range(),
             modifier: match token.kind {
                 TokenKind::Keyword(Keyword::Als) => LspSymbolModifier::ControlFlow,
+                TokenKind::Keyword(Keyword::Anders) => LspSymbolModifier::ControlFlow,
                 TokenKind::Keyword(Keyword::Bekeer) => LspSymbolModifier::ControlFlow,
+                TokenKind::Keyword(Keyword::Door) => LspSymbolModifier::ControlFlow,
+                TokenKind::Keyword(Keyword::Ga) => LspSymbolModifier::ControlFlow,
                 TokenKind::Keyword(Keyword::In) => LspSymbolModifier::ControlFlow,
+                TokenKind::Keyword(Keyword::Kies) => LspSymbolModifier::ControlFlow,
                 TokenKind::Keyword(Keyword::Reeks) => LspSymbolModifier::ControlFlow,
+                TokenKind::Keyword(Keyword::Stop) => LspSymbolModifier::ControlFlow,
                 TokenKind::Keyword(Keyword::Volg) => LspSymbolModifier::ControlFlow,
+                TokenKind::Keyword(Keyword::Zolang) => LspSymbolModifier::ControlFlow,
                 _ => LspSymbolModifier::None,
             },
         });
@@ -126,8 +137,7 @@ impl Symbolizer {
                 self.add_expression(&expr);
             }
             ForIterableKind::Range(range) => {
-                self.add_expression(&range.start);
-                self.add_expression(&range.end);
+                self.add_range(range);
             }
         }
 
@@ -144,6 +154,8 @@ impl Symbolizer {
             modifier: LspSymbolModifier::default(),
         });
 
+        self.add_generic_type_declarations(&statement.generic_types);
+
         if let Some(return_type) = &statement.return_type {
             self.add_type(&return_type);
         }
@@ -163,6 +175,21 @@ impl Symbolizer {
         for statement in &statement.body {
             self.add_statement(statement);
         }
+
+        if let Some(else_statement) = &statement.else_statement {
+            self.add_statement_else(else_statement);
+        }
+    }
+
+    fn add_statement_else(&mut self, statement: &ElseStatement) {
+        match &statement.kind {
+            ElseStatementKind::ElseIf(statement) => self.add_statement_if(statement),
+            ElseStatementKind::Else(body) => {
+                for statement in body {
+                    self.add_statement(statement);
+                }
+            }
+        }
     }
 
     fn add_statement_interface(&mut self, interface: &InterfaceStatement) {
@@ -173,14 +200,7 @@ impl Symbolizer {
             modifier: LspSymbolModifier::default(),
         });
 
-        for ty_param in &interface.generic_types {
-            self.symbols.insert(LspSymbol {
-                name: ty_param.value().clone(),
-                kind: LspTokenType::Class,
-                range: ty_param.range(),
-                modifier: LspSymbolModifier::default(),
-            });
-        }
+        self.add_generic_type_declarations(&interface.generic_types);
 
         for field in &interface.methods {
             self.add_method(field);
@@ -201,13 +221,10 @@ impl Symbolizer {
             modifier: LspSymbolModifier::default(),
         });
 
-        for ty_param in &statement.generic_types {
-            self.symbols.insert(LspSymbol {
-                name: ty_param.value().clone(),
-                kind: LspTokenType::Class,
-                range: ty_param.range(),
-                modifier: LspSymbolModifier::default(),
-            });
+        self.add_generic_type_declarations(&statement.generic_types);
+
+        for constant in &statement.constants {
+            self.add_statement_constant(constant);
         }
 
         for field in &statement.fields {
@@ -219,6 +236,28 @@ impl Symbolizer {
         }
     }
 
+    fn add_statement_enumeration(&mut self, statement: &Enumeration) {
+        self.symbols.insert(LspSymbol {
+            name: statement.name.value().clone(),
+            kind: LspTokenType::Enum,
+            range: statement.name.range(),
+            modifier: LspSymbolModifier::default(),
+        });
+
+        for variant in &statement.variants {
+            self.symbols.insert(LspSymbol {
+                name: variant.name.value().clone(),
+                kind: LspTokenType::EnumMember,
+                range: variant.name.range(),
+                modifier: LspSymbolModifier::default(),
+            });
+
+            for field in &variant.fields {
+                self.add_parameter(field);
+            }
+        }
+    }
+
     fn add_structure_field(&mut self, field: &Field) {
         self.add_attributes(&field.attributes);
 
@@ -245,10 +284,34 @@ impl Symbolizer {
         self.add_statement_function(&method.function);
     }
 
+    fn add_statement_constant(&mut self, statement: &ConstantStatement) {
+        self.symbols.insert(LspSymbol {
+            name: statement.name.value().clone(),
+            kind: LspTokenType::Variable,
+            range: statement.name.range(),
+            modifier: LspSymbolModifier::default(),
+        });
+
+        self.add_type(&statement.ty);
+        self.add_expression(&statement.value);
+    }
+
     fn add_statement_variable(&mut self, statement: &VariableStatement) {
+        if let Some(ty) = &statement.ty {
+            self.add_type(ty);
+        }
+
         self.add_expression(&statement.expression);
     }
 
+    fn add_statement_while(&mut self, statement: &WhileStatement) {
+        self.add_expression(&statement.condition);
+
+        for statement in &statement.body {
+            self.add_statement(statement);
+        }
+    }
+
     fn add_parameter(&mut self, parameter: &Parameter) {
         self.add_attributes(&parameter.attributes);
 
@@ -290,7 +353,10 @@ impl Symbolizer {
                             SemanticLocalKind::FunctionReference => LspTokenType::Function,
                             SemanticLocalKind::Method => LspTokenType::Method,
                             SemanticLocalKind::Variable => LspTokenType::Variable,
+                            SemanticLocalKind::Constant => LspTokenType::Variable,
                             SemanticLocalKind::ReferenceThis => LspTokenType::ParameterName,
+                            SemanticLocalKind::EnumerationReference => LspTokenType::Enum,
+                            SemanticLocalKind::EnumerationVariant => LspTokenType::EnumMember,
                         },
                         range: identifier.range(),
                         modifier: LspSymbolModifier::default(),
@@ -326,6 +392,47 @@ impl Symbolizer {
                 self.add_expression(&size);
             }
 
+            Expression::Primary(PrimaryExpression::Closure(closure)) => {
+                if let Some(return_type) = &closure.function.return_type {
+                    self.add_type(return_type);
+                }
+
+                for parameter in &closure.function.parameters {
+                    self.add_parameter(parameter);
+                }
+
+                for statement in closure.function.body.as_inner_slice() {
+                    self.add_statement(statement);
+                }
+            }
+
+            Expression::Primary(PrimaryExpression::OptionalSome(expression)) => {
+                self.add_expression(expression);
+            }
+
+            Expression::Primary(PrimaryExpression::ResultOk(expression) | PrimaryExpression::ResultError(expression)) => {
+                self.add_expression(expression);
+            }
+
+            Expression::Primary(PrimaryExpression::Match(expression)) => {
+                self.add_expression(&expression.scrutinee);
+
+                for arm in &expression.arms {
+                    self.add_pattern(&arm.pattern);
+                    self.add_expression(&arm.expression);
+                }
+            }
+
+            Expression::Primary(PrimaryExpression::ArrayLiteral(elements) | PrimaryExpression::Tuple(elements)) => {
+                for element in elements {
+                    self.add_expression(element);
+                }
+            }
+
+            Expression::Primary(PrimaryExpression::Range(range)) => {
+                self.add_range(range);
+            }
+
             Expression::Primary(..) => (),
 
             Expression::Unary(unary) => {
@@ -334,15 +441,77 @@ impl Symbolizer {
         }
     }
 
-    fn add_extension(&mut self, extension: &ExtensionStatement) {
-        for ty_param in &extension.generic_types {
-            self.symbols.insert(LspSymbol {
-                name: ty_param.value().clone(),
-                kind: LspTokenType::Class,
-                range: ty_param.range(),
-                modifier: LspSymbolModifier::default(),
-            });
+    fn add_range(&mut self, range: &RangeExpression) {
+        self.add_expression(&range.start);
+        self.add_expression(&range.end);
+
+        if let Some(step) = &range.step {
+            self.add_expression(step);
+        }
+    }
+
+    fn add_pattern(&mut self, pattern: &Ranged<Pattern>) {
+        match pattern.value() {
+            Pattern::Binding(name) => {
+                self.symbols.insert(LspSymbol {
+                    name: name.value().clone(),
+                    kind: LspTokenType::Variable,
+                    range: name.range(),
+                    modifier: LspSymbolModifier::default(),
+                });
+            }
+
+            Pattern::Structure { name, fields } => {
+                self.symbols.insert(LspSymbol {
+                    name: name.value().clone(),
+                    kind: LspTokenType::Class,
+                    range: name.range(),
+                    modifier: LspSymbolModifier::default(),
+                });
+
+                for field in fields {
+                    self.symbols.insert(LspSymbol {
+                        name: field.name.value().clone(),
+                        kind: LspTokenType::Property,
+                        range: field.name.range(),
+                        modifier: LspSymbolModifier::default(),
+                    });
+
+                    if field.pattern.range() != field.name.range() {
+                        self.add_pattern(&field.pattern);
+                    }
+                }
+            }
+
+            Pattern::EnumerationVariant { enumeration, variant, fields } => {
+                self.symbols.insert(LspSymbol {
+                    name: enumeration.value().clone(),
+                    kind: LspTokenType::Enum,
+                    range: enumeration.range(),
+                    modifier: LspSymbolModifier::default(),
+                });
+
+                self.symbols.insert(LspSymbol {
+                    name: variant.value().clone(),
+                    kind: LspTokenType::EnumMember,
+                    range: variant.range(),
+                    modifier: LspSymbolModifier::default(),
+                });
+
+                for field in fields {
+                    self.add_pattern(field);
+                }
+            }
+
+            Pattern::OptionalSome(pattern) => self.add_pattern(pattern),
+            Pattern::ResultOk(pattern) | Pattern::ResultError(pattern) => self.add_pattern(pattern),
+
+            _ => (),
         }
+    }
+
+    fn add_extension(&mut self, extension: &ExtensionStatement) {
+        self.add_generic_type_declarations(&extension.generic_types);
 
         if let Some(interface) = &extension.interface_specifier {
             self.add_interface_specifier(interface);
@@ -356,6 +525,13 @@ impl Symbolizer {
     }
 
     fn add_expression_postfix(&mut self, expression: &PostfixExpression) {
+        let is_enumeration = self.semantic_analyzer.find_reference(expression.lhs.range())
+            .is_some_and(|reference| reference.local_kind == SemanticLocalKind::EnumerationReference);
+
+        if is_enumeration {
+            self.add_expression(&expression.lhs);
+        }
+
         match expression.kind.value() {
             PostfixExpressionKind::Call(..) => {
                 if let Expression::Primary(PrimaryExpression::Reference(ident)) = expression.lhs.value() {
@@ -371,7 +547,7 @@ impl Symbolizer {
             PostfixExpressionKind::MethodCall(method) => {
                 self.symbols.insert(LspSymbol {
                     name: method.method_name.value().clone(),
-                    kind: LspTokenType::Method,
+                    kind: if is_enumeration { LspTokenType::EnumMember } else { LspTokenType::Method },
                     range: method.method_name.range(),
                     modifier: LspSymbolModifier::default(),
                 });
@@ -380,7 +556,7 @@ impl Symbolizer {
             PostfixExpressionKind::Member(member) => {
                 self.symbols.insert(LspSymbol {
                     name: member.value().clone(),
-                    kind: LspTokenType::Property,
+                    kind: if is_enumeration { LspTokenType::EnumMember } else { LspTokenType::Property },
                     range: member.range(),
                     modifier: LspSymbolModifier::default(),
                 });
@@ -389,6 +565,8 @@ impl Symbolizer {
             PostfixExpressionKind::Subscript(ranged) => {
                 self.add_expression(&ranged);
             }
+
+            PostfixExpressionKind::Propagate => (),
         }
     }
 
@@ -442,10 +620,41 @@ impl Symbolizer {
                     self.add_type(param);
                 }
             }
+
+            TypeSpecifier::Function { parameters, return_type } => {
+                for param in parameters {
+                    self.add_type(param);
+                }
+
+                if let Some(return_type) = return_type {
+                    self.add_type(return_type);
+                }
+            }
+
+            TypeSpecifier::Tuple(elements) => {
+                for element in elements {
+                    self.add_type(element);
+                }
+            }
         }
 
     }
 
+    fn add_generic_type_declarations(&mut self, declarations: &[GenericTypeDeclaration]) {
+        for declaration in declarations {
+            self.symbols.insert(LspSymbol {
+                name: declaration.name.value().clone(),
+                kind: LspTokenType::Class,
+                range: declaration.name.range(),
+                modifier: LspSymbolModifier::default(),
+            });
+
+            for bound in &declaration.bounds {
+                self.add_interface_specifier(bound);
+            }
+        }
+    }
+
     fn add_interface_specifier(&mut self, specifier: &InterfaceSpecifier) {
         self.symbols.insert(LspSymbol {
             name: specifier.name.value().clone(),
@@ -582,6 +791,8 @@ pub enum LspTokenType {
     Class,
     Property,
     Interface,
+    Enum,
+    EnumMember,
 }
 
 impl LspTokenType {
@@ -599,7 +810,7 @@ impl From<&TokenKind> for LspTokenType {
             TokenKind::StringLiteral(..) => LspTokenType::String,
             TokenKind::TemplateString(..) => LspTokenType::String,
             TokenKind::Identifier(..) => LspTokenType::Variable,
-            TokenKind::Integer(..) => LspTokenType::Number,
+            TokenKind::Integer(..) | TokenKind::Float(..) => LspTokenType::Number,
 
             TokenKind::Punctuator(..) => LspTokenType::Operator,
             TokenKind::IllegalCharacter(..) => LspTokenType::Operator,
@@ -622,6 +833,8 @@ impl From<LspTokenType> for SemanticTokenType {
             LspTokenType::Class => SemanticTokenType::CLASS,
             LspTokenType::Property => SemanticTokenType::PROPERTY,
             LspTokenType::Interface => SemanticTokenType::INTERFACE,
+            LspTokenType::Enum => SemanticTokenType::ENUM,
+            LspTokenType::EnumMember => SemanticTokenType::ENUM_MEMBER,
         }
     }
 }
@@ -647,6 +860,8 @@ impl From<LspTokenType> for SymbolKind {
             LspTokenType::Class => SymbolKind::CLASS,
             LspTokenType::Property => SymbolKind::PROPERTY,
             LspTokenType::Interface => SymbolKind::INTERFACE,
+            LspTokenType::Enum => SymbolKind::ENUM,
+            LspTokenType::EnumMember => SymbolKind::ENUM_MEMBER,
         }
     }
 }
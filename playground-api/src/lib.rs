@@ -28,7 +28,13 @@ pub fn compile_to_ir(code: &str) -> IrCompileResult {
     };
 
     let mut compiler = Compiler::new();
-    compiler.compile_trees(&[tree]);
+    if let Err(errors) = compiler.compile_trees(&[tree]) {
+        result.errors.extend(errors.into_iter().map(|error| CompileError {
+            text: error.to_string(),
+            range: Some(error.range().into()),
+        }));
+        return result;
+    }
 
     result.program = Some(compiler.finish().to_string());
 
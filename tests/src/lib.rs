@@ -1,26 +1,43 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use std::{path::PathBuf, sync::{Arc, Mutex}};
+use std::{collections::HashMap, path::PathBuf, sync::{Arc, Mutex}};
 
-use babbelaar::{BabString, Expression, Lexer, ParseTree, Parser, Ranged, SemanticAnalysisPhase, SemanticAnalyzer, SemanticDiagnosticSeverity, SourceCode, Token, Value};
-use babbelaar_interpreter::{Debugger, Interpreter};
+use babbelaar::{Attribute, BabString, Expression, Lexer, ParseTree, Parser, Ranged, SemanticAnalysisPhase, SemanticAnalyzer, SemanticDiagnostic, SemanticDiagnosticSeverity, SourceCode, StatementKind, Token, Value};
+use babbelaar_interpreter::{Debugger, Interpreter, RuntimeError};
 
 fn parse<'a>(input: &'a SourceCode) -> ParseTree {
+    let tree = parse_without_analysis(input);
+    analyze(&[input.clone()], std::slice::from_ref(&tree));
+    tree
+}
+
+fn parse_without_analysis(input: &SourceCode) -> ParseTree {
     let tokens: Vec<Token> = Lexer::new(input).collect();
-    let mut parser = Parser::new(PathBuf::new(), &tokens);
+    let mut parser = Parser::new(input.path().to_path_buf(), &tokens);
     let tree = parser.parse_tree();
     assert!(parser.diagnostics().is_empty(), "Parse errors: {:#?}", parser.diagnostics());
+    tree
+}
 
-    let mut semantics = SemanticAnalyzer::new_single(input, true);
+fn analyze(sources: &[SourceCode], trees: &[ParseTree]) {
+    let diagnostics = analyze_and_return_diagnostics(sources, trees);
+    assert!(diagnostics.iter().find(|x| x.severity() == SemanticDiagnosticSeverity::Error).is_none(), "Diagnostics: {diagnostics:#?}");
+}
+
+fn analyze_and_return_diagnostics(sources: &[SourceCode], trees: &[ParseTree]) -> Vec<SemanticDiagnostic> {
+    let files: HashMap<_, _> = sources.iter()
+        .map(|source| (source.file_id(), source.clone()))
+        .collect();
+
+    let mut semantics = SemanticAnalyzer::new(files, true);
     for phase in SemanticAnalysisPhase::iter() {
-        semantics.analyze_tree(&tree, phase);
+        for tree in trees {
+            semantics.analyze_tree(tree, phase);
+        }
     }
     semantics.finish_analysis();
-    let diagnostics = semantics.into_diagnostics();
-    assert!(diagnostics.iter().find(|x| x.severity() == SemanticDiagnosticSeverity::Error).is_none(), "Diagnostics: {diagnostics:#?}");
-
-    tree
+    semantics.into_diagnostics()
 }
 
 fn parse_expression<'a>(input: &'a SourceCode) -> Ranged<Expression> {
@@ -55,15 +72,96 @@ pub fn interpret_and_return_stdout(input: &str) -> Vec<String> {
             buffer: Arc::clone(&buffer),
         });
 
-        for statement in parse(&input).all() {
-            interpreter.execute(&statement)
-        }
+        interpreter.execute_trees(&[parse(&input)]);
     }
 
 
     Arc::try_unwrap(buffer).unwrap().into_inner().unwrap()
 }
 
+/// Interprets the program and returns the message of the runtime error it
+/// stopped with, if any.
+pub fn interpret_and_return_runtime_error(input: &str) -> Option<String> {
+    match std::panic::catch_unwind(|| interpret_and_return_stdout(input)) {
+        Ok(..) => None,
+        Err(payload) => Some(*payload.downcast::<String>().expect("runtime error should be a message")),
+    }
+}
+
+/// Interprets a program consisting of multiple files, given as pairs of the
+/// path and contents of each file.
+pub fn interpret_files_and_return_stdout(files: &[(&str, &str)]) -> Vec<String> {
+    let sources: Vec<SourceCode> = files.iter()
+        .map(|(path, contents)| SourceCode::new(PathBuf::from(path), 0, BabString::new(*contents)))
+        .collect();
+
+    let trees: Vec<ParseTree> = sources.iter().map(parse_without_analysis).collect();
+    analyze(&sources, &trees);
+
+    let buffer = Arc::new(Mutex::new(Vec::new()));
+
+    {
+        let mut interpreter = Interpreter::new(TestDebugger {
+            buffer: Arc::clone(&buffer),
+        });
+
+        interpreter.execute_trees(&trees);
+    }
+
+    Arc::try_unwrap(buffer).unwrap().into_inner().unwrap()
+}
+
+/// Runs each `@test` function of the program, and returns its name together
+/// with the messages of the expectations that weren't met.
+pub fn run_tests_and_return_failures(input: &str) -> Vec<(String, Vec<String>)> {
+    let input = SourceCode::new(PathBuf::new(), 0, BabString::new(input));
+    let trees = [parse(&input)];
+
+    let mut results = Vec::new();
+    for statement in trees[0].functions() {
+        let StatementKind::Function(function) = &statement.kind else { continue };
+        if !statement.attributes.iter().any(|attribute| attribute.name.value() == Attribute::NAME_TEST) {
+            continue;
+        }
+
+        let mut interpreter = Interpreter::new(());
+        interpreter.declare_trees(&trees);
+
+        let failures = interpreter.execute_test(&trees[0], function)
+            .into_iter()
+            .map(|failure| failure.message().to_string())
+            .collect();
+
+        results.push((function.name.value().to_string(), failures));
+    }
+
+    results
+}
+
+/// Analyzes a program consisting of multiple files, given as pairs of the
+/// path and contents of each file, and returns the names of the errors.
+pub fn analyze_files_and_return_errors(files: &[(&str, &str)]) -> Vec<String> {
+    analyze_files_and_return_names(files, SemanticDiagnosticSeverity::Error)
+}
+
+pub fn analyze_files_and_return_warnings(files: &[(&str, &str)]) -> Vec<String> {
+    analyze_files_and_return_names(files, SemanticDiagnosticSeverity::Warning)
+}
+
+fn analyze_files_and_return_names(files: &[(&str, &str)], severity: SemanticDiagnosticSeverity) -> Vec<String> {
+    let sources: Vec<SourceCode> = files.iter()
+        .map(|(path, contents)| SourceCode::new(PathBuf::from(path), 0, BabString::new(*contents)))
+        .collect();
+
+    let trees: Vec<ParseTree> = sources.iter().map(parse_without_analysis).collect();
+
+    analyze_and_return_diagnostics(&sources, &trees)
+        .into_iter()
+        .filter(|x| x.severity() == severity)
+        .map(|x| x.kind().name().to_string())
+        .collect()
+}
+
 struct TestDebugger {
     buffer: Arc<Mutex<Vec<String>>>,
 }
@@ -74,4 +172,10 @@ impl Debugger for TestDebugger {
             self.buffer.lock().unwrap().push(args[0].to_string());
         }
     }
+
+    /// The interpreter exits the process after a runtime error, so it is
+    /// turned into a panic instead, which doesn't take the other tests down.
+    fn on_runtime_error(&mut self, error: &RuntimeError) {
+        panic!("{error}");
+    }
 }
@@ -1,25 +1,32 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use babbelaar::Value;
+use babbelaar::{IntegerType, Value};
 use rstest::rstest;
 use tests::interpret_expression;
 
 #[rstest]
-#[case("10", Value::Integer(10))]
-#[case("5 + 2", Value::Integer(7))]
-#[case("4 * 9", Value::Integer(36))]
-#[case("52 % 30", Value::Integer(22))]
+#[case("10", g32(10))]
+#[case("5 + 2", g32(7))]
+#[case("4 * 9", g32(36))]
+#[case("52 % 30", g32(22))]
 fn binary_operations(#[case] input: &str, #[case] expected: Value) {
     let actual = interpret_expression(input);
     assert_eq!(actual, expected);
 }
 
 #[rstest]
-#[case("10 * 4 + 5", Value::Integer(45))]
-#[case("10 + 4 + 5", Value::Integer(19))]
-#[case("10 + 4 * 5", Value::Integer(30))]
+#[case("10 * 4 + 5", g32(45))]
+#[case("10 + 4 + 5", g32(19))]
+#[case("10 + 4 * 5", g32(30))]
 fn pemdas(#[case] input: &str, #[case] expected: Value) {
     let actual = interpret_expression(input);
     assert_eq!(actual, expected);
 }
+
+fn g32(value: i64) -> Value {
+    Value::Integer {
+        value,
+        ty: IntegerType::G32,
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use rstest::rstest;
+use tests::{interpret_and_return_runtime_error, interpret_and_return_stdout};
+
+#[rstest]
+#[case("stel x: g8 = 126;", "127")]
+#[case("stel x: g16 = 32766;", "32767")]
+#[case("stel x: g32 = 2147483646;", "2147483647")]
+#[case("stel x: g64 = 9223372036854775806;", "9223372036854775807")]
+#[case("stel x: n8 = 254;", "255")]
+#[case("stel x: n16 = 65534;", "65535")]
+#[case("stel x: n32 = 4294967294;", "4294967295")]
+#[case("stel x: n64 = 9223372036854775807;", "9223372036854775808")]
+fn arithmetic_up_to_the_maximum(#[case] declaration: &str, #[case] expected: &str) {
+    let code = format!(r#"
+        {declaration}
+        schrijf(€"{{x + 1}}");
+    "#);
+
+    assert_eq!(interpret_and_return_stdout(&code), &[expected]);
+}
+
+#[rstest]
+#[case("stel x: g8 = 127;", "x + 1", "Getaloverloop: de uitkomst van `127 + 1` past niet in een `g8`")]
+#[case("stel x: g8 = -128;", "x - 1", "Getaloverloop: de uitkomst van `-128 - 1` past niet in een `g8`")]
+#[case("stel x: g16 = 32767;", "x + 1", "Getaloverloop: de uitkomst van `32767 + 1` past niet in een `g16`")]
+#[case("stel x: g32 = 2147483647;", "x + 1", "Getaloverloop: de uitkomst van `2147483647 + 1` past niet in een `g32`")]
+#[case("stel x: g64 = 9223372036854775807;", "x + 1", "Getaloverloop: de uitkomst van `9223372036854775807 + 1` past niet in een `g64`")]
+#[case("stel x: n8 = 255;", "x + 1", "Getaloverloop: de uitkomst van `255 + 1` past niet in een `n8`")]
+#[case("stel x: n8 = 0;", "x - 1", "Getaloverloop: de uitkomst van `0 - 1` past niet in een `n8`")]
+#[case("stel x: n16 = 65535;", "x + 1", "Getaloverloop: de uitkomst van `65535 + 1` past niet in een `n16`")]
+#[case("stel x: n32 = 4294967295;", "x + 1", "Getaloverloop: de uitkomst van `4294967295 + 1` past niet in een `n32`")]
+#[case("stel x: n64 = 9223372036854775807;", "x * 3", "Getaloverloop: de uitkomst van `9223372036854775807 * 3` past niet in een `n64`")]
+#[case("stel x: g8 = 100;", "100 + x", "Getaloverloop: de uitkomst van `100 + 100` past niet in een `g8`")]
+#[case("stel x: n8 = 1;", "0 - x", "Getaloverloop: de uitkomst van `0 - 1` past niet in een `n8`")]
+#[case("stel xs: g8[] = [100, 27]; stel x = xs[0];", "x + 28", "Getaloverloop: de uitkomst van `100 + 28` past niet in een `g8`")]
+#[case("stel paar: (g8, Slinger) = (127, \"a\"); stel (x, _) = paar;", "x + 1", "Getaloverloop: de uitkomst van `127 + 1` past niet in een `g8`")]
+fn arithmetic_beyond_the_range_traps(#[case] declaration: &str, #[case] expression: &str, #[case] expected: &str) {
+    let code = format!(r#"
+        {declaration}
+        stel y = {expression};
+    "#);
+
+    assert_eq!(interpret_and_return_runtime_error(&code).as_deref(), Some(expected));
+}
+
+#[rstest]
+#[case("stel x: g8 = -1;", "x & 127", "127")]
+#[case("stel x: n8 = 240;", "x ^ 255", "15")]
+#[case("stel x: n16 = 65535;", "x | 1", "65535")]
+fn bitwise_operations_stay_within_the_width(#[case] declaration: &str, #[case] expression: &str, #[case] expected: &str) {
+    let code = format!(r#"
+        {declaration}
+        schrijf(€"{{{expression}}}");
+    "#);
+
+    assert_eq!(interpret_and_return_stdout(&code), &[expected]);
+}
@@ -0,0 +1,280 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use rstest::rstest;
+use tests::{analyze_files_and_return_errors, interpret_files_and_return_stdout};
+
+#[rstest]
+#[case(
+    &[
+        ("wiskunde.bab", r#"
+            module wiskunde;
+
+            werkwijze kwadraat(x: g32) -> g32 {
+                bekeer x * x;
+            }
+        "#),
+        ("hoofd.bab", r#"
+            gebruik wiskunde;
+
+            schrijf(€"{wiskunde.kwadraat(7)}");
+        "#),
+    ],
+    &[
+        "49",
+    ],
+)]
+#[case(
+    &[
+        ("wiskunde/meetkunde.bab", r#"
+            module wiskunde.meetkunde;
+
+            structuur Rechthoek {
+                veld breedte: g32,
+                veld hoogte: g32,
+            }
+
+            werkwijze oppervlakte(r: Rechthoek) -> g32 {
+                bekeer r.breedte * r.hoogte;
+            }
+        "#),
+        ("hoofd.bab", r#"
+            gebruik wiskunde.meetkunde;
+
+            stel r = nieuw meetkunde.Rechthoek {
+                breedte: 3,
+                hoogte: 4,
+            };
+
+            schrijf(€"{meetkunde.oppervlakte(r)}");
+        "#),
+    ],
+    &[
+        "12",
+    ],
+)]
+#[case(
+    &[
+        ("a.bab", r#"
+            module a;
+
+            werkwijze naam() -> Slinger {
+                bekeer "a";
+            }
+
+            werkwijze groet() {
+                schrijf(€"Hallo vanuit {naam()}");
+            }
+        "#),
+        ("b.bab", r#"
+            module b;
+
+            werkwijze naam() -> Slinger {
+                bekeer "b";
+            }
+        "#),
+        ("hoofd.bab", r#"
+            gebruik a;
+            gebruik b;
+
+            werkwijze naam() -> Slinger {
+                bekeer "hoofd";
+            }
+
+            a.groet();
+            schrijf(b.naam());
+            schrijf(naam());
+        "#),
+    ],
+    &[
+        "Hallo vanuit a",
+        "b",
+        "hoofd",
+    ],
+)]
+#[case(
+    &[
+        ("vormen.bab", r#"
+            module vormen;
+
+            opsomming Vorm {
+                Punt,
+                Cirkel(straal: g32),
+            }
+
+            werkwijze cirkel(straal: g32) -> Vorm {
+                bekeer Vorm.Cirkel(straal);
+            }
+        "#),
+        ("hoofd.bab", r#"
+            gebruik vormen;
+
+            stel vorm = vormen.cirkel(2);
+            stel beschrijving = kies vorm {
+                vormen.Vorm.Punt => "punt",
+                vormen.Vorm.Cirkel(straal) => €"cirkel met straal {straal}",
+            };
+            schrijf(beschrijving);
+        "#),
+    ],
+    &[
+        "cirkel met straal 2",
+    ],
+)]
+#[case(
+    &[
+        ("bank/rekening.bab", r#"
+            module bank;
+
+            structuur Rekening {
+                intern veld saldo: g32,
+
+                werkwijze stort(bedrag: g32) {
+                    dit.saldo += bedrag;
+                }
+
+                werkwijze saldo() -> g32 {
+                    bekeer dit.saldo;
+                }
+            }
+        "#),
+        ("bank/opening.bab", r#"
+            module bank;
+
+            intern werkwijze beginsaldo() -> g32 {
+                bekeer 10;
+            }
+
+            werkwijze open() -> Rekening {
+                bekeer nieuw Rekening { saldo: beginsaldo() };
+            }
+        "#),
+        ("hoofd.bab", r#"
+            gebruik bank;
+
+            stel rekening = bank.open();
+            rekening.stort(5);
+            schrijf(€"{rekening.saldo()}");
+        "#),
+    ],
+    &[
+        "15",
+    ],
+)]
+fn modules(#[case] files: &[(&str, &str)], #[case] expected: &[&str]) {
+    let actual = interpret_files_and_return_stdout(files);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        werkwijze probeer() {
+            stel a = wiskunde.hulp();
+        }
+    "#,
+    &["not-accessible"],
+)]
+#[case(
+    r#"
+        werkwijze probeer() {
+            stel p = wiskunde.maak();
+            stel a = p.waarde;
+        }
+    "#,
+    &["not-accessible"],
+)]
+#[case(
+    r#"
+        werkwijze probeer() {
+            stel p = nieuw wiskunde.Paar { waarde: 1 };
+        }
+    "#,
+    &["not-accessible"],
+)]
+#[case(
+    r#"
+        werkwijze probeer() {
+            stel p = wiskunde.maak();
+            p.verberg();
+            stel g = wiskunde.Paar.geheim();
+        }
+    "#,
+    &["not-accessible", "not-accessible"],
+)]
+#[case(
+    r#"
+        werkwijze probeer() {
+            stel p = wiskunde.maak();
+            p.toon();
+        }
+    "#,
+    &[],
+)]
+fn internal_declarations_are_not_accessible_outside_of_module(#[case] code: &str, #[case] expected: &[&str]) {
+    let module = r#"
+        module wiskunde;
+
+        structuur Paar {
+            intern veld waarde: g32,
+
+            intern werkwijze verberg() {}
+
+            publiek werkwijze toon() {
+                schrijf(€"{dit.waarde}");
+            }
+
+            intern gedeeld werkwijze geheim() -> g32 {
+                bekeer 1;
+            }
+        }
+
+        intern werkwijze hulp() -> g32 {
+            bekeer 2;
+        }
+
+        werkwijze maak() -> Paar {
+            bekeer nieuw Paar { waarde: hulp() };
+        }
+    "#;
+
+    let code = format!("gebruik wiskunde;\n{code}");
+    let actual = analyze_files_and_return_errors(&[("wiskunde.bab", module), ("hoofd.bab", &code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    &[
+        ("hoofd.bab", r#"
+            gebruik meetkunde;
+        "#),
+    ],
+    &["unknown-module"],
+)]
+#[case(
+    &[
+        ("wiskunde.bab", r#"
+            module wiskunde;
+        "#),
+        ("hoofd.bab", r#"
+            gebruik wiskunde.hulp;
+        "#),
+    ],
+    &["unknown-module"],
+)]
+#[case(
+    &[
+        ("wiskunde.bab", r#"
+            module wiskunde;
+        "#),
+        ("hoofd.bab", r#"
+            gebruik wiskunde;
+        "#),
+    ],
+    &[],
+)]
+fn unknown_modules(#[case] files: &[(&str, &str)], #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(files);
+    assert_eq!(actual, expected);
+}
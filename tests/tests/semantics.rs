@@ -0,0 +1,981 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use rstest::rstest;
+use tests::{analyze_files_and_return_errors, analyze_files_and_return_warnings};
+
+#[rstest]
+#[case(
+    r#"
+        als 3 {
+        }
+    "#,
+    &["condition-expects-boolean"],
+)]
+#[case(
+    r#"
+        als onwaar {
+        } anders als "x" {
+        }
+    "#,
+    &["condition-expects-boolean"],
+)]
+#[case(
+    r#"
+        zolang 1 {
+        }
+    "#,
+    &["condition-expects-boolean"],
+)]
+#[case(
+    r#"
+        als 1 == 2 {
+        } anders als waar {
+        } anders {
+        }
+    "#,
+    &[],
+)]
+fn conditions(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        werkwijze a() {
+            stop;
+        }
+    "#,
+    &["break-outside-loop"],
+)]
+#[case(
+    r#"
+        werkwijze a() {
+            als waar {
+                ga door;
+            }
+        }
+    "#,
+    &["continue-outside-loop"],
+)]
+#[case(
+    r#"
+        werkwijze a() {
+            zolang waar {
+                als waar {
+                    stop;
+                }
+                ga door;
+            }
+        }
+    "#,
+    &[],
+)]
+fn loop_control(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        opsomming Kleur {
+            Rood,
+            Rood,
+        }
+    "#,
+    &["duplicate-variant-name"],
+)]
+#[case(
+    r#"
+        opsomming Kleur {
+            Rood,
+        }
+
+        stel kleur = Kleur.Blauw;
+    "#,
+    &["invalid-enumeration-variant"],
+)]
+#[case(
+    r#"
+        opsomming Kleur {
+            Aangepast(waarde: g32),
+        }
+
+        stel kleur = Kleur.Aangepast(1, 2);
+    "#,
+    &["enumeration-variant-field-count"],
+)]
+#[case(
+    r#"
+        opsomming Vorm {
+            Punt,
+            Cirkel(straal: g32),
+        }
+
+        stel vorm = Vorm.Cirkel(3);
+        stel straal = kies vorm {
+            Vorm.Vierkant => 0,
+            Vorm.Cirkel(straal, diameter) => straal,
+            _ => 1,
+        };
+    "#,
+    &["invalid-enumeration-variant", "enumeration-variant-field-count"],
+)]
+fn enumerations(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel getal = 3;
+        stel tekst = kies getal {
+            1 => "een",
+            2 => "twee",
+        };
+    "#,
+    &["non-exhaustive-match"],
+)]
+#[case(
+    r#"
+        stel getal = 3;
+        stel tekst = kies getal {
+            "drie" => "drie",
+            _ => "anders",
+        };
+    "#,
+    &["pattern-type-mismatch"],
+)]
+#[case(
+    r#"
+        stel getal = 3;
+        stel tekst = kies getal {
+            1 => "een",
+            _ => 2,
+        };
+    "#,
+    &["incompatible-match-arm-types"],
+)]
+#[case(
+    r#"
+        stel waarde = onwaar;
+        stel tekst = kies waarde {
+            waar => "waar",
+            onwaar => "onwaar",
+        };
+    "#,
+    &[],
+)]
+fn match_expressions(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel getal = 3;
+        schrijf(kies getal {
+            _ => "iets",
+            1 => "een",
+        });
+    "#,
+    &["unreachable-match-arm"],
+)]
+#[case(
+    r#"
+        stel getal = 3;
+        schrijf(kies getal {
+            1 => "een",
+            _ => "iets",
+        });
+    "#,
+    &[],
+)]
+fn match_warnings(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_warnings(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel getal = 3;
+        stel omgekeerd = !getal;
+    "#,
+    &["cannot-invert-non-boolean"],
+)]
+#[case(
+    r#"
+        stel a = waar;
+        stel b = a < onwaar;
+    "#,
+    &["unordered-comparison"],
+)]
+#[case(
+    r#"
+        stel a = "appel";
+        stel b = a >= "peer";
+    "#,
+    &["unordered-comparison"],
+)]
+#[case(
+    r#"
+        stel a = !(1 < 2);
+        stel b = 'a' <= 'b';
+        stel c = waar == onwaar;
+    "#,
+    &[],
+)]
+fn boolean_and_comparison_operators(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel a = 1,5 & 2,5;
+    "#,
+    &["bitwise-operation-on-float"],
+)]
+#[case(
+    r#"
+        stel a = 1.5;
+        stel b = a ^ 2.0;
+    "#,
+    &["bitwise-operation-on-float"],
+)]
+#[case(
+    r#"
+        stel a = 6 & 3;
+        stel b = 1,5 * 2,0;
+    "#,
+    &[],
+)]
+fn floating_point_operators(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel a: g8 = 300;
+    "#,
+    &["integer-literal-out-of-range"],
+)]
+#[case(
+    r#"
+        stel a: n8 = -1;
+    "#,
+    &["integer-literal-out-of-range"],
+)]
+#[case(
+    r#"
+        stel a = 9000000000;
+    "#,
+    &["integer-literal-out-of-range"],
+)]
+#[case(
+    r#"
+        stel a: g64 = 9000000000;
+        stel b: n16 = 65535;
+        stel c: g8 = -128;
+    "#,
+    &[],
+)]
+fn integer_literal_ranges(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel a: g8 = 5;
+        stel b: g32 = a;
+    "#,
+    &["incompatible-variable-type"],
+)]
+#[case(
+    r#"
+        stel a: n32 = 5;
+        stel b = 1;
+        stel c = a + b;
+    "#,
+    &["incompatible-types"],
+)]
+#[case(
+    r#"
+        werkwijze verdubbel(x: g64) -> g64 {
+            bekeer x * 2;
+        }
+
+        stel a: g32 = 5;
+        stel b = verdubbel(a);
+    "#,
+    &["incompatible-argument-parameter-type"],
+)]
+#[case(
+    r#"
+        stel a: g8 = 5;
+        stel b = a + 300;
+    "#,
+    &["integer-literal-out-of-range"],
+)]
+#[case(
+    r#"
+        stel a: g8 = 5;
+        stel b: g8 = a + 1;
+        stel c: g8 = 2 * a;
+        stel d: g8 = 1 + 2;
+        stel e: n8 = 200;
+        stel f = e > 100;
+    "#,
+    &[],
+)]
+fn integer_width_mixing(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+        }
+
+        werkwijze zoek() -> Misschien<Persoon> {
+            bekeer Niets;
+        }
+
+        stel naam = zoek().naam;
+    "#,
+    &["optional-not-unpacked"],
+)]
+#[case(
+    r#"
+        werkwijze niets() {
+        }
+
+        stel a = niets();
+    "#,
+    &["expression-has-no-value"],
+)]
+#[case(
+    r#"
+        werkwijze zoek() -> Misschien<g32> {
+            bekeer Iets(5);
+        }
+
+        stel getal = kies zoek() {
+            Iets(getal) => getal,
+            Niets => 0,
+        };
+    "#,
+    &[],
+)]
+fn optional_values(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+        }
+
+        stel personen = nieuw Persoon[3];
+    "#,
+    &["sized-array-element-without-default"],
+)]
+#[case(
+    r#"
+        stel getallen = nieuw g32[3];
+        stel namen = nieuw Slinger[2];
+    "#,
+    &[],
+)]
+fn sized_array_initializers(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        werkwijze deel(a: g32, b: g32) -> Resultaat<g32, Slinger> {
+            als b == 0 {
+                bekeer Fout("delen door nul");
+            }
+            bekeer Goed(a / b);
+        }
+
+        stel a = deel(6, 3).waarde;
+    "#,
+    &["result-not-unpacked"],
+)]
+#[case(
+    r#"
+        werkwijze a() -> Resultaat<g32, Slinger> {
+            stel b = 5?;
+            bekeer Goed(b);
+        }
+    "#,
+    &["propagate-requires-result"],
+)]
+#[case(
+    r#"
+        werkwijze deel(a: g32, b: g32) -> Resultaat<g32, Slinger> {
+            bekeer Goed(a / b);
+        }
+
+        werkwijze a() -> g32 {
+            bekeer deel(6, 3)?;
+        }
+    "#,
+    &["propagate-outside-result-function"],
+)]
+#[case(
+    r#"
+        werkwijze deel(a: g32, b: g32) -> Resultaat<g32, Slinger> {
+            bekeer Goed(a / b);
+        }
+
+        werkwijze a() -> Resultaat<g32, g32> {
+            bekeer Goed(deel(6, 3)?);
+        }
+    "#,
+    &["propagate-incompatible-error"],
+)]
+#[case(
+    r#"
+        werkwijze deel(a: g32, b: g32) -> Resultaat<g32, Slinger> {
+            bekeer Goed(a / b);
+        }
+
+        werkwijze a() -> Resultaat<g32, Slinger> {
+            stel b = deel(6, 3)?;
+            bekeer Goed(b + 1);
+        }
+    "#,
+    &[],
+)]
+fn result_values(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        werkwijze schrijfBestand() -> Resultaat<g32, Slinger> {
+            bekeer Goed(1);
+        }
+
+        werkwijze _a() {
+            schrijfBestand();
+        }
+    "#,
+    &["unhandled-result"],
+)]
+#[case(
+    r#"
+        werkwijze schrijfBestand() -> Resultaat<g32, Slinger> {
+            bekeer Goed(1);
+        }
+
+        werkwijze _a() -> Resultaat<g32, Slinger> {
+            schrijfBestand()?;
+            bekeer Goed(2);
+        }
+    "#,
+    &[],
+)]
+fn result_warnings(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_warnings(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel a = waar;
+        a += onwaar;
+    "#,
+    &["invalid-compound-assignment"],
+)]
+#[case(
+    r#"
+        stel a = "appel";
+        a -= "a";
+    "#,
+    &["invalid-compound-assignment"],
+)]
+#[case(
+    r#"
+        stel a = 1;
+        a += 2;
+        a *= 3;
+        stel b = "appel";
+        b += "taart";
+        stel c = 1,5;
+        c /= 2,0;
+    "#,
+    &[],
+)]
+fn compound_assignments(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        vast GROOTTE: g32 = 16;
+
+        werkwijze a() {
+            GROOTTE = 32;
+        }
+    "#,
+    &["assignment-to-constant"],
+)]
+#[case(
+    r#"
+        werkwijze g() -> g32 {
+            bekeer 3;
+        }
+
+        vast B: g32 = g();
+    "#,
+    &["non-constant-initializer"],
+)]
+#[case(
+    r#"
+        vast A: g32 = 4 / (2 - 2);
+    "#,
+    &["constant-not-evaluable"],
+)]
+#[case(
+    r#"
+        werkwijze a() {
+            stel x = 3;
+            vast B: g32 = x * 2;
+        }
+    "#,
+    &["non-constant-initializer"],
+)]
+#[case(
+    r#"
+        vast GETALLEN: g32[] = [1, 2];
+    "#,
+    &["invalid-constant-type"],
+)]
+#[case(
+    r#"
+        vast NAAM: Slinger = 5;
+    "#,
+    &["incompatible-constant-type"],
+)]
+#[case(
+    r#"
+        vast TOTAAL: g32 = BLOK * AANTAL;
+        vast BLOK: g32 = AANTAL * 2;
+        vast AANTAL: g32 = 4;
+
+        structuur Rooster {
+            vast CELLEN: g32 = BREEDTE * BREEDTE,
+            vast BREEDTE: g32 = AANTAL + 1,
+        }
+
+        werkwijze a() -> g32 {
+            bekeer TOTAAL + Rooster.CELLEN;
+        }
+    "#,
+    &[],
+)]
+fn constants(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        werkwijze _bereken() -> g32 {
+            vast ONGEBRUIKT: g32 = 1;
+            bekeer 2;
+        }
+    "#,
+    &["unused-constant"],
+)]
+#[case(
+    r#"
+        werkwijze _bereken() -> g32 {
+            vast GEBRUIKT: g32 = 1;
+            bekeer GEBRUIKT;
+        }
+    "#,
+    &[],
+)]
+fn constant_warnings(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_warnings(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        werkwijze leeg<T>() -> T[] {
+            bekeer nieuw T[0];
+        }
+
+        stel lijst = leeg();
+    "#,
+    &["cannot-infer-generic-type"],
+)]
+#[case(
+    r#"
+        werkwijze eerste<T>(lijst: T[]) -> T {
+            bekeer lijst[0];
+        }
+
+        stel getal = eerste([1, 2]);
+        stel naam = eerste(["Jan"]);
+    "#,
+    &[],
+)]
+fn generic_functions(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        koppelvlak Gewogen {
+            werkwijze gewicht() -> g32;
+        }
+
+        structuur Peer {
+            veld massa: g32,
+        }
+
+        werkwijze weeg<T: Gewogen>(waarde: T) -> g32 {
+            bekeer waarde.gewicht();
+        }
+
+        stel gewicht = weeg(nieuw Peer { massa: 3 });
+    "#,
+    &["generic-bound-not-satisfied"],
+)]
+#[case(
+    r#"
+        koppelvlak Gewogen {
+            werkwijze gewicht() -> g32;
+        }
+
+        structuur Peer {
+            veld massa: g32,
+        }
+
+        uitbreiding Gewogen op Peer {
+            werkwijze gewicht() -> g32 {
+                bekeer dit.massa;
+            }
+        }
+
+        werkwijze weeg<T: Gewogen>(waarde: T) -> g32 {
+            bekeer waarde.gewicht();
+        }
+
+        stel gewicht = weeg(nieuw Peer { massa: 3 });
+    "#,
+    &[],
+)]
+fn generic_bounds(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel getal = 5;
+        stel (a, b) = getal;
+    "#,
+    &["destructuring-requires-tuple"],
+)]
+#[case(
+    r#"
+        stel paar = (1, "een");
+        stel (a, b, c) = paar;
+    "#,
+    &["destructuring-count-mismatch"],
+)]
+#[case(
+    r#"
+        werkwijze deel(a: g32, b: g32) -> (g32, g32) {
+            bekeer (a / b, a % b);
+        }
+
+        stel (q, r) = deel(17, 5);
+        stel (_, rest) = deel(9, 4);
+    "#,
+    &[],
+)]
+fn destructuring(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+
+            gedeeld werkwijze maak() -> Persoon {
+                bekeer dit;
+            }
+        }
+    "#,
+    &["this-inside-static-method"],
+)]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+
+            gedeeld werkwijze maak(naam: Slinger) -> Persoon {
+                bekeer nieuw Persoon { naam: naam };
+            }
+        }
+
+        stel persoon = Persoon.maak("Jan");
+        stel ander = persoon.maak("Piet");
+    "#,
+    &["static-method-called-on-value"],
+)]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+
+            werkwijze groet() -> Slinger {
+                bekeer dit.naam;
+            }
+        }
+
+        stel groet = Persoon.groet();
+    "#,
+    &["method-requires-value"],
+)]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+
+            gedeeld werkwijze maak(naam: Slinger) -> Persoon {
+                bekeer nieuw Persoon { naam: naam };
+            }
+
+            werkwijze groet() -> Slinger {
+                bekeer dit.naam;
+            }
+        }
+
+        stel groet = Persoon.maak("Jan").groet();
+    "#,
+    &[],
+)]
+fn static_methods(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        stel a = [1, "twee"];
+    "#,
+    &["array-element-type-mismatch"],
+)]
+#[case(
+    r#"
+        stel a = [];
+    "#,
+    &["empty-array-without-type"],
+)]
+#[case(
+    r#"
+        stel a: Slinger = 5;
+    "#,
+    &["incompatible-variable-type"],
+)]
+#[case(
+    r#"
+        stel a: g32 = 5;
+        stel xs: g8[] = [1, a];
+    "#,
+    &["incompatible-variable-type"],
+)]
+#[case(
+    r#"
+        stel xs: g8[] = [1, 300];
+        stel paar: (n8, Slinger) = (-1, "a");
+    "#,
+    &["integer-literal-out-of-range", "integer-literal-out-of-range"],
+)]
+#[case(
+    r#"
+        stel xs: g8[] = [1, 2];
+        stel rooster: n16[][] = [[1], [65535]];
+        stel paar: (g8, Slinger) = (-128, "a");
+        stel leeg: g32[] = [];
+    "#,
+    &[],
+)]
+fn annotated_variables(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        structuur Geld {
+            veld centen: g32,
+        }
+
+        stel totaal = nieuw Geld { centen: 1 } + nieuw Geld { centen: 2 };
+    "#,
+    &["operator-not-implemented"],
+)]
+#[case(
+    r#"
+        structuur Geld {
+            veld centen: g32,
+        }
+
+        stel duurder = nieuw Geld { centen: 1 } < nieuw Geld { centen: 2 };
+    "#,
+    &["operator-not-implemented"],
+)]
+#[case(
+    r#"
+        werkwijze som<T: Optelbaar>(a: T, b: T) -> T {
+            bekeer a + b;
+        }
+
+        stel x = som(waar, onwaar);
+    "#,
+    &["generic-bound-not-satisfied"],
+)]
+#[case(
+    r#"
+        werkwijze grootste<T: Vergelijkbaar>(a: T, b: T) -> T {
+            als a > b {
+                bekeer a;
+            }
+            bekeer b;
+        }
+
+        werkwijze som<T: Optelbaar>(a: T, b: T) -> T {
+            bekeer a + b;
+        }
+
+        werkwijze gelijk<T: Gelijkwaardig>(a: T, b: T) -> bool {
+            bekeer a == b;
+        }
+
+        stel getal = grootste(3, 9);
+        stel letter = grootste('a', 'b');
+        stel tekst = som("a", "b");
+        stel breuk = som(1.5, 2.0);
+        stel zelfde = gelijk(waar, waar);
+    "#,
+    &[],
+)]
+fn operator_interfaces(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        volg i in reeks(0, 10, stap: 0) {
+            schrijf(€"{i}");
+        }
+    "#,
+    &["range-step-is-zero"],
+)]
+#[case(
+    r#"
+        vast STAP: g32 = 2 - 2;
+
+        volg i in reeks(10, 0, stap: STAP) {
+            schrijf(€"{i}");
+        }
+    "#,
+    &["range-step-is-zero"],
+)]
+#[case(
+    r#"
+        werkwijze _tel(stap: g32) {
+            volg i in reeks(10, 0, stap: stap, totEnMet: waar) {
+                schrijf(€"{i}");
+            }
+        }
+
+        stel even = reeks(0, 10, stap: 2);
+    "#,
+    &[],
+)]
+fn ranges(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        @uitheems(naam: "write")
+        werkwijze schrijfNaarBestand(bestandsnummer: g32, buffer: Slinger, lengte: g64) -> g32;
+
+        @uitheems(naam: "free")
+        werkwijze geefVrij(wijzer: g8*);
+    "#,
+    &[],
+)]
+#[case(
+    r#"
+        @uitheems(naam: "sqrt")
+        werkwijze wortel(getal: kommagetal) -> g32;
+    "#,
+    &["attribute-extern-unsupported-parameter-type"],
+)]
+#[case(
+    r#"
+        @uitheems(naam: "puts")
+        werkwijze zeg(tekst: Misschien<Slinger>, getallen: g32[], paar: (g32, g32));
+    "#,
+    &[
+        "attribute-extern-unsupported-parameter-type",
+        "attribute-extern-unsupported-parameter-type",
+        "attribute-extern-unsupported-parameter-type",
+    ],
+)]
+fn extern_functions(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
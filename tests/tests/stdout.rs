@@ -45,6 +45,900 @@ use tests::interpret_and_return_stdout;
         "45"
     ],
 )]
+#[case(
+    r#"
+        werkwijze beschrijf(a: g32) {
+            als a == 0 {
+                schrijf("nul");
+            } anders als a == 1 {
+                schrijf("een");
+            } anders {
+                schrijf("veel");
+            }
+        }
+
+        volg i in reeks(0, 3) {
+            beschrijf(i);
+        }
+    "#,
+    &[
+        "nul",
+        "een",
+        "veel",
+    ],
+)]
+#[case(
+    r#"
+        stel bezig = waar;
+        stel i = 0;
+
+        zolang bezig {
+            schrijf(€"{i}");
+            i = i + 1;
+
+            als i == 3 {
+                bezig = onwaar;
+            }
+        }
+    "#,
+    &[
+        "0",
+        "1",
+        "2",
+    ],
+)]
+#[case(
+    r#"
+        volg i in reeks(0, 10) {
+            als i == 1 {
+                ga door;
+            }
+
+            als i == 4 {
+                stop;
+            }
+
+            schrijf(€"{i}");
+        }
+
+        zolang waar {
+            schrijf("klaar");
+            stop;
+        }
+    "#,
+    &[
+        "0",
+        "2",
+        "3",
+        "klaar",
+    ],
+)]
+#[case(
+    r#"
+        opsomming Kleur {
+            Rood,
+            Groen,
+            Aangepast(waarde: g32),
+        }
+
+        stel kleur = Kleur.Aangepast(5);
+        schrijf(€"{kleur}");
+
+        als Kleur.Rood == Kleur.Rood {
+            schrijf("gelijk");
+        }
+    "#,
+    &[
+        "Aangepast(5)",
+        "gelijk",
+    ],
+)]
+#[case(
+    r#"
+        opsomming Vorm {
+            Punt,
+            Cirkel(straal: g32),
+        }
+
+        werkwijze beschrijf(getal: g32) -> Slinger {
+            bekeer kies getal {
+                0 => "nul",
+                1..10 => "klein",
+                _ => "groot",
+            };
+        }
+
+        schrijf(beschrijf(0));
+        schrijf(beschrijf(7));
+        schrijf(beschrijf(10));
+
+        stel vorm = Vorm.Cirkel(3);
+        stel straal = kies vorm {
+            Vorm.Punt => 0,
+            Vorm.Cirkel(straal) => straal,
+        };
+        schrijf(€"{straal}");
+    "#,
+    &[
+        "nul",
+        "klein",
+        "groot",
+        "3",
+    ],
+)]
+#[case(
+    r#"
+        stel a = 3;
+        stel b = 5;
+
+        als a != b {
+            schrijf("ongelijk");
+        }
+
+        als a < b {
+            schrijf("kleiner");
+        }
+
+        als b <= 5 {
+            schrijf("kleiner of gelijk");
+        }
+
+        als a >= 4 {
+            schrijf("fout");
+        }
+
+        als !(a > b) {
+            schrijf("niet groter");
+        }
+    "#,
+    &[
+        "ongelijk",
+        "kleiner",
+        "kleiner of gelijk",
+        "niet groter",
+    ],
+)]
+#[case(
+    r#"
+        stel a = 3,5;
+        stel b = 1.5;
+        schrijf(€"{a + b}");
+        schrijf(€"{a * b}");
+        schrijf(€"{-a * 2,0 - b}");
+
+        als a > b {
+            schrijf("groter");
+        }
+
+        schrijf(€"{7.naarKommagetal()}");
+        schrijf(€"{3,7.naarGetal()}");
+        schrijf(€"{3,7.afronden()}");
+    "#,
+    &[
+        "5,0",
+        "5,25",
+        "-8,5",
+        "groter",
+        "7,0",
+        "3",
+        "4",
+    ],
+)]
+#[case(
+    r#"
+        werkwijze som(a: g32, b: g32) -> g32 {
+            bekeer a + b;
+        }
+
+        schrijf(€"{som(1,2)}");
+
+        volg i in reeks(0,3) {
+            schrijf(€"{i}");
+        }
+
+        stel getallen = [1,2,3];
+        schrijf(€"{getallen.lengte()}");
+
+        stel a = 2,5;
+        schrijf(€"{a}");
+    "#,
+    &[
+        "3",
+        "0",
+        "1",
+        "2",
+        "3",
+        "2,5",
+    ],
+)]
+#[case(
+    r#"
+        structuur Poort {
+            veld nummer: n16,
+        }
+
+        werkwijze verdubbel(x: g64) -> g64 {
+            bekeer x * 2;
+        }
+
+        werkwijze volgende(x: n8) -> n8 {
+            bekeer x + 1;
+        }
+
+        schrijf(€"{verdubbel(2000000000)}");
+
+        stel poort = nieuw Poort {
+            nummer: 8080,
+        };
+        schrijf(€"{poort.nummer}");
+
+        schrijf(€"{volgende(200)}");
+    "#,
+    &[
+        "4000000000",
+        "8080",
+        "201",
+    ],
+)]
+#[case(
+    r#"
+        structuur Knop {
+            veld bijKlik: werkwijze(Slinger),
+        }
+
+        werkwijze pasToe(getallen: g32[], f: werkwijze(g32) -> g32) {
+            volg getal in getallen {
+                schrijf(€"{f(getal)}");
+            }
+        }
+
+        werkwijze maakOpteller(n: g32) -> werkwijze(g32) -> g32 {
+            bekeer werkwijze(x: g32) -> g32 {
+                bekeer x + n;
+            };
+        }
+
+        werkwijze isGroter(a: g32, b: g32) -> bool {
+            bekeer a > b;
+        }
+
+        werkwijze vergelijk(a: g32, b: g32, vergelijker: werkwijze(g32, g32) -> bool) -> bool {
+            bekeer vergelijker(a, b);
+        }
+
+        stel getallen = nieuw g32[2];
+        getallen[0] = 1;
+        getallen[1] = 2;
+
+        stel factor = 10;
+        pasToe(getallen, werkwijze(x: g32) -> g32 {
+            bekeer x * factor;
+        });
+
+        stel plusVijf = maakOpteller(5);
+        schrijf(€"{plusVijf(2)}");
+        schrijf(€"{vergelijk(3, 2, isGroter)}");
+
+        stel knop = nieuw Knop {
+            bijKlik: werkwijze(tekst: Slinger) {
+                schrijf(€"geklikt op {tekst}");
+            },
+        };
+        knop.bijKlik("OK");
+    "#,
+    &[
+        "10",
+        "20",
+        "7",
+        "waar",
+        "geklikt op OK",
+    ],
+)]
+#[case(
+    r#"
+        stel naam = "buiten";
+
+        werkwijze maakTeller() -> werkwijze() -> g32 {
+            stel n = 0;
+            bekeer werkwijze() -> g32 {
+                n += 1;
+                bekeer n;
+            };
+        }
+
+        werkwijze telOp() {
+            stel totaal = 0;
+            stel voegToe = werkwijze(x: g32) {
+                totaal += x;
+            };
+            voegToe(5);
+            voegToe(6);
+            schrijf(€"{totaal}");
+        }
+
+        werkwijze roepAan(f: werkwijze()) {
+            stel naam = "binnen";
+            f();
+        }
+
+        stel teller = maakTeller();
+        schrijf(€"{teller()}");
+        schrijf(€"{teller()}");
+
+        telOp();
+
+        roepAan(werkwijze() {
+            schrijf(naam);
+        });
+    "#,
+    &[
+        "1",
+        "2",
+        "11",
+        "buiten",
+    ],
+)]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+            veld leeftijd: g32,
+        }
+
+        werkwijze zoek(naam: Slinger) -> Misschien<Persoon> {
+            als naam == "Jan" {
+                bekeer Iets(nieuw Persoon { naam: "Jan", leeftijd: 42 });
+            }
+
+            bekeer Niets;
+        }
+
+        werkwijze beschrijf(persoon: Misschien<Persoon>) -> Slinger {
+            bekeer kies persoon {
+                Iets(Persoon { naam, leeftijd: 0 }) => €"{naam} is net geboren",
+                Iets(gevonden) => €"{gevonden.naam} is {gevonden.leeftijd} jaar",
+                Niets => "niet gevonden",
+            };
+        }
+
+        stel getal = Iets(5);
+        schrijf(beschrijf(zoek("Jan")));
+        schrijf(beschrijf(zoek("Piet")));
+        schrijf(beschrijf(Iets(nieuw Persoon { naam: "Bas", leeftijd: 0 })));
+        schrijf(€"{getal} {getal == Iets(5)} {getal == Niets}");
+    "#,
+    &[
+        "Jan is 42 jaar",
+        "niet gevonden",
+        "Bas is net geboren",
+        "Iets(5) waar onwaar",
+    ],
+)]
+#[case(
+    r#"
+        werkwijze vanVoorraad(voorraad: g32, aantal: g32) -> Resultaat<g32, Slinger> {
+            als aantal > voorraad {
+                bekeer Fout(€"slechts {voorraad} op voorraad");
+            }
+
+            bekeer Goed(voorraad - aantal);
+        }
+
+        werkwijze bestel(voorraad: g32, eerste: g32, tweede: g32) -> Resultaat<g32, Slinger> {
+            stel rest = vanVoorraad(voorraad, eerste)?;
+            schrijf(€"na eerste bestelling: {rest}");
+            bekeer vanVoorraad(rest, tweede);
+        }
+
+        werkwijze beschrijf(resultaat: Resultaat<g32, Slinger>) -> Slinger {
+            bekeer kies resultaat {
+                Goed(rest) => €"rest: {rest}",
+                Fout(fout) => €"mislukt: {fout}",
+            };
+        }
+
+        schrijf(beschrijf(bestel(10, 3, 4)));
+        schrijf(beschrijf(bestel(10, 12, 4)));
+        schrijf(beschrijf(bestel(10, 8, 4)));
+        schrijf(€"{Goed(1)} {Fout(2)}");
+    "#,
+    &[
+        "na eerste bestelling: 7",
+        "rest: 3",
+        "mislukt: slechts 10 op voorraad",
+        "na eerste bestelling: 2",
+        "mislukt: slechts 2 op voorraad",
+        "Goed(1) Fout(2)",
+    ],
+)]
+#[case(
+    r#"
+        structuur Teller {
+            veld waarde: g32,
+        }
+
+        werkwijze index() -> g32 {
+            schrijf("index");
+            bekeer 1;
+        }
+
+        stel som = 0;
+        volg i in reeks(1, 5) {
+            som += i;
+        }
+        som *= 3;
+        som -= 2;
+        som /= 4;
+        schrijf(€"{som}");
+        som %= 4;
+        schrijf(€"{som}");
+
+        stel teller = nieuw Teller { waarde: 10 };
+        teller.waarde += 5;
+        schrijf(€"{teller.waarde}");
+
+        stel lijst = nieuw g32[3];
+        lijst[index()] += 7;
+        schrijf(€"{lijst[1]}");
+
+        stel tekst = "Hallo";
+        tekst += ", wereld";
+        schrijf(tekst);
+    "#,
+    &[
+        "7",
+        "3",
+        "15",
+        "index",
+        "7",
+        "Hallo, wereld",
+    ],
+)]
+#[case(
+    r#"
+        vast MAX_GROOTTE: g32 = 16 * 1024;
+        vast GROET: Slinger = "Hallo, " + "wereld";
+
+        structuur Buffer {
+            vast STANDAARD_GROOTTE: g32 = MAX_GROOTTE / 4,
+            vast HELFT: g32 = STANDAARD_GROOTTE / 2,
+
+            veld grootte: g32,
+
+            werkwijze isStandaard() -> bool {
+                bekeer dit.grootte == STANDAARD_GROOTTE;
+            }
+        }
+
+        werkwijze dubbel() -> g32 {
+            vast FACTOR: g32 = 2;
+            bekeer Buffer.HELFT * FACTOR;
+        }
+
+        schrijf(€"{MAX_GROOTTE}");
+        schrijf(GROET);
+        schrijf(€"{Buffer.STANDAARD_GROOTTE}");
+        schrijf(€"{dubbel()}");
+
+        stel buffer = nieuw Buffer { grootte: 4096 };
+        als buffer.isStandaard() {
+            schrijf("standaard");
+        }
+    "#,
+    &[
+        "16384",
+        "Hallo, wereld",
+        "4096",
+        "4096",
+        "standaard",
+    ],
+)]
+#[case(
+    r#"
+        vast TOTAAL: g32 = BLOK * AANTAL;
+        vast BLOK: g32 = AANTAL * 2;
+        vast AANTAL: g32 = 4;
+
+        structuur Rooster {
+            vast CELLEN: g32 = BREEDTE * BREEDTE,
+            vast BREEDTE: g32 = AANTAL + 1,
+        }
+
+        schrijf(€"{TOTAAL}");
+        schrijf(€"{Rooster.CELLEN}");
+    "#,
+    &[
+        "32",
+        "25",
+    ],
+)]
+#[case(
+    r#"
+        structuur Paar {
+            veld links: g32,
+
+            werkwijze combineer<U>(waarde: U) -> U {
+                bekeer waarde;
+            }
+        }
+
+        werkwijze keuze<T>(voorwaarde: bool, a: T, b: T) -> T {
+            als voorwaarde {
+                bekeer a;
+            }
+            bekeer b;
+        }
+
+        werkwijze eerste<T>(lijst: T[]) -> T {
+            bekeer lijst[0];
+        }
+
+        werkwijze vul<T>(waarde: T, aantal: g32) -> T[] {
+            stel lijst = nieuw T[aantal];
+            volg i in reeks(0, aantal) {
+                lijst[i] = waarde;
+            }
+            bekeer lijst;
+        }
+
+        schrijf(€"{keuze(onwaar, 1, 2)}");
+        schrijf(keuze(waar, "ja", "nee"));
+        schrijf(eerste(vul("x", 3)));
+        stel paar = nieuw Paar { links: 1 };
+        schrijf(paar.combineer("hoi"));
+    "#,
+    &[
+        "2",
+        "ja",
+        "x",
+        "hoi",
+    ],
+)]
+#[case(
+    r#"
+        werkwijze leegAls<T>(lijst: T[]) -> T[] {
+            bekeer nieuw T[1];
+        }
+
+        stel paren = [(1, waar), (2, onwaar)];
+        schrijf(€"{leegAls(paren)[0]}");
+    "#,
+    &[
+        "(0, onwaar)",
+    ],
+)]
+#[case(
+    r#"
+        werkwijze id<T>(x: T) -> T {
+            bekeer x;
+        }
+
+        schrijf(€"{id(Iets(1))}");
+        schrijf(€"{id(Niets)}");
+        stel verdubbel = id(werkwijze(x: g32) -> g32 {
+            bekeer x * 2;
+        });
+        schrijf(€"{verdubbel(4)}");
+    "#,
+    &[
+        "Iets(1)",
+        "Niets",
+        "8",
+    ],
+)]
+#[case(
+    r#"
+        koppelvlak Gewogen {
+            werkwijze gewicht() -> g32;
+        }
+
+        structuur Appel {
+            veld massa: g32,
+        }
+
+        uitbreiding Gewogen op Appel {
+            werkwijze gewicht() -> g32 {
+                bekeer dit.massa;
+            }
+        }
+
+        structuur Mand<T: Gewogen> {
+            veld eerste: T,
+            veld tweede: T,
+
+            werkwijze totaal() -> g32 {
+                bekeer dit.eerste.gewicht() + dit.tweede.gewicht();
+            }
+        }
+
+        structuur Doos<T> {
+            veld inhoud: T,
+        }
+
+        uitbreiding<T: Gewogen> Gewogen op Doos<T> {
+            werkwijze gewicht() -> g32 {
+                bekeer dit.inhoud.gewicht() + 1;
+            }
+        }
+
+        werkwijze zwaarste<T: Gewogen>(a: T, b: T) -> T {
+            als a.gewicht() > b.gewicht() {
+                bekeer a;
+            }
+            bekeer b;
+        }
+
+        stel a = nieuw Appel { massa: 3 };
+        stel b = nieuw Appel { massa: 5 };
+        schrijf(€"{zwaarste(a, b).gewicht()}");
+
+        stel mand = nieuw Mand<Appel> { eerste: a, tweede: b };
+        schrijf(€"{mand.totaal()}");
+
+        stel doos = nieuw Doos<Appel> { inhoud: a };
+        schrijf(€"{zwaarste(doos, doos).gewicht()}");
+    "#,
+    &[
+        "5",
+        "8",
+        "4",
+    ],
+)]
+#[case(
+    r#"
+        werkwijze deel(a: g32, b: g32) -> (g32, g32) {
+            bekeer (a / b, a - (a / b) * b);
+        }
+
+        werkwijze wissel<A, B>(paar: (A, B)) -> (B, A) {
+            stel (a, b) = paar;
+            bekeer (b, a);
+        }
+
+        stel (q, r) = deel(17, 5);
+        schrijf(€"{q} {r}");
+
+        stel (_, rest) = deel(9, 4);
+        schrijf(€"{rest}");
+
+        stel paar = wissel((1, "een"));
+        schrijf(€"{paar}");
+    "#,
+    &[
+        "3 2",
+        "1",
+        "(een, 1)",
+    ],
+)]
+#[case(
+    r#"
+        stel getallen = [1, 2, 3];
+        stel rooster = [[1, 2], [], [3]];
+        stel leeg: Slinger[] = [];
+        schrijf(€"{getallen[1]} {getallen.lengte()}");
+        schrijf(€"{rooster[0][1]} {rooster[1].lengte()} {rooster.lengte()}");
+        schrijf(€"{leeg.lengte()}");
+    "#,
+    &[
+        "2 3",
+        "2 0 3",
+        "0",
+    ],
+)]
+#[case(
+    r#"
+        structuur Persoon {
+            vast STANDAARD_LEEFTIJD: g32 = 18,
+            veld naam: Slinger,
+            veld leeftijd: g32,
+
+            gedeeld werkwijze maak(naam: Slinger) -> Persoon {
+                bekeer nieuw Persoon { naam: naam, leeftijd: STANDAARD_LEEFTIJD };
+            }
+
+            werkwijze groet() {
+                schrijf(€"Hallo, {dit.naam} ({dit.leeftijd})");
+            }
+        }
+
+        Persoon.maak("Jan").groet();
+    "#,
+    &[
+        "Hallo, Jan (18)",
+    ],
+)]
+#[case(
+    r#"
+        structuur Teller {
+            intern veld stand: g32,
+
+            publiek werkwijze verhoog() {
+                dit.stand += Teller.stap();
+            }
+
+            intern gedeeld werkwijze stap() -> g32 {
+                bekeer 2;
+            }
+
+            werkwijze toon() {
+                schrijf(€"stand: {dit.stand}");
+            }
+        }
+
+        intern werkwijze maakTeller() -> Teller {
+            bekeer nieuw Teller { stand: 1 };
+        }
+
+        stel teller = maakTeller();
+        teller.verhoog();
+        teller.toon();
+        schrijf(€"{teller.stand}");
+    "#,
+    &[
+        "stand: 3",
+        "3",
+    ],
+)]
+#[case(
+    r#"
+        structuur Breuk {
+            veld teller: g32,
+            veld noemer: g32,
+        }
+
+        uitbreiding Optelbaar op Breuk {
+            werkwijze optellen(ander: Breuk) -> Breuk {
+                bekeer nieuw Breuk {
+                    teller: dit.teller * ander.noemer + ander.teller * dit.noemer,
+                    noemer: dit.noemer * ander.noemer,
+                };
+            }
+        }
+
+        uitbreiding Vergelijkbaar op Breuk {
+            werkwijze vergelijk(ander: Breuk) -> g32 {
+                bekeer dit.teller * ander.noemer - ander.teller * dit.noemer;
+            }
+        }
+
+        uitbreiding Gelijkwaardig<Breuk> op Breuk {
+            werkwijze isGelijkAan(ander: Breuk) -> bool {
+                bekeer dit.teller * ander.noemer == ander.teller * dit.noemer;
+            }
+        }
+
+        stel half = nieuw Breuk { teller: 1, noemer: 2 };
+        stel derde = nieuw Breuk { teller: 1, noemer: 3 };
+        stel som = half + derde;
+        schrijf(€"{som.teller}/{som.noemer}");
+
+        als derde < half {
+            schrijf("kleiner");
+        }
+
+        als half >= derde {
+            schrijf("groter of gelijk");
+        }
+
+        als half == nieuw Breuk { teller: 2, noemer: 4 } {
+            schrijf("gelijk");
+        }
+
+        als half != derde {
+            schrijf("ongelijk");
+        }
+    "#,
+    &[
+        "5/6",
+        "kleiner",
+        "groter of gelijk",
+        "gelijk",
+        "ongelijk",
+    ],
+)]
+#[case(
+    r#"
+        werkwijze grootste<T: Vergelijkbaar>(a: T, b: T) -> T {
+            als a > b {
+                bekeer a;
+            }
+            bekeer b;
+        }
+
+        werkwijze som<T: Optelbaar>(a: T, b: T) -> T {
+            bekeer a + b;
+        }
+
+        schrijf(€"{grootste(3, 9)}");
+        schrijf(€"{grootste('z', 'b')}");
+        schrijf(som("ab", "cd"));
+    "#,
+    &[
+        "9",
+        "z",
+        "abcd",
+    ],
+)]
+#[case(
+    r#"
+        structuur Persoon {
+            veld naam: Slinger,
+            veld leeftijd: g32,
+        }
+
+        structuur Geld {
+            veld centen: g32,
+        }
+
+        uitbreiding Weergeefbaar op Geld {
+            werkwijze weergeven() -> Slinger {
+                bekeer €"{dit.centen} cent";
+            }
+        }
+
+        uitbreiding Gelijkwaardig op Geld {
+            werkwijze isGelijkAan(ander: Geld) -> bool {
+                bekeer dit.centen / 100 == ander.centen / 100;
+            }
+        }
+
+        stel jan = nieuw Persoon { naam: "Jan", leeftijd: 35 };
+        stel kopie = nieuw Persoon { naam: "Jan", leeftijd: 35 };
+        stel piet = nieuw Persoon { naam: "Piet", leeftijd: 35 };
+
+        schrijf(jan);
+        schrijf(€"{[jan, piet]}");
+        schrijf(nieuw Geld { centen: 250 });
+        schrijf(€"{jan == kopie} {jan == piet} {jan != piet}");
+        schrijf(€"{[1, 2] == [1, 2]} {[jan].bevat(kopie)} {[jan].bevat(piet)}");
+        stel portemonnee = [nieuw Geld { centen: 110 }];
+        stel bedrag = nieuw Geld { centen: 150 };
+        schrijf(€"{portemonnee.bevat(bedrag)}");
+    "#,
+    &[
+        r#"Persoon { naam: "Jan", leeftijd: 35 }"#,
+        r#"[Persoon { naam: "Jan", leeftijd: 35 }, Persoon { naam: "Piet", leeftijd: 35 }]"#,
+        "250 cent",
+        "waar onwaar waar",
+        "waar waar onwaar",
+        "waar",
+    ],
+)]
+#[case(
+    r#"
+        werkwijze som(getallen: Reeks) -> g32 {
+            stel totaal = 0;
+            volg i in getallen {
+                totaal += i;
+            }
+            bekeer totaal;
+        }
+
+        volg i in reeks(6, 0, stap: -2) {
+            schrijf(€"{i}");
+        }
+
+        volg i in reeks(1, 3, totEnMet: waar) {
+            schrijf(€"{i}");
+        }
+
+        stel even = reeks(0, 10, stap: 2);
+        schrijf(€"{even}");
+        schrijf(€"{even.bevat(4)} {even.bevat(5)} {even.bevat(10)}");
+        schrijf(€"{som(reeks(1, 4, totEnMet: waar))}");
+    "#,
+    &[
+        "6",
+        "4",
+        "2",
+        "1",
+        "2",
+        "3",
+        "reeks(0, 10, stap: 2)",
+        "waar onwaar onwaar",
+        "10",
+    ],
+)]
 fn interpret_and_return_stdout_tests(#[case] input: &str, #[case] expected: &[&str]) {
     let expected: Vec<String> = expected.into_iter().map(|x| x.to_string()).collect();
     assert_eq!(interpret_and_return_stdout(input), expected);
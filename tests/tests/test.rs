@@ -0,0 +1,171 @@
+// Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use rstest::rstest;
+use tests::{analyze_files_and_return_errors, run_tests_and_return_failures};
+
+#[rstest]
+#[case(
+    r#"
+        @test
+        werkwijze optellen() {
+            verwacht(1 + 1 == 2);
+            verwachtGelijk(1 + 1, 2);
+        }
+    "#,
+    &[("optellen", &[][..])],
+)]
+#[case(
+    r#"
+        werkwijze som(a: g32, b: g32) -> g32 {
+            bekeer a + b;
+        }
+
+        @test
+        werkwijze goed() {
+            verwachtGelijk(som(1, 2), 3);
+        }
+
+        @test
+        werkwijze fout() {
+            verwachtGelijk(som(1, 2), 4);
+            verwacht(som(1, 2) == 4);
+        }
+    "#,
+    &[
+        ("goed", &[][..]),
+        ("fout", &[
+            "Verwacht dat `3` gelijk is aan `4`",
+            "Verwacht dat de voorwaarde `waar` is, maar deze was `onwaar`",
+        ][..]),
+    ],
+)]
+#[case(
+    r#"
+        structuur Punt {
+            veld x: g32,
+            veld y: g32,
+        }
+
+        @test
+        werkwijze punten() {
+            verwachtGelijk(nieuw Punt { x: 1, y: 2 }, nieuw Punt { x: 1, y: 2 });
+            verwachtGelijk([1, 2], [1, 3]);
+        }
+    "#,
+    &[("punten", &["Verwacht dat `[1, 2]` gelijk is aan `[1, 3]`"][..])],
+)]
+#[case(
+    r#"
+        werkwijze deel(a: g32, b: g32) -> g32 {
+            bekeer a / b;
+        }
+
+        @test
+        werkwijze delen() {
+            verwacht(onwaar);
+            stel nul = 0;
+            verwachtGelijk(deel(1, nul), 0);
+            verwacht(onwaar);
+        }
+
+        @test
+        werkwijze daarna() {
+            verwachtGelijk(deel(6, 3), 2);
+        }
+    "#,
+    &[
+        ("delen", &[
+            "Verwacht dat de voorwaarde `waar` is, maar deze was `onwaar`",
+            "Deling door nul",
+        ][..]),
+        ("daarna", &[][..]),
+    ],
+)]
+#[case(
+    r#"
+        werkwijze vul<T>(waarde: T, aantal: g32) -> T[] {
+            stel lijst = nieuw T[aantal];
+            volg i in reeks(0, aantal) {
+                lijst[i] = waarde;
+            }
+            bekeer lijst;
+        }
+
+        @test
+        werkwijze generiek() {
+            stel lijst = vul(werkwijze(x: g32) -> bool {
+                bekeer x > 0;
+            }, 2);
+            verwachtGelijk(lijst.lengte(), 2);
+        }
+
+        structuur Doos<T> {
+            veld waarde: T,
+
+            werkwijze vul(aantal: g32) -> T[] {
+                bekeer nieuw T[aantal];
+            }
+        }
+
+        @test
+        werkwijze werkwijzetype() {
+            stel doos = nieuw Doos<werkwijze(g32) -> bool> {
+                waarde: werkwijze(x: g32) -> bool {
+                    bekeer x > 0;
+                },
+            };
+            verwachtGelijk(doos.vul(2).lengte(), 2);
+        }
+    "#,
+    &[
+        ("generiek", &["Het type van de elementen heeft geen standaardwaarde om de opeenvolging mee te vullen"][..]),
+        ("werkwijzetype", &["Het type van de elementen heeft geen standaardwaarde om de opeenvolging mee te vullen"][..]),
+    ],
+)]
+fn failed_expectations(#[case] code: &str, #[case] expected: &[(&str, &[&str])]) {
+    let actual = run_tests_and_return_failures(code);
+    let expected: Vec<(String, Vec<String>)> = expected.iter()
+        .map(|(name, failures)| (name.to_string(), failures.iter().map(|x| x.to_string()).collect()))
+        .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case(
+    r#"
+        @test
+        structuur A {}
+    "#,
+    &["attribute-test-only-on-functions"],
+)]
+#[case(
+    r#"
+        @test
+        werkwijze zonderLichaam();
+    "#,
+    &["attribute-test-only-on-functions-with-body"],
+)]
+#[case(
+    r#"
+        @test
+        werkwijze metParameter(a: g32) {
+            verwachtGelijk(a, 1);
+        }
+    "#,
+    &["attribute-test-function-has-parameters"],
+)]
+#[case(
+    r#"
+        @test
+        werkwijze verschillendeTypes() {
+            verwachtGelijk(1, "1");
+        }
+    "#,
+    &["incompatible-argument-parameter-type"],
+)]
+fn test_attribute_diagnostics(#[case] code: &str, #[case] expected: &[&str]) {
+    let actual = analyze_files_and_return_errors(&[("test.bab", code)]);
+    assert_eq!(actual, expected);
+}
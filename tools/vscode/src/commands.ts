@@ -1,7 +1,7 @@
 // Copyright (C) 2024 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-import { commands, tasks, workspace } from "vscode";
+import { commands, tasks, Uri, workspace } from "vscode";
 import { BabbelaarContext } from "./babbelaarContext";
 import { BabbelaarLsp } from "./babbelaarLsp";
 import { taskProvider } from "./tasks";
@@ -20,6 +20,13 @@ const BabbelaarCommands = {
                 tasks.executeTask(taskProvider.createRun(path));
             })
         );
+
+        context.ext.subscriptions.push(
+            commands.registerCommand("babbelaar.testUitvoeren", async (uri: string, name: string) => {
+                const path = Uri.parse(uri).fsPath;
+                tasks.executeTask(taskProvider.createTest(path, name));
+            })
+        );
     }
 };
 
@@ -24,6 +24,20 @@ class BabbelaarTaskProvider implements TaskProvider<Task> {
 		return this.resolveTaskImpl(task);
 	}
 
+	createTest(path: string, name: string): Task {
+		const task = new Task(
+			{
+				type: "babbelaar",
+				path,
+				test: name,
+			},
+			TaskScope.Workspace,
+			`${path} (test ${name})`,
+			"babbelaar",
+		);
+		return this.resolveTaskImpl(task);
+	}
+
 	resolveTask(task: Task, _token: CancellationToken): ProviderResult<Task> {
 		return this.resolveTaskImpl(task);
 	}
@@ -32,7 +46,9 @@ class BabbelaarTaskProvider implements TaskProvider<Task> {
 		const command = process.env.BABBELAAR || "babbelaar";
 
 		const path = task.definition["path"] as string;
-		const execution = new ShellExecution(`clear; \"${command.replaceAll(/(?<!\\)"/g, "\"")}\" uitvoeren \"${path.replaceAll(/(?<!\\)"/g, "\"")}\"`, {
+		const test = task.definition["test"] as string | undefined;
+		const subcommand = test === undefined ? "uitvoeren" : `test --naam ${test}`;
+		const execution = new ShellExecution(`clear; \"${command.replaceAll(/(?<!\\)"/g, "\"")}\" ${subcommand} \"${path.replaceAll(/(?<!\\)"/g, "\"")}\"`, {
 			env: {
 				"RUST_BACKTRACE": "1"
 			}